@@ -1,3 +1,4 @@
+use micro_traffic_sim_core::agents_types::AgentType;
 use micro_traffic_sim_core::grid::road_network::GridRoads;
 use micro_traffic_sim_core::shortest_path::router::shortest_path;
 use micro_traffic_sim_core::utils::generators::generate_one_lane_cells;
@@ -25,6 +26,7 @@ pub fn benchmark_shortest_path(c: &mut Criterion) {
                 black_box(&grid),
                 black_box(true),
                 black_box(None),
+                black_box(AgentType::Car),
             ) {
                 Ok(path) => {
                     // Do something with the path, if needed
@@ -1,10 +1,11 @@
-use crate::behaviour::BehaviourType;
+use crate::behaviour::{BehaviourType, DriverModel, DriverModelRef, MobilParameters, NaSchModel};
 use crate::agents_types::AgentType;
 use crate::agents::{VehicleIntention, TailIntentionManeuver};
 use crate::grid::cell::CellID;
 use crate::maneuver::LaneChangeType;
 use crate::grid::road_network::GridRoads;
 use crate::trips::trip::TripID;
+use crate::route_choice::RouteID;
 use std::fmt;
 use std::rc::Rc;
 use std::cell::RefCell;
@@ -54,6 +55,10 @@ pub type VehicleRef = Rc<RefCell<Vehicle>>;
 /// Vehicle unique identifier type
 pub type VehicleID = u64;
 
+/// Minimal deceleration (in cells per time unit squared) used to plan stopping (see [`Vehicle::braking_distance`]).
+/// Smaller positive decelerations would make vehicles look through the whole network
+pub const MIN_DECELERATION: f64 = 0.25;
+
 /// Represents basic agent in simulation
 #[derive(Debug)]
 pub struct Vehicle {
@@ -81,13 +86,27 @@ pub struct Vehicle {
 
     /// Current speed
     pub speed: i32,
-    /// Maximum speed which can be reached by the vehicle. If value is greater than maximum speed
-    /// in cell then the vehicle will be limited to cell's speed limit
+    /// Maximum speed which can be reached by the vehicle (physical capability). Otherwise the vehicle is limited
+    /// to cell's speed limit adjusted by `speed_compliance` and `speed_limit_offset` (see [`Vehicle::effective_speed_limit`])
     pub speed_limit: i32,
+    /// Desired speed as a factor of the cell's speed limit: values above one mean speeding,
+    /// values below one mean driving slower than allowed. Default is 1.0 (see [`Vehicle::effective_speed_limit`])
+    pub speed_compliance: f64,
+    /// Offset (in cells per time unit) added to the cell's speed limit after `speed_compliance` is applied:
+    /// e.g. `1` for a driver who exceeds every posted limit by one cell per time unit. Default is 0
+    pub speed_limit_offset: i32,
     /// Current bearing (direction angle)
     pub bearing: f64,
     /// Minimal safe distance (in cells) to the vehicle in front
     pub min_safe_distance: i32,
+    /// Maximum acceleration (in cells per time unit squared). Values below one mean that
+    /// the vehicle gains one cell per time unit not on every time unit. See the ref. at `AgentType::max_acceleration_default`
+    pub max_acceleration: f64,
+    /// Maximum comfortable deceleration (in cells per time unit squared) used to plan stopping
+    /// before obstacles ahead. Infinite value disables planning (the builder replaces non-positive values with it),
+    /// positive values below [`MIN_DECELERATION`] act as the minimal one.
+    /// See the ref. at `AgentType::max_deceleration_default`
+    pub max_deceleration: f64,
     /// Final cell for the vehicle's trip
     pub destination: CellID,
 
@@ -99,11 +118,16 @@ pub struct Vehicle {
     transits_made: u64,
     /// Cells which must be traversed in exact given order by the vehicle
     pub transit_cells: Vec<CellID>,
+    /// Identifier of the route assigned by route choice (see `route_choice` module). `None` if vehicle follows shortest path
+    pub route_id: Option<RouteID>,
+    /// Cells of the assigned route from origin to destination. Empty if vehicle follows shortest path
+    pub route: Vec<CellID>,
     /// A value in (0; 1] representing the probability of the vehicle randomly slowing down.
     pub slow_down_factor: f64,
     /// A value in (0; 1] representing cooperative behaviour of the vehicle.
     /// 0 - when behaviour considered to be "aggressive"
     /// 1 - fully cooperative
+    /// It is the probability to hold back letting a blocked neighbour merge (see `find_merging_neighbour`).
     pub cooperativity: f64,
     /// Delay time (in time units) between two possible accelerations vehicle can do.
     /// It could be used to prohibit to the vehicle to do sequential acceleration during simulation.
@@ -118,6 +142,10 @@ pub struct Vehicle {
     pub relax_time: i32,
     // Timer for `relax_time` field
     relax_countdown: i32,
+    // Transit cell of the stop the vehicle is dwelling at (until it leaves the cell)
+    dwell_stop: Option<CellID>,
+    // Whether the vehicle is dwelling in a bus bay, i.e. out of the lane
+    in_bay: bool,
 
     /// Travel time (in time units) which vehicle has been in movement state.
     pub travel_time: i64,
@@ -127,6 +155,30 @@ pub struct Vehicle {
 
     /// Vehicle's intention to perform maneuver and other actions
     pub intention: VehicleIntention,
+
+    /// Custom driver behaviour model. `None` means the default one (see `NaSchModel`)
+    pub driver_model: Option<DriverModelRef>,
+
+    /// Parameters of discretionary (incentive-based) lane changing. `None` means the vehicle
+    /// changes lanes only when it is blocked. See the ref. at `MobilParameters`
+    pub mobil: Option<MobilParameters>,
+
+    /// Distance (in cells) ahead at which the vehicle begins moving to the lane its path needs
+    /// (e.g. before a turn or an exit). Zero disables pre-selection, so the vehicle changes the lane
+    /// only when the lane change is on its way. See the ref. at `find_lane_selection`
+    pub lane_selection_distance: i32,
+
+    /// Distance (in cells) behind the vehicle at which the driver notices an emergency vehicle
+    /// and moves aside to clear the way. Zero disables it. See the ref. at `find_emergency_clearing`
+    pub emergency_awareness_distance: i32,
+
+    /// Minimal time (in time units) until the arrival of the next major stream vehicle which the driver accepts
+    /// to enter a conflict zone from the minor stream. Zero disables gap acceptance, so conflicts are resolved
+    /// on the step they happen only. See the ref. at `apply_gap_acceptance`
+    pub critical_gap: i32,
+    /// Minimal time (in time units) until the arrival of the next major stream vehicle which the driver accepts
+    /// when following the minor stream vehicle which has just entered the conflict zone. See the ref. at `apply_gap_acceptance`
+    pub follow_up_time: i32,
 }
 
 impl Vehicle {
@@ -159,13 +211,19 @@ impl Vehicle {
                 tail_cells: Vec::new(),
                 speed: 1,
                 speed_limit: 4,
+                speed_compliance: 1.0,
+                speed_limit_offset: 0,
                 bearing: 0.0,
                 min_safe_distance: 0,
+                max_acceleration: AgentType::Car.max_acceleration_default(),
+                max_deceleration: AgentType::Car.max_deceleration_default(),
                 destination: -1,
                 is_conflict_participant: false,
                 trip: -1,
                 transits_made: 0,
                 transit_cells: Vec::new(),
+                route_id: None,
+                route: Vec::new(),
                 slow_down_factor: 0.1,
                 cooperativity: 0.0,
                 timer_non_acceleration: 0,
@@ -173,11 +231,146 @@ impl Vehicle {
                 timer_non_slowdown: 0,
                 relax_time: 0,
                 relax_countdown: 0,
+                dwell_stop: None,
+                in_bay: false,
                 travel_time: 0,
                 confusion: false,
                 intention: VehicleIntention::default(),
+                driver_model: None,
+                mobil: None,
+                lane_selection_distance: 0,
+                emergency_awareness_distance: 5,
+                critical_gap: 0,
+                follow_up_time: 0,
             },
+            max_acceleration: None,
+            max_deceleration: None,
+        }
+    }
+
+    /// Returns driver behaviour model of the vehicle: the custom one or the default `NaSchModel`
+    ///
+    /// # Example
+    /// ```
+    /// use micro_traffic_sim_core::agents::Vehicle;
+    /// let vehicle = Vehicle::new(1).build();
+    /// assert_eq!(format!("{:?}", vehicle.driver_model()), "NaSchModel");
+    /// ```
+    pub fn driver_model(&self) -> &dyn DriverModel {
+        match &self.driver_model {
+            Some(model) => model.as_ref(),
+            None => &NaSchModel,
+        }
+    }
+
+    /// Returns the speed limit the vehicle follows in the cell with given speed limit: the cell's limit
+    /// scaled by the vehicle's speed compliance and shifted by its offset. The result is bounded by the vehicle's
+    /// own maximum speed (`Vehicle::speed_limit`), so compliance never exceeds the physical capabilities.
+    /// Compliance never makes a passable cell (non-zero limit) impassable, so the result is at least one cell
+    /// per time unit there.
+    ///
+    /// # Arguments
+    /// * `cell_speed_limit` - Speed limit of the cell
+    ///
+    /// # Example
+    /// ```
+    /// use micro_traffic_sim_core::agents::Vehicle;
+    /// let vehicle = Vehicle::new(1).with_speed_limit(4).with_speed_compliance(1.4).build();
+    /// assert_eq!(vehicle.effective_speed_limit(2), 3);
+    /// assert_eq!(vehicle.effective_speed_limit(3), 4);
+    /// assert_eq!(vehicle.effective_speed_limit(0), 0);
+    /// let vehicle = Vehicle::new(1).with_speed_compliance(0.1).build();
+    /// assert_eq!(vehicle.effective_speed_limit(2), 1);
+    /// let vehicle = Vehicle::new(1).with_speed_limit(5).with_speed_limit_offset(1).build();
+    /// assert_eq!(vehicle.effective_speed_limit(3), 4);
+    /// assert_eq!(vehicle.effective_speed_limit(5), 5);
+    /// ```
+    pub fn effective_speed_limit(&self, cell_speed_limit: i32) -> i32 {
+        if cell_speed_limit <= 0 || (self.speed_compliance == 1.0 && self.speed_limit_offset == 0) {
+            return cell_speed_limit.min(self.speed_limit);
+        }
+        let scaled = (cell_speed_limit as f64 * self.speed_compliance).round() as i32;
+        let desired = scaled.saturating_add(self.speed_limit_offset).max(1);
+        desired.min(self.speed_limit)
+    }
+
+    /// Returns speed gain (in cells per time unit) for a single acceleration: at least one cell
+    ///
+    /// # Example
+    /// ```
+    /// use micro_traffic_sim_core::agents::Vehicle;
+    /// assert_eq!(Vehicle::new(1).with_max_acceleration(2.5).build().speed_increase(), 2);
+    /// assert_eq!(Vehicle::new(1).with_max_acceleration(0.5).build().speed_increase(), 1);
+    /// ```
+    pub fn speed_increase(&self) -> i32 {
+        self.max_acceleration.floor().max(1.0) as i32
+    }
+
+    /// Returns number of time units the vehicle has to wait after acceleration before the next one.
+    /// It is non-zero for vehicles with maximum acceleration below one cell per time unit squared.
+    ///
+    /// # Example
+    /// ```
+    /// use micro_traffic_sim_core::agents::Vehicle;
+    /// assert_eq!(Vehicle::new(1).with_max_acceleration(1.0).build().acceleration_delay(), 0);
+    /// assert_eq!(Vehicle::new(1).with_max_acceleration(0.5).build().acceleration_delay(), 1);
+    /// assert_eq!(Vehicle::new(1).with_max_acceleration(0.3).build().acceleration_delay(), 3);
+    /// ```
+    pub fn acceleration_delay(&self) -> i64 {
+        if self.max_acceleration >= 1.0 {
+            return 0;
+        }
+        if self.max_acceleration <= 0.0 {
+            return i64::MAX;
+        }
+        (1.0 / self.max_acceleration).ceil() as i64 - 1
+    }
+
+    /// Returns number of cells the vehicle passes after the current time unit (when it moves with given speed)
+    /// until it stops with its maximum comfortable deceleration.
+    /// Non-positive or infinite deceleration means that stopping is not planned, so the distance is zero.
+    /// Decelerations below [`MIN_DECELERATION`] are treated as the minimal one, so the distance stays
+    /// within `speed * speed / (2 * MIN_DECELERATION)` cells.
+    ///
+    /// # Example
+    /// ```
+    /// use micro_traffic_sim_core::agents::Vehicle;
+    /// let vehicle = Vehicle::new(1).with_max_deceleration(1.0).build();
+    /// assert_eq!(vehicle.braking_distance(3), 3); // 2 + 1
+    /// let vehicle = Vehicle::new(1).with_max_deceleration(1.5).build();
+    /// assert_eq!(vehicle.braking_distance(4), 4); // 2.5 + 1, rounded up
+    /// let vehicle = Vehicle::new(1).with_max_deceleration(f64::INFINITY).build();
+    /// assert_eq!(vehicle.braking_distance(3), 0);
+    /// ```
+    pub fn braking_distance(&self, speed: i32) -> i32 {
+        let deceleration = self.max_deceleration;
+        if !deceleration.is_finite() || deceleration <= 0.0 || speed <= 0 {
+            return 0;
         }
+        let deceleration = deceleration.max(MIN_DECELERATION);
+        // Speeds after each time unit form the arithmetic series v - d, v - 2d, ..., v - nd (positive terms only)
+        let speed = speed as f64;
+        let terms = ((speed / deceleration).ceil() - 1.0).max(0.0);
+        let distance = terms * speed - deceleration * terms * (terms + 1.0) / 2.0;
+        distance.max(0.0).ceil() as i32
+    }
+
+    /// Returns the maximum speed (not greater than `speed`) which allows the vehicle to stop within
+    /// `distance` cells without exceeding its maximum comfortable deceleration.
+    ///
+    /// # Example
+    /// ```
+    /// use micro_traffic_sim_core::agents::Vehicle;
+    /// let vehicle = Vehicle::new(1).with_max_deceleration(1.0).build();
+    /// assert_eq!(vehicle.safe_speed(5, 6), 3); // 3 + 2 + 1
+    /// assert_eq!(vehicle.safe_speed(2, 6), 2);
+    /// ```
+    pub fn safe_speed(&self, speed: i32, distance: i32) -> i32 {
+        let mut safe_speed = speed.min(distance).max(0);
+        while safe_speed > 0 && safe_speed.saturating_add(self.braking_distance(safe_speed)) > distance {
+            safe_speed -= 1;
+        }
+        safe_speed
     }
 
     /// Increments number of transit have been made by vehicle
@@ -295,6 +488,58 @@ impl Vehicle {
         self.relax_countdown
     }
 
+    /// Starts dwelling at a stop: the vehicle stays in the current cell for the given number of time units.
+    ///
+    /// # Arguments
+    /// * `stop_cell` - Transit cell of the stop
+    /// * `dwell_time` - Dwell time (in time units)
+    /// * `in_bay` - Whether the vehicle dwells in a bus bay (out of the lane) or blocks the lane
+    ///
+    /// # Example
+    /// ```
+    /// use micro_traffic_sim_core::agents_types::AgentType;
+    /// use micro_traffic_sim_core::agents::Vehicle;
+    /// let mut vehicle = Vehicle::new(1)
+    ///     .with_cell(3)
+    ///     .with_type(AgentType::Bus)
+    ///     .build();
+    /// vehicle.start_dwell(3, 5, true);
+    /// assert!(vehicle.is_dwelling());
+    /// assert!(vehicle.is_in_bay());
+    /// assert_eq!(vehicle.get_relax_countdown(), 5);
+    /// assert_eq!(vehicle.get_dwell_stop(), Some(3));
+    /// ```
+    pub fn start_dwell(&mut self, stop_cell: CellID, dwell_time: i32, in_bay: bool) {
+        self.relax_countdown = dwell_time.max(0);
+        self.dwell_stop = Some(stop_cell);
+        self.in_bay = in_bay;
+    }
+
+    /// Checks whether the vehicle stays at a stop: relax countdown is not over or it has not left the bus bay yet.
+    pub fn is_dwelling(&self) -> bool {
+        self.relax_countdown > 0 || self.in_bay
+    }
+
+    /// Checks whether the vehicle is in a bus bay, i.e. it does not occupy the lane.
+    pub fn is_in_bay(&self) -> bool {
+        self.in_bay
+    }
+
+    /// Returns the vehicle from the bus bay to the lane.
+    pub fn leave_bay(&mut self) {
+        self.in_bay = false;
+    }
+
+    /// Returns transit cell of the stop the vehicle is dwelling at (until it leaves the cell).
+    pub fn get_dwell_stop(&self) -> Option<CellID> {
+        self.dwell_stop
+    }
+
+    /// Forgets the stop the vehicle has been dwelling at (when it leaves the stop cell).
+    pub fn clear_dwell_stop(&mut self) {
+        self.dwell_stop = None;
+    }
+
     /// Sets the vehicle's intention
     ///
     /// # Arguments
@@ -474,6 +719,10 @@ impl Vehicle {
 /// `VehicleBuilder` allows for optional configuration of `Vehicle` fields before building the final `Vehicle` object.
 pub struct VehicleBuilder {
     vehicle: Vehicle,
+    /// Maximum acceleration which overrides the agent type's default one
+    max_acceleration: Option<f64>,
+    /// Maximum comfortable deceleration which overrides the agent type's default one
+    max_deceleration: Option<f64>,
 }
 
 impl VehicleBuilder {
@@ -769,6 +1018,31 @@ impl VehicleBuilder {
         self
     }
 
+    /// Sets the route which the vehicle should follow (e.g. picked by route choice model)
+    ///
+    /// # Arguments
+    /// * `route_id` - Identifier of the route among the trip's alternatives
+    /// * `cells` - Cells of the route from origin to destination
+    ///
+    /// # Returns
+    /// A `VehicleBuilder` instance for further method chaining.
+    ///
+    /// # Example
+    /// ```rust
+    /// use micro_traffic_sim_core::agents::Vehicle;
+    /// let vehicle = Vehicle::new(1)
+    ///     .with_cell(1)
+    ///     .with_destination(4)
+    ///     .with_route(1, vec![1, 5, 6, 4])
+    ///     .build();
+    /// assert_eq!(vehicle.route_id, Some(1));
+    /// ```
+    pub fn with_route(mut self, route_id: RouteID, cells: Vec<CellID>) -> Self {
+        self.vehicle.route_id = Some(route_id);
+        self.vehicle.route = cells;
+        self
+    }
+
     /// Sets the probability of the vehicle slowing down randomly.
     ///
     /// # Arguments
@@ -923,6 +1197,213 @@ impl VehicleBuilder {
         self
     }
 
+    /// Sets custom driver behaviour model for the vehicle.
+    ///
+    /// # Arguments
+    /// * `model` - Shared driver model
+    ///
+    /// # Returns
+    /// A `VehicleBuilder` instance for further method chaining.
+    ///
+    /// # Example
+    /// ```rust
+    /// use micro_traffic_sim_core::agents::Vehicle;
+    /// use micro_traffic_sim_core::behaviour::NaSchModel;
+    /// use std::sync::Arc;
+    /// let vehicle = Vehicle::new(1)
+    ///     .with_driver_model(Arc::new(NaSchModel))
+    ///     .build();
+    /// assert!(vehicle.driver_model.is_some());
+    /// ```
+    pub fn with_driver_model(mut self, model: DriverModelRef) -> Self {
+        self.vehicle.driver_model = Some(model);
+        self
+    }
+
+    /// Enables discretionary lane changing: the vehicle changes lanes to go faster
+    /// even when it is not blocked.
+    ///
+    /// # Arguments
+    /// * `params` - Incentive and safety parameters. See the ref. at `MobilParameters`
+    ///
+    /// # Returns
+    /// A `VehicleBuilder` instance for further method chaining.
+    ///
+    /// # Example
+    /// ```rust
+    /// use micro_traffic_sim_core::agents::Vehicle;
+    /// use micro_traffic_sim_core::behaviour::MobilParameters;
+    /// let vehicle = Vehicle::new(1)
+    ///     .with_mobil(MobilParameters::new().with_politeness(0.5).build())
+    ///     .build();
+    /// assert_eq!(vehicle.mobil.unwrap().politeness, 0.5);
+    /// ```
+    pub fn with_mobil(mut self, params: MobilParameters) -> Self {
+        self.vehicle.mobil = Some(params);
+        self
+    }
+
+    /// Enables route-aware lane pre-selection: the vehicle begins moving to the lane it needs
+    /// (before a turn or an exit) `distance` cells upstream and gives up if it fails to reach it in time.
+    ///
+    /// # Arguments
+    /// * `distance` - Look-ahead distance (in cells). Zero disables pre-selection.
+    ///
+    /// # Returns
+    /// A `VehicleBuilder` instance for further method chaining.
+    ///
+    /// # Example
+    /// ```rust
+    /// use micro_traffic_sim_core::agents::Vehicle;
+    /// let vehicle = Vehicle::new(1)
+    ///     .with_lane_selection_distance(20)
+    ///     .build();
+    /// assert_eq!(vehicle.lane_selection_distance, 20);
+    /// ```
+    pub fn with_lane_selection_distance(mut self, distance: i32) -> Self {
+        self.vehicle.lane_selection_distance = distance;
+        self
+    }
+
+    /// Sets the distance (in cells) behind the vehicle at which the driver notices an emergency vehicle
+    /// and moves aside (see `find_emergency_clearing`). Default is 5 cells.
+    ///
+    /// # Arguments
+    /// * `distance` - Distance (in cells). Zero disables moving aside.
+    ///
+    /// # Returns
+    /// A `VehicleBuilder` instance for further method chaining.
+    ///
+    /// # Example
+    /// ```rust
+    /// use micro_traffic_sim_core::agents::Vehicle;
+    /// let vehicle = Vehicle::new(1)
+    ///     .with_emergency_awareness_distance(10)
+    ///     .build();
+    /// assert_eq!(vehicle.emergency_awareness_distance, 10);
+    /// ```
+    pub fn with_emergency_awareness_distance(mut self, distance: i32) -> Self {
+        self.vehicle.emergency_awareness_distance = distance;
+        self
+    }
+
+    /// Sets gap acceptance parameters of the driver for entering conflict zones from the minor stream
+    /// (see `apply_gap_acceptance`). Disabled by default.
+    ///
+    /// # Arguments
+    /// * `critical_gap` - Minimal accepted time (in time units) until the arrival of the next major stream vehicle. Zero disables gap acceptance.
+    /// * `follow_up_time` - Same for the driver following the minor stream vehicle which has just entered the zone
+    ///
+    /// # Returns
+    /// A `VehicleBuilder` instance for further method chaining.
+    ///
+    /// # Example
+    /// ```rust
+    /// use micro_traffic_sim_core::agents::Vehicle;
+    /// let vehicle = Vehicle::new(1)
+    ///     .with_gap_acceptance(4, 2)
+    ///     .build();
+    /// assert_eq!(vehicle.critical_gap, 4);
+    /// assert_eq!(vehicle.follow_up_time, 2);
+    /// ```
+    pub fn with_gap_acceptance(mut self, critical_gap: i32, follow_up_time: i32) -> Self {
+        self.vehicle.critical_gap = critical_gap;
+        self.vehicle.follow_up_time = follow_up_time;
+        self
+    }
+
+    /// Sets the maximum acceleration of the vehicle. Overrides the agent type's default one
+    /// (see [`AgentType::max_acceleration_default`]).
+    ///
+    /// # Arguments
+    /// * `acceleration` - Maximum acceleration (in cells per time unit squared).
+    ///
+    /// # Returns
+    /// A `VehicleBuilder` instance for further method chaining.
+    ///
+    /// # Example
+    /// ```rust
+    /// use micro_traffic_sim_core::agents_types::AgentType;
+    /// use micro_traffic_sim_core::agents::Vehicle;
+    /// let vehicle = Vehicle::new(1)
+    ///     .with_max_acceleration(1.0)
+    ///     .with_type(AgentType::Truck)
+    ///     .build();
+    /// assert_eq!(vehicle.max_acceleration, 1.0);
+    /// assert_eq!(vehicle.max_deceleration, 1.0);
+    /// ```
+    pub fn with_max_acceleration(mut self, acceleration: f64) -> Self {
+        self.max_acceleration = Some(acceleration);
+        self
+    }
+
+    /// Sets the maximum comfortable deceleration of the vehicle. Overrides the agent type's default one
+    /// (see [`AgentType::max_deceleration_default`]).
+    ///
+    /// # Arguments
+    /// * `deceleration` - Maximum comfortable deceleration (in cells per time unit squared).
+    ///   Use `f64::INFINITY` to disable stopping planning. Non-positive (and NaN) values disable it as well.
+    ///
+    /// # Returns
+    /// A `VehicleBuilder` instance for further method chaining.
+    ///
+    /// # Example
+    /// ```rust
+    /// use micro_traffic_sim_core::agents::Vehicle;
+    /// let vehicle = Vehicle::new(1)
+    ///     .with_max_deceleration(2.0)
+    ///     .build();
+    /// assert_eq!(vehicle.max_deceleration, 2.0);
+    /// let vehicle = Vehicle::new(1).with_max_deceleration(0.0).build();
+    /// assert_eq!(vehicle.max_deceleration, f64::INFINITY);
+    /// ```
+    pub fn with_max_deceleration(mut self, deceleration: f64) -> Self {
+        self.max_deceleration = Some(if deceleration > 0.0 { deceleration } else { f64::INFINITY });
+        self
+    }
+
+    /// Sets the desired speed as a factor of the cell's speed limit (see [`Vehicle::effective_speed_limit`]).
+    ///
+    /// # Arguments
+    /// * `compliance` - Non-negative factor: `1.0` - driver follows posted limits, `1.2` - driver exceeds them by 20%
+    ///
+    /// # Returns
+    /// A `VehicleBuilder` instance for further method chaining.
+    ///
+    /// # Example
+    /// ```rust
+    /// use micro_traffic_sim_core::agents::Vehicle;
+    /// let vehicle = Vehicle::new(1)
+    ///     .with_speed_compliance(0.8)
+    ///     .build();
+    /// assert_eq!(vehicle.speed_compliance, 0.8);
+    /// ```
+    pub fn with_speed_compliance(mut self, compliance: f64) -> Self {
+        self.vehicle.speed_compliance = compliance;
+        self
+    }
+
+    /// Sets the offset (in cells per time unit) to the cells' speed limits (see [`Vehicle::effective_speed_limit`]).
+    ///
+    /// # Arguments
+    /// * `offset` - Offset: positive one for speeding, negative one for driving slower than allowed
+    ///
+    /// # Returns
+    /// A `VehicleBuilder` instance for further method chaining.
+    ///
+    /// # Example
+    /// ```rust
+    /// use micro_traffic_sim_core::agents::Vehicle;
+    /// let vehicle = Vehicle::new(1)
+    ///     .with_speed_limit_offset(1)
+    ///     .build();
+    /// assert_eq!(vehicle.speed_limit_offset, 1);
+    /// ```
+    pub fn with_speed_limit_offset(mut self, offset: i32) -> Self {
+        self.vehicle.speed_limit_offset = offset;
+        self
+    }
+
     /// Sets the travel time (in time units) which vehicle has been in movement state.
     ///
     /// # Arguments
@@ -949,7 +1430,14 @@ impl VehicleBuilder {
     /// # Returns
     /// The fully constructed `Vehicle` object.
     pub fn build(self) -> Vehicle {
-        self.vehicle
+        let mut vehicle = self.vehicle;
+        vehicle.max_acceleration = self
+            .max_acceleration
+            .unwrap_or_else(|| vehicle.vehicle_type.max_acceleration_default());
+        vehicle.max_deceleration = self
+            .max_deceleration
+            .unwrap_or_else(|| vehicle.vehicle_type.max_deceleration_default());
+        vehicle
     }
 
     /// Builds a reference to the `Vehicle` object.
@@ -960,7 +1448,7 @@ impl VehicleBuilder {
     /// # Returns
     /// A reference to the `Vehicle` object.
     pub fn build_ref(self) -> VehicleRef {
-        Rc::new(RefCell::new(self.vehicle))
+        Rc::new(RefCell::new(self.build()))
     }
 }
 
@@ -993,6 +1481,31 @@ mod tests {
             "Incorrect countdown after reset"
         );
     }
+    #[test]
+    fn test_braking_distance() {
+        // Closed form matches the step by step deceleration
+        for deceleration in [0.3, 0.5, 1.0, 1.5, 2.0, 3.0, 7.0] {
+            let vehicle = Vehicle::new(1).with_max_deceleration(deceleration).build();
+            for speed in 0..10 {
+                let (mut distance, mut next_speed) = (0.0, speed as f64 - deceleration);
+                while next_speed > 1e-9 {
+                    distance += next_speed;
+                    next_speed -= deceleration;
+                }
+                assert_eq!(vehicle.braking_distance(speed), (distance - 1e-9_f64).ceil().max(0.0) as i32, "{} {}", deceleration, speed);
+            }
+        }
+        // Tiny deceleration is clamped to the minimal one, zero one disables planning
+        let minimal = Vehicle::new(1).with_max_deceleration(MIN_DECELERATION).build();
+        for speed in [1, 5, 20] {
+            let distance = Vehicle::new(1).with_max_deceleration(1e-300).build().braking_distance(speed);
+            assert_eq!(distance, minimal.braking_distance(speed));
+            assert!(distance as f64 <= (speed * speed) as f64 / (2.0 * MIN_DECELERATION), "{} {}", speed, distance);
+        }
+        assert_eq!(Vehicle::new(1).with_max_deceleration(0.0).build().braking_distance(5), 0);
+        assert_eq!(Vehicle::new(1).with_max_deceleration(-1.0).build().braking_distance(5), 0);
+    }
+
     #[test]
     fn test_cooperative_level() {
         let coop_level = 0.75;
@@ -24,13 +24,20 @@ pub struct VehicleIntention {
     pub tail_maneuver: TailIntentionManeuver,
     /// Flag to stop vehicle. @todo: move it to intention?
     pub should_stop: bool,
+    /// Cells straight ahead of `intention_cell_id` which are occupied by the leader now, but could be
+    /// taken additionally if the leader vacates them in the same time unit (velocity anticipation).
+    /// See the ref. at [`DriverModel::anticipates`](crate::behaviour::DriverModel::anticipates).
+    pub anticipated_cells: Vec<CellID>,
+    /// Movement deviates from the vehicle's current bearing (turn at a junction or lane change).
+    /// It is set when intentions are collected. See the ref. at [`is_turning`](crate::intentions::is_turning)
+    pub turning: bool,
 }
 
 impl fmt::Display for VehicleIntention {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
-            "VehicleIntention {{ intention_maneuver: {:?}, intention_speed: {}, destination: {:?}, confusion: {:?}, intention_cell_id: {}, tail_intention_cells: {:?}, intermediate_cells: {:?}, tail_maneuver: {:?}, should_stop: {} }}",
+            "VehicleIntention {{ intention_maneuver: {:?}, intention_speed: {}, destination: {:?}, confusion: {:?}, intention_cell_id: {}, tail_intention_cells: {:?}, intermediate_cells: {:?}, tail_maneuver: {:?}, should_stop: {}, anticipated_cells: {:?}, turning: {} }}",
             self.intention_maneuver,
             self.intention_speed,
             self.destination,
@@ -39,7 +46,9 @@ impl fmt::Display for VehicleIntention {
             self.tail_intention_cells,
             self.intermediate_cells,
             self.tail_maneuver,
-            self.should_stop
+            self.should_stop,
+            self.anticipated_cells,
+            self.turning
         )
     }
 }
@@ -1,5 +1,6 @@
 use std::fmt;
 use rand::Rng;
+use crate::behaviour::ParameterDistribution;
 
 /// Represents the type of an agent in Cellular Automata (CA).
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -16,8 +17,14 @@ pub enum AgentType {
     Truck,
     /// A large bus agent (e.g., articulated bus).
     LargeBus,
-    /// A pedestrian agent (is not used anywhere currently).
+    /// A pedestrian agent. It walks through the pedestrian network (see `pedestrians` module).
     Pedestrian,
+    /// An emergency vehicle (ambulance, fire engine, police car). It has priority in every conflict,
+    /// other vehicles move aside in front of it and traffic lights on its way are preempted.
+    Emergency,
+    /// A cyclist. It is slow, keeps to the kerb lane (cycle lane, see `CYCLE_LANE_AGENTS`, or the slow vehicle lane)
+    /// and has priority over vehicles turning across its path.
+    Cyclist,
 }
 
 impl fmt::Display for AgentType {
@@ -38,6 +45,8 @@ impl fmt::Display for AgentType {
     /// assert_eq!(format!("{}", AgentType::Truck), "truck");
     /// assert_eq!(format!("{}", AgentType::LargeBus), "large_bus");
     /// assert_eq!(format!("{}", AgentType::Pedestrian), "pedestrian");
+    /// assert_eq!(format!("{}", AgentType::Emergency), "emergency");
+    /// assert_eq!(format!("{}", AgentType::Cyclist), "cyclist");
     /// ```
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let as_str = match self {
@@ -48,6 +57,8 @@ impl fmt::Display for AgentType {
             AgentType::Truck => "truck",
             AgentType::LargeBus => "large_bus",
             AgentType::Pedestrian => "pedestrian",
+            AgentType::Emergency => "emergency",
+            AgentType::Cyclist => "cyclist",
         };
         write!(f, "{}", as_str)
     }
@@ -65,13 +76,15 @@ impl AgentType {
     /// ```
     pub fn random() -> Self {
         let mut rng = rand::rng();
-        match rng.random_range(1..=6) {
+        match rng.random_range(1..=8) {
             1 => AgentType::Car,
             2 => AgentType::Bus,
             3 => AgentType::Taxi,
             4 => AgentType::Truck,
             5 => AgentType::LargeBus,
             6 => AgentType::Pedestrian,
+            7 => AgentType::Emergency,
+            8 => AgentType::Cyclist,
             _ => unreachable!(), // Should never happen
         }
     }
@@ -84,7 +97,7 @@ impl AgentType {
     ///
     /// # Returns
     ///
-    /// - `0` for single-cell agents: `Car`, `Taxi`, `Pedestrian`, `Undefined`
+    /// - `0` for single-cell agents: `Car`, `Taxi`, `Pedestrian`, `Emergency`, `Cyclist`, `Undefined`
     /// - `1` for medium multi-cell agents: `Bus`, `Truck`
     /// - `2` for large multi-cell agents: `LargeBus`
     ///
@@ -100,11 +113,119 @@ impl AgentType {
     /// ```
     pub fn tail_size_default(&self) -> usize {
         match self {
-            AgentType::Undefined | AgentType::Car | AgentType::Taxi | AgentType::Pedestrian => 0,
+            AgentType::Undefined
+            | AgentType::Car
+            | AgentType::Taxi
+            | AgentType::Pedestrian
+            | AgentType::Emergency
+            | AgentType::Cyclist => 0,
             AgentType::Bus | AgentType::Truck => 1,
             AgentType::LargeBus => 2,
         }
     }
+
+    /// Returns the default maximum acceleration (in cells per time unit squared) for this agent type.
+    ///
+    /// Values below one mean that the vehicle accelerates by one cell per time unit,
+    /// but not on every time unit (e.g. `0.5` - on every second time unit).
+    ///
+    /// # Returns
+    ///
+    /// - `0.5` for heavy agents: `Truck`, `LargeBus`
+    /// - `1.0` for others
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use micro_traffic_sim_core::agents_types::AgentType;
+    ///
+    /// assert_eq!(AgentType::Car.max_acceleration_default(), 1.0);
+    /// assert_eq!(AgentType::Truck.max_acceleration_default(), 0.5);
+    /// ```
+    pub fn max_acceleration_default(&self) -> f64 {
+        match self {
+            AgentType::Truck | AgentType::LargeBus => 0.5,
+            _ => 1.0,
+        }
+    }
+
+    /// Returns the default maximum comfortable deceleration (in cells per time unit squared) for this agent type.
+    ///
+    /// Vehicles plan their speed so that they could stop before obstacles ahead without exceeding
+    /// this deceleration. Infinite value means that the vehicle does not plan ahead and stops
+    /// right before obstacles (classic cellular automata behaviour).
+    ///
+    /// # Returns
+    ///
+    /// - `1.0` for heavy agents: `Truck`, `LargeBus`
+    /// - `2.0` for `Bus`
+    /// - `f64::INFINITY` for others
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use micro_traffic_sim_core::agents_types::AgentType;
+    ///
+    /// assert_eq!(AgentType::Car.max_deceleration_default(), f64::INFINITY);
+    /// assert_eq!(AgentType::Bus.max_deceleration_default(), 2.0);
+    /// assert_eq!(AgentType::LargeBus.max_deceleration_default(), 1.0);
+    /// ```
+    pub fn max_deceleration_default(&self) -> f64 {
+        match self {
+            AgentType::Truck | AgentType::LargeBus => 1.0,
+            AgentType::Bus => 2.0,
+            _ => f64::INFINITY,
+        }
+    }
+
+    /// Returns the default maximum speed (in cells per time unit) for this agent type.
+    ///
+    /// It caps the speed limit of generated agents when trip does not set it explicitly.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(1)` for `Cyclist`
+    /// - `None` for others (the speed limit comes from the behaviour type)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use micro_traffic_sim_core::agents_types::AgentType;
+    ///
+    /// assert_eq!(AgentType::Cyclist.max_speed_default(), Some(1));
+    /// assert_eq!(AgentType::Car.max_speed_default(), None);
+    /// ```
+    pub fn max_speed_default(&self) -> Option<i32> {
+        match self {
+            AgentType::Cyclist => Some(1),
+            _ => None,
+        }
+    }
+
+    /// Returns the default distribution of the slowdown probability for this agent type.
+    ///
+    /// Generated agents draw their slowdown probability from it unless behaviour distributions
+    /// set it explicitly (see `BehaviourDistributions`).
+    ///
+    /// # Returns
+    ///
+    /// - Wide truncated normal distribution for `Cyclist`: cyclists differ a lot and their speed fluctuates
+    /// - `None` for others (the slowdown probability comes from the behaviour type)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use micro_traffic_sim_core::agents_types::AgentType;
+    ///
+    /// assert!(AgentType::Cyclist.slow_down_distribution_default().is_some());
+    /// assert!(AgentType::Car.slow_down_distribution_default().is_none());
+    /// ```
+    pub fn slow_down_distribution_default(&self) -> Option<ParameterDistribution> {
+        match self {
+            AgentType::Cyclist => Some(ParameterDistribution::TruncatedNormal { mean: 0.4, std_dev: 0.2, min: 0.0, max: 1.0 }),
+            _ => None,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -118,6 +239,8 @@ mod tests {
         assert_eq!(AgentType::Truck.to_string(), "truck");
         assert_eq!(AgentType::LargeBus.to_string(), "large_bus");
         assert_eq!(AgentType::Pedestrian.to_string(), "pedestrian");
+        assert_eq!(AgentType::Emergency.to_string(), "emergency");
+        assert_eq!(AgentType::Cyclist.to_string(), "cyclist");
     }
 
     #[test]
@@ -134,8 +257,36 @@ mod tests {
         assert_eq!(AgentType::Car.tail_size_default(), 0);
         assert_eq!(AgentType::Taxi.tail_size_default(), 0);
         assert_eq!(AgentType::Pedestrian.tail_size_default(), 0);
+        assert_eq!(AgentType::Cyclist.tail_size_default(), 0);
         assert_eq!(AgentType::Bus.tail_size_default(), 1);
         assert_eq!(AgentType::Truck.tail_size_default(), 1);
         assert_eq!(AgentType::LargeBus.tail_size_default(), 2);
     }
+
+    #[test]
+    fn test_dynamics_default() {
+        assert_eq!(AgentType::Car.max_acceleration_default(), 1.0);
+        assert_eq!(AgentType::Bus.max_acceleration_default(), 1.0);
+        assert_eq!(AgentType::Truck.max_acceleration_default(), 0.5);
+        assert_eq!(AgentType::LargeBus.max_acceleration_default(), 0.5);
+        assert!(AgentType::Car.max_deceleration_default().is_infinite());
+        assert!(AgentType::Taxi.max_deceleration_default().is_infinite());
+        assert_eq!(AgentType::Bus.max_deceleration_default(), 2.0);
+        assert_eq!(AgentType::Truck.max_deceleration_default(), 1.0);
+        assert_eq!(AgentType::LargeBus.max_deceleration_default(), 1.0);
+    }
+
+    #[test]
+    fn test_cyclist_defaults() {
+        assert_eq!(AgentType::Cyclist.max_speed_default(), Some(1));
+        assert_eq!(AgentType::Bus.max_speed_default(), None);
+        match AgentType::Cyclist.slow_down_distribution_default() {
+            Some(ParameterDistribution::TruncatedNormal { mean, std_dev, min, max }) => {
+                assert!(mean > 0.3 && std_dev > 0.1);
+                assert_eq!((min, max), (0.0, 1.0));
+            }
+            other => panic!("Unexpected distribution: {:?}", other),
+        }
+        assert!(AgentType::Truck.slow_down_distribution_default().is_none());
+    }
 }
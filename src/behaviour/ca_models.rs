@@ -0,0 +1,281 @@
+//! Classic cellular automata variants of the Nagel–Schreckenberg model.
+//!
+//! Unlike [`NaSchModel`](crate::behaviour::NaSchModel) these models follow the textbook order of rules:
+//! 1. Acceleration: `v = min(v + a, v_max)` where `a` is the vehicle's speed increase (one cell by default);
+//! 2. Deceleration to the gap ahead: `v = min(v, gap)`;
+//! 3. Randomization: `v = max(v - 1, 0)` with some probability;
+//! 4. Movement.
+//!
+//! Each model changes one of these rules. Probability of the regular randomization is
+//! the vehicle's `slow_down_factor`. Timers of the vehicle (`timer_non_acceleration` and `timer_non_slowdown`)
+//! are respected the same way as in the default model.
+//!
+//! The gap is counted straight ahead (see [`DriverContext::gap_ahead`]), so the rules are exact
+//! on a single lane (e.g. ring road) and approximate near lane changes and junctions, where the
+//! path check of the intentions module takes over.
+
+use crate::behaviour::{DriverContext, DriverModel};
+use rand::{Rng, RngCore};
+
+/// Speed after acceleration and deceleration to the gap ahead (rules 1-2)
+fn accelerated_speed(ctx: &DriverContext, extra_gap: i32) -> i32 {
+    let acceleration_allowed = ctx.vehicle.timer_non_acceleration <= 0 || ctx.vehicle.speed == 0;
+    let speed = if acceleration_allowed {
+        (ctx.speed + ctx.vehicle.speed_increase()).min(ctx.speed_limit)
+    } else {
+        ctx.speed
+    };
+    speed.min(ctx.gap_ahead(ctx.speed_limit) + extra_gap)
+}
+
+/// Randomization with given probability (rule 3)
+fn randomized_speed(ctx: &DriverContext, speed: i32, probability: f64, rng: &mut dyn RngCore) -> i32 {
+    if ctx.vehicle.timer_non_slowdown <= 0 && speed > 0 && rng.random::<f64>() < probability {
+        return speed - 1;
+    }
+    speed
+}
+
+/// Velocity-dependent randomization (VDR) model by Barlovic et al.
+///
+/// Randomization probability depends on the current speed: stopped vehicles slow down with
+/// probability `slow_to_start`, moving ones with the vehicle's `slow_down_factor`.
+/// When `slow_to_start` is greater than `slow_down_factor`, vehicles leave jams later than they
+/// join them, which leads to metastable states and hysteresis of the fundamental diagram.
+///
+/// # Example
+/// ```
+/// use micro_traffic_sim_core::behaviour::VdrModel;
+/// use micro_traffic_sim_core::agents::Vehicle;
+/// use std::sync::Arc;
+///
+/// let vehicle = Vehicle::new(1)
+///     .with_slowdown(0.01)
+///     .with_driver_model(Arc::new(VdrModel::new(0.5)))
+///     .build();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VdrModel {
+    /// Randomization probability for stopped vehicles
+    pub slow_to_start: f64,
+}
+
+impl VdrModel {
+    /// Creates VDR model with given randomization probability for stopped vehicles
+    pub fn new(slow_to_start: f64) -> Self {
+        VdrModel { slow_to_start }
+    }
+}
+
+impl DriverModel for VdrModel {
+    fn desired_speed(&self, ctx: &DriverContext) -> i32 {
+        accelerated_speed(ctx, 0)
+    }
+
+    fn slowdown(&self, ctx: &DriverContext, desired_speed: i32, rng: &mut dyn RngCore) -> i32 {
+        let probability = if ctx.speed == 0 {
+            self.slow_to_start
+        } else {
+            ctx.vehicle.slow_down_factor
+        };
+        randomized_speed(ctx, desired_speed, probability, rng)
+    }
+}
+
+/// Takayasu–Takayasu slow-to-start model.
+///
+/// Stopped vehicle which has exactly one free cell ahead stays with probability `slow_to_start`.
+/// Vehicles with larger gaps start as usual. Then the regular randomization is applied.
+///
+/// # Example
+/// ```
+/// use micro_traffic_sim_core::behaviour::TakayasuModel;
+/// use micro_traffic_sim_core::agents::Vehicle;
+/// use std::sync::Arc;
+///
+/// let vehicle = Vehicle::new(1)
+///     .with_driver_model(Arc::new(TakayasuModel::new(0.5)))
+///     .build();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TakayasuModel {
+    /// Probability to stay for a stopped vehicle with exactly one free cell ahead
+    pub slow_to_start: f64,
+}
+
+impl TakayasuModel {
+    /// Creates Takayasu–Takayasu model with given slow-to-start probability
+    pub fn new(slow_to_start: f64) -> Self {
+        TakayasuModel { slow_to_start }
+    }
+}
+
+impl DriverModel for TakayasuModel {
+    fn desired_speed(&self, ctx: &DriverContext) -> i32 {
+        accelerated_speed(ctx, 0)
+    }
+
+    fn slowdown(&self, ctx: &DriverContext, desired_speed: i32, rng: &mut dyn RngCore) -> i32 {
+        if ctx.speed == 0 && desired_speed > 0 && ctx.gap_ahead(2) == 1 && rng.random::<f64>() < self.slow_to_start {
+            return 0;
+        }
+        randomized_speed(ctx, desired_speed, ctx.vehicle.slow_down_factor, rng)
+    }
+}
+
+/// Benjamin–Johnson–Hui (BJH) slow-to-start model.
+///
+/// Vehicle which has been stopped during the previous time unit stays with probability `slow_to_start`
+/// regardless of the gap ahead. If it starts, the regular randomization is applied as well.
+///
+/// # Example
+/// ```
+/// use micro_traffic_sim_core::behaviour::BjhModel;
+/// use micro_traffic_sim_core::agents::Vehicle;
+/// use std::sync::Arc;
+///
+/// let vehicle = Vehicle::new(1)
+///     .with_driver_model(Arc::new(BjhModel::new(0.5)))
+///     .build();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BjhModel {
+    /// Probability to stay for a vehicle which has been stopped in the previous time unit
+    pub slow_to_start: f64,
+}
+
+impl BjhModel {
+    /// Creates BJH model with given slow-to-start probability
+    pub fn new(slow_to_start: f64) -> Self {
+        BjhModel { slow_to_start }
+    }
+}
+
+impl DriverModel for BjhModel {
+    fn desired_speed(&self, ctx: &DriverContext) -> i32 {
+        accelerated_speed(ctx, 0)
+    }
+
+    fn slowdown(&self, ctx: &DriverContext, desired_speed: i32, rng: &mut dyn RngCore) -> i32 {
+        if ctx.vehicle.speed == 0 && desired_speed > 0 && rng.random::<f64>() < self.slow_to_start {
+            return 0;
+        }
+        randomized_speed(ctx, desired_speed, ctx.vehicle.slow_down_factor, rng)
+    }
+}
+
+/// NaSch model with anticipation of the leader's next speed.
+///
+/// The driver expects the leader to move by `v_anti = min(v_leader, gap_leader)` cells and
+/// uses the effective gap `gap + max(v_anti - safety_gap, 0)` in the deceleration rule.
+/// Cells behind the anticipated position of the leader are taken only if the leader actually
+/// vacates them (see [`DriverModel::anticipates`]), so collisions are not possible.
+///
+/// Anticipation works for single-cell vehicles only: vehicles with tail follow the leader
+/// as in the regular NaSch model.
+///
+/// # Example
+/// ```
+/// use micro_traffic_sim_core::behaviour::AnticipationModel;
+/// use micro_traffic_sim_core::agents::Vehicle;
+/// use std::sync::Arc;
+///
+/// let vehicle = Vehicle::new(1)
+///     .with_driver_model(Arc::new(AnticipationModel::new(1)))
+///     .build();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AnticipationModel {
+    /// Number of cells the driver keeps to the anticipated position of the leader
+    pub safety_gap: i32,
+}
+
+impl AnticipationModel {
+    /// Creates anticipation model with given safety gap (non-negative)
+    pub fn new(safety_gap: i32) -> Self {
+        AnticipationModel {
+            safety_gap: safety_gap.max(0),
+        }
+    }
+}
+
+impl Default for AnticipationModel {
+    fn default() -> Self {
+        AnticipationModel::new(1)
+    }
+}
+
+impl DriverModel for AnticipationModel {
+    fn desired_speed(&self, ctx: &DriverContext) -> i32 {
+        let anticipated_gap = match ctx.leader_ahead(ctx.speed_limit) {
+            Some((_, leader)) if leader.tail_cells.is_empty() => ctx
+                .net
+                .get_cell(&leader.cell_id)
+                .map(|leader_cell| {
+                    let leader_ctx = DriverContext::new(ctx.net, ctx.current_state, leader, leader_cell);
+                    leader_ctx.gap_ahead(leader_ctx.speed)
+                })
+                .unwrap_or(0),
+            _ => 0,
+        };
+        accelerated_speed(ctx, (anticipated_gap - self.safety_gap).max(0))
+    }
+
+    fn slowdown(&self, ctx: &DriverContext, desired_speed: i32, rng: &mut dyn RngCore) -> i32 {
+        randomized_speed(ctx, desired_speed, ctx.vehicle.slow_down_factor, rng)
+    }
+
+    fn anticipates(&self, ctx: &DriverContext) -> bool {
+        ctx.vehicle.tail_cells.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::agents::Vehicle;
+    use crate::grid::road_network::GridRoads;
+    use crate::utils::generators::generate_one_lane_cells;
+    use crate::utils::rand::rng;
+    use indexmap::IndexMap;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_ca_models() {
+        let mut net = GridRoads::new();
+        for cell in generate_one_lane_cells(100.0, 10.0, 1) {
+            net.add_cell(cell);
+        }
+        let mut rng = rng();
+        let vehicles = IndexMap::from([
+            (1, Vehicle::new(1).with_cell(1).with_speed(0).with_slowdown(0.0).build()),
+            (2, Vehicle::new(2).with_cell(3).with_speed(2).build()),
+        ]);
+        // Leader is in cell 3, leader's road is free
+        let current_state = HashMap::from([(1, 1), (3, 2)]);
+        let vehicle = &vehicles[&1];
+        let ctx = DriverContext::new(&net, &current_state, vehicle, net.get_cell(&1).unwrap()).with_vehicles(&vehicles);
+        assert_eq!(ctx.gap_ahead(10), 1);
+
+        // Stopped vehicle with one free cell: Takayasu slow-to-start always keeps it
+        assert_eq!(TakayasuModel::new(1.0).desired_speed(&ctx), 1);
+        assert_eq!(TakayasuModel::new(1.0).slowdown(&ctx, 1, &mut rng), 0);
+        assert_eq!(TakayasuModel::new(0.0).slowdown(&ctx, 1, &mut rng), 1);
+        // BJH and VDR keep stopped vehicle too
+        assert_eq!(BjhModel::new(1.0).slowdown(&ctx, 1, &mut rng), 0);
+        assert_eq!(VdrModel::new(1.0).slowdown(&ctx, 1, &mut rng), 0);
+        assert_eq!(VdrModel::new(0.0).slowdown(&ctx, 1, &mut rng), 1);
+        // Anticipation: leader moves by 2 cells, safety gap is 1, so effective gap is 2
+        let model = AnticipationModel::default();
+        assert_eq!(model.desired_speed(&ctx), 1);
+        assert!(model.anticipates(&ctx));
+
+        let vehicle = Vehicle::new(1).with_cell(1).with_speed(3).with_speed_limit(3).with_slowdown(0.0).build();
+        let ctx = DriverContext::new(&net, &current_state, &vehicle, net.get_cell(&1).unwrap()).with_vehicles(&vehicles);
+        assert_eq!(VdrModel::new(1.0).desired_speed(&ctx), 1);
+        assert_eq!(model.desired_speed(&ctx), 2);
+        // Without the storage leader is unknown
+        let ctx = DriverContext::new(&net, &current_state, &vehicle, net.get_cell(&1).unwrap());
+        assert_eq!(model.desired_speed(&ctx), 1);
+    }
+}
@@ -0,0 +1,329 @@
+use crate::agents::Vehicle;
+use rand::Rng;
+use std::f64::consts::PI;
+
+/// Maximum number of draws for rejection sampling of [`ParameterDistribution::TruncatedNormal`].
+/// If every draw falls outside of the bounds the last one is clamped.
+const TRUNCATED_NORMAL_MAX_DRAWS: usize = 100;
+
+/// Probability distribution of a driver parameter (see [`BehaviourDistributions`])
+///
+/// # Example
+/// ```
+/// use micro_traffic_sim_core::behaviour::ParameterDistribution;
+/// use rand::{SeedableRng, rngs::StdRng};
+///
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let distribution = ParameterDistribution::TruncatedNormal { mean: 0.3, std_dev: 0.1, min: 0.0, max: 1.0 };
+/// let value = distribution.sample(&mut rng).unwrap();
+/// assert!((0.0..=1.0).contains(&value));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParameterDistribution {
+    /// Every driver gets the same value
+    Constant(f64),
+    /// Uniform distribution on `[min; max)`
+    Uniform { min: f64, max: f64 },
+    /// Normal (Gaussian) distribution
+    Normal { mean: f64, std_dev: f64 },
+    /// Normal distribution restricted to `[min; max]`
+    TruncatedNormal { mean: f64, std_dev: f64, min: f64, max: f64 },
+    /// Empirical distribution: observed values (e.g. field data) picked with equal probability
+    Empirical(Vec<f64>),
+}
+
+impl ParameterDistribution {
+    /// Draws a value from the distribution.
+    ///
+    /// # Arguments
+    /// * `rng` - Random number generator
+    ///
+    /// # Returns
+    /// Sampled value, or `None` if the empirical distribution has no observed values.
+    pub fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Option<f64> {
+        match self {
+            Self::Constant(value) => Some(*value),
+            Self::Uniform { min, max } => {
+                if max <= min {
+                    return Some(*min);
+                }
+                Some(rng.random_range(*min..*max))
+            }
+            Self::Normal { mean, std_dev } => Some(mean + std_dev * standard_normal(rng)),
+            Self::TruncatedNormal { mean, std_dev, min, max } => {
+                let (low, high) = (min.min(*max), max.max(*min));
+                let mut value = *mean;
+                for _ in 0..TRUNCATED_NORMAL_MAX_DRAWS {
+                    value = mean + std_dev * standard_normal(rng);
+                    if (low..=high).contains(&value) {
+                        return Some(value);
+                    }
+                }
+                Some(value.clamp(low, high))
+            }
+            Self::Empirical(values) => {
+                if values.is_empty() {
+                    return None;
+                }
+                Some(values[rng.random_range(0..values.len())])
+            }
+        }
+    }
+}
+
+/// Draws a value from the standard normal distribution (Box-Muller transform)
+fn standard_normal<R: Rng + ?Sized>(rng: &mut R) -> f64 {
+    // 1 - u is in (0; 1], so the logarithm is finite
+    let u1: f64 = 1.0 - rng.random::<f64>();
+    let u2: f64 = rng.random();
+    (-2.0 * u1.ln()).sqrt() * (2.0 * PI * u2).cos()
+}
+
+/// Distributions of driver parameters, so generated vehicles are not identical within the same [`BehaviourType`](crate::behaviour::BehaviourType).
+///
+/// Every parameter is optional: when it is not set the value derived from the behaviour type
+/// (see [`BehaviourParameters`](crate::behaviour::BehaviourParameters)) is kept. Sampled values are bounded
+/// to their valid ranges:
+/// - `slow_down_factor`, `aggressive_level` and `cooperativity` are in `[0; 1]`;
+/// - `speed_compliance` (desired speed as a factor of the cell's speed limit, see `Vehicle::speed_compliance`) is non-negative;
+/// - `speed_limit_offset` (see `Vehicle::speed_limit_offset`) is rounded to the nearest integer, e.g. a share of speeding drivers
+///   is an empirical distribution like `[0, 0, 0, 1]`;
+/// - `min_safe_distance` is rounded to the nearest non-negative number of cells;
+/// - `critical_gap` and `follow_up_time` (see `Vehicle::critical_gap`) are rounded to the nearest non-negative number of time units.
+///
+/// Both `aggressive_level` and `cooperativity` define `Vehicle::cooperativity`, so the latter one wins when both are set.
+///
+/// # Example
+/// ```
+/// use micro_traffic_sim_core::behaviour::{BehaviourDistributions, ParameterDistribution};
+/// let distributions = BehaviourDistributions::new()
+///     .with_slow_down_factor(ParameterDistribution::Uniform { min: 0.1, max: 0.3 })
+///     .with_speed_compliance(ParameterDistribution::Empirical(vec![0.9, 1.0, 1.0, 1.2]))
+///     .build();
+/// assert!(distributions.cooperativity.is_none());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BehaviourDistributions {
+    /// Probability of random slowdown
+    pub slow_down_factor: Option<ParameterDistribution>,
+    /// Desired speed as a factor of the cell's speed limit
+    pub speed_compliance: Option<ParameterDistribution>,
+    /// Offset (in cells per time unit) to the cell's speed limit
+    pub speed_limit_offset: Option<ParameterDistribution>,
+    /// Aggressiveness level (`1 - cooperativity`)
+    pub aggressive_level: Option<ParameterDistribution>,
+    /// Cooperativity level
+    pub cooperativity: Option<ParameterDistribution>,
+    /// Minimal safe distance (in cells) to the vehicle in front
+    pub min_safe_distance: Option<ParameterDistribution>,
+    /// Critical gap (in time units) for entering conflict zones from the minor stream
+    pub critical_gap: Option<ParameterDistribution>,
+    /// Follow-up time (in time units) for entering conflict zones from the minor stream
+    pub follow_up_time: Option<ParameterDistribution>,
+}
+
+impl BehaviourDistributions {
+    /// Constructs a new `BehaviourDistributionsBuilder` without any distributions.
+    ///
+    /// # Returns
+    /// A `BehaviourDistributionsBuilder` struct which is used to configure and build the `BehaviourDistributions` object.
+    pub fn new() -> BehaviourDistributionsBuilder {
+        BehaviourDistributionsBuilder {
+            distributions: BehaviourDistributions::default(),
+        }
+    }
+
+    /// Samples driver parameters and assigns them to the vehicle.
+    ///
+    /// Parameters are drawn in the fixed order (slowdown, speed compliance, speed limit offset, aggressiveness, cooperativity,
+    /// safe distance, critical gap, follow-up time), so the same random stream gives the same driver.
+    ///
+    /// # Arguments
+    /// * `vehicle` - Vehicle to assign parameters to
+    /// * `rng` - Random number generator
+    ///
+    /// # Example
+    /// ```
+    /// use micro_traffic_sim_core::agents::Vehicle;
+    /// use micro_traffic_sim_core::behaviour::{BehaviourDistributions, ParameterDistribution};
+    /// use rand::{SeedableRng, rngs::StdRng};
+    ///
+    /// let distributions = BehaviourDistributions::new()
+    ///     .with_aggressive_level(ParameterDistribution::Constant(0.8))
+    ///     .with_min_safe_distance(ParameterDistribution::Normal { mean: -5.0, std_dev: 0.1 })
+    ///     .build();
+    /// let mut vehicle = Vehicle::new(1).with_slowdown(0.4).build();
+    /// distributions.apply(&mut vehicle, &mut StdRng::seed_from_u64(42));
+    /// assert_eq!(vehicle.slow_down_factor, 0.4);
+    /// assert!((vehicle.cooperativity - 0.2).abs() < 1e-9);
+    /// assert_eq!(vehicle.min_safe_distance, 0);
+    /// ```
+    pub fn apply<R: Rng + ?Sized>(&self, vehicle: &mut Vehicle, rng: &mut R) {
+        let mut sample = |distribution: &Option<ParameterDistribution>| {
+            distribution.as_ref().and_then(|distribution| distribution.sample(rng))
+        };
+        if let Some(value) = sample(&self.slow_down_factor) {
+            vehicle.slow_down_factor = value.clamp(0.0, 1.0);
+        }
+        if let Some(value) = sample(&self.speed_compliance) {
+            vehicle.speed_compliance = value.max(0.0);
+        }
+        if let Some(value) = sample(&self.speed_limit_offset) {
+            vehicle.speed_limit_offset = value.round() as i32;
+        }
+        if let Some(value) = sample(&self.aggressive_level) {
+            vehicle.cooperativity = 1.0 - value.clamp(0.0, 1.0);
+        }
+        if let Some(value) = sample(&self.cooperativity) {
+            vehicle.cooperativity = value.clamp(0.0, 1.0);
+        }
+        if let Some(value) = sample(&self.min_safe_distance) {
+            vehicle.min_safe_distance = value.round().max(0.0) as i32;
+        }
+        if let Some(value) = sample(&self.critical_gap) {
+            vehicle.critical_gap = value.round().max(0.0) as i32;
+        }
+        if let Some(value) = sample(&self.follow_up_time) {
+            vehicle.follow_up_time = value.round().max(0.0) as i32;
+        }
+    }
+}
+
+/// Builder for [`BehaviourDistributions`]
+pub struct BehaviourDistributionsBuilder {
+    distributions: BehaviourDistributions,
+}
+
+impl BehaviourDistributionsBuilder {
+    /// Sets distribution of the random slowdown probability
+    pub fn with_slow_down_factor(mut self, distribution: ParameterDistribution) -> Self {
+        self.distributions.slow_down_factor = Some(distribution);
+        self
+    }
+
+    /// Sets distribution of the desired speed as a factor of the cell's speed limit
+    pub fn with_speed_compliance(mut self, distribution: ParameterDistribution) -> Self {
+        self.distributions.speed_compliance = Some(distribution);
+        self
+    }
+
+    /// Sets distribution of the offset (in cells per time unit) to the cell's speed limit
+    pub fn with_speed_limit_offset(mut self, distribution: ParameterDistribution) -> Self {
+        self.distributions.speed_limit_offset = Some(distribution);
+        self
+    }
+
+    /// Sets distribution of the aggressiveness level
+    pub fn with_aggressive_level(mut self, distribution: ParameterDistribution) -> Self {
+        self.distributions.aggressive_level = Some(distribution);
+        self
+    }
+
+    /// Sets distribution of the cooperativity level
+    pub fn with_cooperativity(mut self, distribution: ParameterDistribution) -> Self {
+        self.distributions.cooperativity = Some(distribution);
+        self
+    }
+
+    /// Sets distribution of the minimal safe distance (in cells)
+    pub fn with_min_safe_distance(mut self, distribution: ParameterDistribution) -> Self {
+        self.distributions.min_safe_distance = Some(distribution);
+        self
+    }
+
+    /// Sets distribution of the critical gap (in time units)
+    pub fn with_critical_gap(mut self, distribution: ParameterDistribution) -> Self {
+        self.distributions.critical_gap = Some(distribution);
+        self
+    }
+
+    /// Sets distribution of the follow-up time (in time units)
+    pub fn with_follow_up_time(mut self, distribution: ParameterDistribution) -> Self {
+        self.distributions.follow_up_time = Some(distribution);
+        self
+    }
+
+    /// Builds the final `BehaviourDistributions` object
+    pub fn build(self) -> BehaviourDistributions {
+        self.distributions
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    fn mean_and_std_dev(values: &[f64]) -> (f64, f64) {
+        let mean = values.iter().sum::<f64>() / values.len() as f64;
+        let variance = values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / values.len() as f64;
+        (mean, variance.sqrt())
+    }
+
+    #[test]
+    fn test_sample_distributions() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let n = 20000;
+
+        let normal = ParameterDistribution::Normal { mean: 2.0, std_dev: 0.5 };
+        let values: Vec<f64> = (0..n).map(|_| normal.sample(&mut rng).unwrap()).collect();
+        let (mean, std_dev) = mean_and_std_dev(&values);
+        assert!((mean - 2.0).abs() < 0.02, "mean: {}", mean);
+        assert!((std_dev - 0.5).abs() < 0.02, "std_dev: {}", std_dev);
+
+        let truncated = ParameterDistribution::TruncatedNormal { mean: 0.0, std_dev: 1.0, min: 0.0, max: 0.5 };
+        assert!((0..n).all(|_| (0.0..=0.5).contains(&truncated.sample(&mut rng).unwrap())));
+
+        // Bounds far away from the mean
+        let truncated = ParameterDistribution::TruncatedNormal { mean: 0.0, std_dev: 0.1, min: 10.0, max: 11.0 };
+        assert_eq!(truncated.sample(&mut rng), Some(10.0));
+
+        let uniform = ParameterDistribution::Uniform { min: 1.0, max: 3.0 };
+        let values: Vec<f64> = (0..n).map(|_| uniform.sample(&mut rng).unwrap()).collect();
+        assert!(values.iter().all(|v| (1.0..3.0).contains(v)));
+        assert!((mean_and_std_dev(&values).0 - 2.0).abs() < 0.02);
+        let degenerate = ParameterDistribution::Uniform { min: 1.0, max: 1.0 };
+        assert_eq!(degenerate.sample(&mut rng), Some(1.0));
+
+        let empirical = ParameterDistribution::Empirical(vec![1.0, 2.0, 2.0, 2.0]);
+        let twos = (0..n).filter(|_| empirical.sample(&mut rng) == Some(2.0)).count();
+        assert!((twos as f64 / n as f64 - 0.75).abs() < 0.02);
+        assert_eq!(ParameterDistribution::Empirical(vec![]).sample(&mut rng), None);
+    }
+
+    #[test]
+    fn test_apply_distributions() {
+        let distributions = BehaviourDistributions::new()
+            .with_slow_down_factor(ParameterDistribution::Constant(1.5))
+            .with_speed_compliance(ParameterDistribution::Constant(1.2))
+            .with_speed_limit_offset(ParameterDistribution::Constant(-0.6))
+            .with_aggressive_level(ParameterDistribution::Constant(0.9))
+            .with_cooperativity(ParameterDistribution::Constant(0.6))
+            .with_min_safe_distance(ParameterDistribution::Constant(1.6))
+            .with_critical_gap(ParameterDistribution::Constant(3.7))
+            .with_follow_up_time(ParameterDistribution::Constant(-1.0))
+            .build();
+        let mut vehicle = Vehicle::new(1).build();
+        distributions.apply(&mut vehicle, &mut StdRng::seed_from_u64(42));
+        assert_eq!(vehicle.slow_down_factor, 1.0);
+        assert_eq!(vehicle.speed_compliance, 1.2);
+        assert_eq!(vehicle.speed_limit_offset, -1);
+        assert_eq!(vehicle.cooperativity, 0.6);
+        assert_eq!(vehicle.min_safe_distance, 2);
+        assert_eq!(vehicle.critical_gap, 4);
+        assert_eq!(vehicle.follow_up_time, 0);
+
+        // Same stream gives the same driver
+        let distributions = BehaviourDistributions::new()
+            .with_slow_down_factor(ParameterDistribution::Uniform { min: 0.0, max: 1.0 })
+            .with_min_safe_distance(ParameterDistribution::Normal { mean: 3.0, std_dev: 2.0 })
+            .build();
+        let sampled = |seed: u64| {
+            let mut vehicle = Vehicle::new(1).build();
+            distributions.apply(&mut vehicle, &mut StdRng::seed_from_u64(seed));
+            (vehicle.slow_down_factor, vehicle.min_safe_distance)
+        };
+        assert_eq!(sampled(7), sampled(7));
+        assert_ne!(sampled(7), sampled(8));
+    }
+}
@@ -0,0 +1,330 @@
+use crate::agents::{Vehicle, VehicleID};
+use crate::grid::{cell::{Cell, CellID}, road_network::GridRoads};
+use crate::maneuver::{DrivingSide, LaneChangeType};
+use indexmap::IndexMap;
+use rand::{Rng, RngCore};
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+/// Shared reference to a driver model. Many vehicles could share the same model instance.
+pub type DriverModelRef = Arc<dyn DriverModel>;
+
+/// Surroundings of the vehicle which are available to a [`DriverModel`] during intention calculation.
+pub struct DriverContext<'a> {
+    /// The road network grid
+    pub net: &'a GridRoads,
+    /// Mapping from occupied cell ID to vehicle ID
+    pub current_state: &'a HashMap<CellID, VehicleID>,
+    /// The vehicle itself
+    pub vehicle: &'a Vehicle,
+    /// Cell occupied by the vehicle's head
+    pub cell: &'a Cell,
+    /// Effective speed limit: the cell's limit adjusted by the vehicle's compliance and bounded by its own limit (see [`Vehicle::effective_speed_limit`])
+    pub speed_limit: i32,
+    /// Current speed of the vehicle bounded by the effective speed limit
+    pub speed: i32,
+    /// Vehicles storage. It is needed to look at the leader's state and it is `None`
+    /// when the intention is computed for a standalone vehicle
+    pub vehicles: Option<&'a IndexMap<VehicleID, Vehicle>>,
+    /// Driving side of the road network (right-hand traffic by default)
+    pub driving_side: DrivingSide,
+}
+
+impl<'a> DriverContext<'a> {
+    /// Creates context for the vehicle located in the given cell.
+    ///
+    /// # Arguments
+    /// * `net` - The road network grid
+    /// * `current_state` - Mapping from occupied cell ID to vehicle ID
+    /// * `vehicle` - The vehicle
+    /// * `cell` - Cell occupied by the vehicle's head
+    pub fn new(
+        net: &'a GridRoads,
+        current_state: &'a HashMap<CellID, VehicleID>,
+        vehicle: &'a Vehicle,
+        cell: &'a Cell,
+    ) -> Self {
+        let speed_limit = vehicle.effective_speed_limit(cell.get_speed_limit());
+        DriverContext {
+            net,
+            current_state,
+            vehicle,
+            cell,
+            speed_limit,
+            speed: vehicle.speed.min(speed_limit).max(0),
+            vehicles: None,
+            driving_side: DrivingSide::default(),
+        }
+    }
+
+    /// Attaches vehicles storage to the context, so the leader could be inspected
+    /// (see [`DriverContext::leader_ahead`]).
+    pub fn with_vehicles(mut self, vehicles: &'a IndexMap<VehicleID, Vehicle>) -> Self {
+        self.vehicles = Some(vehicles);
+        self
+    }
+
+    /// Sets driving side for the context (see [`DrivingSide`]).
+    pub fn with_driving_side(mut self, driving_side: DrivingSide) -> Self {
+        self.driving_side = driving_side;
+        self
+    }
+
+    /// Counts free cells straight ahead (following forward connections) up to `max_cells`.
+    ///
+    /// Counting stops at the first cell occupied by other vehicle, at missing forward connection
+    /// or at a cell which is not in the grid.
+    ///
+    /// # Example
+    /// ```
+    /// use micro_traffic_sim_core::behaviour::DriverContext;
+    /// use micro_traffic_sim_core::agents::Vehicle;
+    /// use micro_traffic_sim_core::grid::road_network::GridRoads;
+    /// use micro_traffic_sim_core::utils::generators::generate_one_lane_cells;
+    /// use std::collections::HashMap;
+    ///
+    /// let mut net = GridRoads::new();
+    /// for cell in generate_one_lane_cells(100.0, 10.0, 1) {
+    ///     net.add_cell(cell);
+    /// }
+    /// let vehicle = Vehicle::new(1).with_cell(1).build();
+    /// let current_state = HashMap::from([(1, 1), (5, 2)]);
+    /// let ctx = DriverContext::new(&net, &current_state, &vehicle, net.get_cell(&1).unwrap());
+    /// assert_eq!(ctx.gap_ahead(10), 3);
+    /// assert_eq!(ctx.gap_ahead(2), 2);
+    /// ```
+    pub fn gap_ahead(&self, max_cells: i32) -> i32 {
+        self.scan_ahead(max_cells).0
+    }
+
+    /// Finds the nearest vehicle straight ahead within `max_cells` free cells.
+    ///
+    /// # Returns
+    /// Number of free cells to the leader and the leader itself. `None` if there is no leader
+    /// within the distance or vehicles storage is not attached to the context.
+    ///
+    /// # Example
+    /// ```
+    /// use micro_traffic_sim_core::behaviour::DriverContext;
+    /// use micro_traffic_sim_core::agents::Vehicle;
+    /// use micro_traffic_sim_core::grid::road_network::GridRoads;
+    /// use micro_traffic_sim_core::utils::generators::generate_one_lane_cells;
+    /// use indexmap::IndexMap;
+    /// use std::collections::HashMap;
+    ///
+    /// let mut net = GridRoads::new();
+    /// for cell in generate_one_lane_cells(100.0, 10.0, 1) {
+    ///     net.add_cell(cell);
+    /// }
+    /// let vehicles = IndexMap::from([
+    ///     (1, Vehicle::new(1).with_cell(1).build()),
+    ///     (2, Vehicle::new(2).with_cell(5).with_speed(2).build()),
+    /// ]);
+    /// let current_state = HashMap::from([(1, 1), (5, 2)]);
+    /// let ctx = DriverContext::new(&net, &current_state, &vehicles[&1], net.get_cell(&1).unwrap())
+    ///     .with_vehicles(&vehicles);
+    /// let (gap, leader) = ctx.leader_ahead(10).unwrap();
+    /// assert_eq!((gap, leader.id), (3, 2));
+    /// assert!(ctx.leader_ahead(2).is_none());
+    /// ```
+    pub fn leader_ahead(&self, max_cells: i32) -> Option<(i32, &'a Vehicle)> {
+        let (gap, leader_id) = self.scan_ahead(max_cells);
+        let leader = self.vehicles?.get(&leader_id?)?;
+        Some((gap, leader))
+    }
+
+    /// Counts free cells straight ahead up to `max_cells` and returns them with
+    /// the ID of the vehicle which stopped counting (if any).
+    fn scan_ahead(&self, max_cells: i32) -> (i32, Option<VehicleID>) {
+        let mut gap = 0;
+        let mut cell = self.cell;
+        while gap < max_cells {
+            let next_id = cell.get_forward_id();
+            if next_id < 0 {
+                break;
+            }
+            if let Some(&vehicle_id) = self.current_state.get(&next_id)
+                && vehicle_id != self.vehicle.id
+            {
+                return (gap, Some(vehicle_id));
+            }
+            cell = match self.net.get_cell(&next_id) {
+                Some(next_cell) => next_cell,
+                None => break,
+            };
+            gap += 1;
+        }
+        (gap, None)
+    }
+}
+
+/// Driver behaviour model: rules which the vehicle follows to decide its speed and lane changes.
+///
+/// Intentions module asks the model in the following order:
+/// 1. [`DriverModel::desired_speed`] - speed the driver wants to reach in this step (acceleration rule);
+/// 2. [`DriverModel::slowdown`] - random deceleration of the desired speed;
+/// 3. [`DriverModel::safe_distance`] - extra cells to observe ahead;
+/// 4. [`DriverModel::anticipates`] - whether cells vacated by the leader in the same time unit could be taken;
+/// 5. [`DriverModel::lane_change`] - lane to change to when the vehicle is blocked.
+///
+/// Then the path ahead is checked for obstacles (other vehicles, traffic lights, conflicts),
+/// so the model does not need to guarantee collision-free speed.
+/// Returned speeds are bounded by [0; effective speed limit].
+///
+/// [`NaSchModel`] is the default implementation. A vehicle carries a custom model
+/// via [`Vehicle::driver_model`](crate::agents::Vehicle::driver_model).
+///
+/// # Example
+/// ```
+/// use micro_traffic_sim_core::behaviour::{DriverContext, DriverModel};
+/// use rand::RngCore;
+///
+/// /// Drivers who always go at the speed limit and never slow down randomly
+/// #[derive(Debug)]
+/// struct Deterministic;
+///
+/// impl DriverModel for Deterministic {
+///     fn desired_speed(&self, ctx: &DriverContext) -> i32 {
+///         ctx.speed_limit
+///     }
+///     fn slowdown(&self, _ctx: &DriverContext, desired_speed: i32, _rng: &mut dyn RngCore) -> i32 {
+///         desired_speed
+///     }
+/// }
+/// ```
+pub trait DriverModel: fmt::Debug + Send + Sync {
+    /// Returns speed the vehicle wants to reach in the current step (before random slowdown).
+    fn desired_speed(&self, ctx: &DriverContext) -> i32;
+
+    /// Applies random slowdown to the desired speed and returns the resulting speed.
+    fn slowdown(&self, ctx: &DriverContext, desired_speed: i32, rng: &mut dyn RngCore) -> i32;
+
+    /// Returns number of extra cells (beyond the speed) the driver observes ahead.
+    /// Default is the vehicle's `min_safe_distance`.
+    fn safe_distance(&self, ctx: &DriverContext) -> i32 {
+        ctx.vehicle.min_safe_distance
+    }
+
+    /// Tells whether the driver anticipates the leader's movement (velocity anticipation).
+    ///
+    /// Anticipating driver could take cells occupied by the leader right now if the leader
+    /// vacates them in the same time unit. Such cells are collected into
+    /// [`VehicleIntention::anticipated_cells`](crate::agents::VehicleIntention::anticipated_cells)
+    /// (bounded by the speed which has not been realized due the leader) and are taken after
+    /// the regular movement. Default is `false`.
+    fn anticipates(&self, _ctx: &DriverContext) -> bool {
+        false
+    }
+
+    /// Picks lane change for the blocked vehicle.
+    ///
+    /// # Arguments
+    /// * `ctx` - Surroundings of the vehicle
+    /// * `left` - Remaining distance to the destination via the left lane or `None` if the left lane is not available
+    /// * `right` - Remaining distance to the destination via the right lane or `None` if the right lane is not available.
+    ///
+    /// Distance is `f64::INFINITY` if the lane is available, but the destination is unreachable from it.
+    ///
+    /// # Returns
+    /// `ChangeLeft`, `ChangeRight` or `Block` (stay in the current lane).
+    /// Unavailable lane is never picked: such answer is treated as `Block`.
+    fn lane_change(&self, ctx: &DriverContext, left: Option<f64>, right: Option<f64>) -> LaneChangeType {
+        preferred_lane_change(ctx.driving_side, left, right)
+    }
+}
+
+/// Picks the lane with the least distance to the destination. Ties are resolved in favour of
+/// the slow lane defined by the driving side. The overtaking lane is picked only when it is strictly shorter.
+fn preferred_lane_change(driving_side: DrivingSide, left: Option<f64>, right: Option<f64>) -> LaneChangeType {
+    let (slow, overtaking) = match driving_side {
+        DrivingSide::Right => (right, left),
+        DrivingSide::Left => (left, right),
+    };
+    let slow_dist = slow.unwrap_or(f64::INFINITY);
+    let overtaking_dist = overtaking.unwrap_or(f64::INFINITY);
+    if overtaking.is_some() && overtaking_dist < slow_dist {
+        return driving_side.overtaking_maneuver();
+    }
+    if slow.is_some() {
+        return driving_side.slow_lane_maneuver();
+    }
+    LaneChangeType::Block
+}
+
+/// Nagel–Schreckenberg rules (default driver model):
+/// 1. Acceleration by the vehicle's speed increase (one cell per step by default, see
+///    [`Vehicle::speed_increase`]) up to the speed limit. It is prohibited while the
+///    vehicle's `timer_non_acceleration` is active, unless the vehicle is stopped;
+/// 2. With probability `slow_down_factor` the vehicle does not accelerate
+///    (unless `timer_non_slowdown` is active or the vehicle is stopped).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct NaSchModel;
+
+impl DriverModel for NaSchModel {
+    fn desired_speed(&self, ctx: &DriverContext) -> i32 {
+        let acceleration_allowed = ctx.vehicle.timer_non_acceleration <= 0 || ctx.vehicle.speed == 0;
+        if acceleration_allowed {
+            (ctx.speed + ctx.vehicle.speed_increase()).min(ctx.speed_limit)
+        } else {
+            ctx.speed
+        }
+    }
+
+    fn slowdown(&self, ctx: &DriverContext, desired_speed: i32, rng: &mut dyn RngCore) -> i32 {
+        let slowdown_allowed = ctx.vehicle.timer_non_slowdown <= 0;
+        if slowdown_allowed && ctx.speed > 0 && rng.random::<f64>() < ctx.vehicle.slow_down_factor {
+            return desired_speed.min(ctx.speed);
+        }
+        desired_speed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::generators::generate_one_lane_cells;
+    use crate::utils::rand::rng;
+
+    #[test]
+    fn test_nasch_model() {
+        let mut net = GridRoads::new();
+        for cell in generate_one_lane_cells(100.0, 10.0, 1) {
+            net.add_cell(cell);
+        }
+        let current_state = HashMap::new();
+        let cell = net.get_cell(&2).unwrap();
+        let mut rng = rng();
+
+        // Acceleration up to cell's speed limit (3)
+        let vehicle = Vehicle::new(1).with_cell(2).with_speed(3).with_speed_limit(5).with_slowdown(0.0).build();
+        let ctx = DriverContext::new(&net, &current_state, &vehicle, cell);
+        assert_eq!(ctx.speed_limit, 3);
+        assert_eq!(NaSchModel.desired_speed(&ctx), 3);
+        assert_eq!(NaSchModel.slowdown(&ctx, 3, &mut rng), 3);
+
+        // Acceleration is blocked by timer
+        let vehicle = Vehicle::new(1).with_cell(2).with_speed(1).with_acceleration_delay(2).build();
+        let ctx = DriverContext::new(&net, &current_state, &vehicle, cell);
+        assert_eq!(NaSchModel.desired_speed(&ctx), 1);
+
+        // Slowdown always happens: no acceleration
+        let vehicle = Vehicle::new(1).with_cell(2).with_speed(1).with_slowdown(1.0).build();
+        let ctx = DriverContext::new(&net, &current_state, &vehicle, cell);
+        assert_eq!(NaSchModel.slowdown(&ctx, 2, &mut rng), 1);
+
+        // Lane change preferences
+        assert_eq!(NaSchModel.lane_change(&ctx, Some(1.0), Some(2.0)), LaneChangeType::ChangeLeft);
+        assert_eq!(NaSchModel.lane_change(&ctx, Some(2.0), Some(2.0)), LaneChangeType::ChangeRight);
+        assert_eq!(NaSchModel.lane_change(&ctx, None, Some(f64::INFINITY)), LaneChangeType::ChangeRight);
+        assert_eq!(NaSchModel.lane_change(&ctx, Some(f64::INFINITY), None), LaneChangeType::Block);
+        assert_eq!(NaSchModel.lane_change(&ctx, None, None), LaneChangeType::Block);
+
+        // Left-hand traffic prefers the left lane
+        let ctx = ctx.with_driving_side(DrivingSide::Left);
+        assert_eq!(NaSchModel.lane_change(&ctx, Some(2.0), Some(1.0)), LaneChangeType::ChangeRight);
+        assert_eq!(NaSchModel.lane_change(&ctx, Some(2.0), Some(2.0)), LaneChangeType::ChangeLeft);
+        assert_eq!(NaSchModel.lane_change(&ctx, Some(f64::INFINITY), None), LaneChangeType::ChangeLeft);
+        assert_eq!(NaSchModel.lane_change(&ctx, None, Some(f64::INFINITY)), LaneChangeType::Block);
+    }
+}
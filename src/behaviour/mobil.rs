@@ -0,0 +1,138 @@
+/// Parameters of incentive-based discretionary lane changing (MOBIL - "Minimizing Overall Braking Induced by Lane changes").
+///
+/// Vehicle which is not blocked changes the lane when the incentive criterion holds:
+/// ```text
+/// gain + politeness * (new_follower_gain + old_follower_gain) > threshold
+/// ```
+/// where `gain` is the expected speed gain of the vehicle itself, `new_follower_gain` (non-positive)
+/// is the expected speed change of the vehicle which becomes the follower in the target lane and
+/// `old_follower_gain` (non-negative) is the expected speed change of the current follower.
+/// Expected speeds are cellular automata ones: `min(v + a, v_max, gap)`.
+///
+/// Safety criterion: the new follower should not be forced to slow down by more than `safe_deceleration`
+/// cells per time unit.
+///
+/// With `keep_slow_lane` (default) the rules are asymmetric according to the driving side
+/// (see [`DrivingSide`](crate::maneuver::DrivingSide)): the incentive criterion is used for lane changes into
+/// the overtaking lane only, and the vehicle gets back to the slow lane as soon as it is free (keep-right rule
+/// for right-hand traffic, keep-left rule for left-hand traffic). Without it both lanes are evaluated by the incentive criterion.
+///
+/// After a discretionary lane change the vehicle does not change lanes for `maneuver_delay`
+/// time units (see `Vehicle::timer_non_maneuvers`).
+///
+/// # Example
+/// ```
+/// use micro_traffic_sim_core::behaviour::MobilParameters;
+/// let params = MobilParameters::new()
+///     .with_politeness(0.5)
+///     .with_threshold(1.0)
+///     .build();
+/// assert_eq!(params.politeness, 0.5);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MobilParameters {
+    /// Weight of the followers' disadvantage: 0 - selfish driver, 1 - altruistic one
+    pub politeness: f64,
+    /// Minimal advantage (in cells per time unit) to change the lane
+    pub threshold: f64,
+    /// Maximum deceleration (in cells per time unit) which could be imposed on the new follower
+    pub safe_deceleration: i32,
+    /// Number of time units between discretionary lane changes
+    pub maneuver_delay: i64,
+    /// Keep right (left) rule: overtake via the overtaking lane only and get back to the slow lane when it is free
+    pub keep_slow_lane: bool,
+}
+
+impl MobilParameters {
+    /// Constructs a new `MobilParametersBuilder` with default parameters:
+    /// politeness 0.3, threshold 1.0, safe deceleration 2, maneuver delay 2, keep slow lane rule enabled.
+    ///
+    /// # Returns
+    /// A `MobilParametersBuilder` struct which is used to configure and build the `MobilParameters` object.
+    pub fn new() -> MobilParametersBuilder {
+        MobilParametersBuilder {
+            params: MobilParameters {
+                politeness: 0.3,
+                threshold: 1.0,
+                safe_deceleration: 2,
+                maneuver_delay: 2,
+                keep_slow_lane: true,
+            },
+        }
+    }
+
+    /// Checks the incentive criterion.
+    ///
+    /// # Arguments
+    /// * `gain` - Expected speed gain of the vehicle
+    /// * `new_follower_gain` - Expected speed change of the new follower
+    /// * `old_follower_gain` - Expected speed change of the old follower
+    ///
+    /// # Example
+    /// ```
+    /// use micro_traffic_sim_core::behaviour::MobilParameters;
+    /// let params = MobilParameters::new().with_politeness(0.5).with_threshold(1.0).build();
+    /// assert!(params.incentive(2, 0, 0));
+    /// assert!(!params.incentive(2, -2, 0));
+    /// assert!(params.incentive(2, -2, 1));
+    /// ```
+    pub fn incentive(&self, gain: i32, new_follower_gain: i32, old_follower_gain: i32) -> bool {
+        gain as f64 + self.politeness * (new_follower_gain + old_follower_gain) as f64 > self.threshold
+    }
+
+    /// Checks the safety criterion for the new follower moving with `follower_speed` and having
+    /// `follower_gap` free cells ahead after the lane change.
+    ///
+    /// # Example
+    /// ```
+    /// use micro_traffic_sim_core::behaviour::MobilParameters;
+    /// let params = MobilParameters::new().with_safe_deceleration(1).build();
+    /// assert!(params.is_safe(3, 2));
+    /// assert!(!params.is_safe(3, 1));
+    /// ```
+    pub fn is_safe(&self, follower_speed: i32, follower_gap: i32) -> bool {
+        follower_speed - follower_gap <= self.safe_deceleration
+    }
+}
+
+/// Builder for [`MobilParameters`]
+pub struct MobilParametersBuilder {
+    params: MobilParameters,
+}
+
+impl MobilParametersBuilder {
+    /// Sets politeness factor: 0 - selfish driver, 1 - altruistic one
+    pub fn with_politeness(mut self, politeness: f64) -> Self {
+        self.params.politeness = politeness;
+        self
+    }
+
+    /// Sets minimal advantage (in cells per time unit) to change the lane
+    pub fn with_threshold(mut self, threshold: f64) -> Self {
+        self.params.threshold = threshold;
+        self
+    }
+
+    /// Sets maximum deceleration (in cells per time unit) which could be imposed on the new follower
+    pub fn with_safe_deceleration(mut self, safe_deceleration: i32) -> Self {
+        self.params.safe_deceleration = safe_deceleration;
+        self
+    }
+
+    /// Sets number of time units between discretionary lane changes
+    pub fn with_maneuver_delay(mut self, maneuver_delay: i64) -> Self {
+        self.params.maneuver_delay = maneuver_delay;
+        self
+    }
+
+    /// Enables or disables keep right (left) rule. Disabled rule makes lane changes symmetric
+    pub fn with_keep_slow_lane(mut self, keep_slow_lane: bool) -> Self {
+        self.params.keep_slow_lane = keep_slow_lane;
+        self
+    }
+
+    /// Builds the final `MobilParameters` object
+    pub fn build(self) -> MobilParameters {
+        self.params
+    }
+}
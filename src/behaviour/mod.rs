@@ -1,4 +1,14 @@
 //! Behaviour module.
+//!
+//! - [`BehaviourType`] and [`BehaviourParameters`] - predefined behaviour types and their parameters
+//! - [`BehaviourDistributions`] and [`ParameterDistribution`] - distributions of driver parameters (heterogeneous drivers)
+//! - [`DriverModel`] - pluggable rules of speed choice and lane changing. [`NaSchModel`] is the default one
+//! - [`MobilParameters`] - incentive and safety criteria of discretionary lane changing (MOBIL)
+//! - [`VdrModel`], [`TakayasuModel`], [`BjhModel`] and [`AnticipationModel`] - classic cellular automata variants of NaSch
 mod behaviour;
+mod ca_models;
+mod distributions;
+mod driver_model;
+mod mobil;
 
-pub use self::{behaviour::*};
+pub use self::{behaviour::*, ca_models::*, distributions::*, driver_model::*, mobil::*};
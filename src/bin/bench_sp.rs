@@ -1,3 +1,4 @@
+use micro_traffic_sim_core::agents_types::AgentType;
 use micro_traffic_sim_core::grid::road_network::GridRoads;
 use micro_traffic_sim_core::shortest_path::router::shortest_path;
 use micro_traffic_sim_core::utils::generators::generate_one_lane_cells;
@@ -15,7 +16,7 @@ pub fn main() {
     for _ in 0..100 {
         let start_cell = &cells_data[0];
         let end_cell = &cells_data[cells_data.len() - 1];
-        match shortest_path(start_cell, end_cell, &grid, true, None) {
+        match shortest_path(start_cell, end_cell, &grid, true, None, AgentType::Car) {
             Ok(path) => {
                 // Do something with the path, if needed
                 let _ = path;
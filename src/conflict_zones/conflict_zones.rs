@@ -5,14 +5,48 @@ use crate::grid::cell::CellID;
 pub type ConflictZoneID = i32;
 
 /// Types of conflict zones for different management strategies.
-/// 
-/// Currently only [`Undefined`](ConflictZoneType::Undefined) is implemented, 
-/// but this enum is designed for future extensions.
+///
+/// Besides the zone classification the kinds describe priority controls of unsignalised junction approaches
+/// (see [`PriorityJunction`](crate::conflict_zones::PriorityJunction)).
 ///
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ConflictZoneType {
     /// Default uninitialized state.
     Undefined,
+    /// Priority road: vehicles enter the junction without any checks.
+    PriorityRoad,
+    /// Yield sign: vehicles enter the junction only if there is an acceptable gap before priority vehicles arrive.
+    Yield,
+    /// Stop sign: vehicles come to a full stop in front of the junction and then enter it as on yield approaches.
+    Stop,
+    /// All-way stop: vehicles come to a full stop and enter the junction one by one in order of arrival.
+    AllWayStop,
+}
+
+impl fmt::Display for ConflictZoneType {
+    /// Formats the conflict zone type for display.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use micro_traffic_sim_core::conflict_zones::ConflictZoneType;
+    ///
+    /// assert_eq!(format!("{}", ConflictZoneType::Undefined), "undefined");
+    /// assert_eq!(format!("{}", ConflictZoneType::PriorityRoad), "priority_road");
+    /// assert_eq!(format!("{}", ConflictZoneType::Yield), "yield");
+    /// assert_eq!(format!("{}", ConflictZoneType::Stop), "stop");
+    /// assert_eq!(format!("{}", ConflictZoneType::AllWayStop), "all_way_stop");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let as_str = match self {
+            ConflictZoneType::Undefined => "undefined",
+            ConflictZoneType::PriorityRoad => "priority_road",
+            ConflictZoneType::Yield => "yield",
+            ConflictZoneType::Stop => "stop",
+            ConflictZoneType::AllWayStop => "all_way_stop",
+        };
+        write!(f, "{}", as_str)
+    }
 }
 
 /// Priority rules determining which traffic flow has precedence in a conflict zone.
@@ -44,6 +78,10 @@ pub enum ConflictWinnerType {
     /// Second edge (second traffic flow) has priority over first edge.
     /// First edge traffic must yield to second edge traffic.
     Second,
+    /// Traffic flows take turns (zipper merge, e.g. at lane drops).
+    /// First edge has priority in the first conflict, then priority passes to the other edge after each
+    /// conflict in the zone. Applies to edges with the same target cell.
+    Alternate,
 }
 
 /// Represents an edge in a conflict zone.
@@ -74,13 +112,15 @@ impl std::error::Error for ConflictZoneError {}
 /// Represents a conflict zone in the traffic simulation system.
 ///
 /// A `ConflictZone` models an area where paths intersect, with properties like type, winner, and involved edges.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct ConflictZone {
     id: ConflictZoneID,
     zone_type: ConflictZoneType,
     winner_type: ConflictWinnerType,
     first_edge: ConflictEdge,
     second_edge: ConflictEdge,
+    /// Edge which has priority in the next conflict for the alternating zones (`First` or `Second`)
+    turn: ConflictWinnerType,
 }
 
 impl ConflictZone {
@@ -111,6 +151,7 @@ impl ConflictZone {
                 winner_type: ConflictWinnerType::Equal,  // Default: Equal
                 first_edge,
                 second_edge,
+                turn: ConflictWinnerType::First,
             },
         }
     }
@@ -130,6 +171,39 @@ impl ConflictZone {
         self.winner_type
     }
 
+    /// Returns the winner type for the next conflict in the zone: the current turn for
+    /// [`ConflictWinnerType::Alternate`] zones and the winner type otherwise.
+    ///
+    /// # Example
+    /// ```
+    /// use micro_traffic_sim_core::conflict_zones::{ConflictEdge, ConflictZone, ConflictWinnerType};
+    /// let mut zone = ConflictZone::new(1, ConflictEdge { source: 1, target: 3 }, ConflictEdge { source: 2, target: 3 })
+    ///     .with_winner_type(ConflictWinnerType::Alternate)
+    ///     .build();
+    /// assert_eq!(zone.get_current_winner_type(), ConflictWinnerType::First);
+    /// zone.pass_turn();
+    /// assert_eq!(zone.get_current_winner_type(), ConflictWinnerType::Second);
+    /// zone.pass_turn();
+    /// assert_eq!(zone.get_current_winner_type(), ConflictWinnerType::First);
+    /// ```
+    pub fn get_current_winner_type(&self) -> ConflictWinnerType {
+        match self.winner_type {
+            ConflictWinnerType::Alternate => self.turn,
+            winner_type => winner_type,
+        }
+    }
+
+    /// Passes priority to the other edge after a conflict in the alternating zone. Does nothing for other zones.
+    pub fn pass_turn(&mut self) {
+        if self.winner_type != ConflictWinnerType::Alternate {
+            return;
+        }
+        self.turn = match self.turn {
+            ConflictWinnerType::First => ConflictWinnerType::Second,
+            _ => ConflictWinnerType::First,
+        };
+    }
+
     /// Returns the first edge of the conflict zone.
     pub fn get_first_edge(&self) -> &ConflictEdge {
         &self.first_edge
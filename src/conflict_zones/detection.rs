@@ -0,0 +1,288 @@
+use crate::conflict_zones::{ConflictEdge, ConflictWinnerType, ConflictZone, ConflictZoneID};
+use crate::geom::{get_bearing, Point};
+use crate::grid::cell::{Cell, CellID};
+use crate::grid::road_network::GridRoads;
+use crate::intentions::TURN_ANGLE_THRESHOLD;
+use crate::maneuver::LaneChangeType;
+use std::collections::BTreeMap;
+
+/// Default priority rule for detected conflict zones (see [`detect_conflict_zones`]).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ConflictWinnerRule {
+    /// No priority: zones get [`ConflictWinnerType::Equal`] winner
+    #[default]
+    Equal,
+    /// Link which source cell has the higher speed limit has priority (main road vs secondary road)
+    SpeedLimit,
+    /// Straight movement has priority over turning one (lane changes are turns too).
+    /// Movement is straight when it deviates from at least one incoming forward link by no more than
+    /// [`TURN_ANGLE_THRESHOLD`] degrees.
+    StraightOverTurning,
+}
+
+/// Link between two cells: forward, left or right connection
+struct Link<'a> {
+    source: &'a Cell,
+    target: &'a Cell,
+    maneuver: LaneChangeType,
+}
+
+impl Link<'_> {
+    fn edge(&self) -> ConflictEdge {
+        ConflictEdge { source: self.source.get_id(), target: self.target.get_id() }
+    }
+
+    /// Bounding box of the link's segment: (min x, max x, min y, max y)
+    fn bounds(&self) -> (f64, f64, f64, f64) {
+        let (a, b) = (self.source.get_point(), self.target.get_point());
+        (a.x().min(b.x()), a.x().max(b.x()), a.y().min(b.y()), a.y().max(b.y()))
+    }
+}
+
+/// Checks if cells belong to the same link: it is the same cell or cells share the mesoscopic link
+fn same_link(a: &Cell, b: &Cell) -> bool {
+    a.get_id() == b.get_id() || (a.get_meso_link_id() >= 0 && a.get_meso_link_id() == b.get_meso_link_id())
+}
+
+/// Orientation of the point `c` relative to the directed segment `a → b` (sign of the cross product)
+fn orientation(a: (f64, f64), b: (f64, f64), c: (f64, f64)) -> f64 {
+    (b.0 - a.0) * (c.1 - a.1) - (b.1 - a.1) * (c.0 - a.0)
+}
+
+/// Checks if segments of the links properly intersect: they cross each other in a single point
+/// which is not an end of any of them
+fn segments_intersect(one: &Link, two: &Link) -> bool {
+    let xy = |cell: &Cell| (cell.get_point().x(), cell.get_point().y());
+    let (a1, a2) = (xy(one.source), xy(one.target));
+    let (b1, b2) = (xy(two.source), xy(two.target));
+    orientation(b1, b2, a1) * orientation(b1, b2, a2) < 0.0 && orientation(a1, a2, b1) * orientation(a1, a2, b2) < 0.0
+}
+
+/// Checks if the link is a turn: lane change or forward movement deviating from every incoming forward link
+fn is_turning_link(net: &GridRoads, link: &Link) -> bool {
+    if link.maneuver != LaneChangeType::NoChange {
+        return true;
+    }
+    let bearing = get_bearing(link.source.get_point(), link.target.get_point());
+    let incoming = net.get_forward_incoming(&link.source.get_id());
+    !incoming.is_empty()
+        && incoming.iter().filter_map(|cell_id| net.get_cell(cell_id)).all(|previous| {
+            let deviation = (bearing - get_bearing(previous.get_point(), link.source.get_point())).rem_euclid(360.0);
+            deviation.min(360.0 - deviation) > TURN_ANGLE_THRESHOLD
+        })
+}
+
+/// Picks the winner of the conflict between two links according to the rule
+fn winner_type(net: &GridRoads, first: &Link, second: &Link, rule: ConflictWinnerRule) -> ConflictWinnerType {
+    let (first_priority, second_priority) = match rule {
+        ConflictWinnerRule::Equal => return ConflictWinnerType::Equal,
+        ConflictWinnerRule::SpeedLimit => (first.source.get_speed_limit(), second.source.get_speed_limit()),
+        ConflictWinnerRule::StraightOverTurning => {
+            (!is_turning_link(net, first) as i32, !is_turning_link(net, second) as i32)
+        }
+    };
+    match first_priority.cmp(&second_priority) {
+        std::cmp::Ordering::Greater => ConflictWinnerType::First,
+        std::cmp::Ordering::Less => ConflictWinnerType::Second,
+        std::cmp::Ordering::Equal => ConflictWinnerType::Equal,
+    }
+}
+
+/// Scans the grid and proposes conflict zones for every pair of links (forward, left or right connections)
+/// which have different source links and either share the target cell or which segments intersect geometrically.
+///
+/// Source links are different when source cells are different and do not share the mesoscopic link
+/// (see [`Cell::get_meso_link_id`]), so lanes of the same road should share it to skip lane changes between them.
+/// Segments are intersected on the plane of cells coordinates, segments touching each other by ends are not intersecting.
+///
+/// Zones are ordered by their edges: the first edge of a zone is the one with the lower source (then target) cell ID.
+///
+/// # Arguments
+/// * `net` - The road network grid
+/// * `rule` - Default priority rule (see [`ConflictWinnerRule`])
+/// * `first_id` - Identifier of the first zone, others are numbered sequentially
+///
+/// # Returns
+/// Proposed conflict zones. Register them with [`Session::add_conflict_zone`](crate::simulation::session::Session::add_conflict_zone)
+/// or use [`Session::add_detected_conflict_zones`](crate::simulation::session::Session::add_detected_conflict_zones).
+///
+/// # Example
+/// ```
+/// use micro_traffic_sim_core::conflict_zones::{detect_conflict_zones, ConflictEdge, ConflictWinnerRule, ConflictWinnerType};
+/// use micro_traffic_sim_core::geom::new_point;
+/// use micro_traffic_sim_core::grid::{cell::Cell, road_network::GridRoads};
+///
+/// // Main road (1) → (2) crossed by secondary road (3) → (4)
+/// //        (4)
+/// //         ↑
+/// // (1) ────┼───→ (2)
+/// //         │
+/// //        (3)
+/// let mut net = GridRoads::new();
+/// net.add_cell(Cell::new(1).with_point(new_point(0.0, 0.0, None)).with_speed_limit(3).with_forward_node(2).build());
+/// net.add_cell(Cell::new(2).with_point(new_point(2.0, 0.0, None)).build());
+/// net.add_cell(Cell::new(3).with_point(new_point(1.0, -1.0, None)).with_speed_limit(1).with_forward_node(4).build());
+/// net.add_cell(Cell::new(4).with_point(new_point(1.0, 1.0, None)).build());
+///
+/// let zones = detect_conflict_zones(&net, ConflictWinnerRule::SpeedLimit, 1);
+/// assert_eq!(zones.len(), 1);
+/// assert_eq!(zones[0].get_first_edge(), &ConflictEdge { source: 1, target: 2 });
+/// assert_eq!(zones[0].get_second_edge(), &ConflictEdge { source: 3, target: 4 });
+/// assert_eq!(zones[0].get_winner_type(), ConflictWinnerType::First);
+/// ```
+pub fn detect_conflict_zones(net: &GridRoads, rule: ConflictWinnerRule, first_id: ConflictZoneID) -> Vec<ConflictZone> {
+    let mut cells: Vec<&Cell> = net.iter().map(|(_, cell)| cell).collect();
+    cells.sort_by_key(|cell| cell.get_id());
+    let mut links: Vec<Link> = Vec::new();
+    for &source in cells.iter() {
+        let connections = [
+            (source.get_forward_id(), LaneChangeType::NoChange),
+            (source.get_left_id(), LaneChangeType::ChangeLeft),
+            (source.get_right_id(), LaneChangeType::ChangeRight),
+        ];
+        for (target_id, maneuver) in connections {
+            if let Some(target) = net.get_cell(&target_id) {
+                links.push(Link { source, target, maneuver });
+            }
+        }
+    }
+
+    // Pairs of links indices (the lower one goes first, since links are sorted by source cell ID)
+    let mut pairs: BTreeMap<(CellID, CellID, CellID, CellID), (usize, usize)> = BTreeMap::new();
+    let mut add_pair = |one: usize, two: usize, links: &[Link]| {
+        let (first, second) = if (links[one].source.get_id(), links[one].target.get_id())
+            <= (links[two].source.get_id(), links[two].target.get_id())
+        {
+            (one, two)
+        } else {
+            (two, one)
+        };
+        let (a, b) = (links[first].edge(), links[second].edge());
+        pairs.insert((a.source, a.target, b.source, b.target), (first, second));
+    };
+
+    // Shared target cell
+    let mut by_target: BTreeMap<CellID, Vec<usize>> = BTreeMap::new();
+    for (idx, link) in links.iter().enumerate() {
+        by_target.entry(link.target.get_id()).or_default().push(idx);
+    }
+    for group in by_target.values() {
+        for (i, &one) in group.iter().enumerate() {
+            for &two in group.iter().skip(i + 1) {
+                if !same_link(links[one].source, links[two].source) {
+                    add_pair(one, two, &links);
+                }
+            }
+        }
+    }
+
+    // Intersecting segments: sweep along X axis
+    let mut order: Vec<usize> = (0..links.len()).collect();
+    order.sort_by(|&a, &b| links[a].bounds().0.total_cmp(&links[b].bounds().0));
+    for (i, &one) in order.iter().enumerate() {
+        let (_, max_x, min_y, max_y) = links[one].bounds();
+        for &two in order.iter().skip(i + 1) {
+            let (other_min_x, _, other_min_y, other_max_y) = links[two].bounds();
+            if other_min_x > max_x {
+                break;
+            }
+            if other_min_y > max_y || other_max_y < min_y || same_link(links[one].source, links[two].source) {
+                continue;
+            }
+            if segments_intersect(&links[one], &links[two]) {
+                add_pair(one, two, &links);
+            }
+        }
+    }
+
+    pairs
+        .values()
+        .enumerate()
+        .map(|(idx, &(first, second))| {
+            ConflictZone::new(first_id + idx as ConflictZoneID, links[first].edge(), links[second].edge())
+                .with_winner_type(winner_type(net, &links[first], &links[second], rule))
+                .build()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geom::new_point;
+    use crate::utils::generators::generate_one_lane_cells;
+    use crate::utils::test_grids::{create_conflict_zones_grid, create_simple_cross_shape_grid};
+
+    fn cell(id: CellID, x: f64, y: f64, forward_id: CellID) -> Cell {
+        Cell::new(id).with_point(new_point(x, y, None)).with_forward_node(forward_id).with_speed_limit(2).build()
+    }
+
+    #[test]
+    fn test_detect_merge_and_chain() {
+        // Two roads merge into cell 3 and continue to cell 4
+        // (1) ↘
+        //      (3) → (4)
+        // (2) ↗
+        let mut net = GridRoads::new();
+        net.add_cell(cell(1, 0.0, 1.0, 3));
+        net.add_cell(cell(2, 0.0, -1.0, 3));
+        net.add_cell(cell(3, 1.0, 0.0, 4));
+        net.add_cell(cell(4, 2.0, 0.0, -1));
+        let zones = detect_conflict_zones(&net, ConflictWinnerRule::Equal, 10);
+        assert_eq!(zones.len(), 1);
+        assert_eq!(zones[0].get_id(), 10);
+        assert_eq!(zones[0].get_first_edge(), &ConflictEdge { source: 1, target: 3 });
+        assert_eq!(zones[0].get_second_edge(), &ConflictEdge { source: 2, target: 3 });
+        assert_eq!(zones[0].get_winner_type(), ConflictWinnerType::Equal);
+
+        // Cells of the same link are not in conflict
+        let mut net = GridRoads::new();
+        for (id, x, y, forward_id) in [(1, 0.0, 1.0, 3), (2, 0.0, -1.0, 3), (3, 1.0, 0.0, -1)] {
+            net.add_cell(Cell::new(id).with_point(new_point(x, y, None)).with_forward_node(forward_id).with_meso_link(1).build());
+        }
+        assert!(detect_conflict_zones(&net, ConflictWinnerRule::Equal, 1).is_empty());
+    }
+
+    #[test]
+    fn test_straight_over_turning() {
+        // Straight road (1) → (2) → (3) and the left turn (10) → (11) → (3) from the opposite direction
+        //        (11)
+        //      ↗     ↘
+        // (10)         (3)
+        //             ↗
+        // (1) → (2) ──
+        let mut net = GridRoads::new();
+        net.add_cell(cell(1, 0.0, 0.0, 2));
+        net.add_cell(cell(2, 1.0, 0.0, 3));
+        net.add_cell(cell(3, 2.0, 0.0, -1));
+        net.add_cell(cell(10, 0.0, 2.0, 11));
+        net.add_cell(cell(11, 1.0, 2.0, 3));
+        let zones = detect_conflict_zones(&net, ConflictWinnerRule::StraightOverTurning, 1);
+        assert_eq!(zones.len(), 1);
+        assert_eq!(zones[0].get_winner_type(), ConflictWinnerType::First);
+        // Both are straight without the incoming link of the turn
+        let zones = detect_conflict_zones(&net, ConflictWinnerRule::SpeedLimit, 1);
+        assert_eq!(zones[0].get_winner_type(), ConflictWinnerType::Equal);
+    }
+
+    #[test]
+    fn test_hand_declared_zones() {
+        let detected_edges = |net: &GridRoads| -> Vec<(ConflictEdge, ConflictEdge)> {
+            detect_conflict_zones(net, ConflictWinnerRule::Equal, 1)
+                .into_iter()
+                .map(|zone| (zone.get_first_edge().clone(), zone.get_second_edge().clone()))
+                .collect()
+        };
+        let edge = |source: CellID, target: CellID| ConflictEdge { source, target };
+        // Crossing roads
+        assert!(detected_edges(&create_conflict_zones_grid()).contains(&(edge(3, 4), edge(9, 10))));
+        // Merging roads
+        assert!(detected_edges(&create_simple_cross_shape_grid()).contains(&(edge(2, 3), edge(7, 3))));
+        // Crossing lane changes on the multi-lane road
+        let mut net = GridRoads::new();
+        for cell in generate_one_lane_cells(31.5, 4.5, 3) {
+            net.add_cell(cell);
+        }
+        assert!(detected_edges(&net).contains(&(edge(4, 12), edge(11, 5))));
+    }
+}
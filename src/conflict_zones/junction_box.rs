@@ -0,0 +1,534 @@
+use crate::agents::{Vehicle, VehicleID};
+use crate::grid::cell::{CellID, CellState};
+use crate::grid::road_network::GridRoads;
+use crate::intentions::hold_back_intention;
+use crate::shortest_path::router::shortest_path;
+use indexmap::IndexMap;
+use std::collections::{HashMap, HashSet};
+
+/// Identifier for a junction box.
+pub type JunctionBoxID = i32;
+
+/// Movement through the junction box, identified by the approach it enters the box from.
+/// Lanes of the same approach road (sharing the mesoscopic link) make the same movement.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum BoxMovement {
+    Link(i64),
+    Cell(CellID),
+}
+
+/// Vehicle holding the junction box
+#[derive(Debug, Clone, PartialEq)]
+struct BoxOccupant {
+    movement: BoxMovement,
+    /// First cell after the box on the vehicle's way. Negative if the vehicle does not leave the box by known way
+    exit_cell_id: CellID,
+}
+
+/// Vehicle going to enter the junction box on the current step
+struct BoxCandidate {
+    vehicle_id: VehicleID,
+    /// Index of the first box cell in the vehicle's way (intermediate cells and the intention cell)
+    entry_index: usize,
+    movement: BoxMovement,
+    exit_cell_id: CellID,
+    /// Time (in time units) the vehicle needs to reach the exit cell
+    steps_to_exit: i32,
+}
+
+/// Junction box: set of cells (usually the whole junction area) locked by movements passing through it.
+///
+/// Conflict zones (see [`ConflictZone`](crate::conflict_zones::ConflictZone)) resolve conflicts between two
+/// edges only, so vehicles may enter the junction while other movements still occupy it, and stuck vehicles
+/// block crossing flows ("don't block the box" violations ending with gridlocks). The box is locked by the
+/// movement of vehicles inside it: on each step (see [`JunctionBox::hold_entering`]) a vehicle may enter the
+/// box only if
+/// - no other movement holds the box (vehicles of the same movement follow each other unless other movements
+///   are waiting, so flows take turns instead of starving);
+/// - it can reach the first cell after the box within `clearance_steps` time units, accelerating from its
+///   current speed (with its speed increase and acceleration delay) up to its effective speed limit;
+/// - there is room after the box: cells behind the exit are neither banned nor occupied by vehicles staying
+///   in place on the current step, one cell for each vehicle inside the box heading to the same exit plus one
+///   for the vehicle itself.
+///
+/// Vehicles which are not allowed to enter stop in front of the box (see [`hold_back_intention`]).
+#[derive(Debug, Clone)]
+pub struct JunctionBox {
+    id: JunctionBoxID,
+    cells: HashSet<CellID>,
+    /// Maximal time (in time units) to pass the box for entering vehicles
+    clearance_steps: i32,
+    /// Vehicles inside the box (or entering it on the current step)
+    occupants: HashMap<VehicleID, BoxOccupant>,
+    /// Movements waiting for the lock with the steps they have started waiting at
+    waiting: HashMap<BoxMovement, i32>,
+    /// Vehicles held on the last step mapped to vehicles they wait for
+    held: HashMap<VehicleID, Vec<VehicleID>>,
+    /// Vehicles allowed to enter the box regardless of the rules (see [`JunctionBox::grant_priority`])
+    prioritized: HashSet<VehicleID>,
+}
+
+impl JunctionBox {
+    /// Constructs a new `JunctionBoxBuilder` for building a `JunctionBox` object.
+    ///
+    /// # Arguments
+    /// * `id` - A unique identifier for the junction box.
+    ///
+    /// # Returns
+    /// A `JunctionBoxBuilder` struct which is used to configure and build the `JunctionBox` object.
+    ///
+    /// # Example
+    /// ```
+    /// use micro_traffic_sim_core::conflict_zones::JunctionBox;
+    /// // Junction area consists of four cells
+    /// let junction_box = JunctionBox::new(1)
+    ///     .with_cells(vec![10, 11, 20, 21])
+    ///     .with_clearance_steps(3)
+    ///     .build();
+    /// assert_eq!(junction_box.get_cells().len(), 4);
+    /// ```
+    pub fn new(id: JunctionBoxID) -> JunctionBoxBuilder {
+        JunctionBoxBuilder {
+            junction_box: JunctionBox {
+                id,
+                cells: HashSet::new(),
+                clearance_steps: 5,
+                occupants: HashMap::new(),
+                waiting: HashMap::new(),
+                held: HashMap::new(),
+                prioritized: HashSet::new(),
+            },
+        }
+    }
+
+    /// Returns the unique identifier (ID) of the junction box.
+    pub fn get_id(&self) -> JunctionBoxID {
+        self.id
+    }
+
+    /// Returns cells of the junction box.
+    pub fn get_cells(&self) -> &HashSet<CellID> {
+        &self.cells
+    }
+
+    /// Returns maximal time (in time units) to pass the box for entering vehicles.
+    pub fn get_clearance_steps(&self) -> i32 {
+        self.clearance_steps
+    }
+
+    /// Returns identifiers of vehicles holding the box.
+    pub fn get_occupants(&self) -> Vec<VehicleID> {
+        let mut occupants: Vec<VehicleID> = self.occupants.keys().copied().collect();
+        occupants.sort_unstable();
+        occupants
+    }
+
+    /// Returns vehicles held in front of the box on the last step mapped to vehicles they wait for:
+    /// vehicles of other movements inside the box or vehicles staying in place after the box exit.
+    /// The list is empty if the vehicle waits for other reasons (e.g. banned cells after the exit or waiting movements).
+    pub fn get_held(&self) -> &HashMap<VehicleID, Vec<VehicleID>> {
+        &self.held
+    }
+
+    /// Allows the vehicle to enter the box regardless of the locking rules once, e.g. to resolve a gridlock
+    /// (see [`GridlockPolicy::Priority`](crate::simulation::gridlock::GridlockPolicy::Priority)).
+    ///
+    /// # Arguments
+    /// * `vehicle_id` - Vehicle going to enter the box
+    pub fn grant_priority(&mut self, vehicle_id: VehicleID) {
+        self.prioritized.insert(vehicle_id);
+    }
+
+    /// Forgets vehicles holding the box and waiting for it.
+    pub fn reset(&mut self) {
+        self.occupants.clear();
+        self.waiting.clear();
+        self.held.clear();
+        self.prioritized.clear();
+    }
+
+    /// Makes vehicles which are not allowed to enter the box on the current step hold back in front of it
+    /// (see [`JunctionBox`] for the locking rules).
+    ///
+    /// # Arguments
+    /// * `net` - The road network grid
+    /// * `current_state` - Mapping from occupied cell ID to vehicle ID
+    /// * `vehicles` - Vehicles storage with prepared intentions
+    /// * `step` - Current step (used for the order of waiting movements)
+    ///
+    /// # Returns
+    /// Identifiers of vehicles which intentions have been changed (in the storage order).
+    ///
+    /// # Example
+    /// ```
+    /// use micro_traffic_sim_core::agents::{Vehicle, VehicleIntention};
+    /// use micro_traffic_sim_core::conflict_zones::JunctionBox;
+    /// use micro_traffic_sim_core::grid::{cell::Cell, road_network::GridRoads};
+    /// use indexmap::IndexMap;
+    /// use std::collections::HashMap;
+    ///
+    /// // Road 1 → ... → 5 with the box at cells 3 and 4, side road 10 → 3
+    /// let mut net = GridRoads::new();
+    /// for id in 1..=5 {
+    ///     net.add_cell(Cell::new(id).with_forward_node(if id < 5 { id + 1 } else { -1 }).build());
+    /// }
+    /// net.add_cell(Cell::new(10).with_forward_node(3).build());
+    /// let mut junction_box = JunctionBox::new(1).with_cells(vec![3, 4]).build();
+    ///
+    /// // Vehicle 1 is inside the box, vehicle 2 is going to enter it from the side road
+    /// let vehicle = |id, cell_id, intention_cell_id| {
+    ///     let mut vehicle = Vehicle::new(id).with_cell(cell_id).with_speed(1).with_speed_limit(2).build();
+    ///     vehicle.set_intention(VehicleIntention { intention_cell_id, intention_speed: 1, ..Default::default() });
+    ///     vehicle
+    /// };
+    /// let mut vehicles = IndexMap::from([(1, vehicle(1, 3, 4)), (2, vehicle(2, 10, 3))]);
+    /// let current_state = HashMap::from([(3, 1), (10, 2)]);
+    /// let held = junction_box.hold_entering(&net, &current_state, &mut vehicles, 0);
+    /// assert_eq!(held, vec![2]);
+    /// assert_eq!(vehicles[&2].intention.intention_cell_id, 10);
+    /// assert_eq!(junction_box.get_occupants(), vec![1]);
+    /// ```
+    pub fn hold_entering(
+        &mut self,
+        net: &GridRoads,
+        current_state: &HashMap<CellID, VehicleID>,
+        vehicles: &mut IndexMap<VehicleID, Vehicle>,
+        step: i32,
+    ) -> Vec<VehicleID> {
+        // Vehicles which have left the box release it, vehicles met inside for the first time hold it by themselves
+        let inside: HashSet<VehicleID> =
+            self.cells.iter().filter_map(|cell_id| current_state.get(cell_id).copied()).collect();
+        self.occupants.retain(|vehicle_id, _| inside.contains(vehicle_id));
+        for &vehicle_id in inside.iter() {
+            if let Some(vehicle) = vehicles.get(&vehicle_id) {
+                self.occupants.entry(vehicle_id).or_insert(BoxOccupant {
+                    movement: BoxMovement::Cell(vehicle.cell_id),
+                    exit_cell_id: -1,
+                });
+            }
+        }
+
+        let mut candidates: Vec<BoxCandidate> = vehicles
+            .values()
+            .filter(|vehicle| !self.occupants.contains_key(&vehicle.id))
+            .filter_map(|vehicle| self.candidate(net, vehicle))
+            .collect();
+        // Movements waiting longer go first
+        candidates.sort_by_key(|candidate| self.waiting.get(&candidate.movement).copied().unwrap_or(step));
+
+        self.prioritized.retain(|vehicle_id| vehicles.contains_key(vehicle_id));
+        self.held.clear();
+        let mut held: Vec<(VehicleID, usize)> = Vec::new();
+        let mut waiting: HashMap<BoxMovement, i32> = HashMap::new();
+        for candidate in candidates {
+            let others_waiting = waiting
+                .keys()
+                .chain(self.waiting.keys())
+                .any(|&movement| movement != candidate.movement);
+            let lock_holders: Vec<VehicleID> = self
+                .occupants
+                .iter()
+                .filter(|(_, occupant)| occupant.movement != candidate.movement)
+                .map(|(&vehicle_id, _)| vehicle_id)
+                .collect();
+            let locked = !lock_holders.is_empty() || (!self.occupants.is_empty() && others_waiting);
+            if self.prioritized.remove(&candidate.vehicle_id) {
+                // Rules are ignored
+            } else if let Some(blockers) = self.exit_blockers(net, current_state, vehicles, &candidate) {
+                self.held.insert(candidate.vehicle_id, blockers);
+                held.push((candidate.vehicle_id, candidate.entry_index));
+                continue;
+            } else if locked {
+                waiting
+                    .entry(candidate.movement)
+                    .or_insert(self.waiting.get(&candidate.movement).copied().unwrap_or(step));
+                self.held.insert(candidate.vehicle_id, lock_holders);
+                held.push((candidate.vehicle_id, candidate.entry_index));
+                continue;
+            }
+            self.occupants.insert(
+                candidate.vehicle_id,
+                BoxOccupant { movement: candidate.movement, exit_cell_id: candidate.exit_cell_id },
+            );
+        }
+        self.waiting = waiting;
+
+        held.sort_by_key(|&(vehicle_id, _)| vehicles.get_index_of(&vehicle_id));
+        for &(vehicle_id, index) in held.iter() {
+            if let Some(vehicle) = vehicles.get_mut(&vehicle_id) {
+                vehicle.intention = hold_back_intention(vehicle.intention.clone(), vehicle, index);
+            }
+        }
+        held.into_iter().map(|(vehicle_id, _)| vehicle_id).collect()
+    }
+
+    /// Returns the candidate if the vehicle is going to enter the box on the current step
+    fn candidate(&self, net: &GridRoads, vehicle: &Vehicle) -> Option<BoxCandidate> {
+        let intention = &vehicle.intention;
+        let mut way: Vec<CellID> = intention.intermediate_cells.clone();
+        way.push(intention.intention_cell_id);
+        let entry_index = way.iter().position(|cell_id| self.cells.contains(cell_id))?;
+        let approach_id = if entry_index == 0 { vehicle.cell_id } else { way[entry_index - 1] };
+        let movement = match net.get_cell(&approach_id).map(|cell| cell.get_meso_link_id()) {
+            Some(meso_link_id) if meso_link_id >= 0 => BoxMovement::Link(meso_link_id),
+            _ => BoxMovement::Cell(approach_id),
+        };
+
+        // Way through the box: cells of the intention, then the assigned route or the shortest path to the destination,
+        // then forward connections. Only cells up to the exit one are needed, so the path search is bounded by the box size
+        let mut way_ahead: Vec<CellID> = way[entry_index..].to_vec();
+        let last_id = *way.last()?;
+        let search_depth = i32::try_from(self.cells.len() + 2).unwrap_or(i32::MAX);
+        if let Some(route_index) = vehicle.route.iter().position(|&cell_id| cell_id == last_id) {
+            way_ahead.extend(vehicle.route[route_index + 1..].iter());
+        } else if let (Some(last_cell), Some(destination)) = (net.get_cell(&last_id), net.get_cell(&vehicle.destination))
+            && let Ok(path) = shortest_path(last_cell, destination, net, true, Some(search_depth), vehicle.vehicle_type)
+        {
+            way_ahead.extend(path.vertices().iter().skip(1).map(|cell| cell.get_id()));
+        }
+        let mut current_id = way_ahead[0];
+        let mut box_length = 1;
+        // Cells from the vehicle's head up to the last box cell
+        let mut path: Vec<CellID> = Vec::with_capacity(entry_index + self.cells.len() + 1);
+        path.push(vehicle.cell_id);
+        path.extend(way[..=entry_index].iter());
+        let exit_cell_id = loop {
+            let next_id = match way_ahead.get(box_length) {
+                Some(&cell_id) => cell_id,
+                None => net.get_cell(&current_id).map_or(-1, |cell| cell.get_forward_id()),
+            };
+            if next_id < 0 || net.get_cell(&next_id).is_none() || box_length > self.cells.len() {
+                break -1;
+            }
+            if !self.cells.contains(&next_id) {
+                break next_id;
+            }
+            box_length += 1;
+            current_id = next_id;
+            path.push(next_id);
+        };
+
+        // Time to reach the exit cell accelerating the way the driver models do: by `Vehicle::speed_increase`
+        // once the acceleration delay is over, up to the effective speed limit of the cell the vehicle is in
+        let distance = entry_index + box_length + 1;
+        let acceleration_delay = vehicle.acceleration_delay();
+        let (mut speed, mut timer, mut passed, mut steps_to_exit) = (vehicle.speed, vehicle.timer_non_acceleration, 0, 0);
+        while passed < distance {
+            let cell_speed_limit = net.get_cell(&path[passed.min(path.len() - 1)]).map_or(-1, |cell| cell.get_speed_limit());
+            let speed_limit = if cell_speed_limit < 0 {
+                vehicle.speed_limit
+            } else {
+                vehicle.effective_speed_limit(cell_speed_limit)
+            }
+            .max(1);
+            let next_speed = if timer <= 0 || speed == 0 {
+                (speed + vehicle.speed_increase()).min(speed_limit)
+            } else {
+                speed.min(speed_limit)
+            }
+            .max(1);
+            timer = (timer - 1).max(0);
+            if next_speed > speed {
+                timer = timer.max(acceleration_delay);
+            }
+            speed = next_speed;
+            passed += speed as usize;
+            steps_to_exit += 1;
+        }
+        Some(BoxCandidate { vehicle_id: vehicle.id, entry_index, movement, exit_cell_id, steps_to_exit })
+    }
+
+    /// Checks if the candidate passes the box in time and has room after it.
+    /// Returns `None` if it does, otherwise vehicles staying in place after the box exit (if any)
+    fn exit_blockers(
+        &self,
+        net: &GridRoads,
+        current_state: &HashMap<CellID, VehicleID>,
+        vehicles: &IndexMap<VehicleID, Vehicle>,
+        candidate: &BoxCandidate,
+    ) -> Option<Vec<VehicleID>> {
+        if candidate.steps_to_exit > self.clearance_steps {
+            return Some(Vec::new());
+        }
+        if candidate.exit_cell_id < 0 {
+            return None;
+        }
+        let needed = 1 + self
+            .occupants
+            .values()
+            .filter(|occupant| occupant.exit_cell_id == candidate.exit_cell_id)
+            .count();
+        let mut cell_id = candidate.exit_cell_id;
+        for _ in 0..needed {
+            let Some(cell) = net.get_cell(&cell_id) else {
+                // Vehicles leave the network
+                return None;
+            };
+            if cell.get_state() != CellState::Free {
+                return Some(Vec::new());
+            }
+            let standing = current_state
+                .get(&cell_id)
+                .and_then(|vehicle_id| vehicles.get(vehicle_id))
+                .filter(|vehicle| {
+                    vehicle.intention.intention_speed == 0 || vehicle.intention.intention_cell_id == vehicle.cell_id
+                });
+            if let Some(vehicle) = standing {
+                return Some(vec![vehicle.id]);
+            }
+            cell_id = cell.get_forward_id();
+        }
+        None
+    }
+}
+
+/// A builder pattern implementation for constructing `JunctionBox` objects.
+pub struct JunctionBoxBuilder {
+    junction_box: JunctionBox,
+}
+
+impl JunctionBoxBuilder {
+    /// Sets cells of the junction box.
+    ///
+    /// # Arguments
+    /// * `cells` - Cells of the junction area
+    ///
+    /// # Returns
+    /// A `JunctionBoxBuilder` instance for further method chaining.
+    pub fn with_cells(mut self, cells: Vec<CellID>) -> Self {
+        self.junction_box.cells = cells.into_iter().collect();
+        self
+    }
+
+    /// Sets maximal time (in time units) to pass the box for entering vehicles. Default is 5.
+    pub fn with_clearance_steps(mut self, clearance_steps: i32) -> Self {
+        self.junction_box.clearance_steps = clearance_steps;
+        self
+    }
+
+    /// Builds the final `JunctionBox` object.
+    pub fn build(self) -> JunctionBox {
+        self.junction_box
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::agents::VehicleIntention;
+    use crate::grid::cell::Cell;
+    use crate::maneuver::LaneChangeType;
+
+    /// Crossing of two roads: west-east 1..=6 and south-north 11..=16, the box is cells 3, 4 and 13 (13 → 4)
+    fn net() -> GridRoads {
+        let mut net = GridRoads::new();
+        for id in 1..=6 {
+            net.add_cell(Cell::new(id).with_forward_node(if id < 6 { id + 1 } else { -1 }).build());
+        }
+        for id in 11..=16 {
+            let forward_id = match id {
+                13 => 4,
+                16 => -1,
+                _ => id + 1,
+            };
+            net.add_cell(Cell::new(id).with_forward_node(forward_id).build());
+        }
+        net
+    }
+
+    fn vehicle(id: VehicleID, cell_id: CellID, speed: i32, way: Vec<CellID>) -> Vehicle {
+        let mut vehicle = Vehicle::new(id).with_cell(cell_id).with_speed(speed).with_speed_limit(2).build();
+        vehicle.set_intention(VehicleIntention {
+            intention_cell_id: *way.last().unwrap(),
+            intention_speed: way.len() as i32,
+            intermediate_cells: way[..way.len() - 1].to_vec(),
+            ..Default::default()
+        });
+        vehicle
+    }
+
+    fn state(vehicles: &IndexMap<VehicleID, Vehicle>) -> HashMap<CellID, VehicleID> {
+        vehicles.values().map(|vehicle| (vehicle.cell_id, vehicle.id)).collect()
+    }
+
+    #[test]
+    fn test_movement_lock() {
+        let net = net();
+        let mut junction_box = JunctionBox::new(1).with_cells(vec![3, 4, 13]).build();
+        // Both movements are going to enter the free box: the first one locks it
+        let mut vehicles = IndexMap::from([(1, vehicle(1, 2, 1, vec![3])), (2, vehicle(2, 12, 1, vec![13]))]);
+        let held = junction_box.hold_entering(&net, &state(&vehicles), &mut vehicles, 0);
+        assert_eq!(held, vec![2]);
+        assert_eq!(vehicles[&2].intention.intention_maneuver, LaneChangeType::Block);
+        assert_eq!(junction_box.get_occupants(), vec![1]);
+        assert_eq!(junction_box.get_held(), &HashMap::from([(2, vec![1])]));
+
+        // Vehicle of the same movement may not follow while the other movement is waiting
+        let mut vehicles = IndexMap::from([
+            (1, vehicle(1, 3, 1, vec![4])),
+            (2, vehicle(2, 12, 0, vec![13])),
+            (3, vehicle(3, 2, 1, vec![3])),
+        ]);
+        let held = junction_box.hold_entering(&net, &state(&vehicles), &mut vehicles, 1);
+        assert_eq!(held, vec![2, 3]);
+
+        // Box is released: waiting movement goes first
+        let mut vehicles = IndexMap::from([(3, vehicle(3, 2, 0, vec![3])), (2, vehicle(2, 12, 0, vec![13]))]);
+        let held = junction_box.hold_entering(&net, &state(&vehicles), &mut vehicles, 2);
+        assert_eq!(held, vec![3]);
+        assert_eq!(junction_box.get_occupants(), vec![2]);
+
+        // Prioritized vehicle ignores the lock
+        junction_box.grant_priority(3);
+        let mut vehicles = IndexMap::from([(2, vehicle(2, 13, 1, vec![4])), (3, vehicle(3, 2, 0, vec![3]))]);
+        let held = junction_box.hold_entering(&net, &state(&vehicles), &mut vehicles, 3);
+        assert!(held.is_empty());
+        assert_eq!(junction_box.get_occupants(), vec![2, 3]);
+    }
+
+    #[test]
+    fn test_exit_blocked() {
+        let net = net();
+        let mut junction_box = JunctionBox::new(1).with_cells(vec![3, 4, 13]).build();
+        // Vehicle staying in place right after the box
+        let mut vehicles = IndexMap::from([(1, vehicle(1, 1, 2, vec![2, 3])), (2, vehicle(2, 5, 0, vec![5]))]);
+        let held = junction_box.hold_entering(&net, &state(&vehicles), &mut vehicles, 0);
+        assert_eq!(held, vec![1]);
+        assert_eq!(vehicles[&1].intention.intention_cell_id, 2);
+        assert!(junction_box.get_occupants().is_empty());
+        assert_eq!(junction_box.get_held(), &HashMap::from([(1, vec![2])]));
+
+        // Exit is free, but the box can not be passed in time
+        let mut junction_box = JunctionBox::new(1).with_cells(vec![3, 4, 13]).with_clearance_steps(1).build();
+        let mut vehicles = IndexMap::from([(1, vehicle(1, 2, 1, vec![3]))]);
+        let held = junction_box.hold_entering(&net, &state(&vehicles), &mut vehicles, 0);
+        assert_eq!(held, vec![1]);
+    }
+
+    #[test]
+    fn test_slow_acceleration() {
+        // Exit cell 5 is three cells ahead: two time units accelerating to the speed limit of two cells
+        let mut net = net();
+        let mut junction_box = JunctionBox::new(1).with_cells(vec![3, 4, 13]).with_clearance_steps(2).build();
+        let mut vehicles = IndexMap::from([(1, vehicle(1, 2, 1, vec![3]))]);
+        assert!(junction_box.hold_entering(&net, &state(&vehicles), &mut vehicles, 0).is_empty());
+
+        // Heavy vehicle has accelerated recently and waits three time units for the next acceleration
+        let mut junction_box = JunctionBox::new(1).with_cells(vec![3, 4, 13]).with_clearance_steps(2).build();
+        let mut heavy = vehicle(1, 2, 1, vec![3]);
+        heavy.max_acceleration = 0.3;
+        heavy.timer_non_acceleration = heavy.acceleration_delay();
+        let mut vehicles = IndexMap::from([(1, heavy)]);
+        assert_eq!(junction_box.hold_entering(&net, &state(&vehicles), &mut vehicles, 0), vec![1]);
+
+        // Driver keeping half of the cells speed limit
+        for id in 1..=6 {
+            net.add_cell(Cell::new(id).with_forward_node(if id < 6 { id + 1 } else { -1 }).with_speed_limit(2).build());
+        }
+        let mut junction_box = JunctionBox::new(1).with_cells(vec![3, 4, 13]).with_clearance_steps(2).build();
+        let mut slow = vehicle(1, 2, 1, vec![3]);
+        slow.speed_compliance = 0.5;
+        let mut vehicles = IndexMap::from([(1, slow)]);
+        assert_eq!(junction_box.hold_entering(&net, &state(&vehicles), &mut vehicles, 0), vec![1]);
+    }
+}
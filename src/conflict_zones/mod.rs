@@ -16,7 +16,11 @@
 //! - [`conflict_zones::ConflictEdge`] - Defines a movement path through the zone 
 //! - [`conflict_zones::ConflictZoneBuilder`] - Builder API for creating conflict zones
 //! - [`conflict_zones::ConflictWinnerType`] - Priority rules (main vs secondary flow)
-//! - [`conflict_zones::ConflictZoneType`] - Classification of zones types (future works)
+//! - [`conflict_zones::ConflictZoneType`] - Classification of zones types and junction approaches priority controls
+//! - [`priority_junction::PriorityJunction`] - Unsignalised junction with priority road, yield, stop and all-way stop approaches
+//! - [`detection::detect_conflict_zones`] - Proposes conflict zones for crossing and merging links of the grid
+//! - [`junction_box::JunctionBox`] - Set of junction cells locked by movements passing through it ("don't block the box")
+//! - [`roundabout::Roundabout`] - Generated roundabout: ring, arms, entering vs circulating conflict zones and yield-at-entry junctions
 //!
 //! ## Conflict Resolution Model
 //!
@@ -78,6 +82,7 @@
 //! - **`First`** - Main road has priority
 //! - **`Second`** - Secondary road has priority
 //! - **`Equal`** - Equal priority. Both yield to each other: so decision who goes first is based on vehicles speed or random in case of tie
+//! - **`Alternate`** - Flows take turns, one vehicle each (zipper merge). Priority passes to the other edge after each conflict
 //! - **`Undefined`** - Priority not determined. Behavior "should" be same as `Equal` but this state indicates misconfiguration.
 //!
 //! ## Usage Examples
@@ -114,10 +119,107 @@
 //!     .build();
 //! ```
 //!
+//! ### Zipper Merge at Lane Drop
+//!
+//! ```rust
+//! use micro_traffic_sim_core::conflict_zones::{
+//!     ConflictZone, ConflictEdge, ConflictWinnerType
+//! };
+//! // Continuing lane goes forward into the merge cell, ending lane merges via lane change
+//! let through_lane = ConflictEdge { source: 15, target: 16 };
+//! let ending_lane = ConflictEdge { source: 115, target: 16 };
+//!
+//! let lane_drop = ConflictZone::new(1, through_lane, ending_lane)
+//!     .with_winner_type(ConflictWinnerType::Alternate)
+//!     .build();
+//! ```
+//!
+//! ### Priority Controls (Yield and Stop Signs)
+//!
+//! Conflict zones decide who wins when vehicles meet, but vehicles on minor approaches should not even
+//! start entering the junction without a stop or an acceptable gap. [`PriorityJunction`] holds
+//! approaches (vehicles wait in front of the approach cell as in front of a red signal):
+//!
+//! - **`PriorityRoad`** - never held
+//! - **`Yield`** - held while priority vehicles would arrive earlier than in `critical_gap` time units
+//! - **`Stop`** - held until the vehicle comes to a full stop, then as `Yield`
+//! - **`AllWayStop`** - held until the vehicle comes to a full stop, then vehicles enter one by one in order of arrival
+//!
+//! ```rust
+//! use micro_traffic_sim_core::conflict_zones::{ConflictZoneType, PriorityJunction};
+//!
+//! // Main road approaches the junction via cell 14, secondary road via cell 25
+//! let junction = PriorityJunction::new(1)
+//!     .with_approach(14, ConflictZoneType::PriorityRoad)
+//!     .with_approach(25, ConflictZoneType::Stop)
+//!     .build();
+//! ```
+//! See [`Session::add_priority_junction`](crate::simulation::session::Session::add_priority_junction).
+//!
+//! ### Junction Boxes
+//!
+//! Conflict zones cover two edges only. [`JunctionBox`] covers the whole junction area: a vehicle enters it
+//! only if no other movement holds the box and the vehicle can leave it within `clearance_steps` time units
+//! into free cells, so stuck vehicles do not block crossing flows.
+//!
+//! ```rust
+//! use micro_traffic_sim_core::conflict_zones::JunctionBox;
+//!
+//! let junction_box = JunctionBox::new(1)
+//!     .with_cells(vec![10, 11, 20, 21])
+//!     .with_clearance_steps(3)
+//!     .build();
+//! // Then register it in the session: `session.add_junction_box(junction_box)`
+//! assert_eq!(junction_box.get_clearance_steps(), 3);
+//! ```
+//!
+//! ### Roundabouts
+//!
+//! [`Roundabout`] generates the ring with entry and exit arms. Circulating vehicles have priority: each entry
+//! is a yield approach and entering flows lose conflicts against circulating ones. On multi-lane roundabouts
+//! [`Roundabout::route`] picks the entry lane by the exit.
+//!
+//! ```rust
+//! use micro_traffic_sim_core::conflict_zones::Roundabout;
+//! use micro_traffic_sim_core::grid::road_network::GridRoads;
+//!
+//! let roundabout = Roundabout::new().with_arms(4).with_lanes(2).build();
+//! let mut grid = GridRoads::new();
+//! for cell in roundabout.get_cells() {
+//!     grid.add_cell(cell.clone());
+//! }
+//! // Then register it in the session: `session.add_roundabout(&roundabout)`
+//! assert_eq!(roundabout.get_junctions().len(), 4);
+//! ```
+//!
+//! ### Automatic Detection
+//!
+//! [`detect_conflict_zones`] scans the grid for pairs of links which share the target cell or which segments
+//! intersect, so both scenarios above do not have to be declared by hand. Winners are picked by
+//! [`ConflictWinnerRule`]: equal priority, priority by speed limit or straight movement over turning.
+//!
+//! ```rust
+//! use micro_traffic_sim_core::conflict_zones::{detect_conflict_zones, ConflictWinnerRule};
+//! use micro_traffic_sim_core::grid::{cell::Cell, road_network::GridRoads};
+//!
+//! // Two roads merge into cell 3
+//! let mut grid = GridRoads::new();
+//! grid.add_cell(Cell::new(1).with_forward_node(3).build());
+//! grid.add_cell(Cell::new(2).with_forward_node(3).build());
+//! grid.add_cell(Cell::new(3).build());
+//! let zones = detect_conflict_zones(&grid, ConflictWinnerRule::Equal, 1);
+//! assert_eq!(zones.len(), 1);
+//! ```
+//! See [`Session::add_detected_conflict_zones`](crate::simulation::session::Session::add_detected_conflict_zones).
+//!
 //! ## Integration
 //!
 //! - **Grid System**: Works with [`CellID`](crate::grid::cell::CellID) from cellular grid
 //! - **Conflicts detection and solver**: Works with conflicts module at [`collect_conflicts`](crate::conflicts).
 mod conflict_zones;
+mod detection;
+mod junction_box;
+mod priority_junction;
+mod roundabout;
 
-pub use self::conflict_zones::*;
\ No newline at end of file
+pub use self::{conflict_zones::*, detection::*, junction_box::*, priority_junction::*, roundabout::*};
\ No newline at end of file
@@ -0,0 +1,384 @@
+use crate::agents::{Vehicle, VehicleID};
+use crate::conflict_zones::ConflictZoneType;
+use crate::grid::cell::CellID;
+use crate::grid::road_network::GridRoads;
+use indexmap::IndexMap;
+use std::collections::{HashMap, HashSet, VecDeque};
+
+/// Identifier for an unsignalised junction.
+pub type PriorityJunctionID = i32;
+
+/// Approach of an unsignalised junction with its priority control.
+#[derive(Debug, Clone, PartialEq)]
+pub struct JunctionApproach {
+    /// Last cell of the approach before the junction (stop or yield line).
+    /// Vehicles wait in front of it until the approach is released, exactly as in front of a red signal.
+    pub cell_id: CellID,
+    /// Priority control: [`ConflictZoneType::PriorityRoad`], [`ConflictZoneType::Yield`],
+    /// [`ConflictZoneType::Stop`] or [`ConflictZoneType::AllWayStop`]. Other kinds are not controlled.
+    pub control: ConflictZoneType,
+}
+
+/// Unsignalised junction: priority controls (priority road, yield, stop, all-way stop) on its approaches.
+///
+/// On each step the junction decides which approaches are held (see [`PriorityJunction::held_cells`]):
+/// - Priority road approaches are never held;
+/// - Yield approaches are held while a vehicle on any priority road approach would arrive at the junction
+///   earlier than in `critical_gap` time units (see [`time_to_arrival`]);
+/// - Stop approaches are held until the vehicle in front of the stop line has come to a full stop, then as yield ones;
+/// - All-way stop approaches are held until the vehicle in front of the stop line has come to a full stop,
+///   then vehicles are released one by one in order of arrival, when there is no vehicle on other all-way stop approaches cells.
+///
+/// Conflicts inside the junction are still resolved by conflict zones (see [`ConflictZone`](crate::conflict_zones::ConflictZone)).
+#[derive(Debug, Clone)]
+pub struct PriorityJunction {
+    id: PriorityJunctionID,
+    approaches: Vec<JunctionApproach>,
+    /// Minimal acceptable time to arrival (in time units) of priority vehicles
+    critical_gap: i32,
+    /// Distance (in cells) before priority road approaches where approaching vehicles are searched
+    look_ahead: i32,
+    /// Vehicles waiting in front of all-way stop approaches with the steps they have arrived at
+    arrivals: HashMap<CellID, (VehicleID, i32)>,
+}
+
+impl PriorityJunction {
+    /// Constructs a new `PriorityJunctionBuilder` for building a `PriorityJunction` object.
+    ///
+    /// # Arguments
+    /// * `id` - A unique identifier for the junction.
+    ///
+    /// # Returns
+    /// A `PriorityJunctionBuilder` struct which is used to configure and build the `PriorityJunction` object.
+    ///
+    /// # Example
+    /// ```
+    /// use micro_traffic_sim_core::conflict_zones::{ConflictZoneType, PriorityJunction};
+    /// // Main road enters the junction from cell 14, side road from cell 105
+    /// let junction = PriorityJunction::new(1)
+    ///     .with_approach(14, ConflictZoneType::PriorityRoad)
+    ///     .with_approach(105, ConflictZoneType::Stop)
+    ///     .with_critical_gap(4)
+    ///     .build();
+    /// assert_eq!(junction.get_approaches().len(), 2);
+    /// ```
+    pub fn new(id: PriorityJunctionID) -> PriorityJunctionBuilder {
+        PriorityJunctionBuilder {
+            junction: PriorityJunction {
+                id,
+                approaches: Vec::new(),
+                critical_gap: 3,
+                look_ahead: 10,
+                arrivals: HashMap::new(),
+            },
+        }
+    }
+
+    /// Returns the unique identifier (ID) of the junction.
+    pub fn get_id(&self) -> PriorityJunctionID {
+        self.id
+    }
+
+    /// Returns approaches of the junction.
+    pub fn get_approaches(&self) -> &[JunctionApproach] {
+        &self.approaches
+    }
+
+    /// Returns minimal acceptable time to arrival (in time units) of priority vehicles.
+    pub fn get_critical_gap(&self) -> i32 {
+        self.critical_gap
+    }
+
+    /// Returns distance (in cells) before priority road approaches where approaching vehicles are searched.
+    pub fn get_look_ahead(&self) -> i32 {
+        self.look_ahead
+    }
+
+    /// Forgets vehicles waiting in front of all-way stop approaches.
+    pub fn reset(&mut self) {
+        self.arrivals.clear();
+    }
+
+    /// Returns approach cells which vehicles must not enter on the current step.
+    ///
+    /// # Arguments
+    /// * `net` - The road network grid
+    /// * `current_state` - Mapping from occupied cell ID to vehicle ID
+    /// * `vehicles` - Vehicles storage
+    /// * `step` - Current step (used for the order of arrival at all-way stops)
+    ///
+    /// # Example
+    /// ```
+    /// use micro_traffic_sim_core::agents::Vehicle;
+    /// use micro_traffic_sim_core::conflict_zones::{ConflictZoneType, PriorityJunction};
+    /// use micro_traffic_sim_core::grid::road_network::GridRoads;
+    /// use micro_traffic_sim_core::utils::generators::generate_one_lane_cells;
+    /// use indexmap::IndexMap;
+    /// use std::collections::HashMap;
+    ///
+    /// let mut net = GridRoads::new();
+    /// for cell in generate_one_lane_cells(100.0, 10.0, 1) {
+    ///     net.add_cell(cell);
+    /// }
+    /// let mut junction = PriorityJunction::new(1).with_approach(5, ConflictZoneType::Stop).build();
+    /// // Vehicle has just arrived at the stop line
+    /// let mut vehicles = IndexMap::from([(1, Vehicle::new(1).with_cell(4).with_speed(2).build())]);
+    /// let current_state = HashMap::from([(4, 1)]);
+    /// assert_eq!(junction.held_cells(&net, &current_state, &vehicles, 0), vec![5]);
+    /// // Vehicle has stopped
+    /// vehicles.get_mut(&1).unwrap().speed = 0;
+    /// assert!(junction.held_cells(&net, &current_state, &vehicles, 1).is_empty());
+    /// ```
+    pub fn held_cells(
+        &mut self,
+        net: &GridRoads,
+        current_state: &HashMap<CellID, VehicleID>,
+        vehicles: &IndexMap<VehicleID, Vehicle>,
+        step: i32,
+    ) -> Vec<CellID> {
+        let priority_arrival = self
+            .approaches
+            .iter()
+            .filter(|approach| approach.control == ConflictZoneType::PriorityRoad)
+            .filter_map(|approach| time_to_arrival(net, current_state, vehicles, approach.cell_id, self.look_ahead))
+            .min();
+        let gap_accepted = priority_arrival.is_none_or(|arrival| arrival >= self.critical_gap);
+
+        // Order of arrival at all-way stops
+        for approach in self.approaches.iter().filter(|approach| approach.control == ConflictZoneType::AllWayStop) {
+            match waiting_vehicle(net, current_state, vehicles, approach.cell_id) {
+                Some(vehicle) => {
+                    if self.arrivals.get(&approach.cell_id).is_none_or(|&(vehicle_id, _)| vehicle_id != vehicle.id) {
+                        self.arrivals.insert(approach.cell_id, (vehicle.id, step));
+                    }
+                }
+                None => {
+                    self.arrivals.remove(&approach.cell_id);
+                }
+            }
+        }
+        let all_way_busy = self
+            .approaches
+            .iter()
+            .any(|approach| approach.control == ConflictZoneType::AllWayStop && current_state.contains_key(&approach.cell_id));
+        let all_way_first = self
+            .approaches
+            .iter()
+            .filter(|approach| approach.control == ConflictZoneType::AllWayStop)
+            .filter(|approach| is_stopped_in_front(net, current_state, vehicles, approach.cell_id))
+            .filter_map(|approach| self.arrivals.get(&approach.cell_id).map(|&(_, arrived)| (arrived, approach.cell_id)))
+            .min()
+            .map(|(_, cell_id)| cell_id);
+
+        self.approaches
+            .iter()
+            .filter(|approach| match approach.control {
+                ConflictZoneType::Yield => !gap_accepted,
+                ConflictZoneType::Stop => {
+                    !gap_accepted || !is_stopped_in_front(net, current_state, vehicles, approach.cell_id)
+                }
+                ConflictZoneType::AllWayStop => {
+                    !gap_accepted || all_way_busy || all_way_first != Some(approach.cell_id)
+                }
+                ConflictZoneType::PriorityRoad | ConflictZoneType::Undefined => false,
+            })
+            .map(|approach| approach.cell_id)
+            .collect()
+    }
+}
+
+/// A builder pattern implementation for constructing `PriorityJunction` objects.
+pub struct PriorityJunctionBuilder {
+    junction: PriorityJunction,
+}
+
+impl PriorityJunctionBuilder {
+    /// Adds an approach to the junction.
+    ///
+    /// # Arguments
+    /// * `cell_id` - Last cell of the approach before the junction (stop or yield line)
+    /// * `control` - Priority control of the approach (see [`JunctionApproach::control`])
+    ///
+    /// # Returns
+    /// A `PriorityJunctionBuilder` instance for further method chaining.
+    pub fn with_approach(mut self, cell_id: CellID, control: ConflictZoneType) -> Self {
+        self.junction.approaches.push(JunctionApproach { cell_id, control });
+        self
+    }
+
+    /// Sets minimal acceptable time to arrival (in time units) of priority vehicles. Default is 3.
+    pub fn with_critical_gap(mut self, critical_gap: i32) -> Self {
+        self.junction.critical_gap = critical_gap;
+        self
+    }
+
+    /// Sets distance (in cells) before priority road approaches where approaching vehicles are searched. Default is 10.
+    pub fn with_look_ahead(mut self, look_ahead: i32) -> Self {
+        self.junction.look_ahead = look_ahead;
+        self
+    }
+
+    /// Builds the final `PriorityJunction` object.
+    pub fn build(self) -> PriorityJunction {
+        self.junction
+    }
+}
+
+/// Returns the minimal time (in time units) in which vehicles approaching the cell would enter it.
+///
+/// Vehicles are searched backwards along forward connections up to `look_ahead` cells (the cell itself included).
+/// Each vehicle is assumed to keep its current speed (at least one cell per time unit).
+///
+/// # Arguments
+/// * `net` - The road network grid
+/// * `current_state` - Mapping from occupied cell ID to vehicle ID
+/// * `vehicles` - Vehicles storage
+/// * `cell_id` - The cell vehicles approach
+/// * `look_ahead` - Search distance (in cells)
+///
+/// # Returns
+/// Time to arrival of the nearest vehicle, or `None` if there are no approaching vehicles.
+///
+/// # Example
+/// ```
+/// use micro_traffic_sim_core::agents::Vehicle;
+/// use micro_traffic_sim_core::conflict_zones::time_to_arrival;
+/// use micro_traffic_sim_core::grid::road_network::GridRoads;
+/// use micro_traffic_sim_core::utils::generators::generate_one_lane_cells;
+/// use indexmap::IndexMap;
+/// use std::collections::HashMap;
+///
+/// let mut net = GridRoads::new();
+/// for cell in generate_one_lane_cells(100.0, 10.0, 1) {
+///     net.add_cell(cell);
+/// }
+/// // Vehicle in cell 3 moving at two cells per time unit needs to pass cells 4, 5 and 6
+/// let vehicles = IndexMap::from([(1, Vehicle::new(1).with_cell(3).with_speed(2).build())]);
+/// let current_state = HashMap::from([(3, 1)]);
+/// assert_eq!(time_to_arrival(&net, &current_state, &vehicles, 6, 5), Some(2));
+/// assert_eq!(time_to_arrival(&net, &current_state, &vehicles, 6, 2), None);
+/// ```
+pub fn time_to_arrival(
+    net: &GridRoads,
+    current_state: &HashMap<CellID, VehicleID>,
+    vehicles: &IndexMap<VehicleID, Vehicle>,
+    cell_id: CellID,
+    look_ahead: i32,
+) -> Option<i32> {
+    let mut arrival: Option<i32> = None;
+    let mut visited = HashSet::from([cell_id]);
+    // Distance is the number of cells to pass to enter the cell
+    let mut queue = VecDeque::from([(cell_id, 0)]);
+    while let Some((current_id, distance)) = queue.pop_front() {
+        if let Some(vehicle) = current_state.get(&current_id).and_then(|vehicle_id| vehicles.get(vehicle_id))
+            && vehicle.cell_id == current_id
+        {
+            let vehicle_arrival = distance / vehicle.speed.max(1) + i32::from(distance % vehicle.speed.max(1) != 0);
+            arrival = Some(arrival.map_or(vehicle_arrival, |arrival| arrival.min(vehicle_arrival)));
+            continue;
+        }
+        if distance >= look_ahead {
+            continue;
+        }
+        for &behind_id in net.get_forward_incoming(&current_id) {
+            if visited.insert(behind_id) {
+                queue.push_back((behind_id, distance + 1));
+            }
+        }
+    }
+    arrival
+}
+
+/// Returns the vehicle which head is right in front of the cell.
+fn waiting_vehicle<'a>(
+    net: &GridRoads,
+    current_state: &HashMap<CellID, VehicleID>,
+    vehicles: &'a IndexMap<VehicleID, Vehicle>,
+    cell_id: CellID,
+) -> Option<&'a Vehicle> {
+    net.get_forward_incoming(&cell_id).iter().find_map(|behind_id| {
+        current_state
+            .get(behind_id)
+            .and_then(|vehicle_id| vehicles.get(vehicle_id))
+            .filter(|vehicle| vehicle.cell_id == *behind_id)
+    })
+}
+
+/// Returns true if the vehicle right in front of the cell has come to a full stop.
+fn is_stopped_in_front(
+    net: &GridRoads,
+    current_state: &HashMap<CellID, VehicleID>,
+    vehicles: &IndexMap<VehicleID, Vehicle>,
+    cell_id: CellID,
+) -> bool {
+    waiting_vehicle(net, current_state, vehicles, cell_id).is_some_and(|vehicle| vehicle.speed == 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::cell::Cell;
+    use crate::utils::generators::generate_one_lane_cells;
+
+    /// Main road 1..20 and side road 101..105 joining it in front of cell 11
+    fn net() -> GridRoads {
+        let mut net = GridRoads::new();
+        for cell in generate_one_lane_cells(200.0, 10.0, 1) {
+            net.add_cell(cell);
+        }
+        for id in 101..=105 {
+            let forward_id = if id == 105 { 11 } else { id + 1 };
+            net.add_cell(Cell::new(id).with_forward_node(forward_id).build());
+        }
+        net
+    }
+
+    fn state(vehicles: &IndexMap<VehicleID, Vehicle>) -> HashMap<CellID, VehicleID> {
+        vehicles.values().map(|vehicle| (vehicle.cell_id, vehicle.id)).collect()
+    }
+
+    #[test]
+    fn test_yield_gap() {
+        let net = net();
+        let mut junction = PriorityJunction::new(1)
+            .with_approach(10, ConflictZoneType::PriorityRoad)
+            .with_approach(105, ConflictZoneType::Yield)
+            .with_critical_gap(3)
+            .build();
+        let run = |junction: &mut PriorityJunction, main_cell: CellID| {
+            let vehicles = IndexMap::from([
+                (1, Vehicle::new(1).with_cell(main_cell).with_speed(2).build()),
+                (2, Vehicle::new(2).with_cell(104).with_speed(1).build()),
+            ]);
+            junction.held_cells(&net, &state(&vehicles), &vehicles, 0)
+        };
+        // Priority vehicle arrives in two time units
+        assert_eq!(run(&mut junction, 7), vec![105]);
+        // Priority vehicle arrives in three time units
+        assert!(run(&mut junction, 5).is_empty());
+        // Priority vehicle has passed the junction
+        assert!(run(&mut junction, 12).is_empty());
+    }
+
+    #[test]
+    fn test_all_way_stop() {
+        let net = net();
+        let mut junction = PriorityJunction::new(1)
+            .with_approach(10, ConflictZoneType::AllWayStop)
+            .with_approach(105, ConflictZoneType::AllWayStop)
+            .build();
+        // Side road vehicle arrives first
+        let mut vehicles = IndexMap::from([(2, Vehicle::new(2).with_cell(104).with_speed(1).build())]);
+        assert_eq!(junction.held_cells(&net, &state(&vehicles), &vehicles, 0), vec![10, 105]);
+        vehicles.insert(1, Vehicle::new(1).with_cell(9).with_speed(1).build());
+        assert_eq!(junction.held_cells(&net, &state(&vehicles), &vehicles, 1), vec![10, 105]);
+        // Both have stopped: side road vehicle goes first
+        vehicles.values_mut().for_each(|vehicle| vehicle.speed = 0);
+        assert_eq!(junction.held_cells(&net, &state(&vehicles), &vehicles, 2), vec![10]);
+        // Side road vehicle enters the junction: main road one waits until it leaves the approach cell
+        vehicles.get_mut(&2).unwrap().cell_id = 105;
+        assert_eq!(junction.held_cells(&net, &state(&vehicles), &vehicles, 3), vec![10, 105]);
+        vehicles.get_mut(&2).unwrap().cell_id = 11;
+        assert_eq!(junction.held_cells(&net, &state(&vehicles), &vehicles, 4), vec![105]);
+    }
+}
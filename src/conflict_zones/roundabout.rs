@@ -0,0 +1,517 @@
+use crate::conflict_zones::{
+    ConflictEdge, ConflictWinnerType, ConflictZone, ConflictZoneID, ConflictZoneType, PriorityJunction,
+    PriorityJunctionID,
+};
+use crate::geom::new_point;
+use crate::grid::cell::{Cell, CellID};
+use crate::grid::zones::ZoneType;
+use crate::maneuver::DrivingSide;
+use std::f64::consts::PI;
+
+/// Arm of a roundabout: entry lanes leading into the ring and the exit lane leaving it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RoundaboutArm {
+    /// Cells of the entry lanes (from the farthest cell to the yield line), the outer (kerb side) lane goes first.
+    /// Entry lane `k` leads into the ring lane `k`.
+    pub entry_lanes: Vec<Vec<CellID>>,
+    /// Cells of the exit lane (from the ring to the last cell). The exit is reached from the outer ring lane only.
+    pub exit_lane: Vec<CellID>,
+}
+
+/// Roundabout: circulating ring of cells with entry and exit arms.
+///
+/// Layout (right-hand traffic, vehicles circulate counterclockwise; left-hand roundabouts are mirrored, so vehicles
+/// circulate clockwise and lane changes to the left and to the right are swapped, see [`RoundaboutBuilder::with_driving_side`]):
+/// - Ring lanes have the same number of cells, the outer lane goes first. Vehicles on inner lanes may move
+///   to the outer side (right lane change), but never to the inner one;
+/// - Arms are distributed evenly around the ring. Each arm has the exit lane (diverging from the outer ring lane
+///   right before the arm) and entry lanes (merging into ring lanes right after the arm);
+/// - Circulating vehicles have priority: entering and circulating flows meet in conflict zones where the circulating
+///   flow wins, and entries are [`ConflictZoneType::Yield`] approaches of the arm's [`PriorityJunction`]
+///   with circulating lanes as priority road approaches;
+/// - On multi-lane roundabouts the entry lane depends on the exit (see [`Roundabout::entry_lane`]):
+///   the farther the exit, the more inner the lane. [`Roundabout::route`] returns the route following the rule.
+///
+/// ```text
+///                 exit ↑  ↓ entry
+///                 ╭─(·)─(·)─(·)─╮
+///                 │             │
+///   entry → (·)  (·)           (·)  (·) → exit
+///                 │             │
+///                 ╰─(·)─(·)─(·)─╯
+///                 exit ↓  ↑ entry
+/// ```
+#[derive(Debug, Clone)]
+pub struct Roundabout {
+    cells: Vec<Cell>,
+    ring_lanes: Vec<Vec<CellID>>,
+    arms: Vec<RoundaboutArm>,
+    conflict_zones: Vec<ConflictZone>,
+    junctions: Vec<PriorityJunction>,
+    driving_side: DrivingSide,
+}
+
+impl Roundabout {
+    /// Constructs a new `RoundaboutBuilder` for generating a `Roundabout` object.
+    ///
+    /// # Returns
+    /// A `RoundaboutBuilder` struct which is used to configure and generate the `Roundabout` object.
+    ///
+    /// # Example
+    /// ```
+    /// use micro_traffic_sim_core::conflict_zones::Roundabout;
+    /// // Two-lane roundabout with four arms, 4 ring cells per arm in each lane and 5 cells in each arm lane
+    /// let roundabout = Roundabout::new()
+    ///     .with_arms(4)
+    ///     .with_lanes(2)
+    ///     .with_sector_cells(4)
+    ///     .with_arm_cells(5)
+    ///     .build();
+    /// assert_eq!(roundabout.get_ring_lanes().len(), 2);
+    /// assert_eq!(roundabout.get_ring_lanes()[0].len(), 16);
+    /// // Ring cells, two entry lanes and one exit lane per arm
+    /// assert_eq!(roundabout.get_cells().len(), 2 * 16 + 4 * 3 * 5);
+    /// ```
+    pub fn new() -> RoundaboutBuilder {
+        RoundaboutBuilder {
+            arms_num: 4,
+            lanes_num: 1,
+            sector_cells: 4,
+            arm_cells: 5,
+            ring_speed_limit: 2,
+            arm_speed_limit: 3,
+            first_cell_id: 1,
+            first_id: 1,
+            driving_side: DrivingSide::default(),
+        }
+    }
+
+    /// Returns all cells of the roundabout (ring and arms).
+    pub fn get_cells(&self) -> &[Cell] {
+        &self.cells
+    }
+
+    /// Returns cells of the ring lanes in order of circulation, the outer lane goes first.
+    pub fn get_ring_lanes(&self) -> &[Vec<CellID>] {
+        &self.ring_lanes
+    }
+
+    /// Returns arms of the roundabout in order of circulation.
+    pub fn get_arms(&self) -> &[RoundaboutArm] {
+        &self.arms
+    }
+
+    /// Returns conflict zones between entering and circulating flows.
+    pub fn get_conflict_zones(&self) -> &[ConflictZone] {
+        &self.conflict_zones
+    }
+
+    /// Returns yield-at-entry junctions (one per arm).
+    pub fn get_junctions(&self) -> &[PriorityJunction] {
+        &self.junctions
+    }
+
+    /// Returns the driving side the roundabout is generated for.
+    pub fn get_driving_side(&self) -> DrivingSide {
+        self.driving_side
+    }
+
+    /// Returns the entry lane (`0` is the outer one) for the trip between the given arms:
+    /// exits within the first `1 / lanes_num` part of the ring are taken from the outer lane,
+    /// the next part from the next lane and so on. U-turns use the inner lane.
+    ///
+    /// # Arguments
+    /// * `entry_arm` - Index of the arm where the vehicle enters the roundabout
+    /// * `exit_arm` - Index of the arm where the vehicle leaves the roundabout
+    ///
+    /// # Example
+    /// ```
+    /// use micro_traffic_sim_core::conflict_zones::Roundabout;
+    /// let roundabout = Roundabout::new().with_arms(4).with_lanes(2).build();
+    /// // Turning right and going straight on
+    /// assert_eq!(roundabout.entry_lane(0, 1), 0);
+    /// assert_eq!(roundabout.entry_lane(0, 2), 0);
+    /// // Turning left and U-turn
+    /// assert_eq!(roundabout.entry_lane(0, 3), 1);
+    /// assert_eq!(roundabout.entry_lane(0, 0), 1);
+    /// ```
+    pub fn entry_lane(&self, entry_arm: usize, exit_arm: usize) -> usize {
+        let arms_num = self.arms.len();
+        let lanes_num = self.ring_lanes.len();
+        let mut arms_passed = (exit_arm + arms_num - entry_arm % arms_num) % arms_num;
+        if arms_passed == 0 {
+            arms_passed = arms_num;
+        }
+        ((arms_passed - 1) * lanes_num / arms_num).min(lanes_num - 1)
+    }
+
+    /// Returns the route (sequence of cells) through the roundabout following the lane choice by exit
+    /// (see [`Roundabout::entry_lane`]): the vehicle enters the ring lane of its entry lane, circulates and
+    /// moves to the outer lane in the last sector before the exit. Returns an empty route for unknown arms.
+    ///
+    /// # Arguments
+    /// * `entry_arm` - Index of the arm where the vehicle enters the roundabout
+    /// * `exit_arm` - Index of the arm where the vehicle leaves the roundabout
+    ///
+    /// # Example
+    /// ```
+    /// use micro_traffic_sim_core::conflict_zones::Roundabout;
+    /// let roundabout = Roundabout::new().with_arms(4).with_lanes(2).build();
+    /// let route = roundabout.route(0, 3);
+    /// let arms = roundabout.get_arms();
+    /// assert_eq!(route.first(), arms[0].entry_lanes[1].first());
+    /// assert_eq!(route.last(), arms[3].exit_lane.last());
+    /// ```
+    pub fn route(&self, entry_arm: usize, exit_arm: usize) -> Vec<CellID> {
+        let (Some(entry), Some(exit)) = (self.arms.get(entry_arm), self.arms.get(exit_arm)) else {
+            return vec![];
+        };
+        let ring_cells = self.ring_lanes[0].len();
+        let sector_cells = ring_cells / self.arms.len();
+        let mut lane = self.entry_lane(entry_arm, exit_arm);
+        let mut route = entry.entry_lanes[lane].clone();
+        let mut idx = merge_index(entry_arm, sector_cells, ring_cells);
+        let diverge_idx = diverge_index(exit_arm, sector_cells, ring_cells);
+        route.push(self.ring_lanes[lane][idx]);
+        while lane > 0 || idx != diverge_idx {
+            let to_diverge = (diverge_idx + ring_cells - idx) % ring_cells;
+            if lane > 0 && to_diverge <= lane + 1 {
+                lane -= 1;
+            }
+            idx = (idx + 1) % ring_cells;
+            route.push(self.ring_lanes[lane][idx]);
+        }
+        route.extend(exit.exit_lane.iter());
+        route
+    }
+}
+
+/// Index of the ring cell where entry lanes of the arm merge into the ring
+fn merge_index(arm: usize, sector_cells: usize, ring_cells: usize) -> usize {
+    (arm * sector_cells + 1) % ring_cells
+}
+
+/// Index of the outer ring cell where the exit lane of the arm diverges from the ring
+fn diverge_index(arm: usize, sector_cells: usize, ring_cells: usize) -> usize {
+    (arm * sector_cells + ring_cells - 1) % ring_cells
+}
+
+/// A builder pattern implementation for generating `Roundabout` objects.
+pub struct RoundaboutBuilder {
+    arms_num: usize,
+    lanes_num: usize,
+    sector_cells: usize,
+    arm_cells: usize,
+    ring_speed_limit: i32,
+    arm_speed_limit: i32,
+    first_cell_id: CellID,
+    first_id: i32,
+    driving_side: DrivingSide,
+}
+
+impl RoundaboutBuilder {
+    /// Sets the number of arms (default is 4).
+    ///
+    /// # Arguments
+    /// * `arms_num` - Number of arms, at least 1
+    pub fn with_arms(mut self, arms_num: usize) -> Self {
+        self.arms_num = arms_num.max(1);
+        self
+    }
+
+    /// Sets the number of ring lanes and entry lanes of each arm (default is 1).
+    ///
+    /// # Arguments
+    /// * `lanes_num` - Number of lanes, at least 1
+    pub fn with_lanes(mut self, lanes_num: usize) -> Self {
+        self.lanes_num = lanes_num.max(1);
+        self
+    }
+
+    /// Sets the number of ring cells between adjacent arms in each ring lane (default is 4).
+    /// It is increased when needed so that inner lanes vehicles can reach the outer lane between entries and exits.
+    ///
+    /// # Arguments
+    /// * `sector_cells` - Number of cells in the ring sector
+    pub fn with_sector_cells(mut self, sector_cells: usize) -> Self {
+        self.sector_cells = sector_cells;
+        self
+    }
+
+    /// Sets the number of cells in each arm lane (default is 5).
+    ///
+    /// # Arguments
+    /// * `arm_cells` - Number of cells, at least 2
+    pub fn with_arm_cells(mut self, arm_cells: usize) -> Self {
+        self.arm_cells = arm_cells.max(2);
+        self
+    }
+
+    /// Sets speed limits (default are 2 on the ring and 3 on arms).
+    ///
+    /// # Arguments
+    /// * `ring_speed_limit` - Speed limit of ring cells
+    /// * `arm_speed_limit` - Speed limit of arm cells
+    pub fn with_speed_limits(mut self, ring_speed_limit: i32, arm_speed_limit: i32) -> Self {
+        self.ring_speed_limit = ring_speed_limit;
+        self.arm_speed_limit = arm_speed_limit;
+        self
+    }
+
+    /// Sets identifiers of generated objects (default are 1 and 1). Cells are numbered sequentially from
+    /// `first_cell_id`: ring lanes go first, then arms. Conflict zones and junctions are numbered from `first_id`.
+    ///
+    /// # Arguments
+    /// * `first_cell_id` - Identifier of the first cell
+    /// * `first_id` - Identifier of the first conflict zone and the first junction
+    pub fn with_first_ids(mut self, first_cell_id: CellID, first_id: i32) -> Self {
+        self.first_cell_id = first_cell_id;
+        self.first_id = first_id;
+        self
+    }
+
+    /// Sets the driving side (default is [`DrivingSide::Right`]). Left-hand roundabouts are mirrored right-hand ones:
+    /// vehicles circulate clockwise, the outer side of the ring and exits are on the left of circulating vehicles.
+    /// It should match the driving side of the session (see [`Session::set_driving_side`](crate::simulation::session::Session::set_driving_side)).
+    ///
+    /// # Arguments
+    /// * `driving_side` - Right-hand or left-hand traffic
+    ///
+    /// # Example
+    /// ```
+    /// use micro_traffic_sim_core::conflict_zones::Roundabout;
+    /// use micro_traffic_sim_core::maneuver::DrivingSide;
+    ///
+    /// let roundabout = Roundabout::new().with_arms(4).with_driving_side(DrivingSide::Left).build();
+    /// let first_exit = roundabout.get_arms()[0].exit_lane[0];
+    /// // Exits diverge from the outer ring lane to the left
+    /// assert!(roundabout.get_cells().iter().any(|cell| cell.get_left_id() == first_exit));
+    /// assert!(roundabout.get_cells().iter().all(|cell| cell.get_right_id() != first_exit));
+    /// ```
+    pub fn with_driving_side(mut self, driving_side: DrivingSide) -> Self {
+        self.driving_side = driving_side;
+        self
+    }
+
+    /// Generates the roundabout.
+    ///
+    /// # Returns
+    /// A `Roundabout` object with cells, conflict zones and yield-at-entry junctions.
+    pub fn build(self) -> Roundabout {
+        let arms_num = self.arms_num;
+        let lanes_num = self.lanes_num;
+        let sector_cells = self.sector_cells.max(lanes_num + 2);
+        let arm_cells = self.arm_cells;
+        let ring_cells = arms_num * sector_cells;
+        let outer_radius = (ring_cells as f64 / (2.0 * PI)).max(lanes_num as f64);
+        // Left-hand roundabout is the mirror image (about the X axis) of the right-hand one
+        let y_sign = match self.driving_side {
+            DrivingSide::Right => 1.0,
+            DrivingSide::Left => -1.0,
+        };
+        // Left and right neighbours by the slow (outer, kerb) side and the fast (inner) side
+        let lateral_nodes = |slow_id: CellID, fast_id: CellID| match self.driving_side {
+            DrivingSide::Right => (fast_id, slow_id),
+            DrivingSide::Left => (slow_id, fast_id),
+        };
+        let mut cells = Vec::with_capacity(lanes_num * ring_cells + arms_num * (lanes_num + 1) * arm_cells);
+
+        let ring_lanes: Vec<Vec<CellID>> = (0..lanes_num)
+            .map(|lane| {
+                let first = self.first_cell_id + (lane * ring_cells) as CellID;
+                (0..ring_cells).map(|idx| first + idx as CellID).collect()
+            })
+            .collect();
+        let mut next_id = self.first_cell_id + (lanes_num * ring_cells) as CellID;
+        let arms: Vec<RoundaboutArm> = (0..arms_num)
+            .map(|_| {
+                let mut new_lane = || {
+                    let lane = (0..arm_cells).map(|idx| next_id + idx as CellID).collect();
+                    next_id += arm_cells as CellID;
+                    lane
+                };
+                let entry_lanes = (0..lanes_num).map(|_| new_lane()).collect();
+                RoundaboutArm { entry_lanes, exit_lane: new_lane() }
+            })
+            .collect();
+        let is_merge = |idx: usize| (0..arms_num).any(|arm| merge_index(arm, sector_cells, ring_cells) == idx);
+
+        // Ring
+        for (lane, lane_cells) in ring_lanes.iter().enumerate() {
+            let radius = outer_radius - lane as f64;
+            for (idx, &cell_id) in lane_cells.iter().enumerate() {
+                let angle = 2.0 * PI * idx as f64 / ring_cells as f64;
+                let next_idx = (idx + 1) % ring_cells;
+                let slow_id = if lane > 0 {
+                    // Moving to the outer lane, but not in front of entering vehicles
+                    if is_merge(next_idx) { -1 } else { ring_lanes[lane - 1][next_idx] }
+                } else {
+                    (0..arms_num)
+                        .find(|&arm| diverge_index(arm, sector_cells, ring_cells) == idx)
+                        .map_or(-1, |arm| arms[arm].exit_lane[0])
+                };
+                let (left_id, right_id) = lateral_nodes(slow_id, -1);
+                cells.push(
+                    Cell::new(cell_id)
+                        .with_point(new_point(radius * angle.cos(), y_sign * radius * angle.sin(), None))
+                        .with_zone_type(ZoneType::Common)
+                        .with_speed_limit(self.ring_speed_limit)
+                        .with_left_node(left_id)
+                        .with_forward_node(lane_cells[next_idx])
+                        .with_right_node(right_id)
+                        .build(),
+                );
+            }
+        }
+
+        // Arms: radial lanes, entries on the slow side of the arm axis (right one looking at the ring), exit on the other one
+        for (arm_idx, arm) in arms.iter().enumerate() {
+            let angle = 2.0 * PI * (arm_idx * sector_cells) as f64 / ring_cells as f64;
+            let (radial, tangent) = ((angle.cos(), angle.sin()), (-angle.sin(), angle.cos()));
+            let point = |distance: f64, offset: f64| {
+                new_point(
+                    (outer_radius + distance) * radial.0 + offset * tangent.0,
+                    y_sign * ((outer_radius + distance) * radial.1 + offset * tangent.1),
+                    None,
+                )
+            };
+            let merge_idx = merge_index(arm_idx, sector_cells, ring_cells);
+            for (lane, lane_cells) in arm.entry_lanes.iter().enumerate() {
+                let offset = 0.5 + (lanes_num - 1 - lane) as f64;
+                for (idx, &cell_id) in lane_cells.iter().enumerate() {
+                    let is_last = idx == arm_cells - 1;
+                    let forward_id = if is_last { ring_lanes[lane][merge_idx] } else { lane_cells[idx + 1] };
+                    // Lane changes are permitted before the last cell only
+                    let lateral_id = |lane: Option<usize>| match lane {
+                        Some(lane) if !is_last && lane < lanes_num => arm.entry_lanes[lane][idx + 1],
+                        _ => -1,
+                    };
+                    let (left_id, right_id) = lateral_nodes(lateral_id(lane.checked_sub(1)), lateral_id(Some(lane + 1)));
+                    cells.push(
+                        Cell::new(cell_id)
+                            .with_point(point((arm_cells - idx) as f64, offset))
+                            .with_zone_type(if idx == 0 { ZoneType::Birth } else { ZoneType::Common })
+                            .with_speed_limit(self.arm_speed_limit)
+                            .with_left_node(left_id)
+                            .with_forward_node(forward_id)
+                            .with_right_node(right_id)
+                            .build(),
+                    );
+                }
+            }
+            for (idx, &cell_id) in arm.exit_lane.iter().enumerate() {
+                let is_last = idx == arm_cells - 1;
+                cells.push(
+                    Cell::new(cell_id)
+                        .with_point(point((idx + 1) as f64, -0.5))
+                        .with_zone_type(if is_last { ZoneType::Death } else { ZoneType::Common })
+                        .with_speed_limit(self.arm_speed_limit)
+                        .with_left_node(-1)
+                        .with_forward_node(if is_last { -1 } else { arm.exit_lane[idx + 1] })
+                        .with_right_node(-1)
+                        .build(),
+                );
+            }
+        }
+
+        // Yield at entries: circulating flow wins
+        let mut conflict_zones = Vec::with_capacity(arms_num * lanes_num);
+        let mut junctions = Vec::with_capacity(arms_num);
+        for (arm_idx, arm) in arms.iter().enumerate() {
+            let merge_idx = merge_index(arm_idx, sector_cells, ring_cells);
+            let upstream_idx = (merge_idx + ring_cells - 1) % ring_cells;
+            let mut junction = PriorityJunction::new(self.first_id + arm_idx as PriorityJunctionID);
+            for (lane, lane_cells) in arm.entry_lanes.iter().enumerate() {
+                let circulating = ConflictEdge { source: ring_lanes[lane][upstream_idx], target: ring_lanes[lane][merge_idx] };
+                let entering = ConflictEdge { source: lane_cells[arm_cells - 1], target: ring_lanes[lane][merge_idx] };
+                let zone_id = self.first_id + (arm_idx * lanes_num + lane) as ConflictZoneID;
+                conflict_zones.push(
+                    ConflictZone::new(zone_id, circulating, entering)
+                        .with_winner_type(ConflictWinnerType::First)
+                        .build(),
+                );
+                junction = junction
+                    .with_approach(ring_lanes[lane][upstream_idx], ConflictZoneType::PriorityRoad)
+                    .with_approach(lane_cells[arm_cells - 1], ConflictZoneType::Yield);
+            }
+            junctions.push(junction.build());
+        }
+
+        Roundabout { cells, ring_lanes, arms, conflict_zones, junctions, driving_side: self.driving_side }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geom::Point;
+    use crate::grid::road_network::GridRoads;
+
+    #[test]
+    fn test_route_consistency() {
+        for driving_side in [DrivingSide::Right, DrivingSide::Left] {
+            check_route_consistency(Roundabout::new().with_arms(3).with_lanes(2).with_sector_cells(5).with_driving_side(driving_side).build());
+        }
+    }
+
+    #[test]
+    fn test_circulation_direction() {
+        for (driving_side, sign) in [(DrivingSide::Right, 1.0), (DrivingSide::Left, -1.0)] {
+            let roundabout = Roundabout::new().with_arms(4).with_lanes(2).with_driving_side(driving_side).build();
+            let net = to_net(&roundabout);
+            for lane_cells in roundabout.get_ring_lanes() {
+                // Counterclockwise circulation gives positive cross product of positions of consecutive cells
+                for pair in lane_cells.windows(2) {
+                    let (from, to) = (net.get_cell(&pair[0]).unwrap().get_point(), net.get_cell(&pair[1]).unwrap().get_point());
+                    let cross = from.x() * to.y() - from.y() * to.x();
+                    assert!(sign * cross > 0.0, "{}: {} -> {}", driving_side, pair[0], pair[1]);
+                }
+            }
+            // Vehicles move to the outer lane and to the exits on the slow side only
+            let slow_id = |cell: &Cell| match driving_side {
+                DrivingSide::Right => (cell.get_right_id(), cell.get_left_id()),
+                DrivingSide::Left => (cell.get_left_id(), cell.get_right_id()),
+            };
+            for cell_id in roundabout.get_ring_lanes().iter().flatten() {
+                let (slow, fast) = slow_id(net.get_cell(cell_id).unwrap());
+                assert_eq!(fast, -1, "{}: {}", driving_side, cell_id);
+                assert!(slow == -1 || !roundabout.get_ring_lanes()[1].contains(&slow), "{}: {}", driving_side, cell_id);
+            }
+            let exits: Vec<CellID> = roundabout.get_arms().iter().map(|arm| arm.exit_lane[0]).collect();
+            let diverging = roundabout.get_ring_lanes()[0].iter().filter(|cell_id| exits.contains(&slow_id(net.get_cell(cell_id).unwrap()).0));
+            assert_eq!(diverging.count(), 4, "{}", driving_side);
+        }
+    }
+
+    fn to_net(roundabout: &Roundabout) -> GridRoads {
+        let mut net = GridRoads::new();
+        for cell in roundabout.get_cells() {
+            net.add_cell(cell.clone());
+        }
+        net
+    }
+
+    fn check_route_consistency(roundabout: Roundabout) {
+        let net = to_net(&roundabout);
+        for entry_arm in 0..3 {
+            for exit_arm in 0..3 {
+                let route = roundabout.route(entry_arm, exit_arm);
+                let lane = roundabout.entry_lane(entry_arm, exit_arm);
+                assert_eq!(route[0], roundabout.get_arms()[entry_arm].entry_lanes[lane][0]);
+                assert_eq!(route.last(), roundabout.get_arms()[exit_arm].exit_lane.last());
+                // Every next cell is reachable from the previous one
+                for pair in route.windows(2) {
+                    let cell = net.get_cell(&pair[0]).unwrap();
+                    assert!(
+                        [cell.get_forward_id(), cell.get_right_id(), cell.get_left_id()].contains(&pair[1]),
+                        "{} -> {} in {:?}",
+                        pair[0],
+                        pair[1],
+                        route
+                    );
+                }
+            }
+        }
+        assert!(roundabout.route(0, 3).is_empty());
+    }
+}
@@ -1,7 +1,8 @@
+use crate::agents_types::AgentType;
 use crate::behaviour::BehaviourType;
 use crate::agents::{Vehicle, VehicleID};
 use crate::conflicts::ConflictType;
-use crate::maneuver::LaneChangeType;
+use crate::maneuver::{DrivingSide, LaneChangeType};
 use crate::intentions::{CellIntention, IntentionType};
 use rand::Rng;
 use crate::utils::rand::rng;
@@ -20,6 +21,7 @@ struct ConflictRule {
         &'a CellIntention,
         &'a CellIntention,
         &IndexMap<VehicleID, Vehicle>,
+        DrivingSide,
     ) -> (&'a CellIntention, ConflictType),
 }
 
@@ -37,36 +39,42 @@ macro_rules! conflict_rules {
 }
 
 static CONFLICT_RULES: &[ConflictRule] = conflict_rules![
+    // One of the vehicles is an emergency one: it always wins
+    [ |v1, v2| has_emergency_advantage(v1, v2) || has_emergency_advantage(v2, v1), |cin1, cin2, vehicles, _| resolve_emergency(cin1, cin2, vehicles) ],
+
+    // Cyclist keeps its lane while the other vehicle is turning across its path at a junction: cyclist wins
+    [ |v1, v2| has_cyclist_advantage(v1, v2) || has_cyclist_advantage(v2, v1), |cin1, cin2, vehicles, _| resolve_cyclist(cin1, cin2, vehicles) ],
+
     // Both vehicles are changing lane on a single road
     // Two source lanes on one road is going to merge into single lane on another road
     [ |v1, v2| changing_lane(v1) && changing_lane(v2), resolve_merge_lane_change ],
     
     // Both vehicles are moving forward on different lanes of different roads
     // Differet roads are going to merge into single road
-    [ |v1, v2| v1.intention.intention_maneuver == LaneChangeType::NoChange && v2.intention.intention_maneuver == LaneChangeType::NoChange, resolve_merge_forward ],
+    [ |v1, v2| v1.intention.intention_maneuver == LaneChangeType::NoChange && v2.intention.intention_maneuver == LaneChangeType::NoChange, |cin1, cin2, vehicles, _| resolve_merge_forward(cin1, cin2, vehicles) ],
 
     // First vehicle is moving forward, second is changing lane
-    [ |v1, v2| v1.intention.intention_maneuver == LaneChangeType::NoChange && changing_lane(v2), |cin1, _cin2, _| {
+    [ |v1, v2| v1.intention.intention_maneuver == LaneChangeType::NoChange && changing_lane(v2), |cin1, _cin2, _, _| {
         // First vehicle is not doing maneuver, when the second one is doing lane change.
         // Therefore the second vehicle should give way to the first one
         (cin1, ConflictType::ForwardLaneChange)
     } ],
 
     // First vehicle is changing lane, second is moving forward
-    [ |v1, v2| changing_lane(v1) && v2.intention.intention_maneuver == LaneChangeType::NoChange, |_cin1, cin2, _| {
+    [ |v1, v2| changing_lane(v1) && v2.intention.intention_maneuver == LaneChangeType::NoChange, |_cin1, cin2, _, _| {
         // Second vehicle is not doing maneuver, when the first one is doing lane change.
         // Therefore the first vehicle should give way to the second one
         (cin2, ConflictType::ForwardLaneChange)
     } ],
 
     // First vehicle is changing lane, second is blocking its lane
-    [ |v1, v2| changing_lane(v1) && v2.intention.intention_maneuver == LaneChangeType::Block, |_cin1, cin2, _| {
+    [ |v1, v2| changing_lane(v1) && v2.intention.intention_maneuver == LaneChangeType::Block, |_cin1, cin2, _, _| {
         // Second vehicle is not moving therefore it holds the position
         (cin2, ConflictType::BlockLaneChange)
     } ],
 
     // First vehicle is blocking its lane, second is changing lane
-    [ |v1, v2| v1.intention.intention_maneuver == LaneChangeType::Block && changing_lane(v2), |cin1, _cin2, _| {
+    [ |v1, v2| v1.intention.intention_maneuver == LaneChangeType::Block && changing_lane(v2), |cin1, _cin2, _, _| {
         // First vehicle is not moving therefore it holds the position
         (cin1, ConflictType::BlockLaneChange)
     } ]
@@ -136,17 +144,19 @@ static CONFLICT_RULES: &[ConflictRule] = conflict_rules![
 
 /// Applies all simple conflict rules to a pair of intentions and returns the winner and conflict type.
 ///
-/// Rules cover lane changes, merges, blocking, and forward movement.
+/// Rules cover emergency vehicles priority, lane changes, merges, blocking, and forward movement.
+/// Priority in lane change merges depends on the driving side (see [`resolve_merge_lane_change`]).
 pub fn resolve_simple_rules<'a>(
     intention_one: &'a CellIntention,
     intention_two: &'a CellIntention,
     vehicles: &IndexMap<VehicleID, Vehicle>,
+    driving_side: DrivingSide,
 ) -> (&'a CellIntention, ConflictType) {
     let v1 = vehicles.get(&intention_one.get_vehicle_id()).expect("Vehicle not found");
     let v2 = vehicles.get(&intention_two.get_vehicle_id()).expect("Vehicle not found");
     for rule in CONFLICT_RULES {
         if (rule.condition)(v1, v2) {
-            return (rule.resolver)(intention_one, intention_two, vehicles);
+            return (rule.resolver)(intention_one, intention_two, vehicles, driving_side);
         }
     }
     panic!("Unexpected conflict type")
@@ -166,6 +176,64 @@ pub fn changing_lane(agent: &Vehicle) -> bool {
         || agent.intention.intention_maneuver == LaneChangeType::ChangeRight
 }
 
+/// Returns true if the first vehicle is an emergency one (see [`AgentType::Emergency`]) and the second is not.
+pub fn has_emergency_advantage(vehicle_one: &Vehicle, vehicle_two: &Vehicle) -> bool {
+    vehicle_one.vehicle_type == AgentType::Emergency && vehicle_two.vehicle_type != AgentType::Emergency
+}
+
+/// Resolves a conflict where exactly one of the vehicles is an emergency one: it always wins.
+///
+/// # Visualization
+/// ```text
+/// Road A: →→→ (emergency) ↘
+///                         [Cell]
+/// Road B: →→→ (car)       ↗
+/// Priority: Emergency > Any traffic rule
+/// ```
+pub fn resolve_emergency<'a>(
+    intention_one: &'a CellIntention,
+    intention_two: &'a CellIntention,
+    vehicles: &IndexMap<VehicleID, Vehicle>,
+) -> (&'a CellIntention, ConflictType) {
+    let vehicle_one = vehicles.get(&intention_one.get_vehicle_id()).expect("Vehicle not found");
+    if vehicle_one.vehicle_type == AgentType::Emergency {
+        return (intention_one, ConflictType::Emergency);
+    }
+    (intention_two, ConflictType::Emergency)
+}
+
+/// Returns true if the first agent is a cyclist keeping its lane and the second one is a vehicle
+/// turning across the cyclist's path (see [`is_turning`](crate::intentions::is_turning)), so the vehicle has to give way.
+///
+/// Emergency vehicles and other cyclists have no such obligation.
+pub fn has_cyclist_advantage(cyclist: &Vehicle, vehicle: &Vehicle) -> bool {
+    cyclist.vehicle_type == AgentType::Cyclist
+        && !matches!(vehicle.vehicle_type, AgentType::Cyclist | AgentType::Emergency)
+        && cyclist.intention.intention_maneuver == LaneChangeType::NoChange
+        && !cyclist.intention.turning
+        && vehicle.intention.turning
+}
+
+/// Resolves a conflict where a cyclist keeps its lane and a vehicle is turning across its path: cyclist wins.
+///
+/// # Visualization
+/// ```text
+/// Car lane:   →→→ (car)     ↘
+/// Cycle lane: →→→ (cyclist) →→ [Cell] →→
+/// Priority: Cyclist going straight > Vehicle turning
+/// ```
+pub fn resolve_cyclist<'a>(
+    intention_one: &'a CellIntention,
+    intention_two: &'a CellIntention,
+    vehicles: &IndexMap<VehicleID, Vehicle>,
+) -> (&'a CellIntention, ConflictType) {
+    let vehicle_one = vehicles.get(&intention_one.get_vehicle_id()).expect("Vehicle not found");
+    if vehicle_one.vehicle_type == AgentType::Cyclist {
+        return (intention_one, ConflictType::Cyclist);
+    }
+    (intention_two, ConflictType::Cyclist)
+}
+
 /// Returns true if the first vehicle is aggressive and the second is cooperative.
 /// Future works: aggressive vehicles may win conflicts even against traffic rules.
 pub fn has_agressive_level_advantage(vehicle_one: &Vehicle, vehicle_two: &Vehicle) -> bool {
@@ -175,7 +243,9 @@ pub fn has_agressive_level_advantage(vehicle_one: &Vehicle, vehicle_two: &Vehicl
 
 /// Resolves a merge conflict where both vehicles are changing lanes into the same cell.
 ///
-/// Priority is given to aggressive vehicles, or to the vehicle performing a left maneuver (simulating right-hand traffic).
+/// Priority is given to aggressive vehicles, or to the vehicle moving out of the slow lane side
+/// (the one performing the overtaking maneuver, see [`DrivingSide::overtaking_maneuver`]):
+/// LEFT maneuver for right-hand traffic and RIGHT maneuver for left-hand traffic.
 /// 
 /// # Visualization
 /// ```text
@@ -190,6 +260,7 @@ pub fn resolve_merge_lane_change<'a>(
     intention_one: &'a CellIntention,
     intention_two: &'a CellIntention,
     vehicles: &IndexMap<VehicleID, Vehicle>,
+    driving_side: DrivingSide,
 ) -> (&'a CellIntention, ConflictType) {
     // Extract vehicles once (with early panic if missing)
     let vehicle_one = vehicles.get(&intention_one.get_vehicle_id()).expect("Vehicle not found");
@@ -204,22 +275,17 @@ pub fn resolve_merge_lane_change<'a>(
         return (intention_two, ConflictType::MergeLaneChange);
     }
 
-    // Give priority to the one which does LEFT maneuver (so vehicle is in most right position of the road)
+    // Give priority to the one which does overtaking maneuver (so vehicle is in the slow lane side of the road)
     // Check specific maneuver combinations
-    match (
-        vehicle_one.intention.intention_maneuver,
-        vehicle_two.intention.intention_maneuver,
-    ) {
-        (LaneChangeType::ChangeLeft, LaneChangeType::ChangeRight) => {
-            (intention_one, ConflictType::MergeLaneChange)
-        }
-        (LaneChangeType::ChangeRight, LaneChangeType::ChangeLeft) => {
-            (intention_two, ConflictType::MergeLaneChange)
-        }
-        _ => {
-            panic!("Unexpected lane change")
-        }
+    let maneuver_one = vehicle_one.intention.intention_maneuver;
+    let maneuver_two = vehicle_two.intention.intention_maneuver;
+    if !changing_lane(vehicle_one) || !changing_lane(vehicle_two) || maneuver_one == maneuver_two {
+        panic!("Unexpected lane change")
+    }
+    if maneuver_one == driving_side.overtaking_maneuver() {
+        return (intention_one, ConflictType::MergeLaneChange);
     }
+    (intention_two, ConflictType::MergeLaneChange)
 }
 
 /// Resolves a merge conflict by comparing speed and cooperativity.
@@ -343,7 +409,7 @@ mod tests {
         let intention_one = CellIntention::new(1, IntentionType::Target);
         let intention_two = CellIntention::new(2, IntentionType::Target);
         let correct_winner = (intention_one.clone(), ConflictType::MergeLaneChange);
-        let actual_winner = resolve_merge_lane_change(&intention_one, &intention_two, &vehicles);
+        let actual_winner = resolve_merge_lane_change(&intention_one, &intention_two, &vehicles, DrivingSide::Right);
         assert_eq!(
             correct_winner.0.get_vehicle_id(),
             actual_winner.0.get_vehicle_id(),
@@ -377,7 +443,7 @@ mod tests {
         let intention_one = CellIntention::new(1, IntentionType::Target);
         let intention_two = CellIntention::new(2, IntentionType::Target);
         let correct_winner = (intention_one.clone(), ConflictType::MergeLaneChange);
-        let actual_winner = resolve_merge_lane_change(&intention_one, &intention_two, &vehicles);
+        let actual_winner = resolve_merge_lane_change(&intention_one, &intention_two, &vehicles, DrivingSide::Right);
         assert_eq!(
             correct_winner.0.get_vehicle_id(),
             actual_winner.0.get_vehicle_id(),
@@ -387,6 +453,14 @@ mod tests {
             correct_winner.1, actual_winner.1,
             "Conflict type is not correct"
         );
+
+        // Case 3: Vehicle doing RIGHT maneuver should win in left-hand traffic
+        let actual_winner = resolve_merge_lane_change(&intention_one, &intention_two, &vehicles, DrivingSide::Left);
+        assert_eq!(
+            intention_two.get_vehicle_id(),
+            actual_winner.0.get_vehicle_id(),
+            "Vehicle ID for the winner is not correct"
+        );
     }
     #[test]
     fn test_resolve_by_speed_and_cooperativity() {
@@ -578,7 +652,7 @@ mod tests {
         let intention_one = CellIntention::new(1, IntentionType::Target);
         let intention_two = CellIntention::new(2, IntentionType::Target);
         let correct_winner = (intention_two.clone(), ConflictType::MergeLaneChange);
-        let actual_winner = resolve_simple_rules(&intention_one, &intention_two, &vehicles);
+        let actual_winner = resolve_simple_rules(&intention_one, &intention_two, &vehicles, DrivingSide::Right);
         assert_eq!(
             correct_winner.0.get_vehicle_id(),
             actual_winner.0.get_vehicle_id(),
@@ -614,7 +688,7 @@ mod tests {
         let intention_three = CellIntention::new(3, IntentionType::Target);
         let intention_four = CellIntention::new(4, IntentionType::Target);
         let correct_winner = (intention_three.clone(), ConflictType::MergeForward);
-        let actual_winner = resolve_simple_rules(&intention_three, &intention_four, &vehicles);
+        let actual_winner = resolve_simple_rules(&intention_three, &intention_four, &vehicles, DrivingSide::Right);
         assert_eq!(
             correct_winner.0.get_vehicle_id(),
             actual_winner.0.get_vehicle_id(),
@@ -649,7 +723,7 @@ mod tests {
         let intention_five = CellIntention::new(5, IntentionType::Target);
         let intention_six = CellIntention::new(6, IntentionType::Target);
         let correct_winner = (intention_five.clone(), ConflictType::ForwardLaneChange);  
-        let actual_winner = resolve_simple_rules(&intention_five, &intention_six, &vehicles);
+        let actual_winner = resolve_simple_rules(&intention_five, &intention_six, &vehicles, DrivingSide::Right);
         assert_eq!(
             correct_winner.0.get_vehicle_id(),
             actual_winner.0.get_vehicle_id(),
@@ -684,7 +758,7 @@ mod tests {
         let intention_seven = CellIntention::new(7, IntentionType::Target);
         let intention_eight = CellIntention::new(8, IntentionType::Target);
         let correct_winner = (intention_eight.clone(), ConflictType::ForwardLaneChange);
-        let actual_winner = resolve_simple_rules(&intention_seven, &intention_eight, &vehicles);
+        let actual_winner = resolve_simple_rules(&intention_seven, &intention_eight, &vehicles, DrivingSide::Right);
         assert_eq!(
             correct_winner.0.get_vehicle_id(),
             actual_winner.0.get_vehicle_id(),
@@ -719,7 +793,7 @@ mod tests {
         let intention_nine = CellIntention::new(9, IntentionType::Target);
         let intention_ten = CellIntention::new(10, IntentionType::Target);
         let correct_winner = (intention_ten.clone(), ConflictType::BlockLaneChange);
-        let actual_winner = resolve_simple_rules(&intention_nine, &intention_ten, &vehicles);
+        let actual_winner = resolve_simple_rules(&intention_nine, &intention_ten, &vehicles, DrivingSide::Right);
         assert_eq!(
             correct_winner.0.get_vehicle_id(),
             actual_winner.0.get_vehicle_id(),
@@ -754,7 +828,7 @@ mod tests {
         let intention_eleven = CellIntention::new(11, IntentionType::Target);
         let intention_twelve = CellIntention::new(12, IntentionType::Target);
         let correct_winner = (intention_eleven.clone(), ConflictType::BlockLaneChange);
-        let actual_winner = resolve_simple_rules(&intention_eleven, &intention_twelve, &vehicles);
+        let actual_winner = resolve_simple_rules(&intention_eleven, &intention_twelve, &vehicles, DrivingSide::Right);
         assert_eq!(
             correct_winner.0.get_vehicle_id(),
             actual_winner.0.get_vehicle_id(),
@@ -765,4 +839,90 @@ mod tests {
             "Conflict type is not correct"
         );
     }
+
+    #[test]
+    fn test_resolve_emergency() {
+        // Emergency vehicle is changing lane and the aggressive faster one is moving forward:
+        // without emergency type the second one would win
+        let mut emergency = Vehicle::new(1)
+            .with_type(AgentType::Emergency)
+            .with_behaviour(BehaviourType::Cooperative)
+            .with_speed(1)
+            .build();
+        emergency.set_intention(VehicleIntention {
+            intention_maneuver: LaneChangeType::ChangeLeft,
+            ..Default::default()
+        });
+        let mut car = Vehicle::new(2)
+            .with_behaviour(BehaviourType::Aggressive)
+            .with_speed(3)
+            .build();
+        car.set_intention(VehicleIntention {
+            intention_maneuver: LaneChangeType::NoChange,
+            ..Default::default()
+        });
+        let mut vehicles: IndexMap<VehicleID, Vehicle> = IndexMap::new();
+        vehicles.insert(1, emergency);
+        vehicles.insert(2, car);
+        let intention_one = CellIntention::new(1, IntentionType::Target);
+        let intention_two = CellIntention::new(2, IntentionType::Transit);
+        for (first, second) in [(&intention_one, &intention_two), (&intention_two, &intention_one)] {
+            let (winner, conflict_type) = resolve_simple_rules(first, second, &vehicles, DrivingSide::Right);
+            assert_eq!(winner.get_vehicle_id(), 1);
+            assert_eq!(conflict_type, ConflictType::Emergency);
+        }
+
+        // Two emergency vehicles follow regular rules
+        vehicles.get_mut(&2).unwrap().vehicle_type = AgentType::Emergency;
+        let (winner, conflict_type) = resolve_simple_rules(&intention_one, &intention_two, &vehicles, DrivingSide::Right);
+        assert_eq!(winner.get_vehicle_id(), 2);
+        assert_eq!(conflict_type, ConflictType::ForwardLaneChange);
+    }
+
+    #[test]
+    fn test_resolve_cyclist() {
+        // Aggressive faster car turns into the cell the cyclist is heading straight to:
+        // without cyclist type the car would win the merge
+        let mut cyclist = Vehicle::new(1)
+            .with_type(AgentType::Cyclist)
+            .with_behaviour(BehaviourType::Cooperative)
+            .with_speed(1)
+            .build();
+        cyclist.set_intention(VehicleIntention {
+            intention_maneuver: LaneChangeType::NoChange,
+            ..Default::default()
+        });
+        let mut car = Vehicle::new(2)
+            .with_behaviour(BehaviourType::Aggressive)
+            .with_speed(3)
+            .build();
+        car.set_intention(VehicleIntention {
+            intention_maneuver: LaneChangeType::NoChange,
+            turning: true,
+            ..Default::default()
+        });
+        let mut vehicles: IndexMap<VehicleID, Vehicle> = IndexMap::new();
+        vehicles.insert(1, cyclist);
+        vehicles.insert(2, car);
+        let intention_one = CellIntention::new(1, IntentionType::Target);
+        let intention_two = CellIntention::new(2, IntentionType::Target);
+        for (first, second) in [(&intention_one, &intention_two), (&intention_two, &intention_one)] {
+            let (winner, conflict_type) = resolve_simple_rules(first, second, &vehicles, DrivingSide::Right);
+            assert_eq!(winner.get_vehicle_id(), 1);
+            assert_eq!(conflict_type, ConflictType::Cyclist);
+        }
+
+        // Cyclist turning itself follows regular rules
+        vehicles.get_mut(&1).unwrap().intention.turning = true;
+        let (winner, conflict_type) = resolve_simple_rules(&intention_one, &intention_two, &vehicles, DrivingSide::Right);
+        assert_eq!(winner.get_vehicle_id(), 2);
+        assert_eq!(conflict_type, ConflictType::MergeForward);
+
+        // Emergency vehicle does not give way
+        vehicles.get_mut(&1).unwrap().intention.turning = false;
+        vehicles.get_mut(&2).unwrap().vehicle_type = AgentType::Emergency;
+        let (winner, conflict_type) = resolve_simple_rules(&intention_one, &intention_two, &vehicles, DrivingSide::Right);
+        assert_eq!(winner.get_vehicle_id(), 2);
+        assert_eq!(conflict_type, ConflictType::Emergency);
+    }
 }
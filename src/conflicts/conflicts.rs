@@ -1,10 +1,10 @@
 use crate::behaviour::BehaviourType;
 use crate::agents::{VehicleID, Vehicle};
 use crate::conflict_zones::{ConflictWinnerType, ConflictZone, ConflictZoneID};
-use crate::conflicts::resolve_simple_rules;
+use crate::conflicts::{has_cyclist_advantage, has_emergency_advantage, resolve_simple_rules};
 use crate::grid::cell::{Cell, CellID};
 use crate::grid::road_network::GridRoads;
-use crate::maneuver::LaneChangeType;
+use crate::maneuver::{DrivingSide, LaneChangeType};
 use crate::intentions::{CellIntention, IntentionType, Intentions};
 use crate::utils::rand::rng;
 use crate::verbose::{LocalLogger, VerboseLevel};
@@ -46,6 +46,10 @@ pub enum ConflictType {
     /// Both vehicles are going to change lane but trajectories on one vehicle
     /// and other's tail are going to intersect and some.
     TailCrossLaneChange,
+    /// One of the vehicles is an emergency one, so it has priority regardless of other rules.
+    Emergency,
+    /// Cyclist keeps its lane while the other vehicle is turning across its path, so the cyclist has priority.
+    Cyclist,
 }
 
 impl fmt::Display for ConflictType {
@@ -70,6 +74,8 @@ impl fmt::Display for ConflictType {
     /// assert_eq!(format!("{}", ConflictType::Tail), "tail");
     /// assert_eq!(format!("{}", ConflictType::SelfTail), "self_tail");
     /// assert_eq!(format!("{}", ConflictType::TailCrossLaneChange), "tail+cross+lane_change");
+    /// assert_eq!(format!("{}", ConflictType::Emergency), "emergency");
+    /// assert_eq!(format!("{}", ConflictType::Cyclist), "cyclist");
     /// ```
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let as_str = match self {
@@ -84,6 +90,8 @@ impl fmt::Display for ConflictType {
             ConflictType::Tail => "tail",
             ConflictType::SelfTail => "self_tail",
             ConflictType::TailCrossLaneChange => "tail+cross+lane_change",
+            ConflictType::Emergency => "emergency",
+            ConflictType::Cyclist => "cyclist",
         };
         write!(f, "{}", as_str)
     }
@@ -160,12 +168,15 @@ pub struct TrajectoryConflictInfo {
 ///
 /// Checks if a vehicle's intended maneuver (especially lane changes) will intersect with another vehicle's path or tail.
 /// Returns details about the conflict and which vehicle has priority, or None if no conflict is found.
+/// The vehicle doing the overtaking maneuver (see [`DrivingSide::overtaking_maneuver`]) has priority
+/// unless the other one is aggressive.
 pub fn find_cross_trajectories_conflict_naive(
     cell_intention: &CellIntention,
     intention_cell: &Cell,
     collected_intentions: &Intentions,
     net: &GridRoads,
     vehicles: &indexmap::IndexMap<VehicleID, Vehicle>,
+    driving_side: DrivingSide,
 ) -> Result<Option<TrajectoryConflictInfo>, ConflictError> {
     /* No straight conflict, but could be crossing trajectories conflict */
     // A        B
@@ -298,14 +309,22 @@ pub fn find_cross_trajectories_conflict_naive(
             // For tail conflicts, side vehicle has priority
             vehicle.id
         } else {
+            // Emergency vehicle has priority over any other one
+            if has_emergency_advantage(vehicle, side_vehicle) {
+                vehicle.id
+            } else if has_emergency_advantage(side_vehicle, vehicle) {
+                side_vehicle.id
             // Check behavior types for priority
-            if side_vehicle.strategy_type != vehicle.strategy_type && 
+            } else if side_vehicle.strategy_type != vehicle.strategy_type && 
                vehicle.strategy_type == BehaviourType::Aggressive {
                 // Aggressive vehicle has priority
                 vehicle.id
-            } else {
-                // Left maneuver has priority over right maneuver (side_vehicle is doing LEFT)
+            } else if driving_side.overtaking_maneuver() == LaneChangeType::ChangeLeft {
+                // Left maneuver has priority over right maneuver in right-hand traffic (side_vehicle is doing LEFT)
                 side_vehicle.id
+            } else {
+                // Right maneuver has priority over left maneuver in left-hand traffic
+                vehicle.id
             }
         };
         return Ok(Some(TrajectoryConflictInfo {
@@ -346,6 +365,52 @@ impl CellConflict {
     }
 }
 
+/// Checks whether the vehicle passes the cell on the current step: either occupies it (with head or tail)
+/// or moves through it.
+fn passes_cell(vehicle: &Vehicle, cell_id: CellID) -> bool {
+    vehicle.cell_id == cell_id
+        || vehicle.tail_cells.contains(&cell_id)
+        || vehicle.intention.intermediate_cells.contains(&cell_id)
+}
+
+/// Finds the merge conflict zone (both edges target the intention cell) matching ways of two vehicles.
+///
+/// Several conflict zones could share the same target cell, so the zone is picked by its edges: the zone whose
+/// edge sources are passed by both vehicles (one source per vehicle) is preferred, otherwise the first zone with
+/// any of the edge sources passed by any of the vehicles is used.
+/// Returns the conflict zone ID or None if no conflict zone matches the vehicles.
+pub fn find_merge_conflict_zone(
+    intention_cell_id: CellID,
+    conflict_zones: &HashMap<ConflictZoneID, ConflictZone>,
+    cells_conflicts_zones: &HashMap<CellID, Vec<ConflictZoneID>>,
+    vehicle_one: &Vehicle,
+    vehicle_two: &Vehicle,
+) -> Option<ConflictZoneID> {
+    let mut partial_match = None;
+    for &conflict_zone_id in cells_conflicts_zones.get(&intention_cell_id)? {
+        let Some(conflict_zone) = conflict_zones.get(&conflict_zone_id) else {
+            continue;
+        };
+        let first_edge = conflict_zone.get_first_edge();
+        let second_edge = conflict_zone.get_second_edge();
+        if first_edge.source == second_edge.source
+            || first_edge.target != intention_cell_id
+            || second_edge.target != intention_cell_id
+        {
+            continue;
+        }
+        let (one_first, one_second) = (passes_cell(vehicle_one, first_edge.source), passes_cell(vehicle_one, second_edge.source));
+        let (two_first, two_second) = (passes_cell(vehicle_two, first_edge.source), passes_cell(vehicle_two, second_edge.source));
+        if (one_first && two_second) || (one_second && two_first) {
+            return Some(conflict_zone_id);
+        }
+        if partial_match.is_none() && (one_first || one_second || two_first || two_second) {
+            partial_match = Some(conflict_zone_id);
+        }
+    }
+    partial_match
+}
+
 /// Checks if two intentions for the same cell are in a conflict zone and determines the winner.
 ///
 /// Uses the conflict zone's priority rules to decide which vehicle gets to move.
@@ -353,52 +418,49 @@ impl CellConflict {
 pub fn find_zone_conflict_for_two_intentions(
     intention_cell_id: CellID,
     conflict_zones: &HashMap<ConflictZoneID, ConflictZone>,
-    cells_conflicts_zones: &HashMap<CellID, ConflictZoneID>,
+    cells_conflicts_zones: &HashMap<CellID, Vec<ConflictZoneID>>,
+    vehicle_one: &Vehicle,
+    vehicle_two: &Vehicle,
 ) -> Option<CellID> {
-    // Check if the intention cell is part of a conflict zone
-    let conflict_zone_id = cells_conflicts_zones.get(&intention_cell_id)?;
-
-    // Get the conflict zone
-    let conflict_zone = conflict_zones.get(conflict_zone_id)?;
+    // Conflict zone where both edges target the intention cell and sources are passed by the vehicles.
+    // It overrides the normal conflict resolution (e.g. for ForwardLaneChange)
+    let conflict_zone_id = find_merge_conflict_zone(
+        intention_cell_id,
+        conflict_zones,
+        cells_conflicts_zones,
+        vehicle_one,
+        vehicle_two,
+    )?;
+    let conflict_zone = conflict_zones.get(&conflict_zone_id)?;
 
     let first_edge = conflict_zone.get_first_edge();
     let second_edge = conflict_zone.get_second_edge();
 
-    // Check specific conflict conditions:
-    // 1. First and second edges have different source cells
-    // 2. Both edges target the same cell (the intention cell)
-    // 3. If so, this is considered a conflict in a conflict zone
-    // which overrides the normal conflict resolution (e.g. for ForwardLaneChange)
-    if first_edge.source != second_edge.source
-        && first_edge.target == second_edge.target
-        && first_edge.target == intention_cell_id
-    {
-        // Determine winner based on conflict zone winner type
-        match conflict_zone.get_winner_type() {
-            ConflictWinnerType::First => return Some(first_edge.source),
-            ConflictWinnerType::Second => return Some(second_edge.source),
-            _ => {}
-        }
-        // Random selection (coin flip)
-        let mut rng = rng();
-        if rng.random_bool(0.5) {
-            return Some(first_edge.source);
-        }
-        return Some(second_edge.source);
+    // Determine winner based on conflict zone winner type
+    match conflict_zone.get_current_winner_type() {
+        ConflictWinnerType::First => return Some(first_edge.source),
+        ConflictWinnerType::Second => return Some(second_edge.source),
+        _ => {}
     }
-    None // No conflict detected
+    // Random selection (coin flip)
+    let mut rng = rng();
+    if rng.random_bool(0.5) {
+        return Some(first_edge.source);
+    }
+    Some(second_edge.source)
 }
 
 
 /// Finds trajectory conflicts within predefined conflict zones.
 ///
 /// Looks for special intersection scenarios defined by conflict zones, and returns a CellConflict and zone ID if found.
+/// Emergency vehicles and cyclists going straight across the path of turning vehicles win regardless of the zone's winner.
 pub fn find_conflicts_in_conflict_zones(
     cell_intention: &CellIntention,
     intention_cell: &Cell,
     collected_intentions: &Intentions,
     conflict_zones: &HashMap<ConflictZoneID, ConflictZone>,
-    cells_conflicts_zones: &HashMap<CellID, ConflictZoneID>,
+    cells_conflicts_zones: &HashMap<CellID, Vec<ConflictZoneID>>,
     explored_conflict_zones: &mut HashSet<ConflictZoneID>,
     vehicles: &indexmap::IndexMap<VehicleID, Vehicle>,
 ) -> Result<Option<(CellConflict, ConflictZoneID)>, ConflictError> {
@@ -420,57 +482,72 @@ pub fn find_conflicts_in_conflict_zones(
     let cell_b = intention_cell.get_id();
     let conflict_type = ConflictType::CrossConflictZone;
 
+    let first_vehicle = vehicles.get(&vehicle_id).ok_or(
+        ConflictError::InvalidVehicle(format!("Vehicle {} not found", vehicle_id))
+    )?;
+
     // Check if intention cell is part of any conflict zones
-    let cell_b_conflict_zone_id = match cells_conflicts_zones.get(&cell_b) {
-        Some(zone_id) => *zone_id,
+    let cell_b_conflict_zone_ids = match cells_conflicts_zones.get(&cell_b) {
+        Some(zone_ids) => zone_ids,
         None => return Ok(None), // Not part of any conflict zone
     };
 
-    // Check if this conflict zone has already been explored
-    if explored_conflict_zones.contains(&cell_b_conflict_zone_id) {
-        return Ok(None); // Zone already processed
-    }
+    // Several conflict zones could share the intention cell: pick the first one with the conflicting vehicle
+    let mut found: Option<(ConflictZoneID, &ConflictZone, &CellIntention)> = None;
+    for &zone_id in cell_b_conflict_zone_ids {
+        // Check if this conflict zone has already been explored
+        if explored_conflict_zones.contains(&zone_id) {
+            continue; // Zone already processed
+        }
 
-    // Get the conflict zone
-    let conflict_zone = conflict_zones.get(&cell_b_conflict_zone_id)
-        .ok_or(ConflictError::InvalidVehicle(
-            format!("Conflict zone {} not found", cell_b_conflict_zone_id)
-        ))?;
+        // Get the conflict zone
+        let conflict_zone = conflict_zones.get(&zone_id)
+            .ok_or(ConflictError::InvalidVehicle(
+                format!("Conflict zone {} not found", zone_id)
+            ))?;
 
-    let first_edge = conflict_zone.get_first_edge();
-    let second_edge = conflict_zone.get_second_edge();
+        let first_edge = conflict_zone.get_first_edge();
+        let second_edge = conflict_zone.get_second_edge();
 
-    // Determine which edge corresponds to current vehicle intention cell
-    let (cell_a, cell_x) = if first_edge.target == cell_b && second_edge.target != cell_b {
-        (second_edge.target, second_edge.source)
-    } else if first_edge.target != cell_b && second_edge.target == cell_b {
-        (first_edge.target, first_edge.source)
-    } else {
-        return Ok(None); // No valid conflict zone configuration
-    };
+        // Determine which edge corresponds to current vehicle intention cell
+        let (own_source, cell_a, cell_x) = if first_edge.target == cell_b && second_edge.target != cell_b {
+            (first_edge.source, second_edge.target, second_edge.source)
+        } else if first_edge.target != cell_b && second_edge.target == cell_b {
+            (second_edge.source, first_edge.target, first_edge.source)
+        } else {
+            continue; // No valid conflict zone configuration
+        };
+        // The edge should be the one the vehicle is going along
+        if !passes_cell(first_vehicle, own_source) {
+            continue;
+        }
 
-    // Get intentions for cell A
-    let cell_a_intentions = match collected_intentions.get(&cell_a) {
-        Some(intentions) => intentions,
-        None => return Ok(None), // No other intentions in conflict zone
-    };
+        // Get intentions for cell A
+        let cell_a_intentions = match collected_intentions.get(&cell_a) {
+            Some(intentions) => intentions,
+            None => continue, // No other intentions in conflict zone
+        };
 
-    // Find vehicle which has source cell X and is not already in conflict
-    let mut second_cell_intention: Option<&CellIntention> = None;
-    for neighbor_intention in cell_a_intentions {
-        let neighbor_vehicle = vehicles.get(&neighbor_intention.get_vehicle_id()).ok_or(
-            ConflictError::InvalidVehicle(format!("Vehicle {} not found", neighbor_intention.get_vehicle_id()))
-        )?;
-        if neighbor_vehicle.cell_id == cell_x && !neighbor_vehicle.is_conflict_participant {
-            second_cell_intention = Some(neighbor_intention);
+        // Find vehicle which has source cell X and is not already in conflict
+        for neighbor_intention in cell_a_intentions {
+            let neighbor_vehicle = vehicles.get(&neighbor_intention.get_vehicle_id()).ok_or(
+                ConflictError::InvalidVehicle(format!("Vehicle {} not found", neighbor_intention.get_vehicle_id()))
+            )?;
+            if neighbor_vehicle.cell_id == cell_x && !neighbor_vehicle.is_conflict_participant {
+                found = Some((zone_id, conflict_zone, neighbor_intention));
+                break;
+            }
+        }
+        if found.is_some() {
             break;
         }
     }
 
-    let second_cell_intention = match second_cell_intention {
-        Some(intention) => intention,
+    let (cell_b_conflict_zone_id, conflict_zone, second_cell_intention) = match found {
+        Some(found) => found,
         None => return Ok(None), // No conflicts
     };
+    let first_edge = conflict_zone.get_first_edge();
 
     let second_vehicle = vehicles.get(&second_cell_intention.get_vehicle_id()).ok_or(
         ConflictError::InvalidVehicle(format!("Vehicle {} not found", second_cell_intention.get_vehicle_id()))
@@ -494,6 +571,18 @@ pub fn find_conflicts_in_conflict_zones(
             format!("Incorrect cell intentions for tails. first: {:?}, second: {:?}", 
                 cell_intention, second_cell_intention)
         ));
+    } else if has_emergency_advantage(first_vehicle, second_vehicle) {
+        // First vehicle is an emergency one
+        (vec![vehicle_id, second_cell_intention.get_vehicle_id()], 0)
+    } else if has_emergency_advantage(second_vehicle, first_vehicle) {
+        // Second vehicle is an emergency one
+        (vec![second_cell_intention.get_vehicle_id(), vehicle_id], 0)
+    } else if has_cyclist_advantage(first_vehicle, second_vehicle) {
+        // First vehicle is a cyclist going straight, the second one turns across its path
+        (vec![vehicle_id, second_cell_intention.get_vehicle_id()], 0)
+    } else if has_cyclist_advantage(second_vehicle, first_vehicle) {
+        // Second vehicle is a cyclist going straight, the first one turns across its path
+        (vec![second_cell_intention.get_vehicle_id(), vehicle_id], 0)
     } else {
         // No tails - use conflict zone winner
         match conflict_zone.get_winner_type() {
@@ -546,10 +635,11 @@ pub fn find_conflicts_in_conflict_zones(
 pub fn find_conflict_type<'a>(
     intention_cell_id: CellID,
     conflict_zones: &HashMap<ConflictZoneID, ConflictZone>,
-    cells_conflicts_zones: &HashMap<CellID, ConflictZoneID>,
+    cells_conflicts_zones: &HashMap<CellID, Vec<ConflictZoneID>>,
     intention_one: &'a CellIntention,
     intention_two: &'a CellIntention,
     vehicles: &indexmap::IndexMap<VehicleID, Vehicle>,
+    driving_side: DrivingSide,
 ) -> (&'a CellIntention, ConflictType) {
     let intention_type_one = intention_one.int_type;
     let intention_type_two = intention_two.int_type;
@@ -570,11 +660,28 @@ pub fn find_conflict_type<'a>(
     let vehicle_one = vehicles.get(&intention_one.get_vehicle_id()).unwrap();
     let vehicle_two = vehicles.get(&intention_two.get_vehicle_id()).unwrap();
 
+    // Emergency vehicle wins regardless of conflict zones (it could only wait for tails)
+    if has_emergency_advantage(vehicle_one, vehicle_two) {
+        return (intention_one, ConflictType::Emergency);
+    }
+    if has_emergency_advantage(vehicle_two, vehicle_one) {
+        return (intention_two, ConflictType::Emergency);
+    }
+    // Turning vehicle gives way to a cyclist going straight regardless of conflict zones
+    if has_cyclist_advantage(vehicle_one, vehicle_two) {
+        return (intention_one, ConflictType::Cyclist);
+    }
+    if has_cyclist_advantage(vehicle_two, vehicle_one) {
+        return (intention_two, ConflictType::Cyclist);
+    }
+
     // Check if there's a conflict zone for this cell
     let conflict_zone_winner_source_cell = find_zone_conflict_for_two_intentions(
-        intention_cell_id, 
-        conflict_zones, 
-        cells_conflicts_zones
+        intention_cell_id,
+        conflict_zones,
+        cells_conflicts_zones,
+        vehicle_one,
+        vehicle_two,
     );
     // The conflict zone is found - early return
     if let Some(winner_cell) = conflict_zone_winner_source_cell {
@@ -615,7 +722,7 @@ pub fn find_conflict_type<'a>(
     }
 
     // No conflict zone found, use simple rules
-    resolve_simple_rules(intention_one, intention_two, vehicles)
+    resolve_simple_rules(intention_one, intention_two, vehicles, driving_side)
 }
 
 /// Helper function to find element index in slice
@@ -629,9 +736,10 @@ fn find_elem_in_slice(target: CellID, slice: &[CellID]) -> Option<usize> {
 pub fn new_conflict_multiple(
     cell: &Cell,
     conflict_zones: &HashMap<ConflictZoneID, ConflictZone>,
-    cells_conflicts_zones: &HashMap<CellID, ConflictZoneID>,
+    cells_conflicts_zones: &HashMap<CellID, Vec<ConflictZoneID>>,
     cell_intentions: &[CellIntention],
     vehicles: &indexmap::IndexMap<VehicleID, Vehicle>,
+    driving_side: DrivingSide,
 ) -> Result<CellConflict, ConflictError> {
     if cell_intentions.len() < 2 {
         return Err(ConflictError::InvalidVehicle(
@@ -678,6 +786,7 @@ pub fn new_conflict_multiple(
             priority_intention,
             &cell_intentions[i],
             vehicles,
+            driving_side,
         );
         priority_intention = winner;
         conflict_type = c_type;
@@ -702,11 +811,13 @@ pub fn new_conflict_multiple(
 /// Collects all conflicts from the current set of vehicle intentions for this simulation step.
 ///
 /// Iterates over all intentions, detects conflicts (including merges, crossings, and tails), and returns a list of CellConflict records for resolution.
+/// Priorities of lane change conflicts depend on `driving_side`.
 pub fn collect_conflicts(
     collected_intentions: &Intentions,
     net: &GridRoads,
     conflict_zones: &HashMap<ConflictZoneID, ConflictZone>,
-    cells_conflicts_zones: &HashMap<CellID, ConflictZoneID>,
+    cells_conflicts_zones: &HashMap<CellID, Vec<ConflictZoneID>>,
+    driving_side: DrivingSide,
     verbose: &LocalLogger,
     vehicles: &mut indexmap::IndexMap<VehicleID, Vehicle>,
 ) -> Result<Vec<CellConflict>, ConflictError> {
@@ -790,7 +901,7 @@ pub fn collect_conflicts(
 
             // Check for crossing trajectories
             let cross_conflict_info = find_cross_trajectories_conflict_naive(
-                cell_intention, intention_cell, collected_intentions, net, vehicles
+                cell_intention, intention_cell, collected_intentions, net, vehicles, driving_side
             )?;
 
             if verbose.is_at_least(VerboseLevel::Additional) {
@@ -878,7 +989,7 @@ pub fn collect_conflicts(
 
             // Check if there is a conflict between more than two vehicles
             let conflict = new_conflict_multiple(
-                intention_cell, conflict_zones, cells_conflicts_zones, cell_intentions, vehicles
+                intention_cell, conflict_zones, cells_conflicts_zones, cell_intentions, vehicles, driving_side
             )?;
 
             if conflict.conflict_type == ConflictType::SelfTail {
@@ -9,7 +9,7 @@ mod tests {
     use crate::intentions::{CellIntention, IntentionType, Intentions};
     use crate::grid::cell::{Cell, CellID};
     use crate::grid::road_network::GridRoads;
-    use crate::maneuver::LaneChangeType;
+    use crate::maneuver::{DrivingSide, LaneChangeType};
     use std::collections::HashMap;
 
     #[test]
@@ -17,17 +17,31 @@ mod tests {
         // Case 1: Both edges have the same target cell
         // Instead of basic conflict resolution, the conflict zone winner type should be used
         let mut conflict_zones: HashMap<ConflictZoneID, ConflictZone> = HashMap::new();
-        let mut cells_conflicts_zones: HashMap<CellID, ConflictZoneID> = HashMap::new();
+        let mut cells_conflicts_zones: HashMap<CellID, Vec<ConflictZoneID>> = HashMap::new();
         let first_edge = ConflictEdge { source: 100, target: 200 };
         let second_edge = ConflictEdge { source: 111, target: 200 };
         let zone = ConflictZone::new(1, first_edge, second_edge)
             .with_winner_type(ConflictWinnerType::Second)
             .build();
         conflict_zones.insert(1, zone);
-        cells_conflicts_zones.insert(200, 1);
+        // Case 2: Another conflict zone shares the target cell, but its edges come from other cells
+        let other_zone = ConflictZone::new(2, ConflictEdge { source: 120, target: 200 }, ConflictEdge { source: 121, target: 200 })
+            .with_winner_type(ConflictWinnerType::First)
+            .build();
+        conflict_zones.insert(2, other_zone);
+        cells_conflicts_zones.insert(200, vec![2, 1]);
+        let vehicle_one = Vehicle::new(1).with_cell(100).build();
+        let vehicle_two = Vehicle::new(2).with_cell(111).build();
         let correct_winner = 111;
-        let winner = find_zone_conflict_for_two_intentions(200, &conflict_zones, &cells_conflicts_zones);
+        let winner = find_zone_conflict_for_two_intentions(200, &conflict_zones, &cells_conflicts_zones, &vehicle_one, &vehicle_two);
         assert_eq!(winner, Some(correct_winner), "Conflict winner is incorrect");
+        let winner = find_zone_conflict_for_two_intentions(200, &conflict_zones, &cells_conflicts_zones, &vehicle_two, &vehicle_one);
+        assert_eq!(winner, Some(correct_winner), "Conflict winner should not depend on the order of vehicles");
+        // Case 3: Vehicles are coming from cells which are not sources of any conflict zone
+        let outsider_one = Vehicle::new(3).with_cell(50).build();
+        let outsider_two = Vehicle::new(4).with_cell(51).build();
+        let winner = find_zone_conflict_for_two_intentions(200, &conflict_zones, &cells_conflicts_zones, &outsider_one, &outsider_two);
+        assert_eq!(winner, None, "There should be no conflict zone for vehicles coming from other cells");
     }
 
     #[test]
@@ -50,7 +64,7 @@ mod tests {
     let mut vehicles = VehiclesStorage::new();
         vehicles.insert(vehicle.id, vehicle);
         let result = find_cross_trajectories_conflict_naive(
-            &cell_intention, &intention_cell, &collected_intentions, &grid, &vehicles
+            &cell_intention, &intention_cell, &collected_intentions, &grid, &vehicles, DrivingSide::Right
         );
         assert!(result.is_ok());
         assert!(result.unwrap().is_none());
@@ -80,7 +94,7 @@ mod tests {
     let mut vehicles = VehiclesStorage::new();
         vehicles.insert(vehicle.id, vehicle);
         let result = find_cross_trajectories_conflict_naive(
-            &cell_intention, &intention_cell, &collected_intentions, &grid, &vehicles
+            &cell_intention, &intention_cell, &collected_intentions, &grid, &vehicles, DrivingSide::Right
         );
         assert!(result.is_ok());
         assert!(result.unwrap().is_none());
@@ -130,7 +144,7 @@ mod tests {
         vehicles.insert(v2_id, vehicle2);
 
         let result = find_cross_trajectories_conflict_naive(
-            &vehicle1_intention, &intention_cell, &collected_intentions, &net, &vehicles
+            &vehicle1_intention, &intention_cell, &collected_intentions, &net, &vehicles, DrivingSide::Right
         );
 
         assert!(result.is_ok());
@@ -199,7 +213,7 @@ mod tests {
     vehicles.insert(2, vehicle2);
 
         let result = find_cross_trajectories_conflict_naive(
-            &vehicle1_intention, intention_cell, &collected_intentions, &net, &vehicles
+            &vehicle1_intention, intention_cell, &collected_intentions, &net, &vehicles, DrivingSide::Right
         );
 
         // Assertions
@@ -256,6 +270,7 @@ mod tests {
             &intention_one,
             &intention_two,
             &vehicles,
+            DrivingSide::Right,
         );
 
         assert_eq!(winner.get_vehicle_id(), correct_id, "Tail intention should win over transit");
@@ -271,7 +286,7 @@ mod tests {
             .with_winner_type(ConflictWinnerType::First)
             .build();
         conflict_zones.insert(1, zone);
-        cells_conflicts_zones.insert(15, 1);
+        cells_conflicts_zones.insert(15, vec![1]);
 
         let mut vehicle_three = Vehicle::new(3)
             .with_cell(10)
@@ -309,6 +324,7 @@ mod tests {
             &intention_three,
             &intention_four,
             &vehicles,
+            DrivingSide::Right,
         );
 
         assert_eq!(winner.get_vehicle_id(), correct_id, "First edge source vehicle should win");
@@ -351,6 +367,7 @@ mod tests {
             &intention_five,
             &intention_six,
             &vehicles,
+            DrivingSide::Right,
         );
 
         assert_eq!(winner.get_vehicle_id(), correct_id, "Left maneuver should have priority over right");
@@ -393,6 +410,7 @@ mod tests {
             &intention_seven,
             &intention_eight,
             &vehicles,
+            DrivingSide::Right,
         );
 
         assert_eq!(winner.get_vehicle_id(), correct_id, "Aggressive vehicle should win over cooperative");
@@ -435,7 +453,7 @@ mod tests {
         vehicles.insert(1, vehicle1);
         vehicles.insert(2, vehicle2);
 
-        let result = new_conflict_multiple(&cell, &conflict_zones, &cells_conflicts_zones, &intentions, &vehicles);
+        let result = new_conflict_multiple(&cell, &conflict_zones, &cells_conflicts_zones, &intentions, &vehicles, DrivingSide::Right);
         
         assert!(result.is_ok());
         let conflict = result.unwrap();
@@ -464,7 +482,7 @@ mod tests {
         let cell = Cell::new(15).build();
             let mut vehicles = VehiclesStorage::new();
         vehicles.insert(1, vehicle1);
-        let result = new_conflict_multiple(&cell, &HashMap::new(), &HashMap::new(), &intentions, &vehicles);
+        let result = new_conflict_multiple(&cell, &HashMap::new(), &HashMap::new(), &intentions, &vehicles, DrivingSide::Right);
         
         assert!(result.is_ok());
         let conflict = result.unwrap();
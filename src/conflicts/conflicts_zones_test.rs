@@ -10,7 +10,7 @@ mod tests {
     use crate::grid::cell::{Cell};
     use crate::grid::zones::ZoneType;
     use crate::grid::road_network::GridRoads;
-    use crate::maneuver::LaneChangeType;
+    use crate::maneuver::{DrivingSide, LaneChangeType};
     use crate::verbose::{LocalLogger, VerboseLevel};
     use std::collections::{HashMap, HashSet};
     use indexmap::IndexMap;
@@ -59,8 +59,8 @@ mod tests {
         conflict_zones.insert(1, zone);
 
         let mut cells_conflicts_zones = HashMap::new();
-        cells_conflicts_zones.insert(10, 1);
-        cells_conflicts_zones.insert(4, 1);
+        cells_conflicts_zones.insert(10, vec![1]);
+        cells_conflicts_zones.insert(4, vec![1]);
 
         let mut collected_intentions = Intentions::new();
         collected_intentions.add_intention(vehicles.get_mut(&2).unwrap(), IntentionType::Target);
@@ -143,8 +143,8 @@ mod tests {
         conflict_zones.insert(1, zone);
 
         let mut cells_conflicts_zones = HashMap::new();
-        cells_conflicts_zones.insert(10, 1);
-        cells_conflicts_zones.insert(4, 1);
+        cells_conflicts_zones.insert(10, vec![1]);
+        cells_conflicts_zones.insert(4, vec![1]);
 
     let mut collected_intentions = Intentions::new();
     collected_intentions.add_intention(vehicles.get_mut(&2).unwrap(), IntentionType::Target);
@@ -223,7 +223,7 @@ mod tests {
         conflict_zones.insert(1, zone);
 
         let mut cells_conflicts_zones = HashMap::new();
-        cells_conflicts_zones.insert(3, 1);
+        cells_conflicts_zones.insert(3, vec![1]);
 
     let mut collected_intentions = Intentions::new();
     collected_intentions.add_intention(vehicles.get_mut(&2).unwrap(), IntentionType::Target);
@@ -302,8 +302,8 @@ mod tests {
         conflict_zones.insert(1, zone);
 
         let mut cells_conflicts_zones = HashMap::new();
-        cells_conflicts_zones.insert(5, 1);
-        cells_conflicts_zones.insert(12, 1);
+        cells_conflicts_zones.insert(5, vec![1]);
+        cells_conflicts_zones.insert(12, vec![1]);
 
     let mut collected_intentions = Intentions::new();
     collected_intentions.add_intention(vehicles.get_mut(&2).unwrap(), IntentionType::Target);
@@ -513,8 +513,8 @@ mod tests {
         conflict_zones.insert(1, zone);
 
         let mut cells_conflicts_zones = HashMap::new();
-        cells_conflicts_zones.insert(5, 1);
-        cells_conflicts_zones.insert(12, 1);
+        cells_conflicts_zones.insert(5, vec![1]);
+        cells_conflicts_zones.insert(12, vec![1]);
 
     let mut collected_intentions = Intentions::new();
     collected_intentions.add_intention(vehicles.get_mut(&2).unwrap(), IntentionType::Target);
@@ -599,8 +599,8 @@ mod tests {
         .build());
 
         let mut cells_conflicts_zones = HashMap::new();
-        cells_conflicts_zones.insert(10, 1);
-        cells_conflicts_zones.insert(4, 1);
+        cells_conflicts_zones.insert(10, vec![1]);
+        cells_conflicts_zones.insert(4, vec![1]);
 
     let mut intentions_data = Intentions::new();
     intentions_data.add_intention(vehicles.get_mut(&1).unwrap(), IntentionType::Target);
@@ -618,6 +618,7 @@ mod tests {
             &grid, 
             &conflict_zones, 
             &cells_conflicts_zones, 
+            DrivingSide::Right,
             &verbose,
             &mut vehicles,
         );
@@ -700,8 +701,8 @@ mod tests {
         .build());
 
         let mut cells_conflicts_zones = HashMap::new();
-        cells_conflicts_zones.insert(10, 1);
-        cells_conflicts_zones.insert(4, 1);
+        cells_conflicts_zones.insert(10, vec![1]);
+        cells_conflicts_zones.insert(4, vec![1]);
 
         let mut intentions_data = Intentions::new();
         intentions_data.add_intention(vehicles.get_mut(&1).unwrap(), IntentionType::Target);
@@ -724,6 +725,7 @@ mod tests {
             &grid, 
             &conflict_zones, 
             &cells_conflicts_zones, 
+            DrivingSide::Right,
             &verbose,
             &mut vehicles,
         );
@@ -803,7 +805,7 @@ mod tests {
         .build());
 
         let mut cells_conflicts_zones = HashMap::new();
-        cells_conflicts_zones.insert(3, 1);
+        cells_conflicts_zones.insert(3, vec![1]);
 
     let mut intentions_data = Intentions::new();
     intentions_data.add_intention(vehicles.get_mut(&1).unwrap(), IntentionType::Target);
@@ -821,6 +823,7 @@ mod tests {
             &grid, 
             &conflict_zones, 
             &cells_conflicts_zones, 
+            DrivingSide::Right,
             &verbose,
             &mut vehicles,
         );
@@ -909,8 +912,8 @@ mod tests {
         .build());
 
         let mut cells_conflicts_zones = HashMap::new();
-        cells_conflicts_zones.insert(5, 1);
-        cells_conflicts_zones.insert(12, 1);
+        cells_conflicts_zones.insert(5, vec![1]);
+        cells_conflicts_zones.insert(12, vec![1]);
 
     let mut intentions_data = Intentions::new();
     intentions_data.add_intention(vehicles.get_mut(&1).unwrap(), IntentionType::Target);
@@ -928,6 +931,7 @@ mod tests {
             &grid, 
             &conflict_zones, 
             &cells_conflicts_zones, 
+            DrivingSide::Right,
             &verbose,
             &mut vehicles,
         );
@@ -1134,8 +1138,8 @@ mod tests {
         .build());
 
         let mut cells_conflicts_zones = HashMap::new();
-        cells_conflicts_zones.insert(5, 1);
-        cells_conflicts_zones.insert(12, 1);
+        cells_conflicts_zones.insert(5, vec![1]);
+        cells_conflicts_zones.insert(12, vec![1]);
 
     let mut intentions_data = Intentions::new();
     intentions_data.add_intention(vehicles.get_mut(&1).unwrap(), IntentionType::Target);
@@ -1153,6 +1157,7 @@ mod tests {
             &grid, 
             &conflict_zones, 
             &cells_conflicts_zones, 
+            DrivingSide::Right,
             &verbose,
             &mut vehicles,
         );
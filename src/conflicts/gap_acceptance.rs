@@ -0,0 +1,256 @@
+use crate::agents::{Vehicle, VehicleID};
+use crate::conflict_zones::{time_to_arrival, ConflictEdge, ConflictWinnerType, ConflictZone, ConflictZoneID};
+use crate::grid::cell::CellID;
+use crate::grid::road_network::GridRoads;
+use crate::intentions::hold_back_intention;
+use indexmap::IndexMap;
+use std::collections::HashMap;
+
+/// Distance (in cells) before the major stream edge where approaching vehicles are searched
+pub const GAP_ACCEPTANCE_LOOK_AHEAD: i32 = 20;
+
+/// Returns major and minor stream edges of the conflict zone. Zones without fixed priority
+/// (equal, alternate or undefined winner) have no minor stream.
+fn major_and_minor(zone: &ConflictZone) -> Option<(&ConflictEdge, &ConflictEdge)> {
+    match zone.get_winner_type() {
+        ConflictWinnerType::First => Some((zone.get_first_edge(), zone.get_second_edge())),
+        ConflictWinnerType::Second => Some((zone.get_second_edge(), zone.get_first_edge())),
+        _ => None,
+    }
+}
+
+/// Returns index of the minor edge target in the vehicle's way (intermediate cells and the intention cell)
+/// if the vehicle passes the minor edge on the current step
+fn minor_edge_index(vehicle: &Vehicle, minor: &ConflictEdge) -> Option<usize> {
+    let intention = &vehicle.intention;
+    let mut way = Vec::with_capacity(intention.intermediate_cells.len() + 2);
+    way.push(vehicle.cell_id);
+    way.extend(intention.intermediate_cells.iter());
+    way.push(intention.intention_cell_id);
+    way.windows(2)
+        .position(|pair| pair[0] == minor.source && pair[1] == minor.target)
+}
+
+/// Makes minor stream drivers hold back until they accept the gap in the major stream.
+///
+/// Gap acceptance applies to conflict zones with fixed priority (see [`ConflictWinnerType::First`] and
+/// [`ConflictWinnerType::Second`]) and to drivers with a positive `critical_gap` (see `Vehicle::critical_gap`)
+/// going to pass the minor edge of the zone on the current step. The gap is the time until the next
+/// major stream vehicle arrives at the major edge source (see [`time_to_arrival`]), hence vehicles approaching
+/// the zone are taken into account, not only the ones colliding with the driver on the current step.
+///
+/// The driver accepts the gap when it is not shorter than the driver's critical gap. The driver following
+/// the minor stream vehicle which has entered the zone within the last `follow_up_time` time units
+/// (see `Vehicle::follow_up_time`) accepts the gap not shorter than the follow-up time, so queued drivers
+/// use the same gap one after another. Drivers rejecting the gap stop in front of the minor edge target
+/// (see [`hold_back_intention`]).
+///
+/// # Arguments
+/// * `net` - The road network grid
+/// * `current_state` - Mapping from occupied cell ID to vehicle ID
+/// * `vehicles` - Vehicles storage with prepared intentions
+/// * `conflict_zones` - Conflict zones
+/// * `minor_entries` - Steps when minor stream vehicles have entered conflict zones last time. Updated by the function.
+/// * `step` - Current step
+///
+/// # Returns
+/// Identifiers of vehicles which intentions have been changed (in the storage order).
+///
+/// # Example
+/// ```
+/// use micro_traffic_sim_core::agents::{Vehicle, VehicleIntention};
+/// use micro_traffic_sim_core::conflict_zones::{ConflictEdge, ConflictWinnerType, ConflictZone};
+/// use micro_traffic_sim_core::conflicts::apply_gap_acceptance;
+/// use micro_traffic_sim_core::grid::{cell::Cell, road_network::GridRoads};
+/// use indexmap::IndexMap;
+/// use std::collections::HashMap;
+///
+/// // Main road 1 → ... → 10, on-ramp cell 20 merges into cell 6
+/// let mut net = GridRoads::new();
+/// for id in 1..=10 {
+///     net.add_cell(Cell::new(id).with_forward_node(if id < 10 { id + 1 } else { -1 }).build());
+/// }
+/// net.add_cell(Cell::new(20).with_left_node(6).build());
+/// let zone = ConflictZone::new(1, ConflictEdge { source: 5, target: 6 }, ConflictEdge { source: 20, target: 6 })
+///     .with_winner_type(ConflictWinnerType::First)
+///     .build();
+/// let conflict_zones = HashMap::from([(1, zone)]);
+///
+/// // Merging driver accepts gaps of three time units, major stream vehicle arrives in two
+/// let mut merging = Vehicle::new(1).with_cell(20).with_gap_acceptance(3, 2).build();
+/// merging.set_intention(VehicleIntention { intention_cell_id: 6, intention_speed: 1, ..Default::default() });
+/// let major = Vehicle::new(2).with_cell(1).with_speed(2).build();
+/// let mut vehicles = IndexMap::from([(1, merging), (2, major)]);
+/// let current_state = HashMap::from([(20, 1), (1, 2)]);
+///
+/// let held = apply_gap_acceptance(&net, &current_state, &mut vehicles, &conflict_zones, &mut HashMap::new(), 0);
+/// assert_eq!(held, vec![1]);
+/// assert_eq!(vehicles[&1].intention.intention_cell_id, 20);
+/// ```
+pub fn apply_gap_acceptance(
+    net: &GridRoads,
+    current_state: &HashMap<CellID, VehicleID>,
+    vehicles: &mut IndexMap<VehicleID, Vehicle>,
+    conflict_zones: &HashMap<ConflictZoneID, ConflictZone>,
+    minor_entries: &mut HashMap<ConflictZoneID, i32>,
+    step: i32,
+) -> Vec<VehicleID> {
+    let mut held: Vec<(VehicleID, usize)> = Vec::new();
+    let mut entered: Vec<ConflictZoneID> = Vec::new();
+    for vehicle in vehicles.values().filter(|vehicle| vehicle.critical_gap > 0) {
+        let mut yield_index: Option<usize> = None;
+        let mut accepted: Vec<(ConflictZoneID, usize)> = Vec::new();
+        for (&zone_id, zone) in conflict_zones.iter() {
+            let Some((major, minor)) = major_and_minor(zone) else {
+                continue;
+            };
+            let Some(index) = minor_edge_index(vehicle, minor) else {
+                continue;
+            };
+            let gap = time_to_arrival(net, current_state, vehicles, major.source, GAP_ACCEPTANCE_LOOK_AHEAD);
+            let following = minor_entries
+                .get(&zone_id)
+                .is_some_and(|&entry_step| step - entry_step <= vehicle.follow_up_time);
+            let required_gap = if following { vehicle.follow_up_time } else { vehicle.critical_gap };
+            if gap.is_none_or(|gap| gap >= required_gap) {
+                accepted.push((zone_id, index));
+            } else {
+                yield_index = Some(yield_index.map_or(index, |current| current.min(index)));
+            }
+        }
+        // Vehicle held back by a zone still passes the zones before it
+        entered.extend(
+            accepted
+                .into_iter()
+                .filter(|&(_, index)| yield_index.is_none_or(|yield_index| index < yield_index))
+                .map(|(zone_id, _)| zone_id),
+        );
+        if let Some(index) = yield_index {
+            held.push((vehicle.id, index));
+        }
+    }
+    for zone_id in entered {
+        minor_entries.insert(zone_id, step);
+    }
+    for &(vehicle_id, index) in held.iter() {
+        if let Some(vehicle) = vehicles.get_mut(&vehicle_id) {
+            vehicle.intention = hold_back_intention(vehicle.intention.clone(), vehicle, index);
+        }
+    }
+    held.into_iter().map(|(vehicle_id, _)| vehicle_id).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::agents::VehicleIntention;
+    use crate::grid::cell::Cell;
+    use crate::maneuver::LaneChangeType;
+
+    /// Main road 1..=20 and side road 101..=103 joining it at cell 11
+    fn net() -> GridRoads {
+        let mut net = GridRoads::new();
+        for id in 1..=20 {
+            net.add_cell(Cell::new(id).with_forward_node(if id < 20 { id + 1 } else { -1 }).build());
+        }
+        for id in 101..=103 {
+            net.add_cell(Cell::new(id).with_forward_node(if id < 103 { id + 1 } else { 11 }).build());
+        }
+        net
+    }
+
+    fn zones() -> HashMap<ConflictZoneID, ConflictZone> {
+        let zone = ConflictZone::new(1, ConflictEdge { source: 10, target: 11 }, ConflictEdge { source: 103, target: 11 })
+            .with_winner_type(ConflictWinnerType::First)
+            .build();
+        HashMap::from([(1, zone)])
+    }
+
+    /// Minor stream vehicle going from `cell_id` to the junction and major stream vehicle in `main_cell` with speed 2
+    fn run(cell_id: CellID, main_cell: CellID, minor_entries: &mut HashMap<ConflictZoneID, i32>) -> (Vec<VehicleID>, VehicleIntention) {
+        let mut minor = Vehicle::new(1).with_cell(cell_id).with_gap_acceptance(4, 2).build();
+        let mut way: Vec<CellID> = (cell_id + 1..=103).collect();
+        way.push(11);
+        minor.set_intention(VehicleIntention {
+            intention_cell_id: *way.last().unwrap(),
+            intention_speed: way.len() as i32,
+            intermediate_cells: way[..way.len() - 1].to_vec(),
+            ..Default::default()
+        });
+        let major = Vehicle::new(2).with_cell(main_cell).with_speed(2).build();
+        let mut vehicles = IndexMap::from([(1, minor), (2, major)]);
+        let current_state = HashMap::from([(cell_id, 1), (main_cell, 2)]);
+        let held = apply_gap_acceptance(&net(), &current_state, &mut vehicles, &zones(), minor_entries, 10);
+        (held, vehicles[&1].intention.clone())
+    }
+
+    #[test]
+    fn test_critical_gap() {
+        // Major stream vehicle arrives in three time units: the gap is rejected
+        let mut minor_entries = HashMap::new();
+        let (held, intention) = run(103, 4, &mut minor_entries);
+        assert_eq!(held, vec![1]);
+        assert_eq!(intention.intention_maneuver, LaneChangeType::Block);
+        assert_eq!(intention.intention_speed, 0);
+        assert!(minor_entries.is_empty());
+
+        // Vehicle two cells before the junction stops in front of it
+        let (held, intention) = run(101, 4, &mut minor_entries);
+        assert_eq!(held, vec![1]);
+        assert_eq!(intention.intention_cell_id, 103);
+        assert_eq!(intention.intermediate_cells, vec![102]);
+
+        // Major stream vehicle arrives in four time units: the gap is accepted
+        let (held, intention) = run(103, 2, &mut minor_entries);
+        assert!(held.is_empty());
+        assert_eq!(intention.intention_cell_id, 11);
+        assert_eq!(minor_entries.get(&1), Some(&10));
+    }
+
+    #[test]
+    fn test_held_by_later_zone() {
+        // Side road crosses another road 41..=50 at cell 102 before joining the main road
+        let mut net = net();
+        for id in 41..=50 {
+            net.add_cell(Cell::new(id).with_forward_node(if id < 50 { id + 1 } else { 102 }).build());
+        }
+        let mut conflict_zones = zones();
+        let crossing = ConflictZone::new(2, ConflictEdge { source: 50, target: 102 }, ConflictEdge { source: 101, target: 102 })
+            .with_winner_type(ConflictWinnerType::First)
+            .build();
+        conflict_zones.insert(2, crossing);
+
+        let mut minor = Vehicle::new(1).with_cell(101).with_gap_acceptance(4, 2).build();
+        minor.set_intention(VehicleIntention {
+            intention_cell_id: 11,
+            intention_speed: 3,
+            intermediate_cells: vec![102, 103],
+            ..Default::default()
+        });
+        let major = Vehicle::new(2).with_cell(4).with_speed(2).build();
+        let mut vehicles = IndexMap::from([(1, minor), (2, major)]);
+        let current_state = HashMap::from([(101, 1), (4, 2)]);
+        let mut minor_entries = HashMap::new();
+        // The crossing is free, but the gap in the main road is rejected
+        let held = apply_gap_acceptance(&net, &current_state, &mut vehicles, &conflict_zones, &mut minor_entries, 10);
+        assert_eq!(held, vec![1]);
+        assert_eq!(vehicles[&1].intention.intention_cell_id, 103);
+        // Held vehicle still passes the crossing, but does not enter the main road
+        assert_eq!(minor_entries, HashMap::from([(2, 10)]));
+    }
+
+    #[test]
+    fn test_follow_up_time() {
+        // Previous minor stream vehicle has just entered the zone: shorter gap is accepted
+        let mut minor_entries = HashMap::from([(1, 9)]);
+        let (held, _) = run(103, 6, &mut minor_entries);
+        assert!(held.is_empty());
+        // ... but not shorter than the follow-up time
+        let (held, _) = run(103, 8, &mut minor_entries);
+        assert_eq!(held, vec![1]);
+        // Previous one has entered long ago
+        let mut minor_entries = HashMap::from([(1, 5)]);
+        let (held, _) = run(103, 6, &mut minor_entries);
+        assert_eq!(held, vec![1]);
+    }
+}
@@ -19,6 +19,7 @@
 //! 2. Update positions  
 //! 3. Traffic light updates
 //! 4. Prepare intentions      ← intentions module
+//!    Gap acceptance          ← THIS MODULE
 //! 5. Collect conflicts       ← THIS MODULE
 //! 6. Solve conflicts         ← THIS MODULE
 //! 7. Execute movement        ← movement module
@@ -34,6 +35,7 @@
 //! - [`conflicts::ConflictError`] – Error types for conflict detection
 //! - [`conflicts_solver::ConflictSolverError`] – Error types for conflict resolution
 //! - [`conflict_rule`](crate::conflicts::conflict_rule) – Priority rules for resolving conflicts
+//! - [`gap_acceptance::apply_gap_acceptance`] – Minor stream drivers hold back until they accept the gap in the major stream
 //!
 //! ## Conflict types
 //!
@@ -70,10 +72,11 @@
 mod conflicts;
 mod conflict_rule;
 mod conflicts_solver;
+mod gap_acceptance;
 
 #[cfg(test)]
 mod conflicts_test;
 #[cfg(test)]
 mod conflicts_zones_test;
 
-pub use self::{conflicts::*, conflict_rule::*, conflicts_solver::*};
+pub use self::{conflicts::*, conflict_rule::*, conflicts_solver::*, gap_acceptance::*};
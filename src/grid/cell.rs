@@ -1,3 +1,4 @@
+use crate::agents_types::AgentType;
 use crate::geom::{new_point, Point, PointType};
 use crate::grid::zones::ZoneType;
 use std::fmt;
@@ -61,6 +62,8 @@ pub struct Cell {
     meso_link_id: i64,
     /// Current state of the cell (e.g., free, banned).
     state: CellState,
+    /// Agent types permitted in the cell. `None` means the zone's default (see [`ZoneType::allowed_agents`]).
+    allowed_agents: Option<Vec<AgentType>>,
 }
 
 impl Cell {
@@ -94,6 +97,7 @@ impl Cell {
                 right_cell: -1,
                 meso_link_id: -1,
                 state: CellState::Free,
+                allowed_agents: None,
             },
         }
     }
@@ -296,6 +300,80 @@ impl Cell {
     pub fn get_zone_type(&self) -> ZoneType {
         self.type_zone
     }
+
+    /// Returns the mesoscopic link identifier of the cell.
+    ///
+    /// # Returns
+    /// The mesoscopic link identifier (`-1` if the cell is not linked to a mesoscopic graph).
+    ///
+    /// # Example
+    /// ```
+    /// use micro_traffic_sim_core::grid::cell::Cell;
+    /// let cell = Cell::new(1).with_meso_link(7).build();
+    /// assert_eq!(cell.get_meso_link_id(), 7);
+    /// ```
+    pub fn get_meso_link_id(&self) -> i64 {
+        self.meso_link_id
+    }
+
+    /// Returns agent types permitted in the cell: its own permissions or the zone's default ones.
+    ///
+    /// # Returns
+    /// `None` if every agent type is permitted.
+    pub fn get_allowed_agents(&self) -> Option<&[AgentType]> {
+        self.allowed_agents.as_deref().or(self.type_zone.allowed_agents())
+    }
+
+    /// Checks whether agents of the given type may enter the cell.
+    ///
+    /// # Example
+    /// ```
+    /// use micro_traffic_sim_core::agents_types::AgentType;
+    /// use micro_traffic_sim_core::grid::cell::Cell;
+    /// use micro_traffic_sim_core::grid::zones::ZoneType;
+    /// let bus_lane = Cell::new(1).with_zone_type(ZoneType::LaneForBus).build();
+    /// assert!(bus_lane.is_allowed_for(AgentType::Bus));
+    /// assert!(!bus_lane.is_allowed_for(AgentType::Car));
+    /// let common = Cell::new(2).with_zone_type(ZoneType::Common).build();
+    /// assert!(common.is_allowed_for(AgentType::Car));
+    /// ```
+    pub fn is_allowed_for(&self, agent_type: AgentType) -> bool {
+        self.get_allowed_agents().is_none_or(|agents| agents.contains(&agent_type))
+    }
+
+    /// Sets agent types permitted in the cell (e.g. bus lane operating at peak hours only).
+    ///
+    /// # Arguments
+    /// * `agent_types` - Permitted agent types. `None` restores the zone's default permissions.
+    pub fn set_allowed_agents(&mut self, agent_types: Option<Vec<AgentType>>) {
+        self.allowed_agents = agent_types;
+    }
+
+    /// Permits agents of the given type to enter the cell in addition to the already permitted ones
+    /// (e.g. cars turning right at the end of a bus lane).
+    ///
+    /// # Example
+    /// ```
+    /// use micro_traffic_sim_core::agents_types::AgentType;
+    /// use micro_traffic_sim_core::grid::cell::Cell;
+    /// use micro_traffic_sim_core::grid::zones::ZoneType;
+    /// let mut cell = Cell::new(1).with_zone_type(ZoneType::LaneForBus).build();
+    /// cell.allow_agent_type(AgentType::Car);
+    /// assert!(cell.is_allowed_for(AgentType::Car));
+    /// assert!(cell.is_allowed_for(AgentType::Bus));
+    /// assert!(!cell.is_allowed_for(AgentType::Truck));
+    /// ```
+    pub fn allow_agent_type(&mut self, agent_type: AgentType) {
+        let Some(agents) = self.get_allowed_agents() else {
+            return;
+        };
+        if agents.contains(&agent_type) {
+            return;
+        }
+        let mut agents = agents.to_vec();
+        agents.push(agent_type);
+        self.allowed_agents = Some(agents);
+    }
 }
 
 /// A builder pattern implementation for constructing `Cell` objects.
@@ -390,6 +468,19 @@ impl CellBuilder {
         self
     }
 
+    /// Sets agent types permitted in the cell. It overrides the zone's default permissions
+    /// (e.g. [`BUS_LANE_AGENTS`](crate::grid::zones::BUS_LANE_AGENTS) for [`ZoneType::LaneForBus`]).
+    ///
+    /// # Arguments
+    /// * `agent_types` - Permitted agent types.
+    ///
+    /// # Returns
+    /// A `CellBuilder` instance for further method chaining.
+    pub fn with_allowed_agents(mut self, agent_types: Vec<AgentType>) -> Self {
+        self.cell.allowed_agents = Some(agent_types);
+        self
+    }
+
     /// Builds the final `Cell` object with the configured properties.
     ///
     /// # Returns
@@ -42,10 +42,14 @@
 //! let zt = ZoneType::Common;        // Regular road segments
 //! let zt = ZoneType::Coordination;  // Junction/intersection cells
 //! 
-//! // Specialized zones (future use)
+//! // Public transport zones
 //! let zt = ZoneType::LaneForBus;    // Dedicated bus lanes
 //! let zt = ZoneType::Transit;       // Public transport stops
-//! let zt = ZoneType::Crosswalk;     // Pedestrian crossings
+//!
+//! // Pedestrian crossings (shared with the pedestrian network)
+//! let zt = ZoneType::Crosswalk;
+//!
+//! // Specialized zones (future use)
 //! let zt = ZoneType::Isolated;      // Disconnected cells
 //! ```
 //!
@@ -144,6 +148,27 @@
 //! println!("Cell state: {}", cell.get_state()); // Cell is accessible
 //! ```
 //!
+//! ### Agent Permissions
+//!
+//! Cells could be restricted to some agent types: routing and lane changes never lead other agents into them.
+//! Cells of [`zones::ZoneType::LaneForBus`] permit buses, taxis and emergency vehicles only
+//! (see [`zones::BUS_LANE_AGENTS`]) unless the cell has its own permissions.
+//! ```rust
+//! use micro_traffic_sim_core::agents_types::AgentType;
+//! use micro_traffic_sim_core::grid::{road_network::GridRoads, cell::Cell, zones::ZoneType};
+//!
+//! let mut grid = GridRoads::new();
+//! for id in 1..=10 {
+//!     grid.add_cell(Cell::new(id).with_zone_type(ZoneType::LaneForBus).with_forward_node(id + 1).build());
+//! }
+//! // Trucks only lane
+//! grid.add_cell(Cell::new(11).with_allowed_agents(vec![AgentType::Truck]).build());
+//! // Cars could enter last 3 cells of the bus lane to turn right
+//! grid.allow_agents_before(10, 3, &[AgentType::Car]);
+//! assert!(grid.get_cell(&8).unwrap().is_allowed_for(AgentType::Car));
+//! assert!(!grid.get_cell(&7).unwrap().is_allowed_for(AgentType::Car));
+//! ```
+//!
 //! ## Connection Rules
 //!
 //! - Use `-1` to indicate "no connection available" for any cell reference
@@ -1,4 +1,5 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use crate::agents_types::AgentType;
 use crate::grid::cell::{CellID, Cell};
 
 /// `GridRoads` is a struct representing a 2D grid of cells of the road network.
@@ -10,6 +11,10 @@ use crate::grid::cell::{CellID, Cell};
 pub struct GridRoads {
     // A `HashMap` mapping each `CellID` to its corresponding `Cell` object.
     cells: HashMap<CellID, Cell>,
+    // Reverse forward connections: cell ID to IDs of cells which have forward connection to it.
+    forward_incoming: HashMap<CellID, Vec<CellID>>,
+    // Reverse lane change connections: cell ID to IDs of cells which have left or right connection to it.
+    lateral_incoming: HashMap<CellID, Vec<CellID>>,
 }
 
 impl GridRoads {
@@ -26,6 +31,8 @@ impl GridRoads {
     pub fn new() -> Self {
         GridRoads {
             cells: HashMap::new(),
+            forward_incoming: HashMap::new(),
+            lateral_incoming: HashMap::new(),
         }
     }
 
@@ -47,7 +54,27 @@ impl GridRoads {
     /// grid.add_cell(cell);
     /// ```
     pub fn add_cell(&mut self, cell: Cell) {
-        self.cells.insert(cell.get_id(), cell);
+        let cell_id = cell.get_id();
+        let forward_id = cell.get_forward_id();
+        let lateral_ids = [cell.get_left_id(), cell.get_right_id()];
+        if let Some(replaced) = self.cells.insert(cell_id, cell) {
+            if let Some(incoming) = self.forward_incoming.get_mut(&replaced.get_forward_id()) {
+                incoming.retain(|&id| id != cell_id);
+            }
+            for lateral_id in [replaced.get_left_id(), replaced.get_right_id()] {
+                if let Some(incoming) = self.lateral_incoming.get_mut(&lateral_id) {
+                    incoming.retain(|&id| id != cell_id);
+                }
+            }
+        }
+        if forward_id >= 0 {
+            self.forward_incoming.entry(forward_id).or_default().push(cell_id);
+        }
+        for lateral_id in lateral_ids {
+            if lateral_id >= 0 {
+                self.lateral_incoming.entry(lateral_id).or_default().push(cell_id);
+            }
+        }
     }
 
     /// Retrieves a reference to a `Cell` in the grid by its `CellID`.
@@ -80,10 +107,49 @@ impl GridRoads {
         self.cells.get(id)
     }
 
+    /// Returns IDs of cells which have forward connection to the given cell (i.e. cells right behind it in the lane).
+    ///
+    /// # Example
+    /// ```
+    /// use micro_traffic_sim_core::grid::{cell::Cell, road_network::GridRoads};
+    /// let mut grid = GridRoads::new();
+    /// grid.add_cell(Cell::new(1).with_forward_node(3).build());
+    /// grid.add_cell(Cell::new(2).with_forward_node(3).build());
+    /// grid.add_cell(Cell::new(3).build());
+    /// let mut incoming = grid.get_forward_incoming(&3).to_vec();
+    /// incoming.sort();
+    /// assert_eq!(incoming, vec![1, 2]);
+    /// assert!(grid.get_forward_incoming(&1).is_empty());
+    /// ```
+    pub fn get_forward_incoming(&self, id: &CellID) -> &[CellID] {
+        self.forward_incoming.get(id).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    /// Returns IDs of cells which have left or right connection to the given cell
+    /// (i.e. cells from which vehicles could change the lane into it).
+    ///
+    /// # Example
+    /// ```
+    /// use micro_traffic_sim_core::grid::{cell::Cell, road_network::GridRoads};
+    /// let mut grid = GridRoads::new();
+    /// grid.add_cell(Cell::new(1).with_forward_node(2).with_left_node(4).build());
+    /// grid.add_cell(Cell::new(2).build());
+    /// grid.add_cell(Cell::new(3).with_forward_node(4).with_right_node(2).build());
+    /// grid.add_cell(Cell::new(4).build());
+    /// assert_eq!(grid.get_lateral_incoming(&4), &[1]);
+    /// assert_eq!(grid.get_lateral_incoming(&2), &[3]);
+    /// assert!(grid.get_lateral_incoming(&1).is_empty());
+    /// ```
+    pub fn get_lateral_incoming(&self, id: &CellID) -> &[CellID] {
+        self.lateral_incoming.get(id).map(Vec::as_slice).unwrap_or(&[])
+    }
+
     /// Retrieves a mutable reference to a `Cell` in the grid by its `CellID`.
     ///
     /// This method provides mutable access to a cell for modification operations
-    /// like updating cell state or properties.
+    /// like updating cell state or properties. Changes of connections should be
+    /// done via [`GridRoads::add_cell`] (replacing the cell), otherwise they are not reflected
+    /// by [`GridRoads::get_forward_incoming`] and [`GridRoads::get_lateral_incoming`].
     ///
     /// # Arguments
     /// - `id`: The `CellID` to look up.
@@ -106,6 +172,48 @@ impl GridRoads {
         self.cells.get_mut(&id)
     }
 
+    /// Permits agents of the given types to enter the last cells of a lane ending with the given cell
+    /// (e.g. cars turning right at the end of a bus lane, see [`Cell::allow_agent_type`]).
+    /// Cells are collected backwards via forward connections (see [`GridRoads::get_forward_incoming`]).
+    ///
+    /// # Arguments
+    /// - `cell_id`: Last cell of the lane.
+    /// - `cells_num`: Number of cells (including the last one) where the agents are permitted.
+    /// - `agent_types`: Agent types to permit.
+    ///
+    /// # Example
+    /// ```
+    /// use micro_traffic_sim_core::agents_types::AgentType;
+    /// use micro_traffic_sim_core::grid::{cell::Cell, road_network::GridRoads, zones::ZoneType};
+    /// let mut grid = GridRoads::new();
+    /// for id in 1..=4 {
+    ///     grid.add_cell(Cell::new(id).with_zone_type(ZoneType::LaneForBus).with_forward_node(id + 1).build());
+    /// }
+    /// grid.allow_agents_before(4, 2, &[AgentType::Car]);
+    /// assert!(!grid.get_cell(&2).unwrap().is_allowed_for(AgentType::Car));
+    /// assert!(grid.get_cell(&3).unwrap().is_allowed_for(AgentType::Car));
+    /// assert!(grid.get_cell(&4).unwrap().is_allowed_for(AgentType::Car));
+    /// ```
+    pub fn allow_agents_before(&mut self, cell_id: CellID, cells_num: usize, agent_types: &[AgentType]) {
+        let mut visited = HashSet::new();
+        let mut current = vec![cell_id];
+        for _ in 0..cells_num {
+            let mut previous = Vec::new();
+            for id in current {
+                if !visited.insert(id) {
+                    continue;
+                }
+                if let Some(cell) = self.cells.get_mut(&id) {
+                    for &agent_type in agent_types {
+                        cell.allow_agent_type(agent_type);
+                    }
+                }
+                previous.extend_from_slice(self.get_forward_incoming(&id));
+            }
+            current = previous;
+        }
+    }
+
     /// Returns an iterator over all cells in the grid.
     ///
     /// This method provides read-only access to all cells and their IDs
@@ -1,3 +1,4 @@
+use crate::agents_types::AgentType;
 use std::fmt;
 
 /// ZoneType gives meaning to the cell in terms of its application.
@@ -15,11 +16,11 @@ pub enum ZoneType {
     Common,
     // Cell that is disconnected from the road network (for future use)
     Isolated,
-    // Dedicated bus lane (for future use)
+    // Dedicated bus lane: only public transport, taxis and emergency vehicles are permitted (see `BUS_LANE_AGENTS`)
     LaneForBus,
     // Relaxation cells (stops basically) for public transport vehicles (for future use)
     Transit,
-    // Pedestrian crossing area (for future use)
+    // Pedestrian crossing area: cell of the pedestrian network shared with the road cell of the same ID (see `pedestrians` module)
     Crosswalk,
 }
 
@@ -57,4 +58,35 @@ impl fmt::Display for ZoneType {
             ZoneType::Crosswalk => write!(f, "crosswalk"),
         }
     }
-}
\ No newline at end of file
+}
+
+/// Agent types permitted in cells of [`ZoneType::LaneForBus`] unless the cell has its own permissions
+/// (see [`CellBuilder::with_allowed_agents`](crate::grid::cell::CellBuilder::with_allowed_agents)).
+pub const BUS_LANE_AGENTS: [AgentType; 4] = [AgentType::Bus, AgentType::LargeBus, AgentType::Taxi, AgentType::Emergency];
+
+/// Agent types permitted in cycle lane cells. Cycle lane is a lane which cells are restricted to these agents
+/// (see [`CellBuilder::with_allowed_agents`](crate::grid::cell::CellBuilder::with_allowed_agents)).
+/// Cyclists are permitted everywhere else too, so they share the kerb vehicle lane where there is no cycle lane.
+pub const CYCLE_LANE_AGENTS: [AgentType; 1] = [AgentType::Cyclist];
+
+impl ZoneType {
+    /// Returns agent types permitted in cells of the zone by default.
+    ///
+    /// # Returns
+    /// `None` if every agent type is permitted.
+    ///
+    /// # Example
+    /// ```rust
+    /// use micro_traffic_sim_core::agents_types::AgentType;
+    /// use micro_traffic_sim_core::grid::zones::ZoneType;
+    ///
+    /// assert!(ZoneType::Common.allowed_agents().is_none());
+    /// assert!(ZoneType::LaneForBus.allowed_agents().is_some_and(|agents| agents.contains(&AgentType::Taxi)));
+    /// ```
+    pub fn allowed_agents(&self) -> Option<&'static [AgentType]> {
+        match self {
+            ZoneType::LaneForBus => Some(&BUS_LANE_AGENTS),
+            _ => None,
+        }
+    }
+}
@@ -1,10 +1,13 @@
-use super::{process_no_route_found, process_path, NoRouteError};
-use crate::behaviour::BehaviourType;
+use super::{find_discretionary_lane_change, find_emergency_clearing, find_lane_selection, find_merging_neighbour, hold_back_intention, process_no_route_found, LaneSelection, process_vehicle_path, process_route, NoRouteError};
+use crate::agents_types::AgentType;
+use crate::behaviour::{BehaviourType, DriverContext};
 use crate::agents::{
     TailIntentionManeuver, Vehicle, VehicleError, VehicleID, VehicleIntention,
 };
-use crate::grid::cell::CellState;
-use crate::maneuver::LaneChangeType;
+use crate::grid::cell::{Cell, CellState};
+use crate::grid::zones::ZoneType;
+use crate::geom::get_bearing;
+use crate::maneuver::{DrivingSide, LaneChangeType};
 use crate::grid::{cell::CellID, road_network::GridRoads};
 use crate::intentions::{intention_type::IntentionType, Intentions};
 use crate::shortest_path;
@@ -12,7 +15,9 @@ use crate::shortest_path::router::{shortest_path, path_no_goal};
 use crate::shortest_path::router::AStarError;
 use crate::verbose::*;
 use indexmap::IndexMap;
-use rand::random;
+use crate::utils::rand::derive_seed;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 use std::collections::HashMap;
 use std::f64::INFINITY;
 use std::fmt;
@@ -72,10 +77,28 @@ impl fmt::Display for IntentionError {
 /// For each vehicle, determines the desired maneuver and target cell,
 /// handling blocked vehicles and alternate maneuvers if needed.
 /// Returns a storage of all intentions for conflict resolution.
+///
+/// Calculation is split into two phases:
+/// 1. Compute phase - every vehicle's intention is computed independently since it only reads
+///    the grid and the `current_state` map. With the `rayon` feature enabled this phase runs in parallel.
+/// 2. Merge phase - intentions are applied to vehicles and stored in `Intentions` sequentially
+///    in the vehicles storage order, so the output does not depend on threads scheduling.
+///
+/// # Arguments
+/// * `net` - The road network grid
+/// * `current_state` - Mapping from occupied cell ID to vehicle ID
+/// * `vehicles` - Vehicles storage
+/// * `rng_seed` - Optional seed for the current step. When it is set each vehicle gets its own
+///   random stream derived from the seed and the vehicle's ID (see [`derive_seed`]),
+///   hence results are reproducible regardless of the processing order.
+/// * `driving_side` - Driving side of the road network (see [`DrivingSide`])
+/// * `verbose` - Logger
 pub fn prepare_intentions<'a, 'b>(
     net: &'a GridRoads,
     current_state: &HashMap<CellID, VehicleID>,
     vehicles: &'b mut IndexMap<VehicleID, Vehicle>,
+    rng_seed: Option<u64>,
+    driving_side: DrivingSide,
     verbose: &LocalLogger,
 ) -> Result<Intentions, IntentionError> {
     let mut intentions = Intentions::new();
@@ -88,7 +111,9 @@ pub fn prepare_intentions<'a, 'b>(
             ]
         );
     }
-    for (_, vehicle) in vehicles.iter_mut() {
+    let computed = compute_intentions(net, current_state, vehicles, rng_seed, driving_side, verbose)?;
+    for ((_, vehicle), mut possible_intention) in vehicles.iter_mut().zip(computed) {
+        possible_intention.turning = is_turning(net, vehicle, &possible_intention);
         if verbose.is_at_least(VerboseLevel::Additional) {
             verbose.log_with_fields(
                 EVENT_INTENTION_VEHICLE,
@@ -100,21 +125,6 @@ pub fn prepare_intentions<'a, 'b>(
                     ("destination", &vehicle.destination),
                 ]
             );
-        }
-        let possible_intention = find_intention(net, current_state, &vehicle, verbose)?;
-        if possible_intention.should_stop {
-            // Calculate maneuvers_allowed for find_alternate_intention
-            // Maneuvers are blocked if tail is still completing a previous maneuver
-            let tail_maneuver = possible_intention.tail_maneuver.intention_maneuver;
-            let maneuvers_allowed = vehicle.timer_non_maneuvers <= 0
-                && tail_maneuver != LaneChangeType::ChangeRight
-                && tail_maneuver != LaneChangeType::ChangeLeft;
-            let alternate_possible_intention = find_alternate_intention(net, current_state, &vehicle, maneuvers_allowed)?;
-            vehicle.set_intention(alternate_possible_intention);
-            intentions.add_intention(vehicle, IntentionType::Target);
-            continue;
-        }
-        if verbose.is_at_least(VerboseLevel::Additional) {
             verbose.log_with_fields(
                 EVENT_INTENTION_ADD,
                 &format!("Adding intentions with vehicle {}", vehicle.id),
@@ -125,19 +135,291 @@ pub fn prepare_intentions<'a, 'b>(
             );
         }
         vehicle.set_intention(possible_intention);
-        intentions.add_intention(vehicle, IntentionType::Target);
+        // Buses in bays are out of the lane, so they do not take part in conflicts (see `BusStop`)
+        if !vehicle.is_in_bay() {
+            intentions.add_intention(vehicle, IntentionType::Target);
+        }
     }
     Ok(intentions)
 }
 
+/// Minimal deviation (in degrees) of the movement direction from the vehicle's bearing to treat the movement as a turn.
+pub const TURN_ANGLE_THRESHOLD: f64 = 30.0;
+
+/// Returns true if the intended movement turns away from the vehicle's bearing (direction of its last movement).
+///
+/// Lane changes are turns too, since the vehicle leaves its lane. Turning vehicles give way
+/// to cyclists keeping their lane (see [`has_cyclist_advantage`](crate::conflicts::has_cyclist_advantage)).
+///
+/// # Arguments
+/// * `net` - The road network grid
+/// * `vehicle` - The vehicle
+/// * `intention` - The vehicle's intention
+///
+/// # Example
+/// ```
+/// use micro_traffic_sim_core::agents::{Vehicle, VehicleIntention};
+/// use micro_traffic_sim_core::geom::new_point;
+/// use micro_traffic_sim_core::grid::{cell::Cell, road_network::GridRoads};
+/// use micro_traffic_sim_core::intentions::is_turning;
+///
+/// let mut net = GridRoads::new();
+/// net.add_cell(Cell::new(1).with_point(new_point(0.0, 0.0, None)).build());
+/// net.add_cell(Cell::new(2).with_point(new_point(0.0, 1.0, None)).build());
+/// net.add_cell(Cell::new(3).with_point(new_point(1.0, 0.0, None)).build());
+/// // Heading to the north
+/// let vehicle = Vehicle::new(1).with_cell(1).with_bearing(0.0).build();
+/// let straight = VehicleIntention { intention_cell_id: 2, ..Default::default() };
+/// let turn = VehicleIntention { intention_cell_id: 3, ..Default::default() };
+/// assert!(!is_turning(&net, &vehicle, &straight));
+/// assert!(is_turning(&net, &vehicle, &turn));
+/// ```
+pub fn is_turning(net: &GridRoads, vehicle: &Vehicle, intention: &VehicleIntention) -> bool {
+    if matches!(intention.intention_maneuver, LaneChangeType::ChangeLeft | LaneChangeType::ChangeRight) {
+        return true;
+    }
+    if intention.intention_cell_id == vehicle.cell_id {
+        return false;
+    }
+    let (Some(source_cell), Some(target_cell)) = (net.get_cell(&vehicle.cell_id), net.get_cell(&intention.intention_cell_id)) else {
+        return false;
+    };
+    let deviation = (get_bearing(source_cell.get_point(), target_cell.get_point()) - vehicle.bearing).rem_euclid(360.0);
+    deviation.min(360.0 - deviation) > TURN_ANGLE_THRESHOLD
+}
+
+/// Computes intentions for every vehicle in the storage order (sequential version).
+#[cfg(not(feature = "rayon"))]
+fn compute_intentions(
+    net: &GridRoads,
+    current_state: &HashMap<CellID, VehicleID>,
+    vehicles: &IndexMap<VehicleID, Vehicle>,
+    rng_seed: Option<u64>,
+    driving_side: DrivingSide,
+    verbose: &LocalLogger,
+) -> Result<Vec<VehicleIntention>, IntentionError> {
+    vehicles
+        .values()
+        .map(|vehicle| {
+            match rng_seed {
+                Some(seed) => {
+                    find_vehicle_intention(net, current_state, vehicles, vehicle, &mut vehicle_rng(seed, vehicle.id), driving_side, verbose)
+                }
+                None => find_vehicle_intention(net, current_state, vehicles, vehicle, &mut rand::rng(), driving_side, verbose),
+            }
+        })
+        .collect()
+}
+
+/// Computes intentions for every vehicle in the storage order (parallel version).
+///
+/// Results are collected in the storage order. In case of failures the error of the
+/// first vehicle (in the storage order) is returned, exactly as the sequential version does.
+#[cfg(feature = "rayon")]
+fn compute_intentions(
+    net: &GridRoads,
+    current_state: &HashMap<CellID, VehicleID>,
+    vehicles: &IndexMap<VehicleID, Vehicle>,
+    rng_seed: Option<u64>,
+    driving_side: DrivingSide,
+    verbose: &LocalLogger,
+) -> Result<Vec<VehicleIntention>, IntentionError> {
+    use rayon::prelude::*;
+    let vehicles_refs: Vec<&Vehicle> = vehicles.values().collect();
+    let computed: Vec<Result<VehicleIntention, IntentionError>> = vehicles_refs
+        .par_iter()
+        .map(|vehicle| {
+            match rng_seed {
+                Some(seed) => {
+                    find_vehicle_intention(net, current_state, vehicles, vehicle, &mut vehicle_rng(seed, vehicle.id), driving_side, verbose)
+                }
+                None => find_vehicle_intention(net, current_state, vehicles, vehicle, &mut rand::rng(), driving_side, verbose),
+            }
+        })
+        .collect();
+    computed.into_iter().collect()
+}
+
+/// Creates random stream of the vehicle derived from the seed, so results do not depend on the processing order.
+/// Without a seed vehicles share the thread-local generator (see `rand::rng`) instead.
+fn vehicle_rng(seed: u64, vehicle_id: VehicleID) -> StdRng {
+    StdRng::seed_from_u64(derive_seed(seed, vehicle_id))
+}
+
+/// Computes the final intention for a single vehicle: the main one or
+/// the alternate one (lane change) if the vehicle is blocked.
+fn find_vehicle_intention<R: Rng>(
+    net: &GridRoads,
+    current_state: &HashMap<CellID, VehicleID>,
+    vehicles: &IndexMap<VehicleID, Vehicle>,
+    vehicle: &Vehicle,
+    rng: &mut R,
+    driving_side: DrivingSide,
+    verbose: &LocalLogger,
+) -> Result<VehicleIntention, IntentionError> {
+    // Bus dwelling at a stop stays in place
+    if vehicle.is_dwelling() {
+        return Ok(VehicleIntention {
+            intention_maneuver: LaneChangeType::Block,
+            intention_speed: 0,
+            destination: None,
+            confusion: None,
+            intention_cell_id: vehicle.cell_id,
+            tail_intention_cells: vec![],
+            intermediate_cells: Vec::with_capacity(0),
+            tail_maneuver: TailIntentionManeuver::default(),
+            should_stop: false,
+            anticipated_cells: vec![],
+            turning: false,
+        });
+    }
+    let possible_intention = find_intention_with_vehicles(net, current_state, Some(vehicles), vehicle, rng, verbose)?;
+    // Calculate maneuvers_allowed for lane changes
+    // Maneuvers are blocked if tail is still completing a previous maneuver
+    let tail_maneuver = possible_intention.tail_maneuver.intention_maneuver;
+    let maneuvers_allowed = vehicle.timer_non_maneuvers <= 0
+        && tail_maneuver != LaneChangeType::ChangeRight
+        && tail_maneuver != LaneChangeType::ChangeLeft;
+    // Vehicle moves aside to clear the way for an emergency vehicle approaching from behind
+    if maneuvers_allowed
+        && vehicle.strategy_type != BehaviourType::Block
+        && let Some((maneuver, target_cell_id)) = find_emergency_clearing(net, current_state, vehicles, vehicle, driving_side)
+    {
+        return Ok(VehicleIntention {
+            intention_maneuver: maneuver,
+            intention_speed: 1,
+            destination: None,
+            confusion: None,
+            intention_cell_id: target_cell_id,
+            tail_intention_cells: vec![],
+            intermediate_cells: Vec::with_capacity(0),
+            tail_maneuver: TailIntentionManeuver::default(),
+            should_stop: false,
+            anticipated_cells: vec![],
+            turning: false,
+        });
+    }
+    // Vehicle moves to the lane it needs before a turn or an exit, or gives up if it is too late (see `find_lane_selection`)
+    if vehicle.strategy_type != BehaviourType::Block {
+        match find_lane_selection(net, current_state, vehicle, maneuvers_allowed)? {
+            LaneSelection::Change(maneuver, target_cell_id) => {
+                return Ok(VehicleIntention {
+                    intention_maneuver: maneuver,
+                    intention_speed: 1,
+                    destination: None,
+                    confusion: None,
+                    intention_cell_id: target_cell_id,
+                    tail_intention_cells: vec![],
+                    intermediate_cells: Vec::with_capacity(0),
+                    tail_maneuver: TailIntentionManeuver::default(),
+                    should_stop: false,
+                    anticipated_cells: vec![],
+                    turning: false,
+                });
+            }
+            // Destination is kept, so the vehicle reroutes from the next cell
+            LaneSelection::GiveUp(target_cell_id) => {
+                return Ok(VehicleIntention {
+                    intention_maneuver: LaneChangeType::NoChange,
+                    intention_speed: 1,
+                    destination: None,
+                    confusion: Some(true),
+                    intention_cell_id: target_cell_id,
+                    tail_intention_cells: vec![],
+                    intermediate_cells: Vec::with_capacity(0),
+                    tail_maneuver: possible_intention.tail_maneuver,
+                    should_stop: false,
+                    anticipated_cells: vec![],
+                    turning: false,
+                });
+            }
+            LaneSelection::Keep => {}
+        }
+    }
+    if !possible_intention.should_stop {
+        // Cooperative vehicle holds back to let a blocked neighbour merge (see `find_merging_neighbour`)
+        if vehicle.cooperativity > 0.0
+            && possible_intention.intention_maneuver == LaneChangeType::NoChange
+            && possible_intention.intention_speed > 0
+        {
+            let mut way = possible_intention.intermediate_cells.clone();
+            way.push(possible_intention.intention_cell_id);
+            if let Some(yield_index) = find_merging_neighbour(net, current_state, vehicles, vehicle, &way)
+                && rng.random_bool(vehicle.cooperativity.min(1.0))
+            {
+                return Ok(hold_back_intention(possible_intention, vehicle, yield_index));
+            }
+        }
+        // Moving vehicle could change the lane to go faster (see `MobilParameters`)
+        if let Some(params) = &vehicle.mobil
+            && maneuvers_allowed
+            && vehicle.strategy_type != BehaviourType::Block
+            && possible_intention.intention_maneuver == LaneChangeType::NoChange
+            && let Some((maneuver, target_cell_id)) =
+                find_discretionary_lane_change(net, current_state, Some(vehicles), vehicle, params, driving_side)
+        {
+            return Ok(VehicleIntention {
+                intention_maneuver: maneuver,
+                intention_speed: 1,
+                destination: None,
+                confusion: None,
+                intention_cell_id: target_cell_id,
+                tail_intention_cells: vec![],
+                intermediate_cells: Vec::with_capacity(0),
+                tail_maneuver: TailIntentionManeuver::default(),
+                should_stop: false,
+                anticipated_cells: vec![],
+                turning: false,
+            });
+        }
+        return Ok(possible_intention);
+    }
+    let mut alternate_intention = find_alternate_intention(net, current_state, vehicle, maneuvers_allowed, driving_side)?;
+    // Vehicle stays in its lane: it still could follow the leader if the leader moves away
+    if alternate_intention.intention_maneuver == LaneChangeType::Block {
+        alternate_intention.anticipated_cells = possible_intention.anticipated_cells;
+    }
+    Ok(alternate_intention)
+}
+
 /// Computes the movement intention for a single vehicle.
 ///
 /// Determines the best maneuver (forward, lane change, block, etc.)
 /// and target cell, considering speed, acceleration, obstacles, and pathfinding.
+///
+/// Random slowdown uses thread-local random generator. See [`find_intention_with_rng`]
+/// to provide own random stream.
 pub fn find_intention<'a>(
     net: &'a GridRoads,
     current_state: &HashMap<CellID, VehicleID>,
     vehicle: &'a Vehicle,
+    verbose: &LocalLogger,
+) -> Result<VehicleIntention, IntentionError> {
+    find_intention_with_rng(net, current_state, vehicle, &mut rand::rng(), verbose)
+}
+
+/// Computes the movement intention for a single vehicle using given random stream.
+///
+/// Same as [`find_intention`], but the random slowdown (see [`DriverModel::slowdown`](crate::behaviour::DriverModel::slowdown)) is drawn from `rng`.
+/// It makes it possible to give each vehicle its own (seeded) random stream.
+pub fn find_intention_with_rng<'a, R: Rng>(
+    net: &'a GridRoads,
+    current_state: &HashMap<CellID, VehicleID>,
+    vehicle: &'a Vehicle,
+    rng: &mut R,
+    verbose: &LocalLogger,
+) -> Result<VehicleIntention, IntentionError> {
+    find_intention_with_vehicles(net, current_state, None, vehicle, rng, verbose)
+}
+
+/// Computes the movement intention for a single vehicle. Vehicles storage (if provided)
+/// is passed to the driver model, so it could take the leader's state into account.
+fn find_intention_with_vehicles<'a, R: Rng>(
+    net: &'a GridRoads,
+    current_state: &HashMap<CellID, VehicleID>,
+    vehicles: Option<&'a IndexMap<VehicleID, Vehicle>>,
+    vehicle: &'a Vehicle,
+    rng: &mut R,
     _verbose: &LocalLogger,
 ) -> Result<VehicleIntention, IntentionError> {
     if vehicle.strategy_type == BehaviourType::Block {
@@ -151,6 +433,8 @@ pub fn find_intention<'a>(
             intermediate_cells: Vec::with_capacity(0),
             tail_maneuver: TailIntentionManeuver::default(),
             should_stop: false,
+            anticipated_cells: vec![],
+            turning: false,
         };
         return Ok(result);
     }
@@ -176,11 +460,13 @@ pub fn find_intention<'a>(
             intermediate_cells: Vec::with_capacity(0),
             tail_maneuver: tail_maneuver,
             should_stop: false,
+            anticipated_cells: vec![],
+            turning: false,
         };
         return Ok(result);
     }
 
-    let speed_limit = source_cell.get_speed_limit().min(vehicle.speed_limit);
+    let speed_limit = vehicle.effective_speed_limit(source_cell.get_speed_limit());
 
     if speed_limit < 0 {
         return Err(IntentionError::BadSpeedLimit(
@@ -199,36 +485,32 @@ pub fn find_intention<'a>(
             intermediate_cells: Vec::with_capacity(0),
             tail_maneuver: tail_maneuver,
             should_stop: false,
+            anticipated_cells: vec![],
+            turning: false,
         };
         return Ok(result);
     }
 
-    // Vehicle's speed should not be greater than speed limit
-    let mut intention_speed = vehicle.speed.min(speed_limit);
+    // Speed rules are defined by the vehicle's driver model (NaSch by default)
+    let driver_model = vehicle.driver_model();
+    let mut driver_ctx = DriverContext::new(net, current_state, vehicle, source_cell);
+    if let Some(vehicles) = vehicles {
+        driver_ctx = driver_ctx.with_vehicles(vehicles);
+    }
 
     // Consider acceleration
-    // Allow stopped vehicle (speed == 0) to start moving even if acceleration timer is active
-    let mut speed_possible = intention_speed;
-    let acceleration_allowed = vehicle.timer_non_acceleration <= 0 || vehicle.speed == 0;
-    if acceleration_allowed {
-        // Vehicle should could have (speed + 1) as possible speed untill it reaches speed limit
-        speed_possible = (speed_possible + 1).min(speed_limit);
-    }
+    let mut speed_possible = driver_model.desired_speed(&driver_ctx).clamp(0, speed_limit);
 
     // Random slowdown
-    // let mut _is_slowdown = false;
-    let slowdown_allowed = vehicle.timer_non_slowdown <= 0;
-    // tmp code:
-    let slow_down_factor = vehicle.slow_down_factor;
-    if slowdown_allowed && intention_speed > 0 && random::<f64>() < slow_down_factor {
-        // @todo: consider to switch two lines below.
-        speed_possible = intention_speed;
-        // intention_speed = (intention_speed - 1).max(0);
-        // _is_slowdown = true;
-    }
+    speed_possible = driver_model.slowdown(&driver_ctx, speed_possible, rng).clamp(0, speed_possible);
 
-    // Considering that vehicle always wants to accelerate:
-    let observe_distance = speed_possible + vehicle.min_safe_distance;
+    // Vehicle should see far enough to stop comfortably (see `Vehicle::max_deceleration`)
+    let observe_distance = speed_possible.saturating_add(
+        driver_model
+            .safe_distance(&driver_ctx)
+            .max(vehicle.braking_distance(speed_possible))
+            .max(0),
+    );
 
     // Check if maneuvers are allowed (they could be prohibeted due the vehicle's tail is not done previous maneuver yet)
     let maneuvers_allowed = vehicle.timer_non_maneuvers <= 0
@@ -239,26 +521,38 @@ pub fn find_intention<'a>(
     let mut confusion: Option<bool> = None;
 
     // println!(
-    //     "Vehicle {} at cell {} with speed {} (possible {}) towards {} with observe distance {} and slow factor {}: {}",
+    //     "Vehicle {} at cell {} with speed {} (possible {}) towards {} with observe distance {}",
     //     vehicle.id,
     //     source_cell.get_id(),
-    //     intention_speed,
+    //     driver_ctx.speed,
     //     speed_possible,
     //     vehicle.destination,
     //     observe_distance,
-    //     slow_down_factor,
-    //     if _is_slowdown { " (slowdown)" } else { "" }
     // );
 
-    let mut path = match vehicle.destination {
+    let route_path = if vehicle.destination >= 0 && !vehicle.route.is_empty() {
+        process_route(
+            &vehicle.route,
+            source_cell,
+            net,
+            maneuvers_allowed,
+            observe_distance.saturating_add(1),
+        )
+        .map_err(IntentionError::NoPathFound)?
+    } else {
+        None
+    };
+
+    let mut path = match (vehicle.destination, route_path) {
         // Handle case when vehicle has no destination,H
         // therefore it should be considered as keep going where possible
-        dest if dest < 0 => {
+        (dest, _) if dest < 0 => {
             match path_no_goal(
                 source_cell,
                 net,
                 maneuvers_allowed,
-                observe_distance + 1,
+                observe_distance.saturating_add(1),
+                vehicle.vehicle_type,
             ) {
                 Ok(path) => path,
                 Err(e) => {
@@ -269,6 +563,8 @@ pub fn find_intention<'a>(
                 }
             }
         },
+        // Vehicle follows its assigned route while it is on it
+        (_, Some(route_path)) => route_path,
         _ => {
             let target_cell = net
                 .get_cell(&vehicle.destination)
@@ -278,7 +574,8 @@ pub fn find_intention<'a>(
                 target_cell,
                 net,
                 maneuvers_allowed,
-                Some(observe_distance + 1),
+                Some(observe_distance.saturating_add(1)),
+                vehicle.vehicle_type,
             ) {
                 Ok(path) => path,
                 Err(e)
@@ -290,13 +587,12 @@ pub fn find_intention<'a>(
                     return Err(IntentionError::NoPathFound(e));
                 }
                 Err(_) => {
-                    let new_path = match process_no_route_found(source_cell, net) {
+                    let new_path = match process_no_route_found(source_cell, net, vehicle.vehicle_type) {
                         Ok(path) => path,
                         Err(e) => return Err(IntentionError::NoPathForNoRoute(e)),
                     };
                     destination = Some(new_path.vertices()[new_path.vertices().len() - 1].get_id());
-                    intention_speed = 1;
-                    speed_possible = intention_speed;
+                    speed_possible = 1;
                     confusion = Some(true);
                     new_path
                 }
@@ -312,11 +608,28 @@ pub fn find_intention<'a>(
     //         .map(|cell| cell.get_id())
     //         .collect::<Vec<CellID>>()
     // );
+    // Plan speed to stop before obstacles ahead without exceeding comfortable deceleration
+    if let Some(distance) = obstacle_distance(path.vertices(), vehicle, current_state, vehicles) {
+        speed_possible = vehicle.safe_speed(speed_possible, distance);
+    }
+
+    // Keep the path ahead for the velocity anticipation: path is trimmed by the processing
+    let path_ahead: Vec<(CellID, LaneChangeType)> = if driver_model.anticipates(&driver_ctx) {
+        path.vertices()
+            .iter()
+            .skip(1)
+            .zip(path.maneuvers().iter())
+            .map(|(cell, maneuver)| (cell.get_id(), *maneuver))
+            .collect()
+    } else {
+        vec![]
+    };
+    let speed_wanted = speed_possible;
     // Process path to find wanted maneuver, success forward movement and to trim path
-    let observable_path = process_path(
+    let observable_path = process_vehicle_path(
         &mut path,
         speed_possible,
-        vehicle.destination,
+        vehicle,
         current_state,
     );
     // println!(
@@ -332,6 +645,11 @@ pub fn find_intention<'a>(
     let wanted_maneuver = observable_path.wanted_maneuver;
     let last_cell_state = observable_path.last_cell_state;
     let vertices = observable_path.trimmed_path;
+    let anticipated_cells = if observable_path.has_vehicle_on_path {
+        collect_anticipated_cells(net, vehicle, &path_ahead, vertices.len(), speed_wanted)
+    } else {
+        vec![]
+    };
 
     // Possible speed should not be greater than success forward movement counter
     // If len(vertices) < speed, then it means that vehicle slow downed due conflict #1.1. Otherwise vehicle could accelerate
@@ -352,6 +670,8 @@ pub fn find_intention<'a>(
                 .collect(),
             tail_maneuver: tail_maneuver,
             should_stop: false,
+            anticipated_cells,
+            turning: false,
         };
         return Ok(result);
     }
@@ -367,6 +687,8 @@ pub fn find_intention<'a>(
             intermediate_cells: Vec::with_capacity(0),
             tail_maneuver: tail_maneuver,
             should_stop: false,
+            anticipated_cells: vec![],
+            turning: false,
         };
         return Ok(result);
     }
@@ -374,7 +696,7 @@ pub fn find_intention<'a>(
     // - Vehicles with speed_possible = 0 (e.g., acceleration blocked by timer after lane change)
     // - Vehicles blocked by other reasons (has_vehicle_on_path handled earlier)
     // Then they are trying to lane change in separate loop
-    // (in further we could make cooperative drives which allow other vehicles to change lane).
+    // (cooperative drivers in the next lane could hold back to let them merge, see `find_merging_neighbour`).
     // Then we know vehicles which can't move at all.
     let result = VehicleIntention {
         intention_maneuver: LaneChangeType::Block,
@@ -386,26 +708,102 @@ pub fn find_intention<'a>(
         intermediate_cells: Vec::with_capacity(0),
         tail_maneuver: tail_maneuver,
         should_stop: true,
+        anticipated_cells,
+        turning: false,
     };
     Ok(result)
 }
 
-/* Change it according to right-hand or left-hand traffic (driving side) */
-/* @todo: should be an argument in further  */
-const UNDEFINED_MANEUVER: LaneChangeType = LaneChangeType::ChangeRight;
+/// Returns distance (in cells) which the vehicle could pass before it has to stop due to
+/// an obstacle on the path: a banned cell (e.g. red traffic light) or other vehicle.
+/// Braking distance of the leading vehicle is added to the distance when vehicles storage is known.
+///
+/// Returns `None` when there are no obstacles on the path or the vehicle does not plan
+/// stopping (infinite maximum deceleration).
+fn obstacle_distance(
+    path_cells: &[&Cell],
+    vehicle: &Vehicle,
+    current_state: &HashMap<CellID, VehicleID>,
+    vehicles: Option<&IndexMap<VehicleID, Vehicle>>,
+) -> Option<i32> {
+    if !vehicle.max_deceleration.is_finite() || vehicle.max_deceleration <= 0.0 {
+        return None;
+    }
+    for (free_cells, cell) in path_cells.iter().skip(1).enumerate() {
+        let free_cells = free_cells as i32;
+        if let Some(&leader_id) = current_state.get(&cell.get_id())
+            && leader_id != vehicle.id
+        {
+            let leader_braking = vehicles
+                .and_then(|vehicles| vehicles.get(&leader_id))
+                .map(|leader| leader.braking_distance(leader.speed))
+                .unwrap_or(0);
+            return Some(free_cells.saturating_add(leader_braking));
+        }
+        if cell.get_state() != CellState::Free {
+            return Some(free_cells);
+        }
+        if cell.get_id() == vehicle.destination {
+            return None;
+        }
+    }
+    None
+}
+
+/// Collects cells straight ahead of the reached cell which the anticipating vehicle takes
+/// in case they are vacated by the leader in the same time unit.
+///
+/// # Arguments
+/// * `net` - The road network grid
+/// * `vehicle` - The vehicle
+/// * `path_ahead` - Path cells (without the source one) and maneuvers leading into them
+/// * `moved` - Number of path cells the vehicle takes without anticipation
+/// * `speed_wanted` - Speed chosen by the driver model
+///
+/// Cells are collected while the path goes forward (no lane changes) and up to the unrealized speed.
+/// Cells with traffic lights, cells with lower speed limit, despawn cells and the destination itself
+/// are not anticipated: the vehicle reaches them via the regular movement only.
+fn collect_anticipated_cells(
+    net: &GridRoads,
+    vehicle: &Vehicle,
+    path_ahead: &[(CellID, LaneChangeType)],
+    moved: usize,
+    speed_wanted: i32,
+) -> Vec<CellID> {
+    let unrealized_speed = (speed_wanted - moved as i32).max(0) as usize;
+    path_ahead
+        .iter()
+        .skip(moved)
+        .take(unrealized_speed)
+        .take_while(|(cell_id, maneuver)| {
+            *maneuver == LaneChangeType::NoChange
+                && *cell_id != vehicle.destination
+                && net.get_cell(cell_id).is_some_and(|cell| {
+                    cell.get_state() == CellState::Free
+                        && cell.get_zone_type() != ZoneType::Death
+                        && vehicle.effective_speed_limit(cell.get_speed_limit()) >= speed_wanted
+                })
+        })
+        .map(|(cell_id, _)| *cell_id)
+        .collect()
+}
 
 /// Attempts to find an alternate maneuver (lane change) for a blocked vehicle.
 ///
-/// If the vehicle cannot move forward, tries left or right lane changes
-/// and selects the best available option.
+/// If the vehicle cannot move forward, tries left or right lane changes (into cells the vehicle is permitted to enter)
+/// and lets the vehicle's driver model (see [`DriverModel::lane_change`](crate::behaviour::DriverModel::lane_change))
+/// select the best available option. Hence vehicles overtake slow agents (e.g. cyclists) only when the adjacent lane
+/// is free and permitted. Cyclists never move to the overtaking side.
 ///
 /// # Arguments
 /// * `maneuvers_allowed` - Whether lane changes are allowed (false if tail is still completing a maneuver)
+/// * `driving_side` - Driving side of the road network. Equal lanes are resolved in favour of the slow lane
 pub fn find_alternate_intention<'a>(
     net: &'a GridRoads,
     current_state: &HashMap<CellID, VehicleID>,
     vehicle: &'a Vehicle,
     maneuvers_allowed: bool,
+    driving_side: DrivingSide,
 ) -> Result<VehicleIntention, IntentionError> {
     let source_cell_id = vehicle.cell_id;
     let target_cell_id = vehicle.destination;
@@ -422,6 +820,8 @@ pub fn find_alternate_intention<'a>(
             intermediate_cells: Vec::with_capacity(0),
             tail_maneuver: TailIntentionManeuver::default(),
             should_stop: false,
+            anticipated_cells: vec![],
+            turning: false,
         };
         return Ok(result);
     }
@@ -430,9 +830,14 @@ pub fn find_alternate_intention<'a>(
         .get_cell(&source_cell_id)
         .ok_or(IntentionError::NoSourceCell(source_cell_id))?;
 
-    let target_cell = net
-        .get_cell(&target_cell_id)
-        .ok_or(IntentionError::NoTargetCell(target_cell_id))?;
+    // Vehicles without destination have no lane preferences (distances are unknown)
+    let target_cell = if target_cell_id < 0 {
+        None
+    } else {
+        Some(net
+            .get_cell(&target_cell_id)
+            .ok_or(IntentionError::NoTargetCell(target_cell_id))?)
+    };
 
     let mut min_left_dist = INFINITY;
     let mut min_right_dist = INFINITY;
@@ -450,22 +855,24 @@ pub fn find_alternate_intention<'a>(
             .map(|&id| id > 0)
             .unwrap_or(false);
 
-        if !is_blocked && left_cell.get_state() == CellState::Free {
-            match shortest_path(left_cell, target_cell, net, true, Some(vehicle.speed)) {
-                Ok(path) => {
-                    let cost = path.cost();
-                    min_left_dist = cost + source_cell.distance_to(left_cell);
-                }
-                Err(e)
-                    if e != shortest_path::router::AStarError::NoPathFound {
-                        start_id: left_cell_id,
-                        end_id: target_cell_id,
-                    } =>
-                {
-                    return Err(IntentionError::LeftPathFind(left_cell_id))
-                }
-                Err(_) => {
-                    min_left_dist = INFINITY;
+        if !is_blocked && left_cell.get_state() == CellState::Free && left_cell.is_allowed_for(vehicle.vehicle_type) {
+            if let Some(target_cell) = target_cell {
+                match shortest_path(left_cell, target_cell, net, true, Some(vehicle.speed), vehicle.vehicle_type) {
+                    Ok(path) => {
+                        let cost = path.cost();
+                        min_left_dist = cost + source_cell.distance_to(left_cell);
+                    }
+                    Err(e)
+                        if e != shortest_path::router::AStarError::NoPathFound {
+                            start_id: left_cell_id,
+                            end_id: target_cell_id,
+                        } =>
+                    {
+                        return Err(IntentionError::LeftPathFind(left_cell_id))
+                    }
+                    Err(_) => {
+                        min_left_dist = INFINITY;
+                    }
                 }
             }
         } else {
@@ -485,22 +892,24 @@ pub fn find_alternate_intention<'a>(
             .map(|&id| id > 0)
             .unwrap_or(false);
 
-        if !is_blocked && right_cell.get_state() == CellState::Free {
-            match shortest_path(right_cell, target_cell, net, true, Some(vehicle.speed)) {
-                Ok(path) => {
-                    let cost = path.cost();
-                    min_right_dist = cost + source_cell.distance_to(right_cell);
-                }
-                Err(e)
-                    if e != shortest_path::router::AStarError::NoPathFound {
-                        start_id: right_cell_id,
-                        end_id: target_cell_id,
-                    } =>
-                {
-                    return Err(IntentionError::RightPathFind(right_cell_id))
-                }
-                Err(_) => {
-                    min_right_dist = INFINITY;
+        if !is_blocked && right_cell.get_state() == CellState::Free && right_cell.is_allowed_for(vehicle.vehicle_type) {
+            if let Some(target_cell) = target_cell {
+                match shortest_path(right_cell, target_cell, net, true, Some(vehicle.speed), vehicle.vehicle_type) {
+                    Ok(path) => {
+                        let cost = path.cost();
+                        min_right_dist = cost + source_cell.distance_to(right_cell);
+                    }
+                    Err(e)
+                        if e != shortest_path::router::AStarError::NoPathFound {
+                            start_id: right_cell_id,
+                            end_id: target_cell_id,
+                        } =>
+                    {
+                        return Err(IntentionError::RightPathFind(right_cell_id))
+                    }
+                    Err(_) => {
+                        min_right_dist = INFINITY;
+                    }
                 }
             }
         } else {
@@ -508,25 +917,27 @@ pub fn find_alternate_intention<'a>(
         }
     }
 
-    // Choose best maneuver
-    let mut min_cell: CellID;
-    let mut intention_maneuver: LaneChangeType;
-    if UNDEFINED_MANEUVER == LaneChangeType::ChangeRight {
-        min_cell = right_cell_id;
-        intention_maneuver = LaneChangeType::ChangeRight;
-        if min_left_dist < min_right_dist {
-            min_cell = left_cell_id;
-            intention_maneuver = LaneChangeType::ChangeLeft;
-        }
-    } else {
-        min_cell = left_cell_id;
-        intention_maneuver = LaneChangeType::ChangeLeft;
-        if min_right_dist < min_left_dist {
-            min_cell = right_cell_id;
-            intention_maneuver = LaneChangeType::ChangeRight;
+    // Cyclists keep to the kerb lane, so they never move to the overtaking side
+    if vehicle.vehicle_type == AgentType::Cyclist {
+        match driving_side.overtaking_maneuver() {
+            LaneChangeType::ChangeLeft => left_cell_id = -1,
+            _ => right_cell_id = -1,
         }
     }
 
+    // Choose best maneuver (it is up to the vehicle's driver model)
+    let left_option = if left_cell_id > 0 { Some(min_left_dist) } else { None };
+    let right_option = if right_cell_id > 0 { Some(min_right_dist) } else { None };
+    let driver_ctx = DriverContext::new(net, current_state, vehicle, source_cell).with_driving_side(driving_side);
+    let intention_maneuver = vehicle
+        .driver_model()
+        .lane_change(&driver_ctx, left_option, right_option);
+    let min_cell = match intention_maneuver {
+        LaneChangeType::ChangeLeft => left_cell_id,
+        LaneChangeType::ChangeRight => right_cell_id,
+        _ => -1,
+    };
+
     // Apply the chosen maneuver
     if min_cell > 0 {
         let result = VehicleIntention {
@@ -539,6 +950,8 @@ pub fn find_alternate_intention<'a>(
             intermediate_cells: Vec::with_capacity(0),
             tail_maneuver: TailIntentionManeuver::default(),
             should_stop: true,
+            anticipated_cells: vec![],
+            turning: false,
         };
         return Ok(result);
     }
@@ -552,6 +965,8 @@ pub fn find_alternate_intention<'a>(
         intermediate_cells: Vec::with_capacity(0),
         tail_maneuver: TailIntentionManeuver::default(),
         should_stop: false,
+        anticipated_cells: vec![],
+        turning: false,
     };
 
     Ok(result)
@@ -803,7 +1218,7 @@ mod tests {
         current_state.insert(blocked_cell.get_id(), blocking_vehicle.id);
 
         let mut intentions: Intentions = Intentions::new();
-    let collected_intention = find_alternate_intention(&net, &current_state, &vehicle, true);
+    let collected_intention = find_alternate_intention(&net, &current_state, &vehicle, true, DrivingSide::Right);
         assert!(collected_intention.is_ok());
         let unwrapped_intention = collected_intention.unwrap();
     vehicle.set_intention(unwrapped_intention);
@@ -886,7 +1301,7 @@ mod tests {
         current_state.insert(blocked_cell.get_id(), blocking_vehicle.id);
 
         let mut intentions = Intentions::new();
-    let collected_intention = find_alternate_intention(&net, &current_state, &vehicle, true);
+    let collected_intention = find_alternate_intention(&net, &current_state, &vehicle, true, DrivingSide::Right);
         assert!(collected_intention.is_ok());
         let unwrapped_intention = collected_intention.unwrap();
     vehicle.set_intention(unwrapped_intention);
@@ -973,7 +1388,7 @@ mod tests {
         current_state.insert(source_cell.get_right_id(), blocking_vehicle2.id);
 
         let mut intentions = Intentions::new();
-    let collected_intention = find_alternate_intention(&net, &current_state, &vehicle, true);
+    let collected_intention = find_alternate_intention(&net, &current_state, &vehicle, true, DrivingSide::Right);
         assert!(collected_intention.is_ok());
         let unwrapped_intention = collected_intention.unwrap();
     vehicle.set_intention(unwrapped_intention);
@@ -1040,4 +1455,139 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_prepare_intentions_seeded() {
+        use crate::utils::generators::generate_one_lane_cells;
+        let mut net = GridRoads::new();
+        for cell in generate_one_lane_cells(300.0, 5.0, 2) {
+            net.add_cell(cell);
+        }
+        let make_vehicles = |reversed: bool| -> IndexMap<VehicleID, Vehicle> {
+            let mut ids: Vec<VehicleID> = (1..=20).collect();
+            if reversed {
+                ids.reverse();
+            }
+            ids.into_iter()
+                .map(|id| {
+                    let vehicle = Vehicle::new(id)
+                        .with_cell(id as CellID * 3)
+                        .with_speed(2)
+                        .with_speed_limit(3)
+                        .with_slowdown(0.5)
+                        .with_destination(60)
+                        .build();
+                    (id, vehicle)
+                })
+                .collect()
+        };
+        let mut vehicles_a = make_vehicles(false);
+        let mut vehicles_b = make_vehicles(true);
+        let current_state: HashMap<CellID, VehicleID> = vehicles_a
+            .values()
+            .map(|v| (v.cell_id, v.id))
+            .collect();
+        let intentions_a = prepare_intentions(&net, &current_state, &mut vehicles_a, Some(777), DrivingSide::Right, &LocalLogger::none()).unwrap();
+        let intentions_b = prepare_intentions(&net, &current_state, &mut vehicles_b, Some(777), DrivingSide::Right, &LocalLogger::none()).unwrap();
+        // Same seed must give same intentions regardless of the vehicles processing order
+        assert_eq!(intentions_a.len(), intentions_b.len());
+        for (id, vehicle) in vehicles_a.iter() {
+            assert_eq!(vehicle.intention, vehicles_b.get(id).unwrap().intention, "Intentions differ for vehicle #{}", id);
+        }
+        // Merged storage follows the vehicles storage order
+        let first_cell = vehicles_a.get(&1).unwrap().intention.intention_cell_id;
+        assert_eq!(intentions_a.get(&first_cell).unwrap()[0].vehicle_id, 1);
+    }
+
+    #[test]
+    fn test_intention_custom_driver_model() {
+        use crate::behaviour::{DriverContext, DriverModel};
+        use crate::utils::generators::generate_one_lane_cells;
+        use rand::RngCore;
+        use std::sync::Arc;
+
+        /// Jumps straight to the speed limit and never slows down randomly
+        #[derive(Debug)]
+        struct Jump;
+        impl DriverModel for Jump {
+            fn desired_speed(&self, ctx: &DriverContext) -> i32 {
+                ctx.speed_limit
+            }
+            fn slowdown(&self, _ctx: &DriverContext, desired_speed: i32, _rng: &mut dyn RngCore) -> i32 {
+                desired_speed
+            }
+        }
+
+        let mut net = GridRoads::new();
+        for cell in generate_one_lane_cells(100.0, 10.0, 1) {
+            net.add_cell(cell);
+        }
+        let current_state: HashMap<CellID, VehicleID> = HashMap::from([(2, 1)]);
+        let make_vehicle = |jump: bool| {
+            let builder = Vehicle::new(1)
+                .with_cell(2)
+                .with_speed(0)
+                .with_speed_limit(3)
+                .with_slowdown(0.0)
+                .with_destination(10);
+            if jump { builder.with_driver_model(Arc::new(Jump)).build() } else { builder.build() }
+        };
+
+        // Default NaSch: acceleration by one cell
+        let intention = find_intention(&net, &current_state, &make_vehicle(false), &LocalLogger::none()).unwrap();
+        assert_eq!(intention.intention_speed, 1);
+        assert_eq!(intention.intention_cell_id, 3);
+
+        // Custom model: speed limit right away
+        let intention = find_intention(&net, &current_state, &make_vehicle(true), &LocalLogger::none()).unwrap();
+        assert_eq!(intention.intention_speed, 3);
+        assert_eq!(intention.intention_cell_id, 5);
+        assert_eq!(intention.intermediate_cells, vec![3, 4]);
+    }
+
+    #[test]
+    fn test_intention_vehicle_dynamics() {
+        use crate::agents_types::AgentType;
+        use crate::utils::generators::generate_one_lane_cells;
+
+        let mut net = GridRoads::new();
+        for cell in generate_one_lane_cells(100.0, 10.0, 1) {
+            net.add_cell(cell);
+        }
+        // Red light at cell 8: there are five free cells (3..7) ahead of the vehicle in cell 2
+        net.get_cell_mut(8).unwrap().set_state(CellState::Banned);
+        let current_state: HashMap<CellID, VehicleID> = HashMap::from([(2, 1)]);
+        let make_vehicle = |agent_type: AgentType| {
+            Vehicle::new(1)
+                .with_type(agent_type)
+                .with_cell(2)
+                .with_speed(3)
+                .with_speed_limit(3)
+                .with_slowdown(0.0)
+                .with_destination(10)
+                .build()
+        };
+
+        // Car does not plan stopping: it goes as far as it can
+        let intention = find_intention(&net, &current_state, &make_vehicle(AgentType::Car), &LocalLogger::none()).unwrap();
+        assert_eq!(intention.intention_speed, 3);
+        assert_eq!(intention.intention_cell_id, 5);
+
+        // Truck (comfortable deceleration is 1) slows down: 2 + 1 cells to stop before the light
+        let intention = find_intention(&net, &current_state, &make_vehicle(AgentType::Truck), &LocalLogger::none()).unwrap();
+        assert_eq!(intention.intention_speed, 2);
+        assert_eq!(intention.intention_cell_id, 4);
+
+        // Stopped vehicle with acceleration of two cells per time unit squared
+        let vehicle = Vehicle::new(1)
+            .with_cell(2)
+            .with_speed(0)
+            .with_speed_limit(3)
+            .with_slowdown(0.0)
+            .with_destination(10)
+            .with_max_acceleration(2.0)
+            .build();
+        let intention = find_intention(&net, &current_state, &vehicle, &LocalLogger::none()).unwrap();
+        assert_eq!(intention.intention_speed, 2);
+    }
 }
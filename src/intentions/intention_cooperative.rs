@@ -0,0 +1,172 @@
+use crate::agents::{Vehicle, VehicleID, VehicleIntention};
+use crate::behaviour::BehaviourType;
+use crate::grid::cell::CellID;
+use crate::grid::road_network::GridRoads;
+use crate::maneuver::LaneChangeType;
+use indexmap::IndexMap;
+use std::collections::HashMap;
+
+/// Checks if the neighbour is stopped and can't go forward, so it waits for a gap in the next lane
+fn waits_for_merge(
+    net: &GridRoads,
+    current_state: &HashMap<CellID, VehicleID>,
+    neighbour: &Vehicle,
+) -> bool {
+    if neighbour.speed > 0 || neighbour.strategy_type == BehaviourType::Block || neighbour.timer_non_maneuvers > 0 {
+        return false;
+    }
+    match net.get_cell(&neighbour.cell_id) {
+        // Lane drop or the lane is jammed
+        Some(cell) => cell.get_forward_id() < 0
+            || current_state.get(&cell.get_forward_id()).is_some_and(|&id| id != neighbour.id),
+        None => false,
+    }
+}
+
+/// Finds the first cell on the vehicle's way which a blocked neighbour wants to merge into.
+///
+/// Neighbour is a vehicle in the adjacent lane having a lane change connection to the cell (see [`GridRoads::get_lateral_incoming`]).
+/// It waits for a merge when it is stopped and can't go forward: its lane drops or it is jammed.
+///
+/// # Arguments
+/// * `net` - The road network grid
+/// * `current_state` - Mapping from occupied cell ID to vehicle ID
+/// * `vehicles` - Vehicles storage
+/// * `vehicle` - The (cooperative) vehicle
+/// * `way` - Cells the vehicle is going to pass in the current time unit (intermediate ones and the intention cell)
+///
+/// # Returns
+/// Index of the cell in `way`, or `None` if nobody waits for a merge.
+///
+/// # Example
+/// ```
+/// use micro_traffic_sim_core::agents::Vehicle;
+/// use micro_traffic_sim_core::grid::{cell::Cell, road_network::GridRoads};
+/// use micro_traffic_sim_core::intentions::find_merging_neighbour;
+/// use indexmap::IndexMap;
+/// use std::collections::HashMap;
+///
+/// // Right lane drops at cell 10, vehicles from there merge into cell 3
+/// // (10)
+/// //    ↘
+/// // (1) → (2) → (3) → (4)
+/// let mut net = GridRoads::new();
+/// net.add_cell(Cell::new(1).with_forward_node(2).build());
+/// net.add_cell(Cell::new(2).with_forward_node(3).build());
+/// net.add_cell(Cell::new(3).with_forward_node(4).build());
+/// net.add_cell(Cell::new(4).build());
+/// net.add_cell(Cell::new(10).with_right_node(3).build());
+///
+/// let vehicle = Vehicle::new(1).with_cell(1).with_speed(2).build();
+/// let neighbour = Vehicle::new(2).with_cell(10).with_speed(0).build();
+/// let current_state = HashMap::from([(1, 1), (10, 2)]);
+/// let vehicles = IndexMap::from([(2, neighbour)]);
+/// let found = find_merging_neighbour(&net, &current_state, &vehicles, &vehicle, &[2, 3]);
+/// assert_eq!(found, Some(1));
+/// ```
+pub fn find_merging_neighbour(
+    net: &GridRoads,
+    current_state: &HashMap<CellID, VehicleID>,
+    vehicles: &IndexMap<VehicleID, Vehicle>,
+    vehicle: &Vehicle,
+    way: &[CellID],
+) -> Option<usize> {
+    way.iter().position(|cell_id| {
+        net.get_lateral_incoming(cell_id).iter().any(|neighbour_cell_id| {
+            current_state
+                .get(neighbour_cell_id)
+                .filter(|&&id| id != vehicle.id)
+                .and_then(|id| vehicles.get(id))
+                .is_some_and(|neighbour| {
+                    neighbour.cell_id == *neighbour_cell_id && waits_for_merge(net, current_state, neighbour)
+                })
+        })
+    })
+}
+
+/// Makes the vehicle hold back before the `yield_index`-th cell of its way, so the cell stays free
+/// for the merging neighbour (see [`find_merging_neighbour`]).
+///
+/// Vehicle which has to stay in its cell gets the stop (block) intention.
+pub fn hold_back_intention(intention: VehicleIntention, vehicle: &Vehicle, yield_index: usize) -> VehicleIntention {
+    let mut way = intention.intermediate_cells.clone();
+    way.push(intention.intention_cell_id);
+    if yield_index == 0 {
+        return VehicleIntention {
+            intention_maneuver: LaneChangeType::Block,
+            intention_speed: 0,
+            intention_cell_id: vehicle.cell_id,
+            intermediate_cells: Vec::with_capacity(0),
+            anticipated_cells: vec![],
+            ..intention
+        };
+    }
+    VehicleIntention {
+        intention_speed: yield_index as i32,
+        intention_cell_id: way[yield_index - 1],
+        intermediate_cells: way[..yield_index - 1].to_vec(),
+        anticipated_cells: vec![],
+        ..intention
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::cell::Cell;
+
+    #[test]
+    fn test_find_merging_neighbour() {
+        // Right lane drops at cell 12
+        // (10) → (11) → (12)
+        //                   ↘
+        // (1) → (2) → (3) → (4) → (5)
+        let mut net = GridRoads::new();
+        for id in 1..5 {
+            net.add_cell(Cell::new(id).with_forward_node(id + 1).build());
+        }
+        net.add_cell(Cell::new(5).build());
+        net.add_cell(Cell::new(10).with_forward_node(11).with_right_node(2).build());
+        net.add_cell(Cell::new(11).with_forward_node(12).with_right_node(3).build());
+        net.add_cell(Cell::new(12).with_right_node(4).build());
+
+        let vehicle = Vehicle::new(1).with_cell(1).with_speed(3).build();
+        let neighbour = |cell_id: CellID, speed: i32| Vehicle::new(2).with_cell(cell_id).with_speed(speed).build();
+        let way = [2, 3, 4];
+
+        // Case 1: neighbour is stopped at the lane drop
+        let vehicles = IndexMap::from([(2, neighbour(12, 0))]);
+        let current_state = HashMap::from([(1, 1), (12, 2)]);
+        assert_eq!(find_merging_neighbour(&net, &current_state, &vehicles, &vehicle, &way), Some(2));
+
+        // Case 2: neighbour is moving
+        let vehicles = IndexMap::from([(2, neighbour(12, 1))]);
+        assert_eq!(find_merging_neighbour(&net, &current_state, &vehicles, &vehicle, &way), None);
+
+        // Case 3: neighbour is stopped, but it is free to go forward
+        let vehicles = IndexMap::from([(2, neighbour(11, 0))]);
+        let current_state = HashMap::from([(1, 1), (11, 2)]);
+        assert_eq!(find_merging_neighbour(&net, &current_state, &vehicles, &vehicle, &way), None);
+
+        // Case 4: neighbour is stopped in the jammed lane
+        let mut vehicles = IndexMap::from([(2, neighbour(11, 0))]);
+        vehicles.insert(3, Vehicle::new(3).with_cell(12).with_speed(0).build());
+        let current_state = HashMap::from([(1, 1), (11, 2), (12, 3)]);
+        assert_eq!(find_merging_neighbour(&net, &current_state, &vehicles, &vehicle, &way), Some(1));
+
+        // Holding back before the merge cell
+        let intention = VehicleIntention {
+            intention_speed: 3,
+            intention_cell_id: 4,
+            intermediate_cells: vec![2, 3],
+            ..VehicleIntention::default()
+        };
+        let held = hold_back_intention(intention.clone(), &vehicle, 1);
+        assert_eq!(held.intention_speed, 1);
+        assert_eq!(held.intention_cell_id, 2);
+        assert!(held.intermediate_cells.is_empty());
+        let held = hold_back_intention(intention, &vehicle, 0);
+        assert_eq!(held.intention_maneuver, LaneChangeType::Block);
+        assert_eq!(held.intention_cell_id, 1);
+    }
+}
@@ -0,0 +1,196 @@
+use super::{planned_path, IntentionError};
+use crate::agents::{Vehicle, VehicleID};
+use crate::agents_types::AgentType;
+use crate::grid::cell::{CellID, CellState};
+use crate::grid::road_network::GridRoads;
+use crate::maneuver::{DrivingSide, LaneChangeType};
+use indexmap::IndexMap;
+use std::collections::{HashMap, HashSet, VecDeque};
+
+/// Searches for an emergency vehicle behind the vehicle (following forward connections backwards)
+/// within `Vehicle::emergency_awareness_distance` cells. Other vehicles in between do not hide it.
+///
+/// # Arguments
+/// * `net` - The road network grid
+/// * `current_state` - Mapping from occupied cell ID to vehicle ID
+/// * `vehicles` - Vehicles storage
+/// * `vehicle` - The vehicle
+///
+/// # Returns
+/// ID of the nearest emergency vehicle, or `None` if there is no one (or the vehicle is an emergency one itself).
+pub fn find_approaching_emergency(
+    net: &GridRoads,
+    current_state: &HashMap<CellID, VehicleID>,
+    vehicles: &IndexMap<VehicleID, Vehicle>,
+    vehicle: &Vehicle,
+) -> Option<VehicleID> {
+    if vehicle.emergency_awareness_distance <= 0 || vehicle.vehicle_type == AgentType::Emergency {
+        return None;
+    }
+    // Search starts from the rear of the vehicle
+    let rear_cell_id = vehicle.tail_cells.first().copied().unwrap_or(vehicle.cell_id);
+    let mut visited = HashSet::from([rear_cell_id]);
+    let mut queue = VecDeque::from([(rear_cell_id, 0)]);
+    while let Some((current_id, gap)) = queue.pop_front() {
+        if gap >= vehicle.emergency_awareness_distance {
+            continue;
+        }
+        for &behind_id in net.get_forward_incoming(&current_id) {
+            if !visited.insert(behind_id) {
+                continue;
+            }
+            if let Some(&other_id) = current_state.get(&behind_id)
+                && other_id != vehicle.id
+                && vehicles.get(&other_id).is_some_and(|other| other.vehicle_type == AgentType::Emergency)
+            {
+                return Some(other_id);
+            }
+            queue.push_back((behind_id, gap + 1));
+        }
+    }
+    None
+}
+
+/// Finds a lane change which clears the way for an emergency vehicle approaching from behind
+/// (see [`find_approaching_emergency`]).
+///
+/// Vehicle moves aside towards the slow lane (kerb side according to the driving side) or, if it is not possible,
+/// towards the fast lane. Target cell should be free: not occupied and not banned.
+///
+/// # Arguments
+/// * `net` - The road network grid
+/// * `current_state` - Mapping from occupied cell ID to vehicle ID
+/// * `vehicles` - Vehicles storage
+/// * `vehicle` - The vehicle
+/// * `driving_side` - Driving side of the road network
+///
+/// # Returns
+/// Lane change maneuver and the target cell, or `None` if there is no emergency vehicle behind or the vehicle can't move aside.
+///
+/// # Example
+/// ```
+/// use micro_traffic_sim_core::agents::Vehicle;
+/// use micro_traffic_sim_core::agents_types::AgentType;
+/// use micro_traffic_sim_core::grid::road_network::GridRoads;
+/// use micro_traffic_sim_core::intentions::find_emergency_clearing;
+/// use micro_traffic_sim_core::maneuver::{DrivingSide, LaneChangeType};
+/// use micro_traffic_sim_core::utils::generators::generate_one_lane_cells;
+/// use indexmap::IndexMap;
+/// use std::collections::HashMap;
+///
+/// // Two lanes: 1..10 (right) and 11..20 (left)
+/// let mut net = GridRoads::new();
+/// for cell in generate_one_lane_cells(100.0, 10.0, 2) {
+///     net.add_cell(cell);
+/// }
+/// // Car in the left lane, ambulance is two cells behind it
+/// let car = Vehicle::new(1).with_cell(15).build();
+/// let ambulance = Vehicle::new(2).with_cell(13).with_type(AgentType::Emergency).build();
+/// let vehicles = IndexMap::from([(1, car), (2, ambulance)]);
+/// let current_state = HashMap::from([(15, 1), (13, 2)]);
+/// let clearing = find_emergency_clearing(&net, &current_state, &vehicles, &vehicles[&1], DrivingSide::Right);
+/// assert_eq!(clearing, Some((LaneChangeType::ChangeRight, net.get_cell(&15).unwrap().get_right_id())));
+/// ```
+pub fn find_emergency_clearing(
+    net: &GridRoads,
+    current_state: &HashMap<CellID, VehicleID>,
+    vehicles: &IndexMap<VehicleID, Vehicle>,
+    vehicle: &Vehicle,
+    driving_side: DrivingSide,
+) -> Option<(LaneChangeType, CellID)> {
+    find_approaching_emergency(net, current_state, vehicles, vehicle)?;
+    let source_cell = net.get_cell(&vehicle.cell_id)?;
+    [driving_side.slow_lane_maneuver(), driving_side.overtaking_maneuver()]
+        .into_iter()
+        .map(|maneuver| {
+            let target_id = match maneuver {
+                LaneChangeType::ChangeLeft => source_cell.get_left_id(),
+                _ => source_cell.get_right_id(),
+            };
+            (maneuver, target_id)
+        })
+        .find(|&(_, target_id)| {
+            target_id >= 0
+                && !current_state.contains_key(&target_id)
+                && net.get_cell(&target_id).is_some_and(|cell| cell.get_state() == CellState::Free)
+        })
+}
+
+/// Collects cells which emergency vehicles occupy or are going to pass within `distance` cells
+/// along their planned paths: the assigned route or the shortest path to the destination.
+/// Traffic lights controlling these cells are preempted (see `TrafficLight::set_preemption`).
+///
+/// # Arguments
+/// * `net` - The road network grid
+/// * `vehicles` - Vehicles storage
+/// * `distance` - Number of cells ahead of an emergency vehicle. Zero or negative disables preemption.
+///
+/// # Returns
+/// Set of cells IDs or an error if a planned path goes through a missing cell.
+pub fn find_preemption_cells(
+    net: &GridRoads,
+    vehicles: &IndexMap<VehicleID, Vehicle>,
+    distance: i32,
+) -> Result<HashSet<CellID>, IntentionError> {
+    let mut cells = HashSet::new();
+    if distance <= 0 {
+        return Ok(cells);
+    }
+    for vehicle in vehicles.values().filter(|vehicle| vehicle.vehicle_type == AgentType::Emergency) {
+        let source_cell = net.get_cell(&vehicle.cell_id).ok_or(IntentionError::NoSourceCell(vehicle.cell_id))?;
+        let planned = planned_path(net, vehicle, source_cell, distance as usize).map_err(IntentionError::NoPathFound)?;
+        cells.insert(vehicle.cell_id);
+        cells.extend(planned.into_iter().map(|(cell_id, _)| cell_id));
+    }
+    Ok(cells)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::generators::generate_one_lane_cells;
+
+    #[test]
+    fn test_find_emergency_clearing() {
+        // Two lanes: 1..10 (right) and 11..20 (left)
+        let mut net = GridRoads::new();
+        for cell in generate_one_lane_cells(100.0, 10.0, 2) {
+            net.add_cell(cell);
+        }
+        let right_of = |cell_id: CellID| net.get_cell(&cell_id).unwrap().get_right_id();
+        let left_of = |cell_id: CellID| net.get_cell(&cell_id).unwrap().get_left_id();
+        let vehicles = |car_cell: CellID, other_cell: CellID, other_type: AgentType| {
+            IndexMap::from([
+                (1, Vehicle::new(1).with_cell(car_cell).build()),
+                (2, Vehicle::new(2).with_cell(other_cell).with_type(other_type).build()),
+            ])
+        };
+
+        // Case 1: ambulance is behind within the distance. Car moves to the slow lane
+        let storage = vehicles(15, 12, AgentType::Emergency);
+        let current_state = HashMap::from([(15, 1), (12, 2)]);
+        let clearing = find_emergency_clearing(&net, &current_state, &storage, &storage[&1], DrivingSide::Right);
+        assert_eq!(clearing, Some((LaneChangeType::ChangeRight, right_of(15))));
+
+        // Case 2: car in the right lane moves to the left one: it is the slow lane in left-hand traffic
+        // and the only one available in right-hand traffic
+        let storage = vehicles(5, 3, AgentType::Emergency);
+        let current_state = HashMap::from([(5, 1), (3, 2)]);
+        let clearing = find_emergency_clearing(&net, &current_state, &storage, &storage[&1], DrivingSide::Left);
+        assert_eq!(clearing, Some((LaneChangeType::ChangeLeft, left_of(5))));
+        let clearing = find_emergency_clearing(&net, &current_state, &storage, &storage[&1], DrivingSide::Right);
+        assert_eq!(clearing, Some((LaneChangeType::ChangeLeft, left_of(5))));
+
+        // Case 3: ambulance is too far
+        let storage = vehicles(15, 11, AgentType::Emergency);
+        let current_state = HashMap::from([(15, 1), (11, 2)]);
+        let far = Vehicle::new(1).with_cell(15).with_emergency_awareness_distance(3).build();
+        assert_eq!(find_approaching_emergency(&net, &current_state, &storage, &far), None);
+        assert_eq!(find_approaching_emergency(&net, &current_state, &storage, &storage[&1]), Some(2));
+
+        // Case 4: regular vehicle behind
+        let storage = vehicles(15, 12, AgentType::Car);
+        let current_state = HashMap::from([(15, 1), (12, 2)]);
+        assert_eq!(find_emergency_clearing(&net, &current_state, &storage, &storage[&1], DrivingSide::Right), None);
+    }
+}
@@ -0,0 +1,239 @@
+use super::{process_no_route_found, IntentionError};
+use crate::agents::{Vehicle, VehicleID};
+use crate::grid::cell::{Cell, CellID, CellState};
+use crate::grid::road_network::GridRoads;
+use crate::maneuver::LaneChangeType;
+use crate::shortest_path::router::{shortest_path, AStarError};
+use std::collections::HashMap;
+
+/// Outcome of the route-aware lane pre-selection (see [`find_lane_selection`])
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LaneSelection {
+    /// Vehicle is in the lane it needs (or can't change the lane yet), so regular rules apply
+    Keep,
+    /// Vehicle moves to the lane it needs: lane change maneuver and the target cell
+    Change(LaneChangeType, CellID),
+    /// Vehicle has failed to reach the lane in time: it goes on into the given cell and reroutes
+    GiveUp(CellID),
+}
+
+/// Returns the cell next to `cell` for the given lane change maneuver (`-1` if there is no such cell)
+fn lateral_id(cell: &Cell, maneuver: LaneChangeType) -> CellID {
+    match maneuver {
+        LaneChangeType::ChangeLeft => cell.get_left_id(),
+        LaneChangeType::ChangeRight => cell.get_right_id(),
+        _ => -1,
+    }
+}
+
+/// Checks that `cell_id` is free for the vehicle: not occupied by others and not banned (e.g. red traffic light)
+fn is_free(
+    net: &GridRoads,
+    current_state: &HashMap<CellID, VehicleID>,
+    vehicle_id: VehicleID,
+    cell_id: CellID,
+) -> bool {
+    let occupied = current_state.get(&cell_id).is_some_and(|&id| id != vehicle_id);
+    !occupied && net.get_cell(&cell_id).is_some_and(|cell| cell.get_state() == CellState::Free)
+}
+
+/// Collects cells (without the source one) and maneuvers leading into them along the vehicle's
+/// planned path: the assigned route while the vehicle is on it or the shortest path to the destination otherwise.
+pub(crate) fn planned_path(
+    net: &GridRoads,
+    vehicle: &Vehicle,
+    source_cell: &Cell,
+    depth: usize,
+) -> Result<Vec<(CellID, LaneChangeType)>, AStarError> {
+    if let Some(position) = vehicle.route.iter().position(|&cell_id| cell_id == source_cell.get_id()) {
+        let mut planned = Vec::with_capacity(depth);
+        let mut last_cell = source_cell;
+        for &next_id in vehicle.route.iter().skip(position + 1).take(depth) {
+            let maneuver = match next_id {
+                id if id == last_cell.get_forward_id() => LaneChangeType::NoChange,
+                id if id == last_cell.get_left_id() => LaneChangeType::ChangeLeft,
+                id if id == last_cell.get_right_id() => LaneChangeType::ChangeRight,
+                // Route is not consistent with the grid anymore
+                _ => break,
+            };
+            last_cell = net.get_cell(&next_id).ok_or(AStarError::BadData { cell_id: next_id })?;
+            planned.push((next_id, maneuver));
+        }
+        return Ok(planned);
+    }
+    let target_cell = net
+        .get_cell(&vehicle.destination)
+        .ok_or(AStarError::BadData { cell_id: vehicle.destination })?;
+    // Only the first `depth` cells are needed, so the search does not go further than that
+    let max_depth = i32::try_from(depth).unwrap_or(i32::MAX).saturating_add(1);
+    let path = match shortest_path(source_cell, target_cell, net, true, Some(max_depth), vehicle.vehicle_type) {
+        Ok(path) => path,
+        // Unreachable destination is handled by the regular rules (see `process_no_route_found`)
+        Err(AStarError::NoPathFound { .. }) => return Ok(vec![]),
+        Err(e) => return Err(e),
+    };
+    Ok(path
+        .vertices()
+        .iter()
+        .skip(1)
+        .zip(path.maneuvers().iter())
+        .take(depth)
+        .map(|(cell, maneuver)| (cell.get_id(), *maneuver))
+        .collect())
+}
+
+/// Checks that `lane_cell_id` leads to `needed_cell_id` going straight forward within `max_cells` cells
+fn leads_to(net: &GridRoads, lane_cell_id: CellID, needed_cell_id: CellID, max_cells: usize) -> bool {
+    let mut cell_id = lane_cell_id;
+    for _ in 0..=max_cells {
+        if cell_id == needed_cell_id {
+            return true;
+        }
+        cell_id = match net.get_cell(&cell_id) {
+            Some(cell) => cell.get_forward_id(),
+            None => return false,
+        };
+    }
+    false
+}
+
+/// Route-aware lane pre-selection.
+///
+/// Vehicle looks `Vehicle::lane_selection_distance` cells ahead along its planned path (the assigned route
+/// or the shortest path to the destination). If the path changes the lane there (e.g. before a turn or an exit)
+/// the vehicle moves to that lane right away instead of weaving at the last moment:
+/// - when the adjacent cell leads to the needed lane and it is free, the vehicle changes the lane;
+/// - when the lane change is due now, it can't be done and it is the last chance (the next cell ahead
+///   has no connection to the needed lane), the vehicle gives up: it goes on via [`process_no_route_found`]
+///   and reroutes to its destination on the next steps (see `Vehicle::confusion`);
+/// - otherwise regular rules apply.
+///
+/// # Arguments
+/// * `net` - The road network grid
+/// * `current_state` - Mapping from occupied cell ID to vehicle ID
+/// * `vehicle` - The vehicle
+/// * `maneuvers_allowed` - Whether lane changes are allowed (false if tail is still completing a maneuver)
+///
+/// # Returns
+/// * `Ok(LaneSelection)` - Lane pre-selection outcome. It is always [`LaneSelection::Keep`] for vehicles
+///   without destination or with zero distance.
+/// * `Err(IntentionError)` - Vehicle's cell, destination or route cells do not exist in the grid
+///
+/// # Example
+/// ```
+/// use micro_traffic_sim_core::agents::Vehicle;
+/// use micro_traffic_sim_core::grid::road_network::GridRoads;
+/// use micro_traffic_sim_core::intentions::{find_lane_selection, LaneSelection};
+/// use micro_traffic_sim_core::maneuver::LaneChangeType;
+/// use micro_traffic_sim_core::utils::generators::generate_one_lane_cells;
+/// use std::collections::HashMap;
+///
+/// // Two lanes: 1..20 and 21..40. Destination is at the end of the left lane
+/// let mut net = GridRoads::new();
+/// for cell in generate_one_lane_cells(200.0, 10.0, 2) {
+///     net.add_cell(cell);
+/// }
+/// let vehicle = Vehicle::new(1)
+///     .with_cell(2)
+///     .with_destination(40)
+///     .with_lane_selection_distance(30)
+///     .build();
+/// let selection = find_lane_selection(&net, &HashMap::new(), &vehicle, true).unwrap();
+/// assert_eq!(selection, LaneSelection::Change(LaneChangeType::ChangeLeft, 23));
+/// ```
+pub fn find_lane_selection(
+    net: &GridRoads,
+    current_state: &HashMap<CellID, VehicleID>,
+    vehicle: &Vehicle,
+    maneuvers_allowed: bool,
+) -> Result<LaneSelection, IntentionError> {
+    if vehicle.lane_selection_distance <= 0 || vehicle.destination < 0 {
+        return Ok(LaneSelection::Keep);
+    }
+    let source_cell = net
+        .get_cell(&vehicle.cell_id)
+        .ok_or(IntentionError::NoSourceCell(vehicle.cell_id))?;
+    let planned = planned_path(net, vehicle, source_cell, vehicle.lane_selection_distance as usize)
+        .map_err(IntentionError::NoPathFound)?;
+    let (steps_before, &(needed_cell_id, maneuver)) = match planned
+        .iter()
+        .enumerate()
+        .find(|(_, (_, maneuver))| *maneuver != LaneChangeType::NoChange)
+    {
+        Some(found) => found,
+        None => return Ok(LaneSelection::Keep),
+    };
+    // Adjacent cell should lead to the needed lane
+    let adjacent_id = lateral_id(source_cell, maneuver);
+    if adjacent_id < 0 || !leads_to(net, adjacent_id, needed_cell_id, steps_before) {
+        return Ok(LaneSelection::Keep);
+    }
+    if maneuvers_allowed && is_free(net, current_state, vehicle.id, adjacent_id) {
+        return Ok(LaneSelection::Change(maneuver, adjacent_id));
+    }
+    if steps_before > 0 {
+        return Ok(LaneSelection::Keep);
+    }
+    // The lane change is due now, but it can't be done. Vehicle still could wait if it is not the last chance
+    let forward_cell = match net.get_cell(&source_cell.get_forward_id()) {
+        Some(forward_cell) => forward_cell,
+        None => return Ok(LaneSelection::Keep),
+    };
+    if lateral_id(forward_cell, maneuver) >= 0 {
+        return Ok(LaneSelection::Keep);
+    }
+    let path = process_no_route_found(source_cell, net, vehicle.vehicle_type).map_err(IntentionError::NoPathForNoRoute)?;
+    let give_up_cell_id = path.vertices()[path.vertices().len() - 1].get_id();
+    if !is_free(net, current_state, vehicle.id, give_up_cell_id) {
+        return Ok(LaneSelection::Keep);
+    }
+    Ok(LaneSelection::GiveUp(give_up_cell_id))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::generators::generate_one_lane_cells;
+
+    #[test]
+    fn test_find_lane_selection() {
+        // Two lanes: 1..20 (right) and 21..40 (left)
+        let mut net = GridRoads::new();
+        for cell in generate_one_lane_cells(200.0, 10.0, 2) {
+            net.add_cell(cell);
+        }
+        let vehicle = |cell_id: CellID, distance: i32| {
+            Vehicle::new(1)
+                .with_cell(cell_id)
+                .with_destination(40)
+                .with_lane_selection_distance(distance)
+                .build()
+        };
+
+        // Case 1: pre-selection is disabled
+        let selection = find_lane_selection(&net, &HashMap::new(), &vehicle(2, 0), true).unwrap();
+        assert_eq!(selection, LaneSelection::Keep);
+
+        // Case 2: vehicle moves to the left lane
+        let selection = find_lane_selection(&net, &HashMap::new(), &vehicle(2, 30), true).unwrap();
+        assert_eq!(selection, LaneSelection::Change(LaneChangeType::ChangeLeft, 23));
+
+        // Case 3: maneuvers are not allowed yet
+        let selection = find_lane_selection(&net, &HashMap::new(), &vehicle(2, 30), false).unwrap();
+        assert_eq!(selection, LaneSelection::Keep);
+
+        // Case 4: adjacent cell is occupied, but there is time to wait
+        let current_state = HashMap::from([(23, 2)]);
+        let selection = find_lane_selection(&net, &current_state, &vehicle(2, 30), true).unwrap();
+        assert_eq!(selection, LaneSelection::Keep);
+
+        // Case 5: the last chance is missed - vehicle gives up and goes forward
+        let current_state = HashMap::from([(40, 2)]);
+        let selection = find_lane_selection(&net, &current_state, &vehicle(19, 30), true).unwrap();
+        assert_eq!(selection, LaneSelection::GiveUp(20));
+
+        // Case 6: vehicle is in the needed lane already
+        let selection = find_lane_selection(&net, &HashMap::new(), &vehicle(25, 30), true).unwrap();
+        assert_eq!(selection, LaneSelection::Keep);
+    }
+}
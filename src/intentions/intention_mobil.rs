@@ -0,0 +1,366 @@
+use crate::agents::{Vehicle, VehicleID};
+use crate::agents_types::AgentType;
+use crate::behaviour::MobilParameters;
+use crate::grid::cell::{Cell, CellID, CellState};
+use crate::grid::road_network::GridRoads;
+use crate::maneuver::{DrivingSide, LaneChangeType};
+use crate::shortest_path::router::shortest_path;
+use indexmap::IndexMap;
+use std::collections::{HashMap, HashSet, VecDeque};
+
+/// Expected cellular automata speed: acceleration bounded by the speed limit and the gap ahead
+fn expected_speed(speed: i32, increase: i32, speed_limit: i32, gap: i32) -> i32 {
+    (speed + increase).min(speed_limit).min(gap).max(0)
+}
+
+/// Counts free cells straight ahead of `cell` up to `max_cells`.
+/// Counting stops at a cell occupied by other vehicle or at a cell which is not free (e.g. red traffic light).
+fn free_cells_ahead(
+    net: &GridRoads,
+    current_state: &HashMap<CellID, VehicleID>,
+    vehicle_id: VehicleID,
+    cell: &Cell,
+    max_cells: i32,
+) -> i32 {
+    let mut gap = 0;
+    let mut cell = cell;
+    while gap < max_cells {
+        let next_cell = match net.get_cell(&cell.get_forward_id()) {
+            Some(next_cell) => next_cell,
+            None => break,
+        };
+        let occupied = current_state
+            .get(&next_cell.get_id())
+            .is_some_and(|&id| id != vehicle_id);
+        if occupied || next_cell.get_state() != CellState::Free {
+            break;
+        }
+        gap += 1;
+        cell = next_cell;
+    }
+    gap
+}
+
+/// Vehicle which follows the given cell in its lane
+struct Follower {
+    /// Number of free cells between the follower and the cell
+    gap: i32,
+    /// Current speed
+    speed: i32,
+    /// Speed gain per time unit when accelerating
+    increase: i32,
+    /// Effective speed limit
+    speed_limit: i32,
+}
+
+impl Follower {
+    /// Expected speed of the follower having `gap` free cells ahead
+    fn expected_speed(&self, gap: i32) -> i32 {
+        expected_speed(self.speed, self.increase, self.speed_limit, gap)
+    }
+}
+
+/// Searches for the nearest vehicle behind `cell_id` (following forward connections backwards) within `max_cells`.
+///
+/// When vehicles storage is not provided the follower is assumed to move at the speed limit of its cell.
+fn find_follower(
+    net: &GridRoads,
+    current_state: &HashMap<CellID, VehicleID>,
+    vehicles: Option<&IndexMap<VehicleID, Vehicle>>,
+    vehicle_id: VehicleID,
+    cell_id: CellID,
+    max_cells: i32,
+) -> Option<Follower> {
+    let mut visited = HashSet::from([cell_id]);
+    let mut queue = VecDeque::from([(cell_id, 0)]);
+    while let Some((current_id, gap)) = queue.pop_front() {
+        if gap >= max_cells {
+            continue;
+        }
+        for &behind_id in net.get_forward_incoming(&current_id) {
+            if !visited.insert(behind_id) {
+                continue;
+            }
+            match current_state.get(&behind_id) {
+                Some(&follower_id) if follower_id != vehicle_id => {
+                    let cell_limit = net.get_cell(&behind_id).map(|cell| cell.get_speed_limit()).unwrap_or(0);
+                    let follower = match vehicles.and_then(|vehicles| vehicles.get(&follower_id)) {
+                        Some(follower) => Follower {
+                            gap,
+                            speed: follower.speed,
+                            increase: follower.speed_increase(),
+                            speed_limit: follower.effective_speed_limit(cell_limit),
+                        },
+                        None => Follower {
+                            gap,
+                            speed: cell_limit,
+                            increase: 1,
+                            speed_limit: cell_limit,
+                        },
+                    };
+                    return Some(follower);
+                }
+                // Own tail is not a follower
+                Some(_) => {}
+                None => queue.push_back((behind_id, gap + 1)),
+            }
+        }
+    }
+    None
+}
+
+/// Vehicle and its surroundings for lane changing decision
+struct LaneChangeContext<'a> {
+    net: &'a GridRoads,
+    current_state: &'a HashMap<CellID, VehicleID>,
+    vehicles: Option<&'a IndexMap<VehicleID, Vehicle>>,
+    vehicle: &'a Vehicle,
+    params: &'a MobilParameters,
+    source_cell: &'a Cell,
+}
+
+impl LaneChangeContext<'_> {
+    /// Evaluates discretionary lane change into `target_cell` and returns the incentive value
+    /// (the left-hand side of the incentive criterion) if the lane change should be done.
+    ///
+    /// Lane change into the overtaking lane (or into any lane when the keep lane rule is disabled)
+    /// should satisfy the incentive criterion. Lane change into the slow lane is done only when
+    /// the slow lane is free ahead and the new follower is not forced to slow down.
+    /// Safety criterion holds in both cases.
+    fn lane_change_incentive(&self, target_cell: &Cell, slow_lane: bool) -> Option<f64> {
+        let (net, current_state, vehicle, params) = (self.net, self.current_state, self.vehicle, self.params);
+        let target_id = target_cell.get_id();
+        if current_state.contains_key(&target_id)
+            || target_cell.get_state() != CellState::Free
+            || !target_cell.is_allowed_for(vehicle.vehicle_type)
+        {
+            return None;
+        }
+        // Vehicle following the route should stay on it
+        if !vehicle.route.is_empty() && !vehicle.route.contains(&target_id) {
+            return None;
+        }
+
+        // Own advantage: the maneuver itself takes one cell
+        let increase = own_speed_increase(vehicle);
+        let speed_limit = vehicle.effective_speed_limit(self.source_cell.get_speed_limit());
+        let current_lane_gap = free_cells_ahead(net, current_state, vehicle.id, self.source_cell, speed_limit);
+        let current_lane_speed = expected_speed(vehicle.speed.min(speed_limit), increase, speed_limit, current_lane_gap);
+        let speed_limit = vehicle.effective_speed_limit(target_cell.get_speed_limit());
+        let target_gap = free_cells_ahead(net, current_state, vehicle.id, target_cell, speed_limit);
+        let target_lane_speed = expected_speed(vehicle.speed.min(speed_limit), increase, speed_limit, 1 + target_gap);
+        let gain = target_lane_speed - current_lane_speed;
+
+        // New follower loses the cells ahead of it starting from the target cell
+        let look_back = target_cell.get_speed_limit();
+        let new_follower_gain = match find_follower(net, current_state, self.vehicles, vehicle.id, target_id, look_back) {
+            Some(follower) => {
+                if !params.is_safe(follower.speed, follower.gap) {
+                    return None;
+                }
+                follower.expected_speed(follower.gap) - follower.expected_speed(follower.gap + 1 + target_gap)
+            }
+            None => 0,
+        };
+
+        // Old follower gets the cells occupied by the vehicle (including its tail)
+        let rear_cell_id = vehicle.tail_cells.first().copied().unwrap_or(vehicle.cell_id);
+        let look_back = self.source_cell.get_speed_limit();
+        let old_follower_gain = match find_follower(net, current_state, self.vehicles, vehicle.id, rear_cell_id, look_back) {
+            Some(follower) => {
+                let freed_cells = vehicle.tail_cells.len() as i32 + 1 + current_lane_gap;
+                follower.expected_speed(follower.gap + freed_cells) - follower.expected_speed(follower.gap)
+            }
+            None => 0,
+        };
+
+        if slow_lane && params.keep_slow_lane {
+            // Keep right (left) rule: get back as soon as the slow lane is free
+            if target_gap < speed_limit || new_follower_gain < 0 {
+                return None;
+            }
+        } else if !params.incentive(gain, new_follower_gain, old_follower_gain) {
+            return None;
+        }
+
+        // Destination should stay reachable from the target lane
+        if vehicle.destination >= 0 {
+            let destination_cell = net.get_cell(&vehicle.destination)?;
+            shortest_path(target_cell, destination_cell, net, true, None, vehicle.vehicle_type).ok()?;
+        }
+        Some(gain as f64 + params.politeness * (new_follower_gain + old_follower_gain) as f64)
+    }
+}
+
+/// Speed gain of the vehicle in the current time unit (zero if acceleration is prohibited by the timer)
+fn own_speed_increase(vehicle: &Vehicle) -> i32 {
+    if vehicle.timer_non_acceleration <= 0 || vehicle.speed == 0 {
+        vehicle.speed_increase()
+    } else {
+        0
+    }
+}
+
+/// Searches for discretionary lane change (MOBIL - "Minimizing Overall Braking Induced by Lane changes")
+/// for the vehicle which is able to move in its current lane.
+///
+/// Adjacent lanes (left and right cells of the vehicle's head) are evaluated by
+/// the incentive and safety criteria (see [`MobilParameters`]). Expected speeds are estimated by
+/// cellular automata rules using free cells straight ahead of the vehicle in each lane.
+/// The followers are the nearest vehicles behind the target cell and behind the vehicle's rear
+/// within the cells' speed limits.
+///
+/// When [`MobilParameters::keep_slow_lane`] is set, the driving side defines the rules: vehicles overtake
+/// via the overtaking lane only and get back to the slow lane as soon as it is free (keep-right rule
+/// for right-hand traffic, keep-left rule for left-hand traffic).
+///
+/// Lane is not considered when the target cell is occupied or not free, when it is not on the vehicle's
+/// assigned route or when the destination is not reachable from it. Cyclists consider the slow lane only (they keep to the kerb).
+///
+/// # Arguments
+/// * `net` - The road network grid
+/// * `current_state` - Mapping from occupied cell ID to vehicle ID
+/// * `vehicles` - Vehicles storage. If it is not provided, followers are assumed to move at the speed limit
+/// * `vehicle` - The vehicle
+/// * `params` - Lane changing parameters
+/// * `driving_side` - Driving side of the road network
+///
+/// # Returns
+/// `ChangeLeft` or `ChangeRight` with the target cell for the lane with the greatest incentive.
+/// `None` if the vehicle should stay in its lane.
+///
+/// # Example
+/// ```
+/// use micro_traffic_sim_core::agents::Vehicle;
+/// use micro_traffic_sim_core::behaviour::MobilParameters;
+/// use micro_traffic_sim_core::grid::road_network::GridRoads;
+/// use micro_traffic_sim_core::intentions::find_discretionary_lane_change;
+/// use micro_traffic_sim_core::maneuver::{DrivingSide, LaneChangeType};
+/// use micro_traffic_sim_core::utils::generators::generate_one_lane_cells;
+/// use std::collections::HashMap;
+///
+/// // Two lanes: cells 1..10 (right one) and 11..20 (left one)
+/// let mut net = GridRoads::new();
+/// for cell in generate_one_lane_cells(100.0, 10.0, 2) {
+///     net.add_cell(cell);
+/// }
+/// let params = MobilParameters::new().build();
+/// let vehicle = Vehicle::new(1).with_cell(2).with_speed(2).with_speed_limit(3).with_mobil(params).build();
+/// // Slow leader right ahead, the left lane is empty
+/// let current_state = HashMap::from([(2, 1), (3, 2)]);
+/// let lane_change = find_discretionary_lane_change(&net, &current_state, None, &vehicle, &params, DrivingSide::Right);
+/// assert_eq!(lane_change, Some((LaneChangeType::ChangeLeft, 13)));
+/// // Nothing to gain on the free road
+/// let current_state = HashMap::from([(2, 1)]);
+/// assert_eq!(find_discretionary_lane_change(&net, &current_state, None, &vehicle, &params, DrivingSide::Right), None);
+/// // Overtaking vehicle gets back to the free right lane
+/// let vehicle = Vehicle::new(1).with_cell(12).with_speed(2).with_speed_limit(3).with_mobil(params).build();
+/// let current_state = HashMap::from([(12, 1)]);
+/// let lane_change = find_discretionary_lane_change(&net, &current_state, None, &vehicle, &params, DrivingSide::Right);
+/// assert_eq!(lane_change, Some((LaneChangeType::ChangeRight, 3)));
+/// ```
+pub fn find_discretionary_lane_change(
+    net: &GridRoads,
+    current_state: &HashMap<CellID, VehicleID>,
+    vehicles: Option<&IndexMap<VehicleID, Vehicle>>,
+    vehicle: &Vehicle,
+    params: &MobilParameters,
+    driving_side: DrivingSide,
+) -> Option<(LaneChangeType, CellID)> {
+    let source_cell = net.get_cell(&vehicle.cell_id)?;
+    let ctx = LaneChangeContext {
+        net,
+        current_state,
+        vehicles,
+        vehicle,
+        params,
+        source_cell,
+    };
+    let mut best: Option<(LaneChangeType, CellID, f64)> = None;
+    for (maneuver, target_id) in [
+        (LaneChangeType::ChangeRight, source_cell.get_right_id()),
+        (LaneChangeType::ChangeLeft, source_cell.get_left_id()),
+    ] {
+        let Some(target_cell) = net.get_cell(&target_id) else {
+            continue;
+        };
+        let slow_lane = maneuver == driving_side.slow_lane_maneuver();
+        // Cyclists keep to the kerb lane
+        if !slow_lane && vehicle.vehicle_type == AgentType::Cyclist {
+            continue;
+        }
+        let Some(incentive) = ctx.lane_change_incentive(target_cell, slow_lane) else {
+            continue;
+        };
+        if best.is_none_or(|(_, _, best_incentive)| incentive > best_incentive) {
+            best = Some((maneuver, target_id, incentive));
+        }
+    }
+    best.map(|(maneuver, target_id, _)| (maneuver, target_id))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::generators::generate_one_lane_cells;
+
+    #[test]
+    fn test_discretionary_lane_change() {
+        // Lane 1: cells 1..10, lane 2: cells 11..20 (speed limit 3)
+        let mut net = GridRoads::new();
+        for cell in generate_one_lane_cells(100.0, 10.0, 2) {
+            net.add_cell(cell);
+        }
+        let params = MobilParameters::new().with_politeness(1.0).build();
+        let vehicles = IndexMap::from([
+            (1, Vehicle::new(1).with_cell(3).with_speed(3).with_speed_limit(3).build()),
+            (2, Vehicle::new(2).with_cell(4).with_speed(0).build()),
+            (3, Vehicle::new(3).with_cell(12).with_speed(3).with_speed_limit(3).build()),
+            (4, Vehicle::new(4).with_cell(2).with_speed(3).with_speed_limit(3).build()),
+        ]);
+        let vehicle = &vehicles[&1];
+
+        // Stopped leader ahead, the left lane is free: gain is 3
+        let current_state = HashMap::from([(3, 1), (4, 2)]);
+        assert_eq!(
+            find_discretionary_lane_change(&net, &current_state, Some(&vehicles), vehicle, &params, DrivingSide::Right),
+            Some((LaneChangeType::ChangeLeft, 14))
+        );
+
+        // Fast vehicle right behind the target cell: lane change is not safe
+        let current_state = HashMap::from([(3, 1), (4, 2), (13, 3)]);
+        assert_eq!(find_discretionary_lane_change(&net, &current_state, Some(&vehicles), vehicle, &params, DrivingSide::Right), None);
+        let unsafe_params = MobilParameters::new().with_safe_deceleration(3).build();
+        assert_eq!(
+            find_discretionary_lane_change(&net, &current_state, Some(&vehicles), vehicle, &unsafe_params, DrivingSide::Right),
+            Some((LaneChangeType::ChangeLeft, 14))
+        );
+
+        // New follower is far enough to be safe, but polite driver does not force it to slow down
+        let current_state = HashMap::from([(3, 1), (4, 2), (12, 3)]);
+        assert_eq!(find_discretionary_lane_change(&net, &current_state, Some(&vehicles), vehicle, &params, DrivingSide::Right), None);
+        let selfish_params = MobilParameters::new().with_politeness(0.0).build();
+        assert_eq!(
+            find_discretionary_lane_change(&net, &current_state, Some(&vehicles), vehicle, &selfish_params, DrivingSide::Right),
+            Some((LaneChangeType::ChangeLeft, 14))
+        );
+
+        // Old follower gains from the lane change as well
+        let current_state = HashMap::from([(3, 1), (4, 2), (12, 3), (2, 4)]);
+        assert_eq!(
+            find_discretionary_lane_change(&net, &current_state, Some(&vehicles), vehicle, &params, DrivingSide::Right),
+            Some((LaneChangeType::ChangeLeft, 14))
+        );
+
+        // Vehicle follows the route in the right lane
+        let vehicle = Vehicle::new(1)
+            .with_cell(3)
+            .with_speed(3)
+            .with_speed_limit(3)
+            .with_destination(10)
+            .with_route(1, (1..=10).collect())
+            .build();
+        let current_state = HashMap::from([(3, 1), (4, 2)]);
+        assert_eq!(find_discretionary_lane_change(&net, &current_state, Some(&vehicles), &vehicle, &params, DrivingSide::Right), None);
+    }
+}
@@ -1,3 +1,4 @@
+use crate::agents_types::AgentType;
 use crate::maneuver::LaneChangeType;
 use crate::{
     grid::{
@@ -48,31 +49,41 @@ impl fmt::Display for NoRouteError {
 /// Handles cases where no route to the destination is found.
 ///
 /// Returns a single-step path in a possible direction (forward, left, or right),
-/// or an error if no exit is possible.
+/// or an error if no exit is possible. Directions to cells the agent is not permitted
+/// to enter (see [`Cell::is_allowed_for`]) are not considered.
 pub fn process_no_route_found<'a>(
     current_cell: &'a Cell,
     net: &'a GridRoads,
+    agent_type: AgentType,
 ) -> Result<Path<'a>, NoRouteError> {
     // Try to find possible move direction
-    let (destination_cell_id, maneuver) = if current_cell.get_forward_id() >= 0 {
-        (current_cell.get_forward_id(), LaneChangeType::NoChange)
-    } else if current_cell.get_right_id() >= 0 {
-        (current_cell.get_right_id(), LaneChangeType::ChangeRight)
-    } else if current_cell.get_left_id() >= 0 {
-        (current_cell.get_left_id(), LaneChangeType::ChangeLeft)
-    } else {
+    let directions = [
+        (current_cell.get_forward_id(), LaneChangeType::NoChange),
+        (current_cell.get_right_id(), LaneChangeType::ChangeRight),
+        (current_cell.get_left_id(), LaneChangeType::ChangeLeft),
+    ];
+    let mut destination = None;
+    for (destination_cell_id, maneuver) in directions {
+        if destination_cell_id < 0 {
+            continue;
+        }
+        // Get destination cell from grid
+        let destination_cell = net
+            .get_cell(&destination_cell_id)
+            .ok_or(NoRouteError::NoVertexFound {
+                cell_id: destination_cell_id,
+            })?;
+        if destination_cell.is_allowed_for(agent_type) {
+            destination = Some((destination_cell, maneuver));
+            break;
+        }
+    }
+    let Some((destination_cell, maneuver)) = destination else {
         return Err(NoRouteError::DeadEndReached {
             cell_id: current_cell.get_id(),
         });
     };
 
-    // Get destination cell from grid
-    let destination_cell =
-        net.get_cell(&destination_cell_id)
-            .ok_or(NoRouteError::NoVertexFound {
-                cell_id: destination_cell_id,
-            })?;
-
     // Create path with single step
     Ok(Path::new(
         vec![current_cell, destination_cell],
@@ -87,6 +98,7 @@ mod tests {
     use super::*;
     use crate::grid::cell::Cell;
     use crate::grid::road_network::GridRoads;
+    use crate::grid::zones::ZoneType;
 
     #[test]
     fn test_process_no_route_found_deadend() {
@@ -100,7 +112,7 @@ mod tests {
         net.add_cell(cell.clone());
 
         // Should return DeadEndReached error
-        let result = process_no_route_found(&cell, &net);
+        let result = process_no_route_found(&cell, &net, AgentType::Car);
         match result {
             Err(NoRouteError::DeadEndReached { cell_id }) => {
                 assert_eq!(cell_id, cell.get_id());
@@ -108,4 +120,21 @@ mod tests {
             other => panic!("Expected DeadEndReached error, got {:?}", other),
         }
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_process_no_route_found_bus_lane() {
+        // Forward cell is the bus lane, right one is the regular lane
+        let cell = Cell::new(1).with_forward_node(2).with_right_node(3).build();
+        let mut net = GridRoads::new();
+        net.add_cell(cell.clone());
+        net.add_cell(Cell::new(2).with_zone_type(ZoneType::LaneForBus).build());
+        net.add_cell(Cell::new(3).build());
+
+        let path = process_no_route_found(&cell, &net, AgentType::Car).unwrap();
+        assert_eq!(path.vertices()[1].get_id(), 3);
+        assert_eq!(path.maneuvers(), &vec![LaneChangeType::ChangeRight]);
+
+        let path = process_no_route_found(&cell, &net, AgentType::Bus).unwrap();
+        assert_eq!(path.vertices()[1].get_id(), 2);
+    }
+}
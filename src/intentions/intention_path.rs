@@ -1,6 +1,6 @@
 use crate::maneuver::LaneChangeType;
 use crate::{
-    agents::VehicleID,
+    agents::{Vehicle, VehicleID},
     grid::{
         cell::{Cell, CellID, CellState},
     },
@@ -79,6 +79,39 @@ pub fn process_path<'a>(
     speed_possible: i32,
     destination: CellID,
     current_state: &HashMap<CellID, VehicleID>,
+) -> ObservablePath<'a> {
+    trim_path(shortest_path, speed_possible, destination, current_state, |cell| cell.get_speed_limit())
+}
+
+/// Same as [`process_path`], but cells' speed limits are the ones the vehicle follows
+/// (see [`Vehicle::effective_speed_limit`]), e.g. a speeding driver is not stopped by the posted limits.
+///
+/// # Arguments
+/// * `shortest_path` - Reference to path to process
+/// * `speed_possible` - Maximum possible speed
+/// * `vehicle` - The vehicle moving along the path (its destination is used as the path's end)
+/// * `current_state` - Current occupancy state mapping cells to vehicles
+///
+/// # Returns
+/// Returns ObservablePath containing movement analysis
+pub fn process_vehicle_path<'a>(
+    shortest_path: &'a mut Path,
+    speed_possible: i32,
+    vehicle: &Vehicle,
+    current_state: &HashMap<CellID, VehicleID>,
+) -> ObservablePath<'a> {
+    trim_path(shortest_path, speed_possible, vehicle.destination, current_state, |cell| {
+        vehicle.effective_speed_limit(cell.get_speed_limit())
+    })
+}
+
+/// Trims the path (see [`process_path`]) using `speed_limit_of` to get the speed limit of a cell
+fn trim_path<'a>(
+    shortest_path: &'a mut Path,
+    speed_possible: i32,
+    destination: CellID,
+    current_state: &HashMap<CellID, VehicleID>,
+    speed_limit_of: impl Fn(&Cell) -> i32,
 ) -> ObservablePath<'a> {
     // Remove first cell from path since it's vehicle's position
     shortest_path.vertices_mut().remove(0);
@@ -141,7 +174,7 @@ pub fn process_path<'a>(
         }
 
         // Check speed limit
-        if speed_possible > cell.get_speed_limit() {
+        if speed_possible > speed_limit_of(cell) {
             if success_forward_movement == 0 {
                 // Ensure at least one cell is moved into if speed limit is lower than possible speed
                 // Happens when very first cell has lower speed limit than vehicle's speed
@@ -0,0 +1,102 @@
+use crate::maneuver::LaneChangeType;
+use crate::{
+    grid::{
+        cell::{Cell, CellID},
+        road_network::GridRoads,
+    },
+    shortest_path::{heuristics::heuristic, path::Path, router::AStarError},
+};
+
+/// Builds the path ahead of the vehicle along its assigned route.
+///
+/// Route is the full list of cells from the trip's origin to the destination (see
+/// [`route_choice`](crate::route_choice)). The path starts at `current_cell` and contains
+/// at most `depth` next cells of the route.
+///
+/// # Arguments
+/// * `route` - Cells of the assigned route
+/// * `current_cell` - Cell occupied by the vehicle's head
+/// * `net` - The road network grid
+/// * `maneuvers_allowed` - Whether lane changes are allowed. If not, the path is cut right before the first lane change
+/// * `depth` - Maximum number of steps in the path
+///
+/// # Returns
+/// * `Ok(Some(Path))` - Path along the route with at least one step
+/// * `Ok(None)` - Vehicle is off the route (e.g. after forced lane change), route is over or the first step is prohibited.
+///   Caller should fall back to the regular pathfinding.
+/// * `Err(AStarError::BadData)` - Route references a cell which doesn't exist in the grid
+pub fn process_route<'a>(
+    route: &[CellID],
+    current_cell: &'a Cell,
+    net: &'a GridRoads,
+    maneuvers_allowed: bool,
+    depth: i32,
+) -> Result<Option<Path<'a>>, AStarError> {
+    let position = match route.iter().position(|&cell_id| cell_id == current_cell.get_id()) {
+        Some(position) => position,
+        None => return Ok(None),
+    };
+    let mut vertices: Vec<&'a Cell> = vec![current_cell];
+    let mut maneuvers: Vec<LaneChangeType> = Vec::new();
+    let mut cost = 0.0;
+    let mut last_cell = current_cell;
+    for &next_id in route.iter().skip(position + 1).take(depth.max(0) as usize) {
+        let maneuver = match next_id {
+            id if id == last_cell.get_forward_id() => LaneChangeType::NoChange,
+            id if id == last_cell.get_left_id() => LaneChangeType::ChangeLeft,
+            id if id == last_cell.get_right_id() => LaneChangeType::ChangeRight,
+            // Route is not consistent with the grid anymore
+            _ => break,
+        };
+        if maneuver != LaneChangeType::NoChange && !maneuvers_allowed {
+            break;
+        }
+        let next_cell = net
+            .get_cell(&next_id)
+            .ok_or(AStarError::BadData { cell_id: next_id })?;
+        cost += heuristic(last_cell, next_cell);
+        vertices.push(next_cell);
+        maneuvers.push(maneuver);
+        last_cell = next_cell;
+    }
+    if vertices.len() < 2 {
+        return Ok(None);
+    }
+    Ok(Some(Path::new(vertices, maneuvers, cost)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geom::new_point;
+
+    #[test]
+    fn test_process_route() {
+        // (4) -> (5) -> (6)
+        //  ^
+        // (1) -> (2) -> (3)
+        let mut net = GridRoads::new();
+        net.add_cell(Cell::new(1).with_point(new_point(0.0, 0.0, None)).with_forward_node(2).with_left_node(4).build());
+        net.add_cell(Cell::new(2).with_point(new_point(1.0, 0.0, None)).with_forward_node(3).build());
+        net.add_cell(Cell::new(3).with_point(new_point(2.0, 0.0, None)).build());
+        net.add_cell(Cell::new(4).with_point(new_point(1.0, 1.0, None)).with_forward_node(5).build());
+        net.add_cell(Cell::new(5).with_point(new_point(2.0, 1.0, None)).with_forward_node(6).build());
+        net.add_cell(Cell::new(6).with_point(new_point(3.0, 1.0, None)).build());
+        let route = vec![1, 4, 5, 6];
+
+        // Case 1: follow route with lane change
+        let path = process_route(&route, net.get_cell(&1).unwrap(), &net, true, 2).unwrap().unwrap();
+        let ids: Vec<CellID> = path.vertices().iter().map(|cell| cell.get_id()).collect();
+        assert_eq!(ids, vec![1, 4, 5]);
+        assert_eq!(path.maneuvers(), &vec![LaneChangeType::ChangeLeft, LaneChangeType::NoChange]);
+
+        // Case 2: lane change is prohibited
+        assert!(process_route(&route, net.get_cell(&1).unwrap(), &net, false, 2).unwrap().is_none());
+
+        // Case 3: vehicle is off the route
+        assert!(process_route(&route, net.get_cell(&2).unwrap(), &net, true, 2).unwrap().is_none());
+
+        // Case 4: route is over
+        assert!(process_route(&route, net.get_cell(&6).unwrap(), &net, true, 2).unwrap().is_none());
+    }
+}
@@ -0,0 +1,63 @@
+use super::{planned_path, IntentionError};
+use crate::agents::{Vehicle, VehicleID};
+use crate::agents_types::AgentType;
+use crate::grid::cell::CellID;
+use crate::grid::road_network::GridRoads;
+use indexmap::IndexMap;
+use std::collections::HashMap;
+
+/// Collects cells which buses following their transit cells are going to pass within `distance` cells
+/// along their planned paths. Traffic lights controlling these cells could give priority to buses
+/// (see `TrafficLight::request_priority`).
+///
+/// # Arguments
+/// * `net` - The road network grid
+/// * `vehicles` - Vehicles storage
+/// * `distance` - Number of cells ahead of a bus. Zero or negative means no cells.
+///
+/// # Returns
+/// Mapping from cell ID to the distance (in cells) to the nearest bus behind it (zero for cells occupied by buses)
+/// or an error if a planned path goes through a missing cell.
+///
+/// # Example
+/// ```
+/// use micro_traffic_sim_core::agents::Vehicle;
+/// use micro_traffic_sim_core::agents_types::AgentType;
+/// use micro_traffic_sim_core::grid::road_network::GridRoads;
+/// use micro_traffic_sim_core::intentions::find_approaching_transit;
+/// use micro_traffic_sim_core::utils::generators::generate_one_lane_cells;
+/// use indexmap::IndexMap;
+///
+/// let mut net = GridRoads::new();
+/// for cell in generate_one_lane_cells(100.0, 10.0, 1) {
+///     net.add_cell(cell);
+/// }
+/// let bus = Vehicle::new(1).with_type(AgentType::Bus).with_cell(2).with_destination(9).with_transit_cells(vec![9]).build();
+/// let vehicles = IndexMap::from([(1, bus)]);
+/// let cells = find_approaching_transit(&net, &vehicles, 3).unwrap();
+/// assert_eq!(cells.get(&5), Some(&3));
+/// assert_eq!(cells.get(&6), None);
+/// ```
+pub fn find_approaching_transit(
+    net: &GridRoads,
+    vehicles: &IndexMap<VehicleID, Vehicle>,
+    distance: i32,
+) -> Result<HashMap<CellID, i32>, IntentionError> {
+    let mut cells = HashMap::new();
+    if distance <= 0 {
+        return Ok(cells);
+    }
+    let buses = vehicles.values().filter(|vehicle| {
+        matches!(vehicle.vehicle_type, AgentType::Bus | AgentType::LargeBus) && !vehicle.transit_cells.is_empty()
+    });
+    for vehicle in buses {
+        let source_cell = net.get_cell(&vehicle.cell_id).ok_or(IntentionError::NoSourceCell(vehicle.cell_id))?;
+        let planned = planned_path(net, vehicle, source_cell, distance as usize).map_err(IntentionError::NoPathFound)?;
+        let path = std::iter::once(vehicle.cell_id).chain(planned.into_iter().map(|(cell_id, _)| cell_id));
+        for (gap, cell_id) in path.enumerate() {
+            let nearest = cells.entry(cell_id).or_insert(gap as i32);
+            *nearest = (*nearest).min(gap as i32);
+        }
+    }
+    Ok(cells)
+}
@@ -223,6 +223,32 @@ impl Intentions {
         self.push_intention(intention_cell_id, vehicle.id, intention_type);
     }
 
+    /// Collects current intentions of all vehicles as target ones (see [`Intentions::add_intention`]).
+    ///
+    /// Used to rebuild the storage when intentions have been changed after [`prepare_intentions`](crate::intentions::prepare_intentions).
+    /// Buses in bays are out of the lane, so they do not take part in conflicts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use micro_traffic_sim_core::agents::{Vehicle, VehicleIntention};
+    /// use micro_traffic_sim_core::intentions::Intentions;
+    /// use indexmap::IndexMap;
+    ///
+    /// let mut vehicle = Vehicle::new(1).with_cell(10).build();
+    /// vehicle.set_intention(VehicleIntention { intention_cell_id: 11, ..Default::default() });
+    /// let mut vehicles = IndexMap::from([(1, vehicle)]);
+    /// let intentions = Intentions::from_vehicles(&mut vehicles);
+    /// assert_eq!(intentions.get(&11).map(|cell_intentions| cell_intentions.len()), Some(1));
+    /// ```
+    pub fn from_vehicles(vehicles: &mut IndexMap<VehicleID, Vehicle>) -> Self {
+        let mut intentions = Self::new();
+        for vehicle in vehicles.values_mut().filter(|vehicle| !vehicle.is_in_bay()) {
+            intentions.add_intention(vehicle, IntentionType::Target);
+        }
+        intentions
+    }
+
     fn extract_tail_intention(vehicle: &Vehicle) -> Vec<CellID> {
         let tail_size = vehicle.tail_cells.len();
         if tail_size == 0 {
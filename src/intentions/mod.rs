@@ -35,9 +35,28 @@
 //! - [`intention_type::CellIntention`] – Vehicle + intention type for a cell
 //! - [`intention::prepare_intentions`] – Main entry point for intention calculation (internal use)
 //! - [`intention::find_intention`] – Computes intention for a single vehicle
+//! - [`intention::find_intention_with_rng`] – Same as above, but with given random stream
 //! - [`intention::find_alternate_intention`] – Attempts lane change if blocked
+//! - [`intention_mobil::find_discretionary_lane_change`] – Incentive-based lane change of a moving vehicle (see [`MobilParameters`](crate::behaviour::MobilParameters))
+//! - [`intention_lane_selection::find_lane_selection`] – Route-aware lane pre-selection before turns and exits (see `Vehicle::lane_selection_distance`)
+//! - [`intention_cooperative::find_merging_neighbour`] – Cooperative yielding: gap creation for a blocked neighbour (see `Vehicle::cooperativity`)
+//! - [`intention_emergency::find_emergency_clearing`] – Moving aside for an emergency vehicle approaching from behind (see `Vehicle::emergency_awareness_distance`)
+//! - [`intention_transit::find_approaching_transit`] – Cells ahead of buses for transit signal priority (see [`TransitPriority`](crate::traffic_lights::priority::TransitPriority))
 //! - [`intention_path::process_path`] – Trims and analyzes possible movement along a path
+//! - [`intention_path::process_vehicle_path`] – Same, but with speed limits the vehicle follows (see `Vehicle::speed_compliance`)
 //! - [`intention_no_route::process_no_route_found`] – Fallback for unreachable destinations
+//! - [`intention_route::process_route`] – Path ahead along the vehicle's assigned route (see [`route_choice`](crate::route_choice))
+//!
+//! ## Driver models
+//!
+//! Acceleration, random slowdown, observed distance and lane change choice of a blocked vehicle
+//! are delegated to the vehicle's [`DriverModel`](crate::behaviour::DriverModel)
+//! ([`NaSchModel`](crate::behaviour::NaSchModel) by default). Pathfinding and obstacles
+//! checks stay in this module, so custom models only decide what the driver wants to do.
+//!
+//! Drivers which anticipate the leader's movement (e.g. [`AnticipationModel`](crate::behaviour::AnticipationModel))
+//! get [`VehicleIntention::anticipated_cells`](crate::agents::VehicleIntention::anticipated_cells): cells occupied
+//! by the leader now, which are taken after the movement if the leader vacates them.
 //!
 //! ## Intention types
 //!
@@ -46,6 +65,18 @@
 //! - **Tail**: Cell is occupied by the tail of a multi-cell vehicle
 //! - **Undefined**: No intention set
 //!
+//! ## Parallel computation
+//!
+//! Intention of a vehicle depends only on the grid and on the current positions of vehicles,
+//! so with the `rayon` cargo feature enabled intentions are computed in parallel.
+//! Results are merged into [`Intentions`] in the vehicles storage order, therefore output is the same
+//! as for sequential computation. Use [`Session::set_rng_seed()`](crate::simulation::session::Session::set_rng_seed)
+//! to make random slowdowns reproducible: each vehicle gets its own random stream per step.
+//!
+//! ```toml
+//! micro_traffic_sim_core = { version = "*", features = ["rayon"] }
+//! ```
+//!
 //! ## Error handling
 //!
 //! - Returns detailed errors if cells are missing, speed limits are invalid, or no route is found
@@ -66,7 +97,13 @@
 mod intention_type;
 mod intentions_datastorage;
 mod intention_no_route;
+mod intention_route;
 mod intention_path;
+mod intention_mobil;
+mod intention_lane_selection;
+mod intention_cooperative;
+mod intention_emergency;
+mod intention_transit;
 mod intention;
 
-pub use self::{intention_type::*, intentions_datastorage::*, intention_no_route::*, intention_path::*, intention::*};
\ No newline at end of file
+pub use self::{intention_type::*, intentions_datastorage::*, intention_no_route::*, intention_route::*, intention_path::*, intention_mobil::*, intention_lane_selection::*, intention_cooperative::*, intention_emergency::*, intention_transit::*, intention::*};
\ No newline at end of file
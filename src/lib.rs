@@ -9,9 +9,12 @@ pub mod grid;
 pub mod intentions;
 pub mod conflicts;
 pub mod movement;
+pub mod pedestrians;
+pub mod route_choice;
 pub mod shortest_path;
 pub mod simulation;
 pub mod traffic_lights;
+pub mod transit;
 pub mod trips;
 pub mod utils;
 pub mod verbose;
\ No newline at end of file
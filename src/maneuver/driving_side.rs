@@ -0,0 +1,75 @@
+use crate::maneuver::LaneChangeType;
+use std::fmt;
+
+/// Side of the road vehicles keep to (right-hand or left-hand traffic).
+///
+/// Lanes of the road are ordered from the slow lane (closest to the kerb) to the fast one.
+/// Driving side defines which lane change leads to the slow lane and which one is the overtaking one:
+///
+/// | Driving side | Slow lane maneuver | Overtaking maneuver |
+/// |--------------|--------------------|---------------------|
+/// | `Right`      | `ChangeRight`      | `ChangeLeft`        |
+/// | `Left`       | `ChangeLeft`       | `ChangeRight`       |
+///
+/// It is used for:
+/// - the fallback lane choice of a blocked vehicle when lanes are equal (slow lane is preferred);
+/// - the overtaking side and keep-right (keep-left) rule of discretionary lane changing (see [`MobilParameters`](crate::behaviour::MobilParameters));
+/// - priority in lane change conflicts: the vehicle moving out of the slow lane side wins.
+///
+/// # Example
+/// ```
+/// use micro_traffic_sim_core::maneuver::{DrivingSide, LaneChangeType};
+/// assert_eq!(DrivingSide::default(), DrivingSide::Right);
+/// assert_eq!(DrivingSide::Right.slow_lane_maneuver(), LaneChangeType::ChangeRight);
+/// assert_eq!(DrivingSide::Left.overtaking_maneuver(), LaneChangeType::ChangeRight);
+/// ```
+#[derive(Default, Debug, Clone, Copy, Eq, PartialEq)]
+pub enum DrivingSide {
+    #[default]
+    /// **Right-hand traffic**
+    ///
+    /// Vehicles keep to the right and overtake on the left.
+    Right,
+    /// **Left-hand traffic**
+    ///
+    /// Vehicles keep to the left and overtake on the right.
+    Left,
+}
+
+impl DrivingSide {
+    /// Returns lane change towards the slow lane (the kerb side)
+    pub fn slow_lane_maneuver(&self) -> LaneChangeType {
+        match self {
+            DrivingSide::Right => LaneChangeType::ChangeRight,
+            DrivingSide::Left => LaneChangeType::ChangeLeft,
+        }
+    }
+
+    /// Returns lane change towards the fast lane (overtaking side)
+    pub fn overtaking_maneuver(&self) -> LaneChangeType {
+        match self {
+            DrivingSide::Right => LaneChangeType::ChangeLeft,
+            DrivingSide::Left => LaneChangeType::ChangeRight,
+        }
+    }
+}
+
+impl fmt::Display for DrivingSide {
+    /// Formats the driving side for display.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use micro_traffic_sim_core::maneuver::DrivingSide;
+    ///
+    /// assert_eq!(format!("{}", DrivingSide::Right), "right");
+    /// assert_eq!(format!("{}", DrivingSide::Left), "left");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let as_str = match self {
+            DrivingSide::Right => "right",
+            DrivingSide::Left => "left",
+        };
+        write!(f, "{}", as_str)
+    }
+}
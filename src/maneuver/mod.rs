@@ -5,6 +5,7 @@
 //! ## Key Components
 //! 
 //! - [`lane_change_type::LaneChangeType`] - Vehicle movement decisions (straight, left, right, block)
+//! - [`driving_side::DrivingSide`] - Right-hand or left-hand traffic: slow lane and overtaking side
 //! 
 //! ## Example
 //! 
@@ -15,5 +16,6 @@
 //! println!("{}", maneuver); // "right"
 //! ```
 pub mod lane_change_type;
+pub mod driving_side;
 
-pub use self::{lane_change_type::*};
\ No newline at end of file
+pub use self::{lane_change_type::*, driving_side::*};
\ No newline at end of file
@@ -31,6 +31,7 @@
 //! ## Components
 //! 
 //! - [`movement::movement`] - Main movement execution function
+//! - [`movement::anticipation_movement`] - Advance of anticipating vehicles into cells vacated by their leaders
 //! - [`movement::MovementError`] - Error types for movement failures
 //!
 //! ## Movement logic
@@ -43,8 +44,8 @@
 //!
 //! ### Special cases
 //! - **Lane change timers**: Sets movement restrictions after lane changes
-//! - **Transit logic**: FUTURE WORKS. Handles routes which contain cells for mandatory traversal
-//! - **Relax countdown**: FUTURE WORKS. Delays movement for specific scenarios (bus waiting at stop)
+//! - **Transit logic**: Handles routes which contain cells for mandatory traversal, records arrivals and departures of buses
+//! - **Relax countdown**: Delays movement of buses dwelling at stops (see [`transit`](crate::transit))
 //! - **Vehicle removal**: Removes vehicles at destinations or despawn zones
 //!
 //! ## Error handling
@@ -6,8 +6,11 @@ use crate::grid::cell::CellID;
 use crate::grid::zones::ZoneType;
 use crate::maneuver::LaneChangeType;
 use crate::geom::get_bearing;
+use crate::simulation::states::{BusStopEvent, BusStopEventType};
+use crate::transit::{BusStop, BusStopID};
 use crate::verbose::*;
 use indexmap::IndexMap;
+use std::collections::{HashMap, HashSet};
 use std::fmt;
 
 /// Errors that can occur during vehicle movement execution.
@@ -58,6 +61,9 @@ impl std::error::Error for MovementError {}
 ///
 /// * `net` - The road network grid containing all cells and connections.
 /// * `vehicles` - Mutable reference to all active vehicles in the simulation.
+/// * `bus_stops` - Bus stops on transit cells.
+/// * `timestamp` - Current time step.
+/// * `bus_stop_events` - Arrivals and departures of buses are appended to it.
 /// * `verbose` - Logging level for debug output.
 ///
 /// # Returns
@@ -76,6 +82,7 @@ impl std::error::Error for MovementError {}
 /// ## 2. Update Vehicle State
 /// - **Bearing calculation**: Updates direction angle based on movement vector
 /// - **Timers/counters management**: Decrements acceleration/maneuver/slowdown counters.
+///   Sets acceleration delay after acceleration of the vehicles with low maximum acceleration.
 /// - **Tail cell updates**: Updates multi-cell vehicle positions.
 /// - **Travel time increment**: Tracks how long vehicle has been in simulation.
 ///
@@ -90,16 +97,17 @@ impl std::error::Error for MovementError {}
 /// // vehicle.timer_non_slowdown = tail_size;      // Can't slow down
 /// ```
 ///
-/// ### Transit Logic **FUTURE WORKS**
-/// Some vehicles e.g. busses follow multi-stop routes through transit cells:
-/// - When reaching a transit stop, increments `transits_made` counter.
+/// ### Transit Logic
+/// Buses follow multi-stop routes through transit cells (see [`transit`](crate::transit)):
+/// - When reaching a transit stop, increments `transits_made` counter and records the arrival (once per stop visit).
 /// - Updates destination to next transit cell in sequence.
-/// - Uses relax countdown for dwell time at stops.
+/// - Starts dwelling: relax countdown is set to the stop's dwell time (or the vehicle's relax time
+///   if there is no [`BusStop`] for the cell). Bus dwelling at a stop with a bay leaves the lane.
+/// - When the bus leaves the stop cell, records the departure.
 ///
-/// ### Relax countdown **FUTURE WORKS**
+/// ### Relax countdown
 /// Some vehicles must wait before moving:
 /// - Buses at transit stops (dwell time).
-/// - Vehicles after certain maneuvers.
 /// - While countdown > 0, vehicle stays in current cell.
 ///
 /// ## 4. Vehicle removal
@@ -141,6 +149,9 @@ impl std::error::Error for MovementError {}
 pub fn movement(
     net: &GridRoads,
     vehicles: &mut IndexMap<VehicleID, Vehicle>,
+    bus_stops: &mut HashMap<CellID, BusStop>,
+    timestamp: i32,
+    bus_stop_events: &mut Vec<BusStopEvent>,
     verbose: &LocalLogger,
 ) -> Result<(), MovementError> {
     if verbose.is_at_least(VerboseLevel::Main) {
@@ -170,6 +181,7 @@ pub fn movement(
         }
 
         // Apply the vehicle's intention
+        let previous_speed = vehicle.speed;
         vehicle.apply_intention();
         vehicle.is_conflict_participant = false;
 
@@ -202,6 +214,11 @@ pub fn movement(
             }
         }
 
+        // Vehicles with acceleration below one cell per time unit squared can't accelerate on every time unit
+        if vehicle.speed > previous_speed {
+            vehicle.timer_non_acceleration = vehicle.timer_non_acceleration.max(vehicle.acceleration_delay());
+        }
+
         // Update tail cells only if vehicle is actually moving
         if vehicle.cell_id != vehicle.intention.intention_cell_id {
             let tail_size = vehicle.tail_cells.len();
@@ -216,7 +233,11 @@ pub fn movement(
            vehicle.intention.intention_maneuver == LaneChangeType::ChangeRight {
             let tail_size = vehicle.tail_cells.len() as i64;
             vehicle.timer_non_acceleration = tail_size;
-            vehicle.timer_non_maneuvers = tail_size;
+            // Discretionary lane changing drivers keep the lane for a while (see `MobilParameters::maneuver_delay`)
+            vehicle.timer_non_maneuvers = match &vehicle.mobil {
+                Some(params) => tail_size.max(params.maneuver_delay),
+                None => tail_size,
+            };
             vehicle.timer_non_slowdown = tail_size;
         }
 
@@ -251,18 +272,59 @@ pub fn movement(
 
         let zone_type = cell.get_zone_type();
 
-        // Handle bus transit logic
-        if vehicle.vehicle_type == AgentType::Bus && zone_type == ZoneType::Transit && vehicle.cell_id == vehicle.destination {
+        vehicle.travel_time += 1;
+
+        // Bus has left the stop it has been dwelling at
+        if let Some(stop_cell_id) = vehicle.get_dwell_stop()
+            && vehicle.cell_id != stop_cell_id
+        {
+            vehicle.clear_dwell_stop();
+            let stop = bus_stops.get_mut(&stop_cell_id);
+            let stop_id = stop.map(|stop| {
+                stop.set_last_departure(timestamp);
+                stop.get_id()
+            });
+            bus_stop_events.push(bus_stop_event(vehicle, stop_id, stop_cell_id, BusStopEventType::Departure, timestamp));
+        }
+
+        // Handle bus transit logic. The stop is served once: the bus staying in the stop cell has it as the dwell stop
+        if matches!(vehicle.vehicle_type, AgentType::Bus | AgentType::LargeBus)
+            && zone_type == ZoneType::Transit
+            && vehicle.cell_id == vehicle.destination
+            && vehicle.get_dwell_stop() != Some(vehicle.cell_id)
+        {
             vehicle.transits_made_inc();
+            let mut stop = bus_stops.get_mut(&vehicle.cell_id);
+            let stop_id = stop.as_ref().map(|stop| stop.get_id());
+            bus_stop_events.push(bus_stop_event(vehicle, stop_id, vehicle.cell_id, BusStopEventType::Arrival, timestamp));
             let transits_made = vehicle.get_transits_made();
             if (transits_made as usize) < vehicle.transit_cells.len() && vehicle.get_relax_countdown() == 0 {
                 vehicle.destination = vehicle.transit_cells[transits_made as usize];
-                vehicle.relax_countdown_reset();
+                let dwell_time = stop.as_ref().map_or(vehicle.relax_time, |stop| stop.dwell_time(timestamp));
+                let in_bay = stop.as_ref().is_some_and(|stop| stop.is_bay());
+                // Passengers arriving while the bus dwells board it, so the next bus does not wait for them
+                if let Some(stop) = stop.as_mut() {
+                    stop.set_last_departure(timestamp + dwell_time);
+                }
+                vehicle.start_dwell(vehicle.cell_id, dwell_time, in_bay);
+                if verbose.is_at_least(VerboseLevel::Additional) {
+                    verbose.log_with_fields(
+                        EVENT_MOVEMENT_VEHICLE,
+                        "Bus dwells at the stop",
+                        &[
+                            ("vehicle_id", &vehicle.id),
+                            ("cell_id", &vehicle.cell_id),
+                            ("dwell_time", &dwell_time),
+                            ("in_bay", &vehicle.is_in_bay()),
+                        ]
+                    );
+                }
+            } else {
+                // Last stop or the bus is still relaxing: nothing to wait for, but the arrival is already recorded
+                vehicle.start_dwell(vehicle.cell_id, vehicle.get_relax_countdown(), vehicle.is_in_bay());
             }
         }
 
-        vehicle.travel_time += 1;
-
         // Check for vehicle removal conditions
     if zone_type == ZoneType::Death && vehicle.cell_id != vehicle.destination {
             // Vehicle has reached the death zone
@@ -296,4 +358,115 @@ pub fn movement(
     }
 
     Ok(())
-}
\ No newline at end of file
+}
+
+/// Creates a record of the bus arrival or departure
+fn bus_stop_event(
+    vehicle: &Vehicle,
+    stop_id: Option<BusStopID>,
+    cell_id: CellID,
+    event_type: BusStopEventType,
+    timestamp: i32,
+) -> BusStopEvent {
+    BusStopEvent {
+        stop_id,
+        cell_id,
+        vehicle_id: vehicle.id,
+        trip_id: vehicle.trip,
+        event_type,
+        timestamp,
+        travel_time: vehicle.travel_time,
+    }
+}
+
+/// Moves anticipating vehicles into the cells vacated by their leaders in the current time unit.
+///
+/// **Internal function** - Do not call directly. Use [`Session::step()`](crate::simulation::session::Session::step) instead.
+///
+/// Velocity anticipation (see [`DriverModel::anticipates`](crate::behaviour::DriverModel::anticipates)) lets
+/// the vehicle go on through cells which were occupied by the leader when intentions were computed.
+/// Such cells are listed in the vehicle's `intention.anticipated_cells` and this function is called
+/// right after [`movement`], when leaders are already in their new positions.
+///
+/// The vehicle advances cell by cell while:
+/// - it has no tail and it has reached its intended cell (e.g. relax countdown has not stopped it);
+/// - the next anticipated cell is straight ahead of the vehicle;
+/// - the next anticipated cell is not occupied (by a head or a tail of any vehicle).
+///
+/// Vehicles are processed in the storage order and the occupancy is updated after each advance,
+/// hence two vehicles never end up in the same cell.
+///
+/// # Arguments
+///
+/// * `net` - The road network grid containing all cells and connections.
+/// * `vehicles` - Mutable reference to all active vehicles in the simulation.
+/// * `verbose` - Logging level for debug output.
+pub fn anticipation_movement(
+    net: &GridRoads,
+    vehicles: &mut IndexMap<VehicleID, Vehicle>,
+    verbose: &LocalLogger,
+) -> Result<(), MovementError> {
+    if vehicles.values().all(|vehicle| vehicle.intention.anticipated_cells.is_empty()) {
+        return Ok(());
+    }
+    // Buses in bays are out of the lane (see `BusStop`)
+    let mut occupied: HashSet<CellID> = vehicles
+        .values()
+        .filter(|vehicle| !vehicle.is_in_bay())
+        .flat_map(|vehicle| std::iter::once(vehicle.cell_id).chain(vehicle.tail_cells.iter().copied()))
+        .collect();
+
+    for vehicle in vehicles.values_mut() {
+        if vehicle.intention.anticipated_cells.is_empty()
+            || !vehicle.tail_cells.is_empty()
+            || vehicle.cell_id != vehicle.intention.intention_cell_id
+        {
+            continue;
+        }
+        let cell_from = net.get_cell(&vehicle.cell_id)
+            .ok_or(MovementError::CellNotFound {
+                cell_id: vehicle.cell_id,
+                vehicle_id: vehicle.id
+            })?;
+        let mut cell_to = cell_from;
+        let mut advanced = Vec::new();
+        for &cell_id in &vehicle.intention.anticipated_cells {
+            if cell_to.get_forward_id() != cell_id || occupied.contains(&cell_id) {
+                break;
+            }
+            cell_to = net.get_cell(&cell_id)
+                .ok_or(MovementError::CellNotFound {
+                    cell_id,
+                    vehicle_id: vehicle.id
+                })?;
+            advanced.push(cell_id);
+        }
+        let Some(&final_cell) = advanced.last() else {
+            continue;
+        };
+        if verbose.is_at_least(VerboseLevel::Additional) {
+            verbose.log_with_fields(
+                EVENT_MOVEMENT_VEHICLE,
+                "Vehicle follows the leader due anticipation",
+                &[
+                    ("vehicle_id", &vehicle.id),
+                    ("current_cell", &vehicle.cell_id),
+                    ("next_cell", &final_cell),
+                    ("anticipated_cells", &format!("{:?}", advanced)),
+                ]
+            );
+        }
+        occupied.remove(&vehicle.cell_id);
+        occupied.insert(final_cell);
+        vehicle.bearing = get_bearing(cell_from.get_point(), cell_to.get_point());
+        if vehicle.speed > 0 {
+            // The cell reached by the regular movement becomes intermediate one
+            vehicle.intention.intermediate_cells.push(vehicle.cell_id);
+        }
+        vehicle.speed += advanced.len() as i32;
+        vehicle.intention.intermediate_cells.extend_from_slice(&advanced[..advanced.len() - 1]);
+        vehicle.intention.intention_cell_id = final_cell;
+        vehicle.cell_id = final_cell;
+    }
+    Ok(())
+}
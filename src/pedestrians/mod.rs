@@ -0,0 +1,55 @@
+//! # Pedestrians module
+//!
+//! Pedestrians walking through a separate pedestrian network and crossing roads at crosswalks.
+//!
+//! The pedestrian network is a grid of walkway cells (see
+//! [`GridsStorageBuilder::with_pedestrians_net`](crate::simulation::grids_storage::GridsStorageBuilder::with_pedestrians_net)).
+//! Its cells of [`ZoneType::Crosswalk`](crate::grid::zones::ZoneType::Crosswalk) are shared with the vehicles network:
+//! a crosswalk cell has the same ID as the road cell it crosses. Walkway connections are directed like road ones,
+//! so two-way sidewalks are made of two opposite chains of cells.
+//!
+//! Pedestrians are generated by trips of [`AgentType::Pedestrian`](crate::agents_types::AgentType::Pedestrian) (any
+//! trip type) and follow the shortest path through the pedestrian network. Each step:
+//! - pedestrians move first, entering a crosswalk only when there are no vehicles on it and its cell is not banned;
+//! - vehicles yield to occupied crosswalks: road cells of such crosswalks are banned for vehicles until pedestrians leave them.
+//!
+//! Signalised crosswalks are controlled by pedestrian signal groups of traffic lights
+//! (see [`TrafficLightGroupBuilder::with_pedestrian`](crate::traffic_lights::groups::TrafficLightGroupBuilder::with_pedestrian)):
+//! the groups' cells are cells of the pedestrian network, pedestrians do not enter them on red. Pedestrians which are
+//! already on a crosswalk finish crossing.
+//!
+//! ## Components
+//!
+//! - [`pedestrian::Pedestrian`] - Pedestrian agent
+//! - [`walking::Crosswalks`] - Crosswalks of the pedestrian network
+//! - [`walking::pedestrians_movement`] - Movement of pedestrians along their paths
+//!
+//! ## Example
+//!
+//! ```rust
+//! use micro_traffic_sim_core::agents_types::AgentType;
+//! use micro_traffic_sim_core::grid::{cell::Cell, road_network::GridRoads, zones::ZoneType};
+//! use micro_traffic_sim_core::simulation::grids_storage::GridsStorage;
+//! use micro_traffic_sim_core::simulation::session::Session;
+//! use micro_traffic_sim_core::trips::trip::{Trip, TripType};
+//!
+//! // Road (1) → (2) → (3) and the walkway crossing it at cell 2
+//! let mut road = GridRoads::new();
+//! for id in 1..=3 {
+//!     road.add_cell(Cell::new(id).with_forward_node(if id < 3 { id + 1 } else { -1 }).build());
+//! }
+//! let mut walkway = GridRoads::new();
+//! walkway.add_cell(Cell::new(101).with_forward_node(2).build());
+//! walkway.add_cell(Cell::new(2).with_zone_type(ZoneType::Crosswalk).with_forward_node(102).build());
+//! walkway.add_cell(Cell::new(102).build());
+//!
+//! let grids_storage = GridsStorage::new().with_vehicles_net(road).with_pedestrians_net(walkway).build();
+//! let mut session = Session::new(grids_storage, None);
+//! session.add_trip(Trip::new(101, 102, TripType::Constant).with_allowed_agent_type(AgentType::Pedestrian).with_time(5).build());
+//! let state = session.step().unwrap();
+//! assert_eq!(state.pedestrians.len(), 1);
+//! ```
+mod pedestrian;
+mod walking;
+
+pub use self::{pedestrian::*, walking::*};
@@ -0,0 +1,117 @@
+use crate::grid::cell::CellID;
+use crate::trips::trip::TripID;
+
+/// Pedestrian unique identifier type
+pub type PedestrianID = u64;
+
+/// Pedestrian walking through the pedestrian network (see [`GridsStorage`](crate::simulation::grids_storage::GridsStorage)).
+///
+/// Pedestrian follows its path cell by cell: a cell holds a single pedestrian, so a pedestrian waits while the next cell
+/// is occupied, banned by a pedestrian signal or is a crosswalk with vehicles on it (see [`Crosswalks`](crate::pedestrians::Crosswalks)).
+#[derive(Debug, Clone)]
+pub struct Pedestrian {
+    /// Unique identifier
+    pub id: PedestrianID,
+    /// Currently occupied cell of the pedestrian network
+    pub cell_id: CellID,
+    /// Final cell of the pedestrian's trip
+    pub destination: CellID,
+    /// Maximum number of cells passed in a time unit
+    pub speed: i32,
+    /// Remaining cells of the path to the destination (the current cell is excluded)
+    pub path: Vec<CellID>,
+    /// Corresponding trip identifier
+    pub trip: TripID,
+    /// Time (in time units) spent in the simulation
+    pub travel_time: i64,
+    /// Time (in time units) spent waiting without any movement
+    pub waiting_time: i64,
+}
+
+impl Pedestrian {
+    /// Creates a new `PedestrianBuilder` for constructing a `Pedestrian` walking one cell per time unit.
+    ///
+    /// # Arguments
+    /// * `id` - The identifier for the pedestrian.
+    ///
+    /// # Returns
+    /// A `PedestrianBuilder` instance for configuring and building the pedestrian.
+    ///
+    /// # Example
+    /// ```
+    /// use micro_traffic_sim_core::pedestrians::Pedestrian;
+    ///
+    /// let pedestrian = Pedestrian::new(1)
+    ///     .with_cell(101)
+    ///     .with_destination(104)
+    ///     .with_path(vec![102, 103, 104])
+    ///     .build();
+    /// assert_eq!(pedestrian.next_cell(), Some(102));
+    /// ```
+    pub fn new(id: PedestrianID) -> PedestrianBuilder {
+        PedestrianBuilder {
+            pedestrian: Pedestrian {
+                id,
+                cell_id: -1,
+                destination: -1,
+                speed: 1,
+                path: Vec::new(),
+                trip: -1,
+                travel_time: 0,
+                waiting_time: 0,
+            },
+        }
+    }
+
+    /// Returns the next cell of the path (if any)
+    pub fn next_cell(&self) -> Option<CellID> {
+        self.path.first().copied()
+    }
+
+    /// Returns true if the pedestrian has reached its destination
+    pub fn is_arrived(&self) -> bool {
+        self.cell_id == self.destination
+    }
+}
+
+/// A builder for constructing `Pedestrian` instances.
+pub struct PedestrianBuilder {
+    pedestrian: Pedestrian,
+}
+
+impl PedestrianBuilder {
+    /// Sets the currently occupied cell.
+    pub fn with_cell(mut self, cell_id: CellID) -> Self {
+        self.pedestrian.cell_id = cell_id;
+        self
+    }
+
+    /// Sets the final cell of the trip.
+    pub fn with_destination(mut self, destination: CellID) -> Self {
+        self.pedestrian.destination = destination;
+        self
+    }
+
+    /// Sets the maximum number of cells passed in a time unit.
+    pub fn with_speed(mut self, speed: i32) -> Self {
+        self.pedestrian.speed = speed;
+        self
+    }
+
+    /// Sets the cells of the path to the destination (excluding the current cell).
+    pub fn with_path(mut self, path: Vec<CellID>) -> Self {
+        self.pedestrian.path = path;
+        self
+    }
+
+    /// Sets the corresponding trip identifier.
+    pub fn with_trip(mut self, trip: TripID) -> Self {
+        self.pedestrian.trip = trip;
+        self
+    }
+
+    /// Builds and returns the final `Pedestrian` instance.
+    pub fn build(self) -> Pedestrian {
+        self.pedestrian
+    }
+}
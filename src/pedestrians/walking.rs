@@ -0,0 +1,276 @@
+use crate::agents::VehicleID;
+use crate::grid::cell::{CellID, CellState};
+use crate::grid::road_network::GridRoads;
+use crate::grid::zones::ZoneType;
+use crate::pedestrians::{Pedestrian, PedestrianID};
+use crate::verbose::*;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt;
+
+/// Errors returned by pedestrians movement.
+#[derive(Debug, Clone)]
+pub enum PedestrianError {
+    /// Cell of the pedestrian's path is missing in the pedestrian network
+    CellNotFound {
+        /// The ID of the missing cell
+        cell_id: CellID,
+        /// The pedestrian's identifier
+        pedestrian_id: PedestrianID,
+    },
+}
+
+impl fmt::Display for PedestrianError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PedestrianError::CellNotFound { cell_id, pedestrian_id } => {
+                write!(f, "Can't find cell with ID '{}' for pedestrian '{}'", cell_id, pedestrian_id)
+            }
+        }
+    }
+}
+
+/// Crosswalks of the pedestrian network.
+///
+/// Crosswalk is a connected set of [`ZoneType::Crosswalk`] cells of the pedestrian network. Crosswalk cells are shared
+/// with the vehicles network: the cell with the same ID in the vehicles network is the part of the road the crosswalk crosses.
+/// Vehicles yield to occupied crosswalks: every shared cell of a crosswalk with a pedestrian on it is banned for vehicles.
+/// Pedestrians enter a crosswalk only when there are no vehicles on it.
+#[derive(Debug, Clone, Default)]
+pub struct Crosswalks {
+    /// Cells of each crosswalk
+    crosswalks: Vec<Vec<CellID>>,
+    /// Mapping from crosswalk cell to the index of its crosswalk
+    cells_crosswalks: HashMap<CellID, usize>,
+}
+
+impl Crosswalks {
+    /// Collects crosswalks of the pedestrian network: crosswalk cells connected by forward, left or right
+    /// connections (in any direction) form a single crosswalk.
+    ///
+    /// # Arguments
+    /// * `pedestrians_net` - The pedestrian network
+    ///
+    /// # Example
+    /// ```
+    /// use micro_traffic_sim_core::grid::{cell::Cell, road_network::GridRoads, zones::ZoneType};
+    /// use micro_traffic_sim_core::pedestrians::Crosswalks;
+    ///
+    /// let mut walkways = GridRoads::new();
+    /// walkways.add_cell(Cell::new(101).with_forward_node(5).build());
+    /// walkways.add_cell(Cell::new(5).with_zone_type(ZoneType::Crosswalk).with_forward_node(35).build());
+    /// walkways.add_cell(Cell::new(35).with_zone_type(ZoneType::Crosswalk).with_forward_node(102).build());
+    /// walkways.add_cell(Cell::new(102).build());
+    /// let crosswalks = Crosswalks::from_net(&walkways);
+    /// assert_eq!(crosswalks.len(), 1);
+    /// assert_eq!(crosswalks.crosswalk_of(35), Some(&vec![5, 35]));
+    /// assert_eq!(crosswalks.crosswalk_of(101), None);
+    /// ```
+    pub fn from_net(pedestrians_net: &GridRoads) -> Self {
+        let is_crosswalk = |cell_id: CellID| {
+            pedestrians_net.get_cell(&cell_id).is_some_and(|cell| cell.get_zone_type() == ZoneType::Crosswalk)
+        };
+        let mut crossing_cells: Vec<CellID> = pedestrians_net
+            .iter()
+            .filter(|(_, cell)| cell.get_zone_type() == ZoneType::Crosswalk)
+            .map(|(&cell_id, _)| cell_id)
+            .collect();
+        crossing_cells.sort();
+
+        let mut crosswalks = Crosswalks::default();
+        for &start_id in crossing_cells.iter() {
+            if crosswalks.cells_crosswalks.contains_key(&start_id) {
+                continue;
+            }
+            let idx = crosswalks.crosswalks.len();
+            let mut cells = vec![];
+            let mut stack = vec![start_id];
+            crosswalks.cells_crosswalks.insert(start_id, idx);
+            while let Some(cell_id) = stack.pop() {
+                cells.push(cell_id);
+                let Some(cell) = pedestrians_net.get_cell(&cell_id) else {
+                    continue;
+                };
+                let neighbours = [cell.get_forward_id(), cell.get_left_id(), cell.get_right_id()]
+                    .into_iter()
+                    .chain(pedestrians_net.get_forward_incoming(&cell_id).iter().copied())
+                    .chain(pedestrians_net.get_lateral_incoming(&cell_id).iter().copied());
+                for neighbour_id in neighbours {
+                    if is_crosswalk(neighbour_id) && !crosswalks.cells_crosswalks.contains_key(&neighbour_id) {
+                        crosswalks.cells_crosswalks.insert(neighbour_id, idx);
+                        stack.push(neighbour_id);
+                    }
+                }
+            }
+            cells.sort();
+            crosswalks.crosswalks.push(cells);
+        }
+        crosswalks
+    }
+
+    /// Returns the number of crosswalks
+    pub fn len(&self) -> usize {
+        self.crosswalks.len()
+    }
+
+    /// Returns true if there are no crosswalks
+    pub fn is_empty(&self) -> bool {
+        self.crosswalks.is_empty()
+    }
+
+    /// Returns cells of the crosswalk containing the given cell (if the cell is a crosswalk one)
+    pub fn crosswalk_of(&self, cell_id: CellID) -> Option<&Vec<CellID>> {
+        self.cells_crosswalks.get(&cell_id).map(|&idx| &self.crosswalks[idx])
+    }
+
+    /// Returns cells of crosswalks occupied by the pedestrians
+    ///
+    /// # Arguments
+    /// * `pedestrians` - The pedestrians
+    pub fn occupied_cells<'a, I: IntoIterator<Item = &'a Pedestrian>>(&self, pedestrians: I) -> HashSet<CellID> {
+        let occupied: HashSet<usize> = pedestrians
+            .into_iter()
+            .filter_map(|pedestrian| self.cells_crosswalks.get(&pedestrian.cell_id).copied())
+            .collect();
+        occupied.into_iter().flat_map(|idx| self.crosswalks[idx].iter().copied()).collect()
+    }
+}
+
+/// Moves pedestrians along their paths.
+///
+/// Pedestrians move in order of their identifiers, up to `speed` cells each. Pedestrian stops before the cell which is:
+/// - occupied by another pedestrian;
+/// - banned (e.g. by a pedestrian signal group, see [`TrafficLightGroupBuilder::with_pedestrian`](crate::traffic_lights::groups::TrafficLightGroupBuilder::with_pedestrian));
+/// - the first cell of a crosswalk with vehicles on it (see [`Crosswalks`]).
+///
+/// Pedestrians which have reached their destinations are removed.
+///
+/// # Arguments
+/// * `pedestrians_net` - The pedestrian network
+/// * `crosswalks` - Crosswalks of the pedestrian network
+/// * `vehicles_positions` - Cells of the vehicles network occupied by vehicles (heads and tails)
+/// * `pedestrians` - The pedestrians storage
+/// * `verbose` - The logger
+///
+/// # Returns
+/// * `Ok(Vec<PedestrianID>)` - Pedestrians which have reached their destinations
+/// * `Err(PedestrianError)` - If a cell of a pedestrian's path is missing
+pub fn pedestrians_movement(
+    pedestrians_net: &GridRoads,
+    crosswalks: &Crosswalks,
+    vehicles_positions: &HashMap<CellID, VehicleID>,
+    pedestrians: &mut BTreeMap<PedestrianID, Pedestrian>,
+    verbose: &LocalLogger,
+) -> Result<Vec<PedestrianID>, PedestrianError> {
+    if verbose.is_at_least(VerboseLevel::Main) {
+        verbose.log_with_fields(
+            EVENT_PEDESTRIANS_MOVEMENT,
+            "Move pedestrians",
+            &[("pedestrians_num", &pedestrians.len())]
+        );
+    }
+    let mut occupied: HashSet<CellID> = pedestrians.values().map(|pedestrian| pedestrian.cell_id).collect();
+    let mut arrived = vec![];
+    for pedestrian in pedestrians.values_mut() {
+        pedestrian.travel_time += 1;
+        let mut moved = false;
+        for _ in 0..pedestrian.speed {
+            let Some(next_id) = pedestrian.next_cell() else {
+                break;
+            };
+            let next_cell = pedestrians_net.get_cell(&next_id).ok_or(PedestrianError::CellNotFound {
+                cell_id: next_id,
+                pedestrian_id: pedestrian.id,
+            })?;
+            if occupied.contains(&next_id) || next_cell.get_state() != CellState::Free {
+                break;
+            }
+            // Pedestrian steps onto a crosswalk only when vehicles are out of it
+            if let Some(crosswalk) = crosswalks.crosswalk_of(next_id)
+                && crosswalks.crosswalk_of(pedestrian.cell_id) != Some(crosswalk)
+                && crosswalk.iter().any(|cell_id| vehicles_positions.contains_key(cell_id))
+            {
+                break;
+            }
+            occupied.remove(&pedestrian.cell_id);
+            occupied.insert(next_id);
+            pedestrian.cell_id = next_id;
+            pedestrian.path.remove(0);
+            moved = true;
+            if pedestrian.is_arrived() {
+                break;
+            }
+        }
+        if !moved {
+            pedestrian.waiting_time += 1;
+        }
+        // Pedestrian without path (e.g. dead end) leaves as well
+        if pedestrian.is_arrived() || pedestrian.path.is_empty() {
+            if verbose.is_at_least(VerboseLevel::Additional) {
+                verbose.log_with_fields(
+                    EVENT_PEDESTRIANS_MOVEMENT,
+                    "Pedestrian has finished the walk",
+                    &[
+                        ("pedestrian_id", &pedestrian.id),
+                        ("cell_id", &pedestrian.cell_id),
+                        ("destination", &pedestrian.destination),
+                    ]
+                );
+            }
+            occupied.remove(&pedestrian.cell_id);
+            arrived.push(pedestrian.id);
+        }
+    }
+    for pedestrian_id in arrived.iter() {
+        pedestrians.remove(pedestrian_id);
+    }
+    Ok(arrived)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::cell::Cell;
+
+    /// Walkway (101) → (102) → crosswalk (5) → crosswalk (35) → (103)
+    fn walkways() -> GridRoads {
+        let mut net = GridRoads::new();
+        net.add_cell(Cell::new(101).with_forward_node(102).build());
+        net.add_cell(Cell::new(102).with_forward_node(5).build());
+        net.add_cell(Cell::new(5).with_zone_type(ZoneType::Crosswalk).with_forward_node(35).build());
+        net.add_cell(Cell::new(35).with_zone_type(ZoneType::Crosswalk).with_forward_node(103).build());
+        net.add_cell(Cell::new(103).build());
+        net
+    }
+
+    fn walker(id: PedestrianID, cell_id: CellID, path: Vec<CellID>) -> Pedestrian {
+        Pedestrian::new(id).with_cell(cell_id).with_destination(103).with_path(path).build()
+    }
+
+    #[test]
+    fn test_pedestrians_movement() {
+        let net = walkways();
+        let crosswalks = Crosswalks::from_net(&net);
+        let verbose = LocalLogger::new(VerboseLevel::None);
+        let mut pedestrians = BTreeMap::new();
+        pedestrians.insert(1, walker(1, 102, vec![5, 35, 103]));
+        pedestrians.insert(2, walker(2, 101, vec![102, 5, 35, 103]));
+
+        // Vehicle is on the crosswalk: both pedestrians wait
+        let vehicles_positions = HashMap::from([(35, 1)]);
+        pedestrians_movement(&net, &crosswalks, &vehicles_positions, &mut pedestrians, &verbose).unwrap();
+        assert_eq!((pedestrians[&1].cell_id, pedestrians[&2].cell_id), (102, 101));
+        assert_eq!(pedestrians[&1].waiting_time, 1);
+
+        // Crosswalk is free: the first pedestrian enters it and keeps crossing even if a vehicle comes
+        pedestrians_movement(&net, &crosswalks, &HashMap::new(), &mut pedestrians, &verbose).unwrap();
+        assert_eq!((pedestrians[&1].cell_id, pedestrians[&2].cell_id), (5, 102));
+        assert_eq!(crosswalks.occupied_cells(pedestrians.values()), HashSet::from([5, 35]));
+        pedestrians_movement(&net, &crosswalks, &vehicles_positions, &mut pedestrians, &verbose).unwrap();
+        assert_eq!((pedestrians[&1].cell_id, pedestrians[&2].cell_id), (35, 102));
+
+        // Arrived pedestrian is removed
+        let arrived = pedestrians_movement(&net, &crosswalks, &HashMap::new(), &mut pedestrians, &verbose).unwrap();
+        assert_eq!(arrived, vec![1]);
+        assert_eq!(pedestrians[&2].cell_id, 5);
+    }
+}
@@ -0,0 +1,329 @@
+use crate::grid::{cell::CellID, road_network::GridRoads};
+use crate::route_choice::{LinkTravelTimes, Route, RouteID};
+use rand::Rng;
+use std::collections::HashMap;
+use std::fmt;
+
+/// Discrete choice model for picking a route among alternatives
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RouteChoiceModel {
+    /// Multinomial logit: `P(i) = exp(-theta * c(i)) / sum(exp(-theta * c(j)))`
+    MultinomialLogit,
+    /// Path-size logit: multinomial logit with `beta * ln(PS(i))` correction term
+    /// which penalizes routes overlapping with other alternatives
+    PathSizeLogit,
+}
+
+impl fmt::Display for RouteChoiceModel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            RouteChoiceModel::MultinomialLogit => "multinomial_logit",
+            RouteChoiceModel::PathSizeLogit => "path_size_logit",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+/// Parameters of stochastic route assignment for vehicles generated by a trip.
+///
+/// Alternatives are K shortest loopless paths between trip's origin and destination.
+/// Cost of an alternative is its current travel time (see [`LinkTravelTimes`]).
+#[derive(Debug, Clone, PartialEq)]
+pub struct RouteChoice {
+    /// Choice model
+    pub model: RouteChoiceModel,
+    /// Maximum number of alternative routes (K)
+    pub routes_num: usize,
+    /// Scale parameter of the logit model (sensitivity to travel time, 1 / time units)
+    pub theta: f64,
+    /// Weight of the path-size correction term. Used by `RouteChoiceModel::PathSizeLogit` only
+    pub path_size_beta: f64,
+}
+
+/// A builder pattern implementation for constructing `RouteChoice` objects.
+pub struct RouteChoiceBuilder {
+    route_choice: RouteChoice,
+}
+
+impl RouteChoice {
+    /// Constructs a new `RouteChoiceBuilder` for building a `RouteChoice` object.
+    ///
+    /// Defaults: 3 routes, `theta = 1.0`, `path_size_beta = 1.0`.
+    ///
+    /// # Arguments
+    /// * `model` - Choice model. See the ref. at `RouteChoiceModel`
+    ///
+    /// # Example
+    /// ```
+    /// use micro_traffic_sim_core::route_choice::{RouteChoice, RouteChoiceModel};
+    /// let route_choice = RouteChoice::new(RouteChoiceModel::PathSizeLogit)
+    ///     .with_routes_num(5)
+    ///     .with_theta(0.5)
+    ///     .build();
+    /// assert_eq!(route_choice.routes_num, 5);
+    /// ```
+    pub fn new(model: RouteChoiceModel) -> RouteChoiceBuilder {
+        RouteChoiceBuilder {
+            route_choice: RouteChoice {
+                model,
+                routes_num: 3,
+                theta: 1.0,
+                path_size_beta: 1.0,
+            },
+        }
+    }
+
+    /// Calculates choice probabilities for the given routes.
+    ///
+    /// # Arguments
+    /// * `routes` - Alternative routes
+    /// * `costs` - Cost (travel time) of each route. Must have the same length as `routes`
+    /// * `net` - The road network grid (used for path-size factors)
+    ///
+    /// # Returns
+    /// Probabilities in the same order as `routes`. Empty vector for empty `routes`.
+    pub fn probabilities(&self, routes: &[Route], costs: &[f64], net: &GridRoads) -> Vec<f64> {
+        let utilities: Vec<f64> = match self.model {
+            RouteChoiceModel::MultinomialLogit => {
+                costs.iter().map(|cost| -self.theta * cost).collect()
+            }
+            RouteChoiceModel::PathSizeLogit => {
+                let path_sizes = path_size_factors(routes, net);
+                costs
+                    .iter()
+                    .zip(path_sizes.iter())
+                    .map(|(cost, ps)| -self.theta * cost + self.path_size_beta * ps.ln())
+                    .collect()
+            }
+        };
+        logit_probabilities(&utilities)
+    }
+
+    /// Picks a route for a vehicle using current travel times.
+    ///
+    /// # Arguments
+    /// * `routes` - Alternative routes
+    /// * `net` - The road network grid
+    /// * `travel_times` - Current links travel times
+    /// * `rng` - Random generator
+    ///
+    /// # Returns
+    /// Picked route or `None` if there are no routes
+    pub fn choose<'a, R: Rng + ?Sized>(
+        &self,
+        routes: &'a [Route],
+        net: &GridRoads,
+        travel_times: &LinkTravelTimes,
+        rng: &mut R,
+    ) -> Option<&'a Route> {
+        let costs: Vec<f64> = routes
+            .iter()
+            .map(|route| route.travel_time(net, travel_times))
+            .collect();
+        let probabilities = self.probabilities(routes, &costs, net);
+        sample_index(&probabilities, rng).map(|idx| &routes[idx])
+    }
+}
+
+impl RouteChoiceBuilder {
+    /// Sets maximum number of alternative routes
+    ///
+    /// # Example
+    /// ```
+    /// use micro_traffic_sim_core::route_choice::{RouteChoice, RouteChoiceModel};
+    /// let route_choice = RouteChoice::new(RouteChoiceModel::MultinomialLogit)
+    ///     .with_routes_num(2)
+    ///     .build();
+    /// assert_eq!(route_choice.routes_num, 2);
+    /// ```
+    pub fn with_routes_num(mut self, k: usize) -> Self {
+        self.route_choice.routes_num = k;
+        self
+    }
+
+    /// Sets scale parameter of the logit model
+    ///
+    /// # Example
+    /// ```
+    /// use micro_traffic_sim_core::route_choice::{RouteChoice, RouteChoiceModel};
+    /// let route_choice = RouteChoice::new(RouteChoiceModel::MultinomialLogit)
+    ///     .with_theta(0.2)
+    ///     .build();
+    /// assert_eq!(route_choice.theta, 0.2);
+    /// ```
+    pub fn with_theta(mut self, theta: f64) -> Self {
+        self.route_choice.theta = theta;
+        self
+    }
+
+    /// Sets weight of the path-size correction term
+    ///
+    /// # Example
+    /// ```
+    /// use micro_traffic_sim_core::route_choice::{RouteChoice, RouteChoiceModel};
+    /// let route_choice = RouteChoice::new(RouteChoiceModel::PathSizeLogit)
+    ///     .with_path_size_beta(0.8)
+    ///     .build();
+    /// assert_eq!(route_choice.path_size_beta, 0.8);
+    /// ```
+    pub fn with_path_size_beta(mut self, beta: f64) -> Self {
+        self.route_choice.path_size_beta = beta;
+        self
+    }
+
+    /// Builds the final `RouteChoice` object
+    pub fn build(self) -> RouteChoice {
+        self.route_choice
+    }
+}
+
+/// Calculates logit probabilities for the given utilities.
+///
+/// Utilities are shifted by their maximum for numerical stability.
+///
+/// # Example
+/// ```
+/// use micro_traffic_sim_core::route_choice::logit_probabilities;
+/// let probabilities = logit_probabilities(&[-1.0, -1.0]);
+/// assert_eq!(probabilities, vec![0.5, 0.5]);
+/// ```
+pub fn logit_probabilities(utilities: &[f64]) -> Vec<f64> {
+    let max_utility = utilities.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    if !max_utility.is_finite() {
+        // Degenerate case: pick uniformly
+        return vec![1.0 / utilities.len() as f64; utilities.len()];
+    }
+    let exps: Vec<f64> = utilities.iter().map(|u| (u - max_utility).exp()).collect();
+    let sum: f64 = exps.iter().sum();
+    exps.iter().map(|e| e / sum).collect()
+}
+
+/// Calculates path-size factors for the given routes.
+///
+/// `PS(i) = sum over cells a of route i: (l(a) / L(i)) * (1 / N(a))`, where `l(a)` is the length of
+/// the step to the cell `a`, `L(i)` is the length of the route and `N(a)` is the number of
+/// routes using cell `a`. Factor is 1 for routes without overlapping and tends to 0 for fully overlapping ones.
+pub fn path_size_factors(routes: &[Route], net: &GridRoads) -> Vec<f64> {
+    let mut usage: HashMap<CellID, usize> = HashMap::new();
+    for route in routes.iter() {
+        for cell_id in route.cells.iter().skip(1) {
+            *usage.entry(*cell_id).or_insert(0) += 1;
+        }
+    }
+    routes
+        .iter()
+        .map(|route| {
+            let mut steps: Vec<(CellID, f64)> = Vec::with_capacity(route.cells.len());
+            for pair in route.cells.windows(2) {
+                let length = match (net.get_cell(&pair[0]), net.get_cell(&pair[1])) {
+                    (Some(from), Some(to)) => from.distance_to(to),
+                    _ => 0.0,
+                };
+                steps.push((pair[1], length));
+            }
+            let total: f64 = steps.iter().map(|(_, length)| length).sum();
+            if total <= f64::EPSILON {
+                return 1.0;
+            }
+            steps
+                .iter()
+                .map(|(cell_id, length)| length / total / usage[cell_id] as f64)
+                .sum()
+        })
+        .collect()
+}
+
+/// Samples an index according to given probabilities
+pub(crate) fn sample_index<R: Rng + ?Sized>(probabilities: &[f64], rng: &mut R) -> Option<RouteID> {
+    if probabilities.is_empty() {
+        return None;
+    }
+    let value: f64 = rng.random();
+    let mut cumulative = 0.0;
+    for (idx, p) in probabilities.iter().enumerate() {
+        cumulative += p;
+        if value < cumulative {
+            return Some(idx);
+        }
+    }
+    Some(probabilities.len() - 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::agents_types::AgentType;
+    use crate::geom::new_point;
+    use crate::grid::cell::Cell;
+    use crate::route_choice::find_routes;
+    use crate::utils::rand::rng;
+
+    fn two_routes_grid() -> GridRoads {
+        // Short route 1 -> 2 -> 3 -> 4 and long one 1 -> 5 -> 6 -> 4
+        let mut net = GridRoads::new();
+        net.add_cell(Cell::new(1).with_point(new_point(0.0, 0.0, None)).with_speed_limit(1).with_forward_node(2).with_left_node(5).with_meso_link(1).build());
+        net.add_cell(Cell::new(2).with_point(new_point(1.0, 0.0, None)).with_speed_limit(1).with_forward_node(3).with_meso_link(1).build());
+        net.add_cell(Cell::new(3).with_point(new_point(2.0, 0.0, None)).with_speed_limit(1).with_forward_node(4).with_meso_link(1).build());
+        net.add_cell(Cell::new(4).with_point(new_point(3.0, 0.0, None)).with_speed_limit(1).with_meso_link(3).build());
+        net.add_cell(Cell::new(5).with_point(new_point(1.0, 2.0, None)).with_speed_limit(1).with_forward_node(6).with_meso_link(2).build());
+        net.add_cell(Cell::new(6).with_point(new_point(2.0, 2.0, None)).with_speed_limit(1).with_forward_node(4).with_meso_link(2).build());
+        net
+    }
+
+    #[test]
+    fn test_probabilities() {
+        let net = two_routes_grid();
+        let routes = find_routes(1, 4, &net, 3, AgentType::Car).unwrap();
+        assert_eq!(routes.len(), 2);
+
+        let mnl = RouteChoice::new(RouteChoiceModel::MultinomialLogit).with_theta(1.0).build();
+        let probabilities = mnl.probabilities(&routes, &[3.0, 3.0], &net);
+        assert!((probabilities[0] - 0.5).abs() < 1e-9);
+        let probabilities = mnl.probabilities(&routes, &[3.0, 4.0], &net);
+        let expected = 1.0 / (1.0 + (-1.0_f64).exp());
+        assert!((probabilities[0] - expected).abs() < 1e-9);
+        assert!((probabilities.iter().sum::<f64>() - 1.0).abs() < 1e-9);
+
+        // Both routes share only destination cell, so path-size factors are slightly less than 1
+        let path_sizes = path_size_factors(&routes, &net);
+        for ps in path_sizes.iter() {
+            assert!(*ps > 0.5 && *ps < 1.0, "Path size: {}", ps);
+        }
+        // Identical routes share the whole path: factor is 0.5
+        let duplicated = vec![routes[0].clone(), routes[0].clone()];
+        let path_sizes = path_size_factors(&duplicated, &net);
+        assert!((path_sizes[0] - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_choose() {
+        let net = two_routes_grid();
+        let routes = find_routes(1, 4, &net, 3, AgentType::Car).unwrap();
+        let mut travel_times = LinkTravelTimes::new();
+        let route_choice = RouteChoice::new(RouteChoiceModel::PathSizeLogit).with_theta(1.0).build();
+        let mut rng = rng();
+        let mut counters = [0; 2];
+        for _ in 0..1000 {
+            let route = route_choice.choose(&routes, &net, &travel_times, &mut rng).unwrap();
+            counters[route.id] += 1;
+        }
+        // Free-flow: shorter route is preferred
+        assert!(counters[0] > counters[1], "Counters: {:?}", counters);
+
+        // Congestion on the shorter route (link #1) makes the longer one preferred
+        let mut observation_vehicles = indexmap::IndexMap::new();
+        let mut stopped_vehicle = crate::agents::Vehicle::new(1).with_cell(2).build();
+        stopped_vehicle.intention.intention_cell_id = 2;
+        observation_vehicles.insert(1, stopped_vehicle);
+        travel_times.set_decay(1.0);
+        for _ in 0..10 {
+            travel_times.observe(&net, &observation_vehicles);
+        }
+        let mut counters = [0; 2];
+        for _ in 0..1000 {
+            let route = route_choice.choose(&routes, &net, &travel_times, &mut rng).unwrap();
+            counters[route.id] += 1;
+        }
+        assert!(counters[1] > counters[0], "Counters: {:?}", counters);
+    }
+}
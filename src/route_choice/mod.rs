@@ -0,0 +1,56 @@
+//! # Route choice module
+//!
+//! Stochastic route assignment for vehicles generated by trips.
+//!
+//! By default every vehicle follows the shortest path (recalculated each step by the intentions module).
+//! When a trip has [`RouteChoice`] parameters, the session finds K shortest loopless paths between
+//! trip's `from_node` and `to_node` once and assigns each generated vehicle to one of them with
+//! a logit model on current travel times. Assigned route is recorded per vehicle (`Vehicle::route_id`, `Vehicle::route`)
+//! and vehicle follows it while it is on the route (it falls back to the shortest path otherwise).
+//!
+//! ## Components
+//!
+//! - [`route::Route`] - One of the alternatives between origin and destination
+//! - [`route::find_routes`] - K shortest loopless routes between two cells
+//! - [`logit::RouteChoice`] - Route choice parameters (model, K, scale parameter)
+//! - [`logit::RouteChoiceModel`] - Multinomial logit or path-size logit
+//! - [`travel_times::LinkTravelTimes`] - Current travel times per mesoscopic link (`meso_link_id` of cells)
+//! - [`sliced_travel_times::SlicedTravelTimes`] - Experienced travel times per mesoscopic link and time slice
+//! - [`route_splits::RouteSplits`] - Fixed routes shares per trip and departure time slice
+//!
+//! ## Travel times
+//!
+//! Each step every vehicle contributes one time unit and the number of moved cells to the link of its head cell.
+//! Travel time per cell is `time / distance` with exponential fading of old observations. Cells of links
+//! without observations use free-flow time `1 / speed_limit`.
+//!
+//! ## Dynamic user equilibrium
+//!
+//! Logit on current travel times is an en-route (myopic) choice. To get equilibrium-consistent
+//! routes shares use [`DynamicEquilibrium`](crate::simulation::equilibrium::DynamicEquilibrium):
+//! it runs the scenario repeatedly, records experienced travel times per link and time slice
+//! and updates [`RouteSplits`] with the method of successive averages until the relative gap converges.
+//!
+//! ## Usage
+//!
+//! ```rust
+//! use micro_traffic_sim_core::trips::trip::{Trip, TripType};
+//! use micro_traffic_sim_core::route_choice::{RouteChoice, RouteChoiceModel};
+//!
+//! let trip = Trip::new(1, 100, TripType::Constant)
+//!     .with_time(5)
+//!     .with_route_choice(
+//!         RouteChoice::new(RouteChoiceModel::PathSizeLogit)
+//!             .with_routes_num(3)
+//!             .with_theta(0.5)
+//!             .build()
+//!     )
+//!     .build();
+//! ```
+mod route;
+mod travel_times;
+mod sliced_travel_times;
+mod route_splits;
+mod logit;
+
+pub use self::{route::*, travel_times::*, sliced_travel_times::*, route_splits::*, logit::*};
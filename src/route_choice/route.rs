@@ -0,0 +1,130 @@
+use crate::agents_types::AgentType;
+use crate::grid::{cell::CellID, road_network::GridRoads};
+use crate::route_choice::LinkTravelTimes;
+use crate::shortest_path::{k_shortest::k_shortest_paths, path::Path, router::AStarError};
+use std::fmt;
+
+/// Route identifier type. It is an index of the route among the alternatives of the trip.
+pub type RouteID = usize;
+
+/// Error types for routes calculation.
+#[derive(Debug, Clone)]
+pub enum RouteError {
+    /// Source cell not found in the grid.
+    NoSourceCell(CellID),
+    /// Target cell not found in the grid.
+    NoTargetCell(CellID),
+    /// No path found between source and target.
+    NoPathFound(AStarError),
+}
+
+impl fmt::Display for RouteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoSourceCell(cell_id) => write!(f, "Source cell {} not found", cell_id),
+            Self::NoTargetCell(cell_id) => write!(f, "Target cell {} not found", cell_id),
+            Self::NoPathFound(e) => write!(f, "No path found: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for RouteError {}
+
+/// Represents one of the alternative routes between trip's origin and destination.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Route {
+    /// Index of the route among the alternatives
+    pub id: RouteID,
+    /// Cells of the route from origin to destination (both included)
+    pub cells: Vec<CellID>,
+    /// Geometric length of the route
+    pub length: f64,
+}
+
+impl Route {
+    /// Creates a new route from the found path.
+    ///
+    /// # Arguments
+    /// * `id` - Index of the route among the alternatives
+    /// * `path` - Found path
+    ///
+    /// # Returns
+    /// A new `Route`
+    pub fn from_path(id: RouteID, path: &Path) -> Self {
+        Route {
+            id,
+            cells: path.vertices().iter().map(|cell| cell.get_id()).collect(),
+            length: path.cost(),
+        }
+    }
+
+    /// Estimates travel time (in time units) along the route using current links travel times.
+    ///
+    /// Origin cell is not counted since vehicle is spawned there.
+    ///
+    /// # Arguments
+    /// * `net` - The road network grid
+    /// * `travel_times` - Current links travel times
+    ///
+    /// # Returns
+    /// Estimated travel time. Cells which do not exist in the grid are skipped.
+    pub fn travel_time(&self, net: &GridRoads, travel_times: &LinkTravelTimes) -> f64 {
+        self.cells
+            .iter()
+            .skip(1)
+            .filter_map(|cell_id| net.get_cell(cell_id))
+            .map(|cell| travel_times.cell_travel_time(cell))
+            .sum()
+    }
+}
+
+/// Finds up to `k` alternative routes between two cells.
+///
+/// Routes are K shortest loopless paths (see [`k_shortest_paths`]) with lane changes allowed.
+///
+/// # Arguments
+/// * `from` - Origin cell
+/// * `to` - Destination cell
+/// * `net` - The road network grid
+/// * `k` - Maximum number of routes
+/// * `agent_type` - Type of agents following the routes: cells they are not permitted to enter are avoided
+///
+/// # Returns
+/// * `Ok(Vec<Route>)` - Routes in ascending order of length
+/// * `Err(RouteError)` - If cells are missing or there is no path at all
+///
+/// # Example
+/// ```rust
+/// use micro_traffic_sim_core::route_choice::find_routes;
+/// use micro_traffic_sim_core::agents_types::AgentType;
+/// use micro_traffic_sim_core::grid::{road_network::GridRoads, cell::Cell};
+/// use micro_traffic_sim_core::geom::new_point;
+///
+/// let mut grid = GridRoads::new();
+/// grid.add_cell(Cell::new(1).with_point(new_point(0.0, 0.0, None)).with_forward_node(2).with_left_node(3).build());
+/// grid.add_cell(Cell::new(2).with_point(new_point(1.0, 0.0, None)).with_forward_node(4).build());
+/// grid.add_cell(Cell::new(3).with_point(new_point(1.0, 2.0, None)).with_forward_node(4).build());
+/// grid.add_cell(Cell::new(4).with_point(new_point(2.0, 0.0, None)).build());
+///
+/// let routes = find_routes(1, 4, &grid, 3, AgentType::Car).unwrap();
+/// assert_eq!(routes.len(), 2);
+/// assert_eq!(routes[0].cells, vec![1, 2, 4]);
+/// assert_eq!(routes[1].id, 1);
+/// ```
+pub fn find_routes(
+    from: CellID,
+    to: CellID,
+    net: &GridRoads,
+    k: usize,
+    agent_type: AgentType,
+) -> Result<Vec<Route>, RouteError> {
+    let source_cell = net.get_cell(&from).ok_or(RouteError::NoSourceCell(from))?;
+    let target_cell = net.get_cell(&to).ok_or(RouteError::NoTargetCell(to))?;
+    let paths = k_shortest_paths(source_cell, target_cell, net, k, true, agent_type)
+        .map_err(RouteError::NoPathFound)?;
+    Ok(paths
+        .iter()
+        .enumerate()
+        .map(|(idx, path)| Route::from_path(idx, path))
+        .collect())
+}
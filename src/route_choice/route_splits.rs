@@ -0,0 +1,71 @@
+use crate::route_choice::time_slice;
+use crate::trips::trip::TripID;
+use std::collections::HashMap;
+
+/// Fixed routes shares per trip and departure time slice.
+///
+/// When a session has route splits, vehicles generated by a trip with route choice pick
+/// their route with the given probabilities instead of the logit model on current travel times.
+/// Splits are produced by [`DynamicEquilibrium`](crate::simulation::equilibrium::DynamicEquilibrium)
+/// but could be set manually as well.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RouteSplits {
+    /// Duration (in time units) of a single time slice
+    slice_duration: i32,
+    /// Probabilities of routes (in order of trip's alternatives) per (trip, departure time slice)
+    splits: HashMap<(TripID, usize), Vec<f64>>,
+}
+
+impl RouteSplits {
+    /// Creates empty splits with given duration of time slices (at least 1 time unit)
+    ///
+    /// # Example
+    /// ```
+    /// use micro_traffic_sim_core::route_choice::RouteSplits;
+    /// let mut splits = RouteSplits::new(60);
+    /// splits.set(1, 0, vec![0.7, 0.3]);
+    /// assert_eq!(splits.get_at(1, 59), Some(&vec![0.7, 0.3]));
+    /// assert_eq!(splits.get_at(1, 60), None);
+    /// ```
+    pub fn new(slice_duration: i32) -> Self {
+        RouteSplits {
+            slice_duration: slice_duration.max(1),
+            splits: HashMap::new(),
+        }
+    }
+
+    /// Returns duration (in time units) of a single time slice
+    pub fn get_slice_duration(&self) -> i32 {
+        self.slice_duration
+    }
+
+    /// Returns index of the time slice which contains given time
+    pub fn slice_of(&self, time: i32) -> usize {
+        time_slice(time, self.slice_duration)
+    }
+
+    /// Sets routes probabilities for the trip and the departure time slice
+    pub fn set(&mut self, trip_id: TripID, slice: usize, probabilities: Vec<f64>) {
+        self.splits.insert((trip_id, slice), probabilities);
+    }
+
+    /// Returns routes probabilities for the trip and the departure time slice
+    pub fn get(&self, trip_id: TripID, slice: usize) -> Option<&Vec<f64>> {
+        self.splits.get(&(trip_id, slice))
+    }
+
+    /// Returns routes probabilities for the trip and the departure time
+    pub fn get_at(&self, trip_id: TripID, time: i32) -> Option<&Vec<f64>> {
+        self.get(trip_id, self.slice_of(time))
+    }
+
+    /// Returns all splits keyed by (trip, departure time slice)
+    pub fn get_splits(&self) -> &HashMap<(TripID, usize), Vec<f64>> {
+        &self.splits
+    }
+
+    /// Checks whether there are no splits at all
+    pub fn is_empty(&self) -> bool {
+        self.splits.is_empty()
+    }
+}
@@ -0,0 +1,191 @@
+use crate::agents::{Vehicle, VehicleID};
+use crate::grid::{cell::Cell, road_network::GridRoads};
+use crate::route_choice::{LinkObservation, Route, free_flow_travel_time, travel_times::step_observations};
+use indexmap::IndexMap;
+use std::collections::HashMap;
+
+/// Returns index of the time slice which contains given time.
+/// Negative time belongs to the first slice. Slice duration less than 1 is treated as 1.
+///
+/// # Example
+/// ```
+/// use micro_traffic_sim_core::route_choice::time_slice;
+/// assert_eq!(time_slice(0, 60), 0);
+/// assert_eq!(time_slice(59, 60), 0);
+/// assert_eq!(time_slice(60, 60), 1);
+/// ```
+pub fn time_slice(time: i32, slice_duration: i32) -> usize {
+    (time.max(0) / slice_duration.max(1)) as usize
+}
+
+/// Experienced travel times of mesoscopic links split by time slices.
+///
+/// Unlike [`LinkTravelTimes`](crate::route_choice::LinkTravelTimes) observations are never faded:
+/// every simulation step each vehicle adds one time unit and the number of cells it moved
+/// to the pair (link of its head cell, current time slice). It is a record of the whole run
+/// which is used to estimate time-dependent routes travel times (see
+/// [`DynamicEquilibrium`](crate::simulation::equilibrium::DynamicEquilibrium)).
+#[derive(Debug, Clone, PartialEq)]
+pub struct SlicedTravelTimes {
+    /// Duration (in time units) of a single time slice
+    slice_duration: i32,
+    /// Accumulated observations per (link, time slice)
+    observations: HashMap<(i64, usize), LinkObservation>,
+}
+
+impl SlicedTravelTimes {
+    /// Creates empty record with given duration of time slices (at least 1 time unit)
+    ///
+    /// # Example
+    /// ```
+    /// use micro_traffic_sim_core::route_choice::SlicedTravelTimes;
+    /// let record = SlicedTravelTimes::new(60);
+    /// assert_eq!(record.get_slice_duration(), 60);
+    /// assert_eq!(record.get(1, 0), None);
+    /// ```
+    pub fn new(slice_duration: i32) -> Self {
+        SlicedTravelTimes {
+            slice_duration: slice_duration.max(1),
+            observations: HashMap::new(),
+        }
+    }
+
+    /// Returns duration (in time units) of a single time slice
+    pub fn get_slice_duration(&self) -> i32 {
+        self.slice_duration
+    }
+
+    /// Returns index of the time slice which contains given time
+    pub fn slice_of(&self, time: i32) -> usize {
+        time_slice(time, self.slice_duration)
+    }
+
+    /// Clears all observations
+    pub fn clear(&mut self) {
+        self.observations.clear();
+    }
+
+    /// Returns all accumulated observations keyed by (link, time slice)
+    pub fn get_observations(&self) -> &HashMap<(i64, usize), LinkObservation> {
+        &self.observations
+    }
+
+    /// Returns accumulated observation for the link in the time slice
+    pub fn get_observation(&self, link_id: i64, slice: usize) -> Option<&LinkObservation> {
+        self.observations.get(&(link_id, slice))
+    }
+
+    /// Returns experienced travel time (in time units) per cell for the link in the time slice
+    /// or `None` if there are no observations
+    pub fn get(&self, link_id: i64, slice: usize) -> Option<f64> {
+        self.observations
+            .get(&(link_id, slice))
+            .and_then(|obs| obs.time_per_cell())
+    }
+
+    /// Returns travel time (in time units) needed to pass the cell in the time slice.
+    ///
+    /// Uses experienced travel time of the cell's link if known, otherwise free-flow time.
+    pub fn cell_travel_time(&self, cell: &Cell, slice: usize) -> f64 {
+        self.get(cell.get_meso_link_id(), slice)
+            .unwrap_or_else(|| free_flow_travel_time(cell))
+    }
+
+    /// Estimates travel time (in time units) along the route for the given departure time.
+    ///
+    /// Route is traversed cell by cell: each cell takes the travel time of the time slice
+    /// in which the vehicle enters it. Origin cell is not counted since vehicle is spawned there.
+    ///
+    /// # Arguments
+    /// * `route` - The route
+    /// * `net` - The road network grid
+    /// * `departure` - Departure time
+    ///
+    /// # Returns
+    /// Estimated travel time. Cells which do not exist in the grid are skipped.
+    ///
+    /// # Example
+    /// ```
+    /// use micro_traffic_sim_core::route_choice::{Route, SlicedTravelTimes};
+    /// use micro_traffic_sim_core::grid::{road_network::GridRoads, cell::Cell};
+    ///
+    /// let mut grid = GridRoads::new();
+    /// for id in 1..=3 {
+    ///     grid.add_cell(Cell::new(id).with_speed_limit(2).with_forward_node(id + 1).build());
+    /// }
+    /// let route = Route { id: 0, cells: vec![1, 2, 3], length: 2.0 };
+    /// let record = SlicedTravelTimes::new(10);
+    /// // No observations: free-flow travel time
+    /// assert_eq!(record.route_travel_time(&route, &grid, 0), 1.0);
+    /// ```
+    pub fn route_travel_time(&self, route: &Route, net: &GridRoads, departure: i32) -> f64 {
+        let mut time = departure.max(0) as f64;
+        for cell in route.cells.iter().skip(1).filter_map(|cell_id| net.get_cell(cell_id)) {
+            time += self.cell_travel_time(cell, self.slice_of(time.floor() as i32));
+        }
+        time - departure.max(0) as f64
+    }
+
+    /// Adds observations of the step at given time.
+    ///
+    /// Must be called after conflicts are solved and before movement (the same as
+    /// [`LinkTravelTimes::observe`](crate::route_choice::LinkTravelTimes::observe)).
+    ///
+    /// # Arguments
+    /// * `net` - The road network grid
+    /// * `vehicles` - Vehicles storage
+    /// * `time` - Current simulation time
+    pub fn observe(&mut self, net: &GridRoads, vehicles: &IndexMap<VehicleID, Vehicle>, time: i32) {
+        let slice = self.slice_of(time);
+        for (link_id, distance) in step_observations(net, vehicles) {
+            let observation = self.observations.entry((link_id, slice)).or_default();
+            observation.time += 1.0;
+            observation.distance += distance as f64;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::agents::VehicleIntention;
+    use crate::route_choice::MIN_ESTIMATION_SPEED;
+    use crate::utils::generators::generate_one_lane_cells;
+
+    #[test]
+    fn test_sliced_observe() {
+        let mut net = GridRoads::new();
+        for cell in generate_one_lane_cells(100.0, 10.0, 1) {
+            net.add_cell(cell);
+        }
+        let link_id = net.get_cell(&1).unwrap().get_meso_link_id();
+        let mut vehicles: IndexMap<VehicleID, Vehicle> = IndexMap::new();
+        let mut stopped = Vehicle::new(1).with_cell(2).build();
+        stopped.set_intention(VehicleIntention {
+            intention_cell_id: 2,
+            ..Default::default()
+        });
+        vehicles.insert(1, stopped);
+
+        let mut record = SlicedTravelTimes::new(10);
+        // Stopped vehicle in the first slice
+        record.observe(&net, &vehicles, 0);
+        record.observe(&net, &vehicles, 9);
+        // Moving vehicle in the second slice: 4 cells per step
+        vehicles[0].set_intention(VehicleIntention {
+            intention_cell_id: 6,
+            intermediate_cells: vec![3, 4, 5],
+            ..Default::default()
+        });
+        record.observe(&net, &vehicles, 10);
+
+        assert_eq!(record.get_observation(link_id, 0).unwrap().time, 2.0);
+        assert_eq!(record.get(link_id, 0), Some(1.0 / MIN_ESTIMATION_SPEED));
+        assert_eq!(record.get(link_id, 1), Some(0.25));
+        assert_eq!(record.get(link_id, 2), None);
+
+        // Route departing in the first slice: the first cell is passed slowly, so the next ones are entered in the second slice
+        let route = Route { id: 0, cells: vec![1, 2, 3, 4], length: 3.0 };
+        assert_eq!(record.route_travel_time(&route, &net, 0), 1.0 / MIN_ESTIMATION_SPEED + 0.25 + 0.25);
+    }
+}
@@ -0,0 +1,215 @@
+use crate::agents::{Vehicle, VehicleID};
+use crate::grid::{cell::Cell, road_network::GridRoads};
+use indexmap::IndexMap;
+use std::collections::HashMap;
+
+/// Minimal speed (cells per time unit) used for travel time estimation.
+/// It bounds travel time per cell for fully stopped traffic (and for cells with zero speed limit).
+pub const MIN_ESTIMATION_SPEED: f64 = 0.1;
+
+/// Accumulated observations for a single mesoscopic link.
+///
+/// Space-mean speed of the link is `distance / time` (Edie's definition),
+/// so travel time per cell is `time / distance`.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct LinkObservation {
+    /// Total time (in time units) spent by vehicles on the link
+    pub time: f64,
+    /// Total distance (in cells) travelled by vehicles on the link
+    pub distance: f64,
+}
+
+impl LinkObservation {
+    /// Returns travel time (in time units) per cell or `None` if there are no observations.
+    ///
+    /// # Example
+    /// ```
+    /// use micro_traffic_sim_core::route_choice::LinkObservation;
+    /// let observation = LinkObservation { time: 4.0, distance: 8.0 };
+    /// assert_eq!(observation.time_per_cell(), Some(0.5));
+    /// // Stopped traffic is bounded by minimal estimation speed
+    /// let observation = LinkObservation { time: 4.0, distance: 0.0 };
+    /// assert_eq!(observation.time_per_cell(), Some(10.0));
+    /// ```
+    pub fn time_per_cell(&self) -> Option<f64> {
+        if self.time <= f64::EPSILON {
+            return None;
+        }
+        Some(self.time / self.distance.max(self.time * MIN_ESTIMATION_SPEED))
+    }
+}
+
+/// Current travel times of mesoscopic links (see `meso_link_id` of [`Cell`]).
+///
+/// Every simulation step each vehicle adds one time unit and the number of cells it moved
+/// to the link of its head cell. Old observations fade out with the `decay` factor, so estimates
+/// reflect current traffic conditions. Links without observations fall back to free-flow travel time.
+#[derive(Debug, Clone)]
+pub struct LinkTravelTimes {
+    /// Factor in [0; 1] applied to accumulated observations each step. 0 - only last step matters, 1 - never forget
+    decay: f64,
+    /// Accumulated observations per link
+    observations: HashMap<i64, LinkObservation>,
+}
+
+impl Default for LinkTravelTimes {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LinkTravelTimes {
+    /// Creates empty storage with default decay factor (0.9)
+    ///
+    /// # Example
+    /// ```
+    /// use micro_traffic_sim_core::route_choice::LinkTravelTimes;
+    /// let travel_times = LinkTravelTimes::new();
+    /// assert_eq!(travel_times.get(1), None);
+    /// ```
+    pub fn new() -> Self {
+        LinkTravelTimes {
+            decay: 0.9,
+            observations: HashMap::new(),
+        }
+    }
+
+    /// Sets decay factor for accumulated observations. Value is clamped to [0; 1].
+    pub fn set_decay(&mut self, decay: f64) {
+        self.decay = decay.clamp(0.0, 1.0);
+    }
+
+    /// Returns decay factor for accumulated observations
+    pub fn get_decay(&self) -> f64 {
+        self.decay
+    }
+
+    /// Clears all observations
+    pub fn clear(&mut self) {
+        self.observations.clear();
+    }
+
+    /// Returns accumulated observation for the link
+    pub fn get_observation(&self, link_id: i64) -> Option<&LinkObservation> {
+        self.observations.get(&link_id)
+    }
+
+    /// Returns current travel time (in time units) per cell for the link or `None` if there are no observations
+    pub fn get(&self, link_id: i64) -> Option<f64> {
+        self.observations.get(&link_id).and_then(|obs| obs.time_per_cell())
+    }
+
+    /// Returns travel time (in time units) needed to pass the cell.
+    ///
+    /// Uses current travel time of the cell's link if known, otherwise free-flow time `1 / speed_limit`.
+    ///
+    /// # Example
+    /// ```
+    /// use micro_traffic_sim_core::route_choice::LinkTravelTimes;
+    /// use micro_traffic_sim_core::grid::cell::Cell;
+    /// let travel_times = LinkTravelTimes::new();
+    /// let cell = Cell::new(1).with_speed_limit(4).with_meso_link(10).build();
+    /// assert_eq!(travel_times.cell_travel_time(&cell), 0.25);
+    /// ```
+    pub fn cell_travel_time(&self, cell: &Cell) -> f64 {
+        // Links with negative identifiers are never observed, so they always fall back to free-flow
+        self.get(cell.get_meso_link_id())
+            .unwrap_or_else(|| free_flow_travel_time(cell))
+    }
+
+    /// Adds observations of the current step.
+    ///
+    /// Must be called after conflicts are solved and before movement: vehicles' intentions
+    /// hold the final number of cells they are going to move.
+    ///
+    /// # Arguments
+    /// * `net` - The road network grid
+    /// * `vehicles` - Vehicles storage
+    pub fn observe(&mut self, net: &GridRoads, vehicles: &IndexMap<VehicleID, Vehicle>) {
+        for observation in self.observations.values_mut() {
+            observation.time *= self.decay;
+            observation.distance *= self.decay;
+        }
+        for (link_id, distance) in step_observations(net, vehicles) {
+            let observation = self.observations.entry(link_id).or_default();
+            observation.time += 1.0;
+            observation.distance += distance as f64;
+        }
+    }
+}
+
+/// Returns free-flow travel time (in time units) needed to pass the cell: `1 / speed_limit`
+pub fn free_flow_travel_time(cell: &Cell) -> f64 {
+    1.0 / (cell.get_speed_limit() as f64).max(MIN_ESTIMATION_SPEED)
+}
+
+/// Yields link of the head cell and number of cells to move in the current step for each vehicle.
+/// Vehicles on cells without link (negative `meso_link_id`) are skipped.
+pub(crate) fn step_observations<'a>(
+    net: &'a GridRoads,
+    vehicles: &'a IndexMap<VehicleID, Vehicle>,
+) -> impl Iterator<Item = (i64, usize)> + 'a {
+    vehicles.values().filter_map(move |vehicle| {
+        let link_id = net.get_cell(&vehicle.cell_id)?.get_meso_link_id();
+        if link_id < 0 {
+            return None;
+        }
+        Some((link_id, moved_cells(vehicle)))
+    })
+}
+
+/// Returns number of cells the vehicle is going to move in the current step (according to its intention)
+pub fn moved_cells(vehicle: &Vehicle) -> usize {
+    if vehicle.get_relax_countdown() > 0 || vehicle.intention.intention_cell_id == vehicle.cell_id {
+        return 0;
+    }
+    vehicle.intention.intermediate_cells.len() + 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::agents::VehicleIntention;
+    use crate::utils::generators::generate_one_lane_cells;
+
+    #[test]
+    fn test_observe() {
+        let mut net = GridRoads::new();
+        for cell in generate_one_lane_cells(100.0, 10.0, 1) {
+            net.add_cell(cell);
+        }
+        let link_id = net.get_cell(&1).unwrap().get_meso_link_id();
+        let mut vehicles: IndexMap<VehicleID, Vehicle> = IndexMap::new();
+        // Moving vehicle: 2 cells per step
+        let mut moving = Vehicle::new(1).with_cell(2).build();
+        moving.set_intention(VehicleIntention {
+            intention_cell_id: 4,
+            intermediate_cells: vec![3],
+            ..Default::default()
+        });
+        vehicles.insert(1, moving);
+        // Stopped vehicle
+        let mut stopped = Vehicle::new(2).with_cell(6).build();
+        stopped.set_intention(VehicleIntention {
+            intention_cell_id: 6,
+            ..Default::default()
+        });
+        vehicles.insert(2, stopped);
+
+        let mut travel_times = LinkTravelTimes::new();
+        travel_times.set_decay(0.5);
+        travel_times.observe(&net, &vehicles);
+        // 2 time units, 2 cells
+        assert_eq!(travel_times.get(link_id), Some(1.0));
+        travel_times.observe(&net, &vehicles);
+        // (2 * 0.5 + 2) time units, (2 * 0.5 + 2) cells
+        assert_eq!(travel_times.get_observation(link_id).unwrap().time, 3.0);
+        assert_eq!(travel_times.get(link_id), Some(1.0));
+
+        // Unknown link falls back to free-flow
+        assert_eq!(travel_times.get(link_id + 100), None);
+        let cell = net.get_cell(&1).unwrap();
+        travel_times.clear();
+        assert_eq!(travel_times.cell_travel_time(cell), 1.0 / cell.get_speed_limit() as f64);
+    }
+}
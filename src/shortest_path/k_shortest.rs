@@ -0,0 +1,196 @@
+use crate::agents_types::AgentType;
+use crate::grid::{
+    cell::{Cell, CellID},
+    road_network::GridRoads,
+};
+use crate::shortest_path::{
+    heuristics::heuristic,
+    path::Path,
+    router::{shortest_path, shortest_path_restricted, AStarError},
+};
+use std::collections::HashSet;
+
+/// Finds up to `k` shortest loopless paths between two cells using Yen's algorithm.
+///
+/// Paths are returned in ascending order of cost. The first path is always the one
+/// found by [`shortest_path`]. Each next path is built from a "root" (common prefix with one of
+/// already found paths) and a "spur" path which avoids connections used by the found paths.
+///
+/// # Arguments
+///
+/// * `start` - The starting cell
+/// * `goal` - The target cell
+/// * `net` - The road network containing all cells and connections
+/// * `k` - Maximum number of paths to find
+/// * `maneuver_allowed` - Whether to consider lane change maneuvers (left/right connections)
+/// * `agent_type` - Type of the routed agent: cells it is not permitted to enter are avoided
+///
+/// # Returns
+///
+/// * `Ok(Vec<Path>)` - From 1 up to `k` paths (less when network has no more loopless paths)
+/// * `Err(AStarError)` - If there is no path at all or network has bad data
+///
+/// # Notice
+///
+/// On multi-lane roads many of found paths could differ by the place of lane change only.
+/// Consider to use path-size logit (see [`route_choice`](crate::route_choice)) to handle overlapping paths.
+///
+/// # Example
+///
+/// ```rust
+/// use micro_traffic_sim_core::shortest_path::k_shortest::k_shortest_paths;
+/// use micro_traffic_sim_core::agents_types::AgentType;
+/// use micro_traffic_sim_core::grid::{road_network::GridRoads, cell::Cell};
+/// use micro_traffic_sim_core::geom::new_point;
+///
+/// // Two parallel branches between 1 and 4: 1 -> 2 -> 4 and 1 -> 3 -> 4
+/// let mut grid = GridRoads::new();
+/// grid.add_cell(Cell::new(1).with_point(new_point(0.0, 0.0, None)).with_forward_node(2).with_left_node(3).build());
+/// grid.add_cell(Cell::new(2).with_point(new_point(1.0, 0.0, None)).with_forward_node(4).build());
+/// grid.add_cell(Cell::new(3).with_point(new_point(1.0, 2.0, None)).with_forward_node(4).build());
+/// grid.add_cell(Cell::new(4).with_point(new_point(2.0, 0.0, None)).build());
+///
+/// let start = grid.get_cell(&1).unwrap();
+/// let goal = grid.get_cell(&4).unwrap();
+/// let paths = k_shortest_paths(start, goal, &grid, 3, true, AgentType::Car).unwrap();
+/// assert_eq!(paths.len(), 2);
+/// assert!(paths[0].cost() <= paths[1].cost());
+/// ```
+pub fn k_shortest_paths<'a>(
+    start: &'a Cell,
+    goal: &'a Cell,
+    net: &'a GridRoads,
+    k: usize,
+    maneuver_allowed: bool,
+    agent_type: AgentType,
+) -> Result<Vec<Path<'a>>, AStarError> {
+    let mut found: Vec<Path<'a>> = Vec::with_capacity(k);
+    if k == 0 {
+        return Ok(found);
+    }
+    found.push(shortest_path(start, goal, net, maneuver_allowed, None, agent_type)?);
+
+    let mut candidates: Vec<Path<'a>> = Vec::new();
+    while found.len() < k {
+        let last_vertices: Vec<&'a Cell> = found[found.len() - 1].vertices().clone();
+        let last_ids: Vec<CellID> = last_vertices.iter().map(|cell| cell.get_id()).collect();
+        for i in 0..last_vertices.len().saturating_sub(1) {
+            let spur_cell = last_vertices[i];
+            let root_ids = &last_ids[..=i];
+
+            // Prohibit connections which are used by already found paths with the same root
+            let mut banned_edges: HashSet<(CellID, CellID)> = HashSet::new();
+            for path in found.iter() {
+                let ids = path_ids(path);
+                if ids.len() > i + 1 && ids[..=i] == *root_ids {
+                    banned_edges.insert((ids[i], ids[i + 1]));
+                }
+            }
+            // Prohibit root cells (except spur one) to keep path loopless
+            let banned_cells: HashSet<CellID> = root_ids[..i].iter().copied().collect();
+
+            let spur_path = match shortest_path_restricted(
+                spur_cell,
+                goal,
+                net,
+                maneuver_allowed,
+                &banned_cells,
+                &banned_edges,
+                agent_type,
+            ) {
+                Ok(path) => path,
+                Err(AStarError::NoPathFound { .. }) => continue,
+                Err(e) => return Err(e),
+            };
+
+            let mut vertices: Vec<&'a Cell> = last_vertices[..i].to_vec();
+            let mut maneuvers = found[found.len() - 1].maneuvers()[..i].to_vec();
+            vertices.extend(spur_path.vertices().iter());
+            maneuvers.extend(spur_path.maneuvers().iter());
+            let cost = path_cost(&vertices);
+            let candidate = Path::new(vertices, maneuvers, cost);
+
+            let candidate_ids = path_ids(&candidate);
+            let is_known = found
+                .iter()
+                .chain(candidates.iter())
+                .any(|path| path_ids(path) == candidate_ids);
+            if !is_known {
+                candidates.push(candidate);
+            }
+        }
+        if candidates.is_empty() {
+            break;
+        }
+        // Pick the cheapest candidate. Ties are resolved by the order of appearance
+        let mut best_idx = 0;
+        for (idx, candidate) in candidates.iter().enumerate() {
+            if candidate.cost() < candidates[best_idx].cost() {
+                best_idx = idx;
+            }
+        }
+        found.push(candidates.remove(best_idx));
+    }
+    Ok(found)
+}
+
+/// Extracts cells identifiers from the path
+fn path_ids(path: &Path) -> Vec<CellID> {
+    path.vertices().iter().map(|cell| cell.get_id()).collect()
+}
+
+/// Calculates cost of the path the same way as A* does: sum of heuristic costs between consecutive cells
+fn path_cost(vertices: &[&Cell]) -> f64 {
+    vertices
+        .windows(2)
+        .map(|pair| heuristic(pair[0], pair[1]))
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geom::new_point;
+
+    #[test]
+    fn test_k_shortest_paths() {
+        // Ladder-like network:
+        // (4) -> (5) -> (6)
+        //  ^      ^  \
+        // (1) -> (2) -> (3)
+        let mut net = GridRoads::new();
+        net.add_cell(Cell::new(1).with_point(new_point(0.0, 0.0, None)).with_forward_node(2).with_left_node(4).build());
+        net.add_cell(Cell::new(2).with_point(new_point(1.0, 0.0, None)).with_forward_node(3).with_left_node(5).build());
+        net.add_cell(Cell::new(3).with_point(new_point(2.0, 0.0, None)).build());
+        net.add_cell(Cell::new(4).with_point(new_point(0.0, 1.0, None)).with_forward_node(5).build());
+        net.add_cell(Cell::new(5).with_point(new_point(1.0, 1.0, None)).with_forward_node(6).with_right_node(3).build());
+        net.add_cell(Cell::new(6).with_point(new_point(2.0, 1.0, None)).build());
+
+        let start = net.get_cell(&1).unwrap();
+        let goal = net.get_cell(&3).unwrap();
+        let paths = k_shortest_paths(start, goal, &net, 5, true, AgentType::Car).unwrap();
+        let ids: Vec<Vec<CellID>> = paths.iter().map(path_ids).collect();
+        assert_eq!(ids.len(), 3, "Found paths: {:?}", ids);
+        assert_eq!(ids[0], vec![1, 2, 3]);
+        // All paths are unique and loopless
+        for (i, path) in ids.iter().enumerate() {
+            let unique: HashSet<&CellID> = path.iter().collect();
+            assert_eq!(unique.len(), path.len(), "Path #{} has loop: {:?}", i, path);
+            for other in ids.iter().skip(i + 1) {
+                assert_ne!(path, other);
+            }
+        }
+        // Costs are not decreasing
+        for pair in paths.windows(2) {
+            assert!(pair[0].cost() <= pair[1].cost());
+        }
+        // Maneuvers are consistent with vertices
+        for path in paths.iter() {
+            assert_eq!(path.maneuvers().len(), path.vertices().len() - 1);
+        }
+
+        // Without maneuvers there is the only path
+        let paths = k_shortest_paths(start, goal, &net, 5, false, AgentType::Car).unwrap();
+        assert_eq!(paths.len(), 1);
+    }
+}
@@ -13,6 +13,8 @@
 //! 
 //! ### Core Functions
 //! - [`router::shortest_path`] - A* algorithm implementation with lane change support
+//! - [`router::shortest_path_restricted`] - Same as above, but avoiding given cells and connections
+//! - [`k_shortest::k_shortest_paths`] - Yen's algorithm for K shortest loopless paths (alternative routes)
 //! - [`heuristics::heuristic`] - Distance-based heuristic function
 //! 
 //! ### Data Structures
@@ -42,6 +44,7 @@
 //!
 //! ```rust
 //! use micro_traffic_sim_core::shortest_path::router::shortest_path;
+//! use micro_traffic_sim_core::agents_types::AgentType;
 //! use micro_traffic_sim_core::grid::{road_network::GridRoads, cell::Cell};
 //! use micro_traffic_sim_core::geom::new_point;
 //! 
@@ -59,7 +62,7 @@
 //! grid.add_cell(end_cell.clone());
 //! 
 //! // Find shortest path
-//! let path_result = shortest_path(&start_cell, &end_cell, &grid, false, None);
+//! let path_result = shortest_path(&start_cell, &end_cell, &grid, false, None, AgentType::Car);
 //! 
 //! match path_result {
 //!     Ok(path) => {
@@ -74,6 +77,7 @@
 //!
 //! ```rust
 //! use micro_traffic_sim_core::shortest_path::router::shortest_path;
+//! use micro_traffic_sim_core::agents_types::AgentType;
 //! use micro_traffic_sim_core::grid::road_network::GridRoads;
 //! 
 //! # let grid = GridRoads::new();
@@ -85,7 +89,8 @@
 //!     &end_cell, 
 //!     &grid, 
 //!     true,  // Allow maneuvers (lane changes)
-//!     None   // No depth limit
+//!     None,  // No depth limit
+//!     AgentType::Car, // Cells not permitted for cars (e.g. bus lanes) are avoided
 //! );
 //! ```
 //!
@@ -93,6 +98,7 @@
 //!
 //! ```rust
 //! use micro_traffic_sim_core::shortest_path::router::shortest_path;
+//! use micro_traffic_sim_core::agents_types::AgentType;
 //! 
 //! # let grid = micro_traffic_sim_core::grid::road_network::GridRoads::new();
 //! # let start_cell = micro_traffic_sim_core::grid::cell::Cell::new(1).build();
@@ -103,7 +109,8 @@
 //!     &end_cell, 
 //!     &grid, 
 //!     true, 
-//!     Some(1000)  // Maximum 1000 vertices explored
+//!     Some(1000), // Maximum 1000 vertices explored
+//!     AgentType::Car,
 //! );
 //! ```
 //!
@@ -113,5 +120,6 @@
 //! - **Geometry**: Uses distance calculations from the [`geom`](crate::geom) module
 //! - **Maneuvers**: Returns [`LaneChangeType`](crate::maneuver::LaneChangeType) for each path segment
 pub mod heuristics;
+pub mod k_shortest;
 pub mod path;
 pub mod router;
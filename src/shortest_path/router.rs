@@ -1,3 +1,4 @@
+use crate::agents_types::AgentType;
 use crate::maneuver::LaneChangeType;
 use crate::grid::{
     cell::{Cell, CellID},
@@ -5,7 +6,7 @@ use crate::grid::{
 };
 use crate::shortest_path::{heuristics::heuristic, path::Path};
 use indexmap::IndexMap;
-use std::{cell::RefCell, cmp::Ordering, collections::BinaryHeap, fmt, rc::Rc};
+use std::{cell::RefCell, cmp::Ordering, collections::{BinaryHeap, HashSet}, fmt, rc::Rc};
 
 /// Error types for A* pathfinding operations.
 ///
@@ -144,6 +145,7 @@ impl<'a> Ord for AStarNode<'a> {
 /// * `net` - The road network containing all nodes and connections
 /// * `maneuver_allowed` - Whether to consider lane change maneuvers (left/right connections)
 /// * `max_depth_opt` - Optional limit on number of vertices to explore (None = unlimited)
+/// * `agent_type` - Type of the routed agent: cells it is not permitted to enter (see [`Cell::is_allowed_for`]) are avoided
 ///
 /// # Returns
 ///
@@ -171,6 +173,7 @@ impl<'a> Ord for AStarNode<'a> {
 ///
 /// ```rust
 /// use micro_traffic_sim_core::shortest_path::router::shortest_path;
+/// use micro_traffic_sim_core::agents_types::AgentType;
 /// use micro_traffic_sim_core::grid::{road_network::GridRoads, cell::Cell};
 /// use micro_traffic_sim_core::geom::new_point;
 /// 
@@ -187,7 +190,7 @@ impl<'a> Ord for AStarNode<'a> {
 /// grid.add_cell(cell2.clone());
 /// 
 /// // Find path without lane changes
-/// let result = shortest_path(&cell1, &cell2, &grid, false, None);
+/// let result = shortest_path(&cell1, &cell2, &grid, false, None, AgentType::Car);
 /// assert!(result.is_ok());
 /// ```
 ///
@@ -195,6 +198,7 @@ impl<'a> Ord for AStarNode<'a> {
 ///
 /// ```rust
 /// use micro_traffic_sim_core::shortest_path::router::shortest_path;
+/// use micro_traffic_sim_core::agents_types::AgentType;
 /// use micro_traffic_sim_core::grid::{road_network::GridRoads, cell::Cell};
 /// use micro_traffic_sim_core::geom::new_point;
 /// 
@@ -222,7 +226,7 @@ impl<'a> Ord for AStarNode<'a> {
 /// grid.add_cell(cell4.clone());
 /// 
 /// // Enable lane changes for optimal routing
-/// let result = shortest_path(&cell1, &cell4, &grid, true, None);
+/// let result = shortest_path(&cell1, &cell4, &grid, true, None, AgentType::Car);
 /// 
 /// match result {
 ///     Ok(path) => {
@@ -241,6 +245,7 @@ impl<'a> Ord for AStarNode<'a> {
 ///
 /// ```rust
 /// use micro_traffic_sim_core::shortest_path::router::shortest_path;
+/// use micro_traffic_sim_core::agents_types::AgentType;
 /// use micro_traffic_sim_core::grid::{road_network::GridRoads, cell::Cell};
 /// use micro_traffic_sim_core::geom::new_point;
 ///
@@ -272,7 +277,7 @@ impl<'a> Ord for AStarNode<'a> {
 /// grid.add_cell(cell5.clone());
 /// 
 /// // Limit search to prevent excessive computation time
-/// let result = shortest_path(&cell1, &cell5, &grid, true, Some(3));
+/// let result = shortest_path(&cell1, &cell5, &grid, true, Some(3), AgentType::Car);
 /// 
 /// match result {
 ///     Ok(path) => {
@@ -288,7 +293,84 @@ pub fn shortest_path<'a>(
     net: &'a GridRoads,
     maneuver_allowed: bool,
     max_depth_opt: Option<i32>,
+    agent_type: AgentType,
 ) -> Result<Path<'a>, AStarError> {
+    astar(start, goal, net, maneuver_allowed, max_depth_opt, None, agent_type)
+}
+
+/// Finds the shortest path between two nodes using the A* algorithm, avoiding given cells and connections.
+///
+/// It is used for finding alternative routes (e.g. spur paths in Yen's algorithm, see
+/// [`k_shortest_paths`](crate::shortest_path::k_shortest::k_shortest_paths)).
+///
+/// # Arguments
+///
+/// * `start` - The starting node for pathfinding
+/// * `goal` - The target node to reach
+/// * `net` - The road network containing all nodes and connections
+/// * `maneuver_allowed` - Whether to consider lane change maneuvers (left/right connections)
+/// * `banned_cells` - Cells which can't be visited
+/// * `banned_edges` - Connections `(from, to)` which can't be used
+/// * `agent_type` - Type of the routed agent: cells it is not permitted to enter are avoided
+///
+/// # Returns
+///
+/// * `Ok(Path)` - Successful pathfinding with complete route information
+/// * `Err(AStarError)` - Pathfinding failure
+///
+/// # Example
+///
+/// ```rust
+/// use std::collections::HashSet;
+/// use micro_traffic_sim_core::shortest_path::router::shortest_path_restricted;
+/// use micro_traffic_sim_core::agents_types::AgentType;
+/// use micro_traffic_sim_core::grid::{road_network::GridRoads, cell::Cell};
+/// use micro_traffic_sim_core::geom::new_point;
+///
+/// let mut grid = GridRoads::new();
+/// grid.add_cell(Cell::new(1).with_point(new_point(0.0, 0.0, None)).with_forward_node(2).with_left_node(3).build());
+/// grid.add_cell(Cell::new(2).with_point(new_point(1.0, 0.0, None)).with_forward_node(4).build());
+/// grid.add_cell(Cell::new(3).with_point(new_point(1.0, 1.0, None)).with_forward_node(4).build());
+/// grid.add_cell(Cell::new(4).with_point(new_point(2.0, 0.0, None)).build());
+///
+/// let start = grid.get_cell(&1).unwrap();
+/// let goal = grid.get_cell(&4).unwrap();
+/// let banned_cells = HashSet::new();
+/// let banned_edges = HashSet::from([(1, 2)]);
+/// let path = shortest_path_restricted(start, goal, &grid, true, &banned_cells, &banned_edges, AgentType::Car).unwrap();
+/// let ids: Vec<i64> = path.vertices().iter().map(|cell| cell.get_id()).collect();
+/// assert_eq!(ids, vec![1, 3, 4]);
+/// ```
+pub fn shortest_path_restricted<'a>(
+    start: &'a Cell,
+    goal: &'a Cell,
+    net: &'a GridRoads,
+    maneuver_allowed: bool,
+    banned_cells: &HashSet<CellID>,
+    banned_edges: &HashSet<(CellID, CellID)>,
+    agent_type: AgentType,
+) -> Result<Path<'a>, AStarError> {
+    astar(start, goal, net, maneuver_allowed, None, Some((banned_cells, banned_edges)), agent_type)
+}
+
+/// Cells which can't be visited and connections `(from, to)` which can't be used (see [`shortest_path_restricted`]).
+type Restrictions<'b> = (&'b HashSet<CellID>, &'b HashSet<(CellID, CellID)>);
+
+/// A* search implementation. See [`shortest_path`] and [`shortest_path_restricted`].
+fn astar<'a>(
+    start: &'a Cell,
+    goal: &'a Cell,
+    net: &'a GridRoads,
+    maneuver_allowed: bool,
+    max_depth_opt: Option<i32>,
+    banned: Option<Restrictions>,
+    agent_type: AgentType,
+) -> Result<Path<'a>, AStarError> {
+    let is_banned = |from_id: CellID, to_id: CellID| -> bool {
+        banned.is_some_and(|(cells, edges)| cells.contains(&to_id) || edges.contains(&(from_id, to_id)))
+            // Agent is not permitted to enter the cell (e.g. bus lane)
+            || net.get_cell(&to_id).is_some_and(|cell| !cell.is_allowed_for(agent_type))
+    };
     let max_depth = max_depth_opt.unwrap_or(0);
     let mut open_set = BinaryHeap::new();
 
@@ -324,7 +406,7 @@ pub fn shortest_path<'a>(
 
         let forward_id = current_cell.get_forward_id();
         // Scan straightforward direction
-        if forward_id > -1 {
+        if forward_id > -1 && !is_banned(current_cell.get_id(), forward_id) {
             if let Some(fcell) = net.get_cell(&forward_id) {
                 process_neighbor(
                     goal,
@@ -347,7 +429,7 @@ pub fn shortest_path<'a>(
 
         // Scan left maneuver
         let left_id = current_cell.get_left_id();
-        if left_id > -1 {
+        if left_id > -1 && !is_banned(current_cell.get_id(), left_id) {
             if let Some(lcell) = net.get_cell(&left_id) {
                 process_neighbor(
                     goal,
@@ -365,7 +447,7 @@ pub fn shortest_path<'a>(
 
         // Scan right maneuver
         let right_id = current_cell.get_right_id();
-        if right_id > -1 {
+        if right_id > -1 && !is_banned(current_cell.get_id(), right_id) {
             if let Some(rcell) = net.get_cell(&right_id) {
                 process_neighbor(
                     goal,
@@ -503,6 +585,7 @@ fn reconstruct_path<'a>(current_node: &Rc<RefCell<AStarNode<'a>>>) -> Path<'a> {
 /// * `net` - The road network containing all cells and connections
 /// * `maneuver_allowed` - Whether to consider lane change maneuvers (left/right connections)
 /// * `depth` - The exact number of steps to take in the path
+/// * `agent_type` - Type of the agent: cells it is not permitted to enter (see [`Cell::is_allowed_for`]) are not used
 ///
 /// # Returns
 ///
@@ -517,6 +600,7 @@ fn reconstruct_path<'a>(current_node: &Rc<RefCell<AStarNode<'a>>>) -> Path<'a> {
 /// # Example
 /// ```rust
 /// use micro_traffic_sim_core::shortest_path::router::path_no_goal;
+/// use micro_traffic_sim_core::agents_types::AgentType;
 /// use micro_traffic_sim_core::grid::{road_network::GridRoads, cell::Cell};
 /// use micro_traffic_sim_core::geom::new_point;
 /// 
@@ -544,7 +628,7 @@ fn reconstruct_path<'a>(current_node: &Rc<RefCell<AStarNode<'a>>>) -> Path<'a> {
 /// grid.add_cell(cell4.clone());
 /// 
 /// // Find path of depth 5 starting from cell #1
-/// let result = path_no_goal(&cell1, &grid, true, 5);
+/// let result = path_no_goal(&cell1, &grid, true, 5, AgentType::Car);
 /// match result {
 ///    Ok(path) => {
 ///       println!("Found path with {} vertices", path.vertices().len());
@@ -560,6 +644,7 @@ pub fn path_no_goal<'a>(
     net: &'a GridRoads,
     maneuver_allowed: bool,
     depth: i32,
+    agent_type: AgentType,
 ) -> Result<Path<'a>, AStarError> {
     let mut vertices = Vec::with_capacity(depth as usize + 1);
     let mut maneuvers = Vec::with_capacity(depth as usize);
@@ -574,6 +659,7 @@ pub fn path_no_goal<'a>(
         let forward_id = current_cell.get_forward_id();
         if forward_id > -1 {
             match net.get_cell(&forward_id) {
+                Some(cell) if !cell.is_allowed_for(agent_type) => {}
                 Some(cell) => {
                     next_cell = Some(cell);
                     next_maneuver = LaneChangeType::NoChange;
@@ -587,6 +673,7 @@ pub fn path_no_goal<'a>(
             let left_id = current_cell.get_left_id();
             if left_id > -1 {
                 match net.get_cell(&left_id) {
+                    Some(cell) if !cell.is_allowed_for(agent_type) => {}
                     Some(cell) => {
                         next_cell = Some(cell);
                         next_maneuver = LaneChangeType::ChangeLeft;
@@ -597,6 +684,7 @@ pub fn path_no_goal<'a>(
             let right_id = current_cell.get_right_id();
             if right_id > -1 && next_cell.is_none() {
                 match net.get_cell(&right_id) {
+                    Some(cell) if !cell.is_allowed_for(agent_type) => {}
                     Some(cell) => {
                         next_cell = Some(cell);
                         next_maneuver = LaneChangeType::ChangeRight;
@@ -735,7 +823,7 @@ mod tests {
         }
         let start_cell = grid.get_cell(&1).unwrap();
         let target_cell = grid.get_cell(&2224).unwrap();
-        let path_result = shortest_path(&start_cell, &target_cell, &grid, true, None);
+        let path_result = shortest_path(&start_cell, &target_cell, &grid, true, None, AgentType::Car);
         assert!(
             path_result.is_ok(),
             "Pathfinding failed: {:?}",
@@ -979,7 +1067,7 @@ mod tests {
         // Run the shortest path algorithm for a specific source and target
         let start_cell = grid.get_cell(&7).unwrap();
         let target_cell = grid.get_cell(&15).unwrap();
-        let path_result = shortest_path(&start_cell, &target_cell, &grid, true, None);
+        let path_result = shortest_path(&start_cell, &target_cell, &grid, true, None, AgentType::Car);
         assert!(
             path_result.is_ok(),
             "Pathfinding failed: {:?}",
@@ -1056,7 +1144,7 @@ mod tests {
         let start_cell = grid.get_cell(&1).unwrap();
         // Traverse 5 steps forward (should wrap around)
         let observe = 5;
-        let path_opt = path_no_goal(start_cell, &grid, false, observe);
+        let path_opt = path_no_goal(start_cell, &grid, false, observe, AgentType::Car);
         assert!(path_opt.is_ok(), "Path should exist");
         let path = path_opt.unwrap();
         let ids: Vec<i64> = path.vertices().iter().map(|c| c.get_id()).collect();
@@ -1069,4 +1157,42 @@ mod tests {
         // Therefore path should be:
         assert_eq!(ids, vec![1, 2, 3, 4, 1, 2], "Incorrect circular path traversal");
     }
+
+    #[test]
+    fn test_shortest_path_bus_lane() {
+        use crate::geom::new_point;
+        use crate::grid::cell::Cell;
+        use crate::grid::road_network::GridRoads;
+        use crate::grid::zones::ZoneType;
+        // Short bus lane 1 → 2 → 4 and long regular road 1 → 3 → 5 → 4
+        let mut grid = GridRoads::new();
+        grid.add_cell(Cell::new(1).with_point(new_point(0.0, 0.0, None)).with_forward_node(2).with_left_node(3).build());
+        grid.add_cell(
+            Cell::new(2)
+                .with_point(new_point(1.0, 0.0, None))
+                .with_zone_type(ZoneType::LaneForBus)
+                .with_forward_node(4)
+                .build(),
+        );
+        grid.add_cell(Cell::new(3).with_point(new_point(1.0, 2.0, None)).with_forward_node(5).build());
+        grid.add_cell(Cell::new(5).with_point(new_point(2.0, 2.0, None)).with_forward_node(4).build());
+        grid.add_cell(Cell::new(4).with_point(new_point(3.0, 0.0, None)).build());
+        let start_cell = grid.get_cell(&1).unwrap();
+        let target_cell = grid.get_cell(&4).unwrap();
+        let ids = |agent_type: AgentType| -> Vec<CellID> {
+            let path = shortest_path(start_cell, target_cell, &grid, true, None, agent_type).unwrap();
+            path.vertices().iter().map(|cell| cell.get_id()).collect()
+        };
+        assert_eq!(ids(AgentType::Bus), vec![1, 2, 4]);
+        assert_eq!(ids(AgentType::Taxi), vec![1, 2, 4]);
+        assert_eq!(ids(AgentType::Car), vec![1, 3, 5, 4]);
+
+        // Without lane changes there is no permitted path for cars
+        let result = shortest_path(start_cell, target_cell, &grid, false, None, AgentType::Car);
+        assert_eq!(result.err(), Some(AStarError::NoPathFound { start_id: 1, end_id: 4 }));
+
+        // Path without goal stops before the bus lane
+        let path = path_no_goal(start_cell, &grid, false, 3, AgentType::Truck).unwrap();
+        assert_eq!(path.vertices().len(), 1);
+    }
 }
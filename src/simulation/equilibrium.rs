@@ -0,0 +1,330 @@
+use crate::route_choice::{RouteID, RouteSplits, SlicedTravelTimes, time_slice};
+use crate::simulation::session::{Session, SessionError};
+use crate::trips::trip::{Trip, TripID};
+use crate::verbose::*;
+use std::collections::HashMap;
+
+/// Summary of a single iteration of [`DynamicEquilibrium`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct EquilibriumIteration {
+    /// Iteration number (starting from 1)
+    pub iteration: usize,
+    /// Relative gap of the iteration: total excess travel time of vehicles over the best routes
+    /// divided by total travel time on the best routes
+    pub relative_gap: f64,
+    /// Number of generated vehicles with assigned route
+    pub vehicles_num: usize,
+}
+
+/// Result of [`DynamicEquilibrium::run`].
+#[derive(Debug, Clone)]
+pub struct EquilibriumResult {
+    /// Convergence trace: one entry per iteration
+    pub iterations: Vec<EquilibriumIteration>,
+    /// Whether the relative gap has reached the threshold
+    pub converged: bool,
+    /// Experienced travel times per link and time slice of the last iteration
+    pub travel_times: SlicedTravelTimes,
+    /// Routes shares applied in the last iteration. Empty if the runner stopped after the first
+    /// iteration (it uses the logit model on current travel times)
+    pub route_splits: RouteSplits,
+}
+
+/// Iterative dynamic user equilibrium (DUE) runner.
+///
+/// Runs the scenario (trips) repeatedly on the same session. Each iteration:
+/// 1. Resets the session (see [`Session::reset`]) and adds the trips again;
+/// 2. Simulates given number of steps with current routes shares ([`RouteSplits`]) and records
+///    experienced travel times per `meso_link_id` and time slice ([`SlicedTravelTimes`]);
+/// 3. Calculates time-dependent routes travel times and the relative gap;
+/// 4. Updates routes shares with the method of successive averages (MSA):
+///    `p(n+1) = p(n) + (y(n) - p(n)) / (n + 1)`, where `y(n)` is the trip's route choice model
+///    applied to experienced routes travel times.
+///
+/// The first iteration uses en-route logit on current travel times and its observed shares
+/// are the starting point of MSA. Only trips with route choice (see [`Trip::route_choice`]) are
+/// assigned; vehicles added to the session manually are dropped by the reset.
+///
+/// Relative gap is measured against the best (shortest experienced time) routes, so with small
+/// logit `theta` it does not reach zero: spread of shares among the routes is a part of the stochastic
+/// equilibrium. Use larger `theta` for near-deterministic assignment.
+///
+/// # Example
+/// ```
+/// use micro_traffic_sim_core::simulation::equilibrium::DynamicEquilibrium;
+/// use micro_traffic_sim_core::simulation::{session::Session, grids_storage::GridsStorage};
+/// use micro_traffic_sim_core::trips::trip::{Trip, TripType};
+/// use micro_traffic_sim_core::route_choice::{RouteChoice, RouteChoiceModel};
+/// use micro_traffic_sim_core::grid::road_network::GridRoads;
+/// use micro_traffic_sim_core::utils::generators::generate_one_lane_cells;
+///
+/// let mut grid = GridRoads::new();
+/// for cell in generate_one_lane_cells(100.0, 10.0, 1) {
+///     grid.add_cell(cell);
+/// }
+/// let trip = Trip::new(1, 10, TripType::Constant)
+///     .with_id(1)
+///     .with_time(2)
+///     .with_route_choice(RouteChoice::new(RouteChoiceModel::MultinomialLogit).build())
+///     .build();
+/// let mut session = Session::new(GridsStorage::new().with_vehicles_net(grid).build(), None);
+/// let result = DynamicEquilibrium::new(vec![trip])
+///     .with_steps(100)
+///     .with_max_iterations(5)
+///     .with_gap_threshold(0.01)
+///     .with_slice_duration(20)
+///     .build()
+///     .run(&mut session)
+///     .unwrap();
+/// // Single lane has the only route, so every vehicle is on the best one
+/// assert!(result.converged);
+/// assert_eq!(result.iterations[0].relative_gap, 0.0);
+/// assert_eq!(result.iterations.len(), 1);
+/// ```
+#[derive(Debug, Clone)]
+pub struct DynamicEquilibrium {
+    /// Trips of the scenario
+    pub trips: Vec<Trip>,
+    /// Number of simulation steps in each iteration
+    pub steps: i32,
+    /// Maximum number of iterations
+    pub max_iterations: usize,
+    /// Relative gap threshold to stop iterations
+    pub gap_threshold: f64,
+    /// Duration (in time units) of a single time slice for travel times and routes shares
+    pub slice_duration: i32,
+}
+
+/// A builder pattern implementation for constructing `DynamicEquilibrium` objects.
+pub struct DynamicEquilibriumBuilder {
+    equilibrium: DynamicEquilibrium,
+}
+
+impl DynamicEquilibrium {
+    /// Constructs a new `DynamicEquilibriumBuilder` for the scenario.
+    ///
+    /// Defaults are: 3600 steps per iteration, 20 iterations at most, relative gap threshold 0.01,
+    /// time slices of 60 time units.
+    ///
+    /// # Arguments
+    /// * `trips` - Trips of the scenario
+    ///
+    /// # Returns
+    /// A `DynamicEquilibriumBuilder` struct which is used to configure the runner
+    pub fn new(trips: Vec<Trip>) -> DynamicEquilibriumBuilder {
+        DynamicEquilibriumBuilder {
+            equilibrium: DynamicEquilibrium {
+                trips,
+                steps: 3600,
+                max_iterations: 20,
+                gap_threshold: 0.01,
+                slice_duration: 60,
+            },
+        }
+    }
+
+    /// Runs iterations until the relative gap reaches the threshold or iterations are exhausted.
+    ///
+    /// Session keeps the state of the last iteration (with its routes shares applied), so it
+    /// could be inspected or simulated further.
+    ///
+    /// # Arguments
+    /// * `session` - Session with the road network (and traffic lights, conflict zones)
+    ///
+    /// # Returns
+    /// * `Ok(EquilibriumResult)` - Convergence trace, final travel times and routes shares
+    /// * `Err(SessionError)` - If any simulation step fails
+    pub fn run(&self, session: &mut Session) -> Result<EquilibriumResult, SessionError> {
+        let verbose = LocalLogger::with_session(session.get_verbose_level(), session.get_id().to_string());
+        let mut iterations: Vec<EquilibriumIteration> = Vec::with_capacity(self.max_iterations);
+        let mut splits = RouteSplits::new(self.slice_duration);
+        let mut travel_times = SlicedTravelTimes::new(self.slice_duration);
+        let mut converged = false;
+        for iteration in 1..=self.max_iterations.max(1) {
+            session.reset();
+            for trip in &self.trips {
+                session.add_trip(trip.clone());
+            }
+            session.set_route_splits(if splits.is_empty() { None } else { Some(splits.clone()) });
+            session.set_travel_times_record(Some(SlicedTravelTimes::new(self.slice_duration)));
+
+            let departures = self.simulate(session)?;
+            travel_times = session
+                .take_travel_times_record()
+                .unwrap_or_else(|| SlicedTravelTimes::new(self.slice_duration));
+            let (relative_gap, updated_splits) = self.update_splits(session, &travel_times, &departures, &splits, iteration);
+            let vehicles_num = departures.values().flat_map(|counts| counts.iter()).sum();
+
+            if verbose.is_at_least(VerboseLevel::Main) {
+                verbose.log_with_fields(
+                    EVENT_EQUILIBRIUM_ITERATION,
+                    "Equilibrium iteration",
+                    &[
+                        ("iteration", &iteration),
+                        ("relative_gap", &relative_gap),
+                        ("vehicles_num", &vehicles_num),
+                    ]
+                );
+            }
+            iterations.push(EquilibriumIteration {
+                iteration,
+                relative_gap,
+                vehicles_num,
+            });
+            if relative_gap <= self.gap_threshold {
+                converged = true;
+                break;
+            }
+            if iteration < self.max_iterations {
+                splits = updated_splits;
+            }
+        }
+        Ok(EquilibriumResult {
+            iterations,
+            converged,
+            travel_times,
+            route_splits: splits,
+        })
+    }
+
+    /// Simulates a single iteration and counts departures per (trip, departure time slice, route)
+    fn simulate(&self, session: &mut Session) -> Result<HashMap<(TripID, usize), Vec<usize>>, SessionError> {
+        let mut departures: HashMap<(TripID, usize), Vec<usize>> = HashMap::new();
+        for _ in 0..self.steps {
+            let state = session.step()?;
+            for departure in state.departures.iter() {
+                let route_id: RouteID = match departure.route_id {
+                    Some(route_id) => route_id,
+                    None => continue,
+                };
+                let counts = departures
+                    .entry((departure.trip_id, time_slice(state.timestamp, self.slice_duration)))
+                    .or_default();
+                if counts.len() <= route_id {
+                    counts.resize(route_id + 1, 0);
+                }
+                counts[route_id] += 1;
+            }
+        }
+        Ok(departures)
+    }
+
+    /// Calculates the relative gap of the iteration and the next routes shares (MSA step).
+    ///
+    /// Routes costs are experienced travel times for departure in the middle of the time slice.
+    fn update_splits(
+        &self,
+        session: &Session,
+        travel_times: &SlicedTravelTimes,
+        departures: &HashMap<(TripID, usize), Vec<usize>>,
+        splits: &RouteSplits,
+        iteration: usize,
+    ) -> (f64, RouteSplits) {
+        let net = session.get_vehicles_net_ref();
+        let slices_num = time_slice(self.steps - 1, self.slice_duration) + 1;
+        let step_size = 1.0 / (iteration as f64 + 1.0);
+        let mut updated_splits = RouteSplits::new(self.slice_duration);
+        let mut excess_time = 0.0;
+        let mut best_time = 0.0;
+        for trip in &self.trips {
+            let route_choice = match &trip.route_choice {
+                Some(route_choice) => route_choice,
+                None => continue,
+            };
+            let routes = match session.get_trip_routes(trip.id) {
+                Some(routes) if !routes.is_empty() => routes,
+                _ => continue,
+            };
+            for slice in 0..slices_num {
+                let departure = slice as i32 * self.slice_duration + self.slice_duration / 2;
+                let costs: Vec<f64> = routes
+                    .iter()
+                    .map(|route| travel_times.route_travel_time(route, net, departure))
+                    .collect();
+                let min_cost = costs.iter().copied().fold(f64::INFINITY, f64::min);
+                let counts = departures.get(&(trip.id, slice));
+                let flows: Vec<f64> = (0..routes.len())
+                    .map(|idx| counts.and_then(|c| c.get(idx)).copied().unwrap_or(0) as f64)
+                    .collect();
+                let total_flow: f64 = flows.iter().sum();
+                excess_time += flows.iter().zip(costs.iter()).map(|(flow, cost)| flow * (cost - min_cost)).sum::<f64>();
+                best_time += total_flow * min_cost;
+
+                let auxiliary = route_choice.probabilities(routes, &costs, net);
+                // Applied shares or observed ones (for the first iteration)
+                let current: Vec<f64> = match splits.get(trip.id, slice) {
+                    Some(probabilities) if probabilities.len() == routes.len() => probabilities.clone(),
+                    _ if total_flow > 0.0 => flows.iter().map(|flow| flow / total_flow).collect(),
+                    _ => auxiliary.clone(),
+                };
+                let next: Vec<f64> = current
+                    .iter()
+                    .zip(auxiliary.iter())
+                    .map(|(p, y)| p + step_size * (y - p))
+                    .collect();
+                updated_splits.set(trip.id, slice, next);
+            }
+        }
+        let relative_gap = if best_time <= f64::EPSILON { 0.0 } else { excess_time / best_time };
+        (relative_gap, updated_splits)
+    }
+}
+
+impl DynamicEquilibriumBuilder {
+    /// Sets number of simulation steps in each iteration
+    ///
+    /// # Example
+    /// ```
+    /// use micro_traffic_sim_core::simulation::equilibrium::DynamicEquilibrium;
+    /// let runner = DynamicEquilibrium::new(vec![]).with_steps(600).build();
+    /// assert_eq!(runner.steps, 600);
+    /// ```
+    pub fn with_steps(mut self, steps: i32) -> Self {
+        self.equilibrium.steps = steps.max(1);
+        self
+    }
+
+    /// Sets maximum number of iterations
+    ///
+    /// # Example
+    /// ```
+    /// use micro_traffic_sim_core::simulation::equilibrium::DynamicEquilibrium;
+    /// let runner = DynamicEquilibrium::new(vec![]).with_max_iterations(50).build();
+    /// assert_eq!(runner.max_iterations, 50);
+    /// ```
+    pub fn with_max_iterations(mut self, max_iterations: usize) -> Self {
+        self.equilibrium.max_iterations = max_iterations.max(1);
+        self
+    }
+
+    /// Sets relative gap threshold to stop iterations
+    ///
+    /// # Example
+    /// ```
+    /// use micro_traffic_sim_core::simulation::equilibrium::DynamicEquilibrium;
+    /// let runner = DynamicEquilibrium::new(vec![]).with_gap_threshold(0.005).build();
+    /// assert_eq!(runner.gap_threshold, 0.005);
+    /// ```
+    pub fn with_gap_threshold(mut self, gap_threshold: f64) -> Self {
+        self.equilibrium.gap_threshold = gap_threshold;
+        self
+    }
+
+    /// Sets duration (in time units) of a single time slice
+    ///
+    /// # Example
+    /// ```
+    /// use micro_traffic_sim_core::simulation::equilibrium::DynamicEquilibrium;
+    /// let runner = DynamicEquilibrium::new(vec![]).with_slice_duration(300).build();
+    /// assert_eq!(runner.slice_duration, 300);
+    /// ```
+    pub fn with_slice_duration(mut self, slice_duration: i32) -> Self {
+        self.equilibrium.slice_duration = slice_duration.max(1);
+        self
+    }
+
+    /// Builds the final `DynamicEquilibrium` object
+    pub fn build(self) -> DynamicEquilibrium {
+        self.equilibrium
+    }
+}
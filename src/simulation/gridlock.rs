@@ -0,0 +1,395 @@
+use crate::agents::{Vehicle, VehicleID, VehicleIntention};
+use crate::behaviour::BehaviourType;
+use crate::geom::get_bearing;
+use crate::grid::cell::{CellID, CellState};
+use crate::grid::road_network::GridRoads;
+use crate::shortest_path::router::shortest_path;
+use indexmap::IndexMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+/// Maximal distance (in cells) downstream the teleported vehicle may be moved to (see [`GridlockPolicy::Teleport`])
+pub const TELEPORT_LOOK_AHEAD: usize = 100;
+
+/// Policy resolving gridlocks (see [`find_gridlocks`]).
+///
+/// Gridlocks are always reported in the step output (see [`AutomataState::gridlocks`](crate::simulation::states::AutomataState::gridlocks)).
+/// Resolving policies are applied once the gridlock has lasted for `timeout` steps, to the participant with the lowest identifier.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GridlockPolicy {
+    /// Gridlocks are reported only
+    #[default]
+    Report,
+    /// The participant is moved downstream along its way to the first cells free enough to fit the vehicle
+    /// (SUMO-like teleport). The vehicle is removed if there is no room on its way.
+    Teleport { timeout: i32 },
+    /// The participant is removed from the simulation
+    Remove { timeout: i32 },
+    /// The participant held in front of a junction box (see [`JunctionBox::grant_priority`](crate::conflict_zones::JunctionBox::grant_priority))
+    /// is allowed to enter it regardless of the locking rules. Gridlocks without such participants are made of vehicles
+    /// waiting for occupied cells only, so they are resolved by teleporting.
+    Priority { timeout: i32 },
+}
+
+/// Action applied to resolve a gridlock
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GridlockResolution {
+    /// The vehicle has been moved to the cell
+    Teleported { vehicle_id: VehicleID, cell_id: CellID },
+    /// The vehicle has been removed
+    Removed { vehicle_id: VehicleID },
+    /// The vehicle has been allowed to enter the junction box
+    Prioritized { vehicle_id: VehicleID },
+}
+
+/// Circular wait of vehicles
+#[derive(Debug, Clone, PartialEq)]
+pub struct Gridlock {
+    /// Vehicles waiting for each other (sorted by identifiers)
+    pub vehicles: Vec<VehicleID>,
+    /// Number of consecutive steps the gridlock has been observed
+    pub duration: i32,
+    /// Action applied on the current step (see [`GridlockPolicy`])
+    pub resolution: Option<GridlockResolution>,
+}
+
+/// Checks if the vehicle stays in place on the current step
+fn is_standing(vehicle: &Vehicle) -> bool {
+    vehicle.intention.intention_speed == 0 || vehicle.intention.intention_cell_id == vehicle.cell_id
+}
+
+/// Returns the number of cells explored by the path search to find `max_cells` cells ahead (the source cell is included)
+fn search_depth(max_cells: usize) -> i32 {
+    i32::try_from(max_cells.saturating_add(1)).unwrap_or(i32::MAX)
+}
+
+/// Returns cells the vehicle is going to pass after its current cell: the assigned route, the shortest path
+/// to the destination or forward connections (up to `max_cells` cells).
+///
+/// The path search is bounded by `max_cells` explored cells (as the one of intentions), so the first cells
+/// of the way are found without searching the whole network when the destination is far away.
+///
+/// # Arguments
+/// * `net` - The road network grid
+/// * `vehicle` - The vehicle
+/// * `max_cells` - Maximal number of cells
+///
+/// # Example
+/// ```
+/// use micro_traffic_sim_core::agents::Vehicle;
+/// use micro_traffic_sim_core::grid::road_network::GridRoads;
+/// use micro_traffic_sim_core::simulation::gridlock::way_ahead;
+/// use micro_traffic_sim_core::utils::generators::generate_one_lane_cells;
+///
+/// let mut net = GridRoads::new();
+/// for cell in generate_one_lane_cells(100.0, 10.0, 1) {
+///     net.add_cell(cell);
+/// }
+/// let vehicle = Vehicle::new(1).with_cell(3).with_destination(6).build();
+/// assert_eq!(way_ahead(&net, &vehicle, 10), vec![4, 5, 6]);
+/// assert_eq!(way_ahead(&net, &vehicle, 1), vec![4]);
+/// ```
+pub fn way_ahead(net: &GridRoads, vehicle: &Vehicle, max_cells: usize) -> Vec<CellID> {
+    if let Some(route_index) = vehicle.route.iter().position(|&cell_id| cell_id == vehicle.cell_id) {
+        return vehicle.route.iter().skip(route_index + 1).take(max_cells).copied().collect();
+    }
+    if let (Some(cell), Some(destination)) = (net.get_cell(&vehicle.cell_id), net.get_cell(&vehicle.destination))
+        && let Ok(path) = shortest_path(cell, destination, net, true, Some(search_depth(max_cells)), vehicle.vehicle_type)
+    {
+        return path.vertices().iter().skip(1).take(max_cells).map(|cell| cell.get_id()).collect();
+    }
+    let mut way = Vec::new();
+    let mut visited = HashSet::from([vehicle.cell_id]);
+    let mut cell_id = vehicle.cell_id;
+    while way.len() < max_cells {
+        cell_id = net.get_cell(&cell_id).map_or(-1, |cell| cell.get_forward_id());
+        if cell_id < 0 || !visited.insert(cell_id) {
+            break;
+        }
+        way.push(cell_id);
+    }
+    way
+}
+
+/// Finds gridlocks: circular waits of vehicles staying in place on the current step.
+///
+/// The "waiting-for" graph is built from prepared (and solved) intentions: the vehicle staying in place waits for
+/// the vehicle staying in place in the next cell of its way (see [`way_ahead`]) and for vehicles given by `held`
+/// (e.g. vehicles held in front of junction boxes, see [`JunctionBox::get_held`](crate::conflict_zones::JunctionBox::get_held)).
+/// Blocked vehicles (see [`BehaviourType::Block`]) wait for nothing. Gridlocks are strongly connected components
+/// of the graph with at least two vehicles.
+///
+/// # Arguments
+/// * `net` - The road network grid
+/// * `current_state` - Mapping from occupied cell ID to vehicle ID
+/// * `vehicles` - Vehicles storage with intentions
+/// * `held` - Vehicles mapped to other vehicles they wait for
+///
+/// # Returns
+/// Vehicles of every gridlock (sorted by identifiers), ordered by the first vehicle.
+///
+/// # Example
+/// ```
+/// use micro_traffic_sim_core::agents::{Vehicle, VehicleIntention};
+/// use micro_traffic_sim_core::grid::{cell::Cell, road_network::GridRoads};
+/// use micro_traffic_sim_core::simulation::gridlock::find_gridlocks;
+/// use indexmap::IndexMap;
+/// use std::collections::HashMap;
+///
+/// // Ring road 1 → 2 → 3 → 1 fully occupied by vehicles
+/// let mut net = GridRoads::new();
+/// for id in 1..=3 {
+///     net.add_cell(Cell::new(id).with_forward_node(id % 3 + 1).build());
+/// }
+/// let vehicles: IndexMap<_, _> = (1..=3)
+///     .map(|id| {
+///         let mut vehicle = Vehicle::new(id as u64).with_cell(id).build();
+///         vehicle.set_intention(VehicleIntention { intention_cell_id: id, ..Default::default() });
+///         (id as u64, vehicle)
+///     })
+///     .collect();
+/// let current_state = HashMap::from([(1, 1), (2, 2), (3, 3)]);
+/// assert_eq!(find_gridlocks(&net, &current_state, &vehicles, &HashMap::new()), vec![vec![1, 2, 3]]);
+/// ```
+pub fn find_gridlocks(
+    net: &GridRoads,
+    current_state: &HashMap<CellID, VehicleID>,
+    vehicles: &IndexMap<VehicleID, Vehicle>,
+    held: &HashMap<VehicleID, Vec<VehicleID>>,
+) -> Vec<Vec<VehicleID>> {
+    let standing = |vehicle_id: &VehicleID| vehicles.get(vehicle_id).is_some_and(is_standing);
+    let occupant = |cell_id: CellID| current_state.get(&cell_id).copied();
+
+    // Waiting-for graph over vehicles staying in place
+    let mut graph: BTreeMap<VehicleID, Vec<VehicleID>> = BTreeMap::new();
+    for vehicle in vehicles.values().filter(|vehicle| is_standing(vehicle)) {
+        if vehicle.strategy_type == BehaviourType::Block {
+            continue;
+        }
+        let mut waits_for: Vec<VehicleID> = held
+            .get(&vehicle.id)
+            .into_iter()
+            .flatten()
+            .copied()
+            .filter(|other_id| *other_id != vehicle.id && standing(other_id))
+            .collect();
+        // Paths are searched only for vehicles surrounded by standing ones
+        let neighbour_standing = net.get_cell(&vehicle.cell_id).is_some_and(|cell| {
+            [cell.get_forward_id(), cell.get_left_id(), cell.get_right_id()]
+                .into_iter()
+                .filter_map(occupant)
+                .any(|other_id| other_id != vehicle.id && standing(&other_id))
+        });
+        if neighbour_standing
+            && let Some(&next_id) = way_ahead(net, vehicle, 1).first()
+            && let Some(other_id) = occupant(next_id)
+            && other_id != vehicle.id
+            && standing(&other_id)
+        {
+            waits_for.push(other_id);
+        }
+        if !waits_for.is_empty() {
+            waits_for.sort_unstable();
+            waits_for.dedup();
+            graph.insert(vehicle.id, waits_for);
+        }
+    }
+
+    // Strongly connected components (Kosaraju): finishing order on the graph, then components on the reversed one
+    let mut finished: Vec<VehicleID> = Vec::with_capacity(graph.len());
+    let mut visited: HashSet<VehicleID> = HashSet::new();
+    for &start in graph.keys() {
+        if !visited.insert(start) {
+            continue;
+        }
+        let mut stack: Vec<(VehicleID, usize)> = vec![(start, 0)];
+        while let Some((vehicle_id, edge_index)) = stack.pop() {
+            match graph.get(&vehicle_id).and_then(|edges| edges.get(edge_index)) {
+                Some(&next_id) => {
+                    stack.push((vehicle_id, edge_index + 1));
+                    if visited.insert(next_id) {
+                        stack.push((next_id, 0));
+                    }
+                }
+                None => finished.push(vehicle_id),
+            }
+        }
+    }
+    let mut reversed: HashMap<VehicleID, Vec<VehicleID>> = HashMap::new();
+    for (&vehicle_id, edges) in graph.iter() {
+        for &next_id in edges {
+            reversed.entry(next_id).or_default().push(vehicle_id);
+        }
+    }
+    let mut assigned: HashSet<VehicleID> = HashSet::new();
+    let mut gridlocks: Vec<Vec<VehicleID>> = Vec::new();
+    for &start in finished.iter().rev() {
+        if !assigned.insert(start) {
+            continue;
+        }
+        let mut component = vec![start];
+        let mut stack = vec![start];
+        while let Some(vehicle_id) = stack.pop() {
+            for &previous_id in reversed.get(&vehicle_id).into_iter().flatten() {
+                if assigned.insert(previous_id) {
+                    component.push(previous_id);
+                    stack.push(previous_id);
+                }
+            }
+        }
+        if component.len() > 1 {
+            component.sort_unstable();
+            gridlocks.push(component);
+        }
+    }
+    gridlocks.sort();
+    gridlocks
+}
+
+/// Moves the vehicle downstream along its way (see [`way_ahead`]) to the first cells which are free enough
+/// to fit the vehicle with its tail. The vehicle stops there: its intention is to stay in the new cell
+/// and its timers (acceleration, maneuvers and slowdown ones) are reset.
+///
+/// # Arguments
+/// * `net` - The road network grid
+/// * `vehicles` - Vehicles storage
+/// * `vehicle_id` - The vehicle to move
+/// * `max_cells` - Maximal search distance (in cells)
+///
+/// # Returns
+/// New cell of the vehicle, or `None` if there is no room on its way.
+///
+/// # Example
+/// ```
+/// use micro_traffic_sim_core::agents::Vehicle;
+/// use micro_traffic_sim_core::grid::road_network::GridRoads;
+/// use micro_traffic_sim_core::simulation::gridlock::teleport_downstream;
+/// use micro_traffic_sim_core::utils::generators::generate_one_lane_cells;
+/// use indexmap::IndexMap;
+///
+/// let mut net = GridRoads::new();
+/// for cell in generate_one_lane_cells(100.0, 10.0, 1) {
+///     net.add_cell(cell);
+/// }
+/// let mut vehicles = IndexMap::from([
+///     (1, Vehicle::new(1).with_cell(3).with_destination(10).build()),
+///     (2, Vehicle::new(2).with_cell(4).build()),
+///     (3, Vehicle::new(3).with_cell(5).build()),
+/// ]);
+/// assert_eq!(teleport_downstream(&net, &mut vehicles, 1, 10), Some(6));
+/// assert_eq!(vehicles[&1].cell_id, 6);
+/// ```
+pub fn teleport_downstream(
+    net: &GridRoads,
+    vehicles: &mut IndexMap<VehicleID, Vehicle>,
+    vehicle_id: VehicleID,
+    max_cells: usize,
+) -> Option<CellID> {
+    let occupied: HashSet<CellID> = vehicles
+        .values()
+        .filter(|vehicle| vehicle.id != vehicle_id)
+        .flat_map(|vehicle| std::iter::once(vehicle.cell_id).chain(vehicle.tail_cells.iter().copied()))
+        .collect();
+    let vehicle = vehicles.get(&vehicle_id)?;
+    let tail_size = vehicle.tail_cells.len();
+    let mut way = vec![vehicle.cell_id];
+    way.extend(way_ahead(net, vehicle, max_cells));
+    let is_free = |cell_id: &CellID| {
+        !occupied.contains(cell_id) && net.get_cell(cell_id).is_some_and(|cell| cell.get_state() == CellState::Free)
+    };
+    let head_index = (tail_size + 1..way.len()).find(|&index| way[index - tail_size..=index].iter().all(is_free))?;
+    let bearing = match (net.get_cell(&way[head_index - 1]), net.get_cell(&way[head_index])) {
+        (Some(previous), Some(cell)) => get_bearing(previous.get_point(), cell.get_point()),
+        _ => vehicle.bearing,
+    };
+    let vehicle = vehicles.get_mut(&vehicle_id)?;
+    vehicle.cell_id = way[head_index];
+    vehicle.tail_cells = way[head_index - tail_size..head_index].to_vec();
+    vehicle.speed = 0;
+    vehicle.bearing = bearing;
+    // Intention and timers refer to the old position
+    vehicle.intention = VehicleIntention {
+        intention_cell_id: vehicle.cell_id,
+        tail_intention_cells: vehicle.tail_cells.clone(),
+        ..Default::default()
+    };
+    vehicle.timer_non_acceleration = 0;
+    vehicle.timer_non_maneuvers = 0;
+    vehicle.timer_non_slowdown = 0;
+    Some(vehicle.cell_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geom::new_point;
+    use crate::grid::cell::Cell;
+
+    fn standing_vehicle(id: VehicleID, cell_id: CellID, destination: CellID) -> Vehicle {
+        let mut vehicle = Vehicle::new(id).with_cell(cell_id).with_destination(destination).build();
+        vehicle.set_intention(VehicleIntention { intention_cell_id: cell_id, ..Default::default() });
+        vehicle
+    }
+
+    #[test]
+    fn test_find_gridlocks() {
+        // Two lanes: 1 → 2 → 3 and 11 → 12 → 13, lane changes 2 → 13 and 12 → 3
+        let mut net = GridRoads::new();
+        for id in [1, 2, 3, 11, 12, 13] {
+            net.add_cell(
+                Cell::new(id)
+                    .with_point(new_point((id % 10 - 1) as f64, (id / 10) as f64, None))
+                    .with_forward_node(if id % 10 < 3 { id + 1 } else { -1 })
+                    .build(),
+            );
+        }
+        net.get_cell_mut(2).unwrap().set_right_id(13);
+        net.get_cell_mut(12).unwrap().set_left_id(3);
+
+        // Vehicles in cells 2 and 12 want to swap lanes, but cells are occupied by vehicles waiting for them
+        let vehicles = IndexMap::from([
+            (1, standing_vehicle(1, 2, 13)),
+            (2, standing_vehicle(2, 13, -1)),
+            (3, standing_vehicle(3, 12, 3)),
+            (4, standing_vehicle(4, 3, -1)),
+            (5, standing_vehicle(5, 1, 3)),
+        ]);
+        let current_state: HashMap<CellID, VehicleID> = vehicles.values().map(|vehicle| (vehicle.cell_id, vehicle.id)).collect();
+        // Queue without a cycle
+        assert!(find_gridlocks(&net, &current_state, &vehicles, &HashMap::new()).is_empty());
+        // Vehicles 2 and 4 are held waiting for vehicles 3 and 1
+        let held = HashMap::from([(2, vec![3]), (4, vec![1])]);
+        assert_eq!(find_gridlocks(&net, &current_state, &vehicles, &held), vec![vec![1, 2, 3, 4]]);
+    }
+
+    #[test]
+    fn test_teleport_with_tail() {
+        let mut net = GridRoads::new();
+        for id in 1..=10 {
+            net.add_cell(Cell::new(id).with_forward_node(if id < 10 { id + 1 } else { -1 }).build());
+        }
+        let mut vehicles = IndexMap::from([
+            (1, Vehicle::new(1).with_cell(3).with_tail_size(2, vec![1, 2]).with_destination(10).build()),
+            (2, Vehicle::new(2).with_cell(4).build()),
+            (3, Vehicle::new(3).with_cell(7).build()),
+        ]);
+        {
+            let vehicle = vehicles.get_mut(&1).unwrap();
+            // Intention of the old position
+            vehicle.set_intention(VehicleIntention { intention_cell_id: 4, intention_speed: 1, tail_intention_cells: vec![2, 3], ..Default::default() });
+            vehicle.timer_non_acceleration = 2;
+            vehicle.timer_non_maneuvers = 2;
+            vehicle.timer_non_slowdown = 2;
+        }
+        assert_eq!(teleport_downstream(&net, &mut vehicles, 1, 10), Some(10));
+        let vehicle = &vehicles[&1];
+        assert_eq!(vehicle.tail_cells, vec![8, 9]);
+        // Vehicle stays in the new cells without timers of the old position
+        assert_eq!(vehicle.intention.intention_cell_id, 10);
+        assert_eq!(vehicle.intention.tail_intention_cells, vec![8, 9]);
+        assert_eq!(vehicle.intention.intention_speed, 0);
+        assert_eq!((vehicle.timer_non_acceleration, vehicle.timer_non_maneuvers, vehicle.timer_non_slowdown), (0, 0, 0));
+        // No room
+        assert_eq!(teleport_downstream(&net, &mut vehicles, 3, 10), None);
+        assert_eq!(vehicles[&3].cell_id, 7);
+    }
+}
@@ -1,9 +1,9 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use crate::grid::road_network::GridRoads;
-use crate::grid::cell::{CellID};
+use crate::grid::cell::{CellID, CellState};
+use crate::pedestrians::Crosswalks;
 use crate::traffic_lights::lights::{TrafficLightID, TrafficLight};
-use crate::traffic_lights::signals::SignalType;
 use crate::simulation::states::{TrafficLightGroupState};
 use crate::verbose::*;
 use std::fmt;
@@ -28,15 +28,25 @@ impl fmt::Display for GridsStorageError {
 
 impl std::error::Error for GridsStorageError {}
 
-/// Storage for TLS and various grids used in the simulation, such as the vehicle road network.
+/// Storage for TLS and various grids used in the simulation: the vehicle road network and the pedestrian network.
 ///
-/// **Future works**: Implement pedestrian network, crosswalks, and other relevant grids.
+/// Crosswalk cells of the pedestrian network are shared with the vehicle road network by their IDs (see [`Crosswalks`]).
 #[derive(Debug)]
 pub struct GridsStorage {
     /// The grid intented for vehicles only
     vehicles_net: GridRoads,
+    /// The grid of walkways intented for pedestrians only
+    pedestrians_net: GridRoads,
+    /// Crosswalks of the pedestrian network
+    crosswalks: Crosswalks,
+    /// Cells of the vehicles grid banned because of pedestrians on crosswalks
+    crosswalks_banned: HashSet<CellID>,
+    /// Approach cells of unsignalised junctions banned by priority controls (see `PriorityJunction`)
+    approaches_held: HashSet<CellID>,
     /// traffic lights and etc.
     tls: HashMap<TrafficLightID, TrafficLight>,
+    /// Cells ahead of buses with distance (in cells) to the nearest bus. Used for transit signal priority
+    approaching_transit: HashMap<CellID, i32>,
 }
 
 /// A builder pattern implementation for constructing `GridsStorage` objects.
@@ -64,7 +74,12 @@ impl GridsStorage {
         GridsStorageBuilder {
             storage: GridsStorage {
                 vehicles_net: GridRoads::new(),
+                pedestrians_net: GridRoads::new(),
+                crosswalks: Crosswalks::default(),
+                crosswalks_banned: HashSet::new(),
+                approaches_held: HashSet::new(),
                 tls: HashMap::new(),
+                approaching_transit: HashMap::new(),
             },
         }
     }
@@ -93,6 +108,14 @@ impl GridsStorage {
         self.vehicles_net.get_cell(cell_id)
     }
 
+    /// Adds set of cells to the pedestrians grid and updates its crosswalks.
+    pub fn add_pedestrian_cells(&mut self, cells_data: Vec<crate::grid::cell::Cell>) {
+        for cell in cells_data {
+            self.pedestrians_net.add_cell(cell);
+        }
+        self.crosswalks = Crosswalks::from_net(&self.pedestrians_net);
+    }
+
     /// Add single traffic light to the storage.
     pub fn add_traffic_light(&mut self, traffic_light: TrafficLight) {
         self.tls.insert(traffic_light.get_id(), traffic_light);
@@ -108,6 +131,68 @@ impl GridsStorage {
         &mut self.vehicles_net
     }
 
+    /// Returns a reference to the pedestrians grid.
+    pub fn get_pedestrians_net_ref(&self) -> &GridRoads {
+        &self.pedestrians_net
+    }
+
+    /// Returns a reference to the crosswalks of the pedestrians grid.
+    pub fn get_crosswalks_ref(&self) -> &Crosswalks {
+        &self.crosswalks
+    }
+
+    /// Releases cells of the vehicles grid banned by [`GridsStorage::occupy_crosswalks`] on the previous step.
+    /// Should be called before [`GridsStorage::tick_traffic_lights`], so signals are applied over released cells.
+    pub fn release_crosswalks(&mut self) {
+        for cell_id in self.crosswalks_banned.drain() {
+            if let Some(cell) = self.vehicles_net.get_cell_mut(cell_id) {
+                cell.set_state(CellState::Free);
+            }
+        }
+    }
+
+    /// Bans given crosswalk cells for vehicles, so vehicles yield to pedestrians on them.
+    /// Cells stay banned until [`GridsStorage::release_crosswalks`] is called.
+    ///
+    /// # Arguments
+    /// * `cells` - Cells of occupied crosswalks (see [`Crosswalks::occupied_cells`])
+    pub fn occupy_crosswalks(&mut self, cells: &HashSet<CellID>) {
+        for &cell_id in cells {
+            if let Some(cell) = self.vehicles_net.get_cell_mut(cell_id)
+                && cell.get_state() == CellState::Free
+            {
+                cell.set_state(CellState::Banned);
+                self.crosswalks_banned.insert(cell_id);
+            }
+        }
+    }
+
+    /// Releases approach cells banned by [`GridsStorage::hold_approaches`] on the previous step.
+    /// Should be called before [`GridsStorage::tick_traffic_lights`], so signals are applied over released cells.
+    pub fn release_approaches(&mut self) {
+        for cell_id in self.approaches_held.drain() {
+            if let Some(cell) = self.vehicles_net.get_cell_mut(cell_id) {
+                cell.set_state(CellState::Free);
+            }
+        }
+    }
+
+    /// Bans given approach cells of unsignalised junctions, so vehicles wait in front of them.
+    /// Cells stay banned until [`GridsStorage::release_approaches`] is called.
+    ///
+    /// # Arguments
+    /// * `cells` - Held approach cells (see [`PriorityJunction::held_cells`](crate::conflict_zones::PriorityJunction::held_cells))
+    pub fn hold_approaches(&mut self, cells: &HashSet<CellID>) {
+        for &cell_id in cells {
+            if let Some(cell) = self.vehicles_net.get_cell_mut(cell_id)
+                && cell.get_state() == CellState::Free
+            {
+                cell.set_state(CellState::Banned);
+                self.approaches_held.insert(cell_id);
+            }
+        }
+    }
+
     /// Returns a reference to the traffic lights storage.
     pub fn get_tls_ref(&self) -> &HashMap<TrafficLightID, TrafficLight> {
         &self.tls
@@ -118,6 +203,47 @@ impl GridsStorage {
         &mut self.tls
     }
 
+    /// Preempts traffic lights for the given cells (e.g. cells ahead of emergency vehicles):
+    /// each traffic light holds open its first group controlling any of them.
+    /// Traffic lights which control none of the cells are released and continue their regular cycles.
+    ///
+    /// # Arguments
+    /// * `cells` - Cells which should be open
+    pub fn apply_preemption(&mut self, cells: &HashSet<CellID>) {
+        for tl in self.tls.values_mut() {
+            let group_id = tl
+                .get_groups()
+                .iter()
+                .find(|group| !group.is_pedestrian() && group.get_cells_ids().iter().any(|cell_id| cells.contains(cell_id)))
+                .map(|group| group.get_id());
+            if tl.get_preempted_group() != group_id {
+                // Switch between groups goes through the release, so the interrupted phase is kept
+                tl.set_preemption(None);
+                tl.set_preemption(group_id);
+            }
+        }
+    }
+
+    /// Returns the maximum transit priority detection distance over all traffic lights (zero if there is no transit priority).
+    pub fn transit_detection_distance(&self) -> i32 {
+        self.tls
+            .values()
+            .filter_map(|tl| tl.get_transit_priority())
+            .map(|priority| priority.detection_distance)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Sets cells ahead of buses (with distance in cells to the nearest bus) for the next tick.
+    /// Traffic lights with transit priority request it for their groups controlling these cells
+    /// (see [`TrafficLight::request_priority`]).
+    ///
+    /// # Arguments
+    /// * `cells` - Mapping from cell ID to the distance to the nearest bus behind it
+    pub fn set_approaching_transit(&mut self, cells: HashMap<CellID, i32>) {
+        self.approaching_transit = cells;
+    }
+
     /// Advances the state of all traffic lights by one tick, updating their phases and cell states accordingly.
     pub fn tick_traffic_lights(&mut self, verbose: &LocalLogger) -> Result<HashMap<TrafficLightID, Vec<TrafficLightGroupState>>, GridsStorageError> {
         if verbose.is_at_least(VerboseLevel::Main) {
@@ -140,22 +266,42 @@ impl GridsStorage {
                     ]
                 );
             }
+            if let Some(priority) = tl.get_transit_priority() {
+                let requested_group_id = tl
+                    .get_groups()
+                    .iter()
+                    .find(|group| {
+                        !group.is_pedestrian() && group.get_cells_ids().iter().any(|cell_id| {
+                            self.approaching_transit.get(cell_id).is_some_and(|&distance| distance <= priority.detection_distance)
+                        })
+                    })
+                    .map(|group| group.get_id());
+                if let Some(group_id) = requested_group_id
+                    && tl.request_priority(group_id)
+                    && verbose.is_at_least(VerboseLevel::Additional)
+                {
+                    verbose.log_with_fields(
+                        EVENT_TL_TICK,
+                        "Transit priority request served",
+                        &[
+                            ("tl_id", &format!("{:?}", tl_id)),
+                            ("group_id", &group_id),
+                            ("active_phase", &tl.get_active_phase()),
+                            ("extension", &tl.get_priority_extension()),
+                        ]
+                    );
+                }
+            }
             tl.step();
-            let active_phase_idx = tl.get_active_phase();
             let mut group_states = Vec::new();
             let tl_groups = tl.get_groups();
             for group in tl_groups {
-                let active_signal = group.get_signal_at(active_phase_idx);
-                let is_banned = match active_signal {
-                    SignalType::Red |
-                    SignalType::Yellow |
-                    SignalType::RedYellow |
-                    SignalType::Blinking => true,
-                    _ => false,
-                };
+                let active_signal = tl.get_signal(group);
+                let is_banned = active_signal.is_banned();
                 let cells_ids = group.get_cells_ids();
+                let net = if group.is_pedestrian() { &mut self.pedestrians_net } else { &mut self.vehicles_net };
                 for &cell_id in cells_ids {
-                    if let Some(cell) = self.vehicles_net.get_cell_mut(cell_id) {
+                    if let Some(cell) = net.get_cell_mut(cell_id) {
                         if is_banned {
                             cell.set_state(crate::grid::cell::CellState::Banned);
                         } else {
@@ -167,7 +313,7 @@ impl GridsStorage {
                 }
                 group_states.push(TrafficLightGroupState {
                     group_id: group.get_id(),
-                    last_signal: *active_signal,
+                    last_signal: active_signal,
                 });
             }
             tl_states.insert(*tl_id, group_states);
@@ -206,6 +352,35 @@ impl GridsStorageBuilder {
         self
     }
 
+    /// Puts the grid of walkways for pedestrians only into the storage.
+    /// Its crosswalk cells should have the same IDs as the cells of the vehicles grid they cross (see [`Crosswalks`]).
+    ///
+    /// # Arguments
+    ///
+    /// * `pedestrians_grid` - the grid intented for pedestrians only
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use micro_traffic_sim_core::grid::{cell::Cell, road_network::GridRoads, zones::ZoneType};
+    /// use micro_traffic_sim_core::simulation::grids_storage::GridsStorage;
+    ///
+    /// let mut walkway = GridRoads::new();
+    /// walkway.add_cell(Cell::new(101).with_forward_node(2).build());
+    /// walkway.add_cell(Cell::new(2).with_zone_type(ZoneType::Crosswalk).with_forward_node(102).build());
+    /// walkway.add_cell(Cell::new(102).build());
+    ///
+    /// let storage = GridsStorage::new()
+    ///     .with_pedestrians_net(walkway)
+    ///     .build();
+    /// assert_eq!(storage.get_crosswalks_ref().len(), 1);
+    /// ```
+    pub fn with_pedestrians_net(mut self, pedestrians_grid: GridRoads) -> Self {
+        self.storage.crosswalks = Crosswalks::from_net(&pedestrians_grid);
+        self.storage.pedestrians_net = pedestrians_grid;
+        self
+    }
+
     /// Puts the traffic lights into the storage
     /// 
     /// # Arguments
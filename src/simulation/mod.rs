@@ -23,6 +23,7 @@
 //! 5. Collect conflicts       ← conflicts module
 //! 6. Solve conflicts         ← conflicts module
 //! 7. Execute movement        ← movement module
+//!    Detect and resolve gridlocks
 //! 8. Collect state dump
 //! ```
 //!
@@ -35,6 +36,8 @@
 //! - [`states::VehicleState`] – State of each vehicle at a given timestamp
 //! - [`states::TrafficLightGroupState`] – State of each traffic light group at a given timestamp
 //! - [`session::SessionError`] – Unified error type for all simulation operations
+//! - [`equilibrium::DynamicEquilibrium`] – Iterative dynamic user equilibrium runner for trips with route choice
+//! - [`gridlock::find_gridlocks`] – Detects circular waits of vehicles, resolved according to [`gridlock::GridlockPolicy`]
 //!
 //! ## Usage
 //!
@@ -103,5 +106,7 @@
 //! The simulation module is the main entry point for running and controlling the traffic simulation. All other modules (intentions, conflicts, movement, traffic lights, trips) are coordinated here.
 pub mod grids_storage;
 pub mod session;
+pub mod equilibrium;
 pub mod states;
-pub mod sessions_storage;
\ No newline at end of file
+pub mod sessions_storage;
+pub mod gridlock;
\ No newline at end of file
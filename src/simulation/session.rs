@@ -1,23 +1,40 @@
-use crate::behaviour::BehaviourParameters;
+use crate::behaviour::{BehaviourDistributions, BehaviourParameters, DriverModelRef};
 use crate::agents_types::AgentType;
 use crate::agents::{VehicleID, Vehicle, VehiclesStorage};
-use crate::conflict_zones::{ConflictZone, ConflictZoneID};
+use crate::conflict_zones::{detect_conflict_zones, ConflictWinnerRule, ConflictZone, ConflictZoneID, JunctionBox, JunctionBoxID, PriorityJunction, PriorityJunctionID, Roundabout};
 use crate::grid::cell::{CellID, Cell};
+use crate::grid::road_network::GridRoads;
 use crate::trips::trip::{Trip, TripID, TripType};
 use crate::simulation::grids_storage::{GridsStorage, GridsStorageError};
-use crate::geom::{Point, SRID};
-use crate::intentions::{IntentionError, prepare_intentions};
-use crate::conflicts::{ConflictError, ConflictSolverError, collect_conflicts, solve_conflicts};
-use crate::movement::{MovementError, movement};
-use crate::simulation::states::{AutomataState, VehicleState};
+use crate::geom::{Point, SRID, get_bearing};
+use crate::maneuver::DrivingSide;
+use crate::intentions::{IntentionError, Intentions, find_approaching_transit, find_preemption_cells, prepare_intentions};
+use crate::conflicts::{
+    ConflictError, ConflictSolverError, ConflictType, apply_gap_acceptance, collect_conflicts, find_merge_conflict_zone, solve_conflicts,
+};
+use crate::movement::{MovementError, anticipation_movement, movement};
+use crate::pedestrians::{Pedestrian, PedestrianError, PedestrianID, pedestrians_movement};
+use crate::shortest_path::router::shortest_path;
+use crate::route_choice::{LinkTravelTimes, Route, RouteSplits, SlicedTravelTimes, find_routes, sample_index};
+use crate::simulation::gridlock::{find_gridlocks, teleport_downstream, Gridlock, GridlockPolicy, GridlockResolution, TELEPORT_LOOK_AHEAD};
+use crate::simulation::states::{AutomataState, BusStopEvent, PedestrianState, VehicleDeparture, VehicleState};
 use crate::traffic_lights::lights::{TrafficLightID, TrafficLight};
+use crate::transit::BusStop;
+use crate::utils::rand::derive_seed;
+use rand::{SeedableRng, rngs::StdRng};
 use crate::verbose::*;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use uuid::Uuid;
 use std::fmt;
 use std::time::{SystemTime, UNIX_EPOCH};
 use rand::Rng;
 
+/// Stream index used to derive route choice random streams from the session's seed
+const ROUTE_CHOICE_STREAM: u64 = 1;
+
+/// Stream index used to derive driver parameters sampling random streams from the session's seed
+const BEHAVIOUR_STREAM: u64 = 2;
+
 /// Custom error types for `Session`.
 #[derive(Debug, Clone)]
 pub enum SessionError {
@@ -35,6 +52,8 @@ pub enum SessionError {
     CellNotFound(CellID),
     /// Movement error
     MovementError(MovementError),
+    /// Pedestrians movement error
+    PedestrianError(PedestrianError),
 }
 
 impl fmt::Display for SessionError {
@@ -62,6 +81,9 @@ impl fmt::Display for SessionError {
             SessionError::MovementError(err) => {
                 write!(f, "Movement error: {}", err)
             },
+            SessionError::PedestrianError(err) => {
+                write!(f, "Pedestrian error: {}", err)
+            },
         }
     }
 }
@@ -98,6 +120,12 @@ impl From<MovementError> for SessionError {
     }
 }
 
+impl From<PedestrianError> for SessionError {
+    fn from(err: PedestrianError) -> Self {
+        SessionError::PedestrianError(err)
+    }
+}
+
 /// Session - representation of session for Cellular Automata with Traffic lights control management
 pub struct Session {
     /// Current position mapping from cell ID to vehicle ID
@@ -109,16 +137,44 @@ pub struct Session {
     /// Trips for automatic vehicle generation
     trips_data: HashMap<TripID, Trip>,
 
+    /// Alternative routes for trips with route choice (calculated on demand)
+    trips_routes: HashMap<TripID, Vec<Route>>,
+
+    /// Current travel times of mesoscopic links (used by route choice)
+    travel_times: LinkTravelTimes,
+
+    /// Record of experienced travel times per link and time slice (disabled by default)
+    travel_times_record: Option<SlicedTravelTimes>,
+
+    /// Fixed routes shares for trips with route choice (e.g. from dynamic user equilibrium)
+    route_splits: Option<RouteSplits>,
+
+    /// Driver model for vehicles which do not have own one (NaSch rules if not set)
+    driver_model: Option<DriverModelRef>,
+
+    /// Distributions of driver parameters for trips which do not have own ones
+    behaviour_distributions: Option<BehaviourDistributions>,
+
     /// Vehicles storage
     vehicles: VehiclesStorage,
 
+    /// Pedestrians storage (ordered by identifiers, so pedestrians move in the same order every run)
+    pedestrians: BTreeMap<PedestrianID, Pedestrian>,
+
     /// Cells under traffic lights control
     /// It could be just Cell, but we'll use CellID for now
     _coordination_cells: HashMap<CellID, CellID>,
 
     /// Information about conflicts zones and corresponding cells
     conflict_zones: HashMap<ConflictZoneID, ConflictZone>,
-    cells_conflicts_zones: HashMap<CellID, ConflictZoneID>,
+    cells_conflicts_zones: HashMap<CellID, Vec<ConflictZoneID>>,
+    /// Steps when minor stream vehicles have entered conflict zones last time (see `apply_gap_acceptance`)
+    minor_entries: HashMap<ConflictZoneID, i32>,
+
+    /// Unsignalised junctions with priority controls on their approaches
+    priority_junctions: HashMap<PriorityJunctionID, PriorityJunction>,
+    /// Junction boxes locked by movements passing through them
+    junction_boxes: HashMap<JunctionBoxID, JunctionBox>,
 
     /// Unique session identifier
     id: Uuid,
@@ -129,6 +185,9 @@ pub struct Session {
     /// Last applied vehicle identifier
     last_vehicle_id: VehicleID,
 
+    /// Last applied pedestrian identifier
+    last_pedestrian_id: PedestrianID,
+
     /// Debugging information level
     verbose: LocalLogger,
 
@@ -140,6 +199,28 @@ pub struct Session {
 
     /// Defines the SRID of the world
     world_srid: SRID,
+
+    /// Seed for vehicles random streams. If it is not set then streams are seeded from OS entropy
+    rng_seed: Option<u64>,
+
+    /// Side of the road vehicles keep to (right-hand traffic by default)
+    driving_side: DrivingSide,
+
+    /// Number of cells ahead of emergency vehicles for which traffic lights are preempted
+    preemption_distance: i32,
+
+    /// Policy resolving gridlocks
+    gridlock_policy: GridlockPolicy,
+    /// Gridlocks observed on the previous step mapped to the steps they have been observed first at
+    gridlocks_since: HashMap<Vec<VehicleID>, i32>,
+
+    /// Bus stops on transit cells
+    bus_stops: HashMap<CellID, BusStop>,
+
+    /// Number of vehicles generated by each trip (used for scheduled departures)
+    trips_departures: HashMap<TripID, usize>,
+    /// Vehicles generated since the last state dump
+    departures: Vec<VehicleDeparture>,
 }
 
 impl Session {
@@ -152,12 +233,23 @@ impl Session {
             id: session_id,
             last_vehicle_id: 1,
             vehicles: VehiclesStorage::new(),
+            last_pedestrian_id: 1,
+            pedestrians: BTreeMap::new(),
             grids_storage: GridsStorage::new().build(),
             trips_data: HashMap::new(),
+            trips_routes: HashMap::new(),
+            travel_times: LinkTravelTimes::new(),
+            travel_times_record: None,
+            route_splits: None,
+            driver_model: None,
+            behaviour_distributions: None,
             verbose,
             _coordination_cells: HashMap::new(),
             conflict_zones: HashMap::new(),
             cells_conflicts_zones: HashMap::new(),
+            minor_entries: HashMap::new(),
+            priority_junctions: HashMap::new(),
+            junction_boxes: HashMap::new(),
             current_position: HashMap::new(),
             _updated_at: SystemTime::now()
                 .duration_since(UNIX_EPOCH)
@@ -166,6 +258,14 @@ impl Session {
             _expire_at: 0,
             steps: 0,
             world_srid: picked_srid,
+            rng_seed: None,
+            driving_side: DrivingSide::default(),
+            preemption_distance: 10,
+            gridlock_policy: GridlockPolicy::default(),
+            gridlocks_since: HashMap::new(),
+            bus_stops: HashMap::new(),
+            trips_departures: HashMap::new(),
+            departures: Vec::new(),
         }
     }
 
@@ -179,12 +279,23 @@ impl Session {
             id: session_id,
             last_vehicle_id: 1,
             vehicles: VehiclesStorage::new(),
+            last_pedestrian_id: 1,
+            pedestrians: BTreeMap::new(),
             grids_storage,
             trips_data: HashMap::new(),
+            trips_routes: HashMap::new(),
+            travel_times: LinkTravelTimes::new(),
+            travel_times_record: None,
+            route_splits: None,
+            driver_model: None,
+            behaviour_distributions: None,
             verbose,
             _coordination_cells: HashMap::new(),
             conflict_zones: HashMap::new(),
             cells_conflicts_zones: HashMap::new(),
+            minor_entries: HashMap::new(),
+            priority_junctions: HashMap::new(),
+            junction_boxes: HashMap::new(),
             current_position: HashMap::new(),
             _updated_at: SystemTime::now()
                 .duration_since(UNIX_EPOCH)
@@ -193,6 +304,14 @@ impl Session {
             _expire_at: 0,
             steps: 0,
             world_srid: picked_srid,
+            rng_seed: None,
+            driving_side: DrivingSide::default(),
+            preemption_distance: 10,
+            gridlock_policy: GridlockPolicy::default(),
+            gridlocks_since: HashMap::new(),
+            bus_stops: HashMap::new(),
+            trips_departures: HashMap::new(),
+            departures: Vec::new(),
         }
     }
 
@@ -226,11 +345,155 @@ impl Session {
         self.verbose.set_level(verbose);
     }
 
+    /// Gets the seed for vehicles random streams
+    pub fn get_rng_seed(&self) -> Option<u64> {
+        self.rng_seed
+    }
+
+    /// Sets the seed for vehicles random streams.
+    ///
+    /// On each step every vehicle gets its own random stream derived from the seed,
+    /// the step number and the vehicle's ID, so simulation is reproducible even when
+    /// intentions are computed in parallel (see `rayon` feature).
+    /// Pass `None` to seed streams from OS entropy (default behaviour).
+    pub fn set_rng_seed(&mut self, seed: Option<u64>) {
+        self.rng_seed = seed;
+    }
+
+    /// Gets the driving side
+    pub fn get_driving_side(&self) -> DrivingSide {
+        self.driving_side
+    }
+
+    /// Sets the side of the road vehicles keep to (right-hand traffic by default).
+    ///
+    /// Driving side defines the overtaking lane and keep-right (keep-left) rule of discretionary lane changing,
+    /// the preferred lane of blocked vehicles and priorities in lane change conflicts.
+    ///
+    /// # Example
+    /// ```
+    /// use micro_traffic_sim_core::simulation::session::Session;
+    /// use micro_traffic_sim_core::maneuver::DrivingSide;
+    ///
+    /// let mut session = Session::default(None);
+    /// session.set_driving_side(DrivingSide::Left);
+    /// assert_eq!(session.get_driving_side(), DrivingSide::Left);
+    /// ```
+    pub fn set_driving_side(&mut self, driving_side: DrivingSide) {
+        self.driving_side = driving_side;
+    }
+
+    /// Gets the signal preemption distance
+    pub fn get_preemption_distance(&self) -> i32 {
+        self.preemption_distance
+    }
+
+    /// Sets the number of cells ahead of emergency vehicles (see [`AgentType::Emergency`](crate::agents_types::AgentType::Emergency))
+    /// for which traffic lights are preempted: signal groups controlling these cells are held open
+    /// until the vehicle passes, then traffic lights return to their regular cycles. Default is 10 cells.
+    /// Zero disables the preemption.
+    ///
+    /// # Example
+    /// ```
+    /// use micro_traffic_sim_core::simulation::session::Session;
+    ///
+    /// let mut session = Session::default(None);
+    /// session.set_preemption_distance(0);
+    /// assert_eq!(session.get_preemption_distance(), 0);
+    /// ```
+    pub fn set_preemption_distance(&mut self, distance: i32) {
+        self.preemption_distance = distance;
+    }
+
+    /// Gets the gridlock resolution policy
+    pub fn get_gridlock_policy(&self) -> GridlockPolicy {
+        self.gridlock_policy
+    }
+
+    /// Sets the policy resolving gridlocks (circular waits of vehicles, see [`find_gridlocks`]).
+    /// Gridlocks are reported in the step output with any policy. Default is [`GridlockPolicy::Report`].
+    ///
+    /// # Example
+    /// ```
+    /// use micro_traffic_sim_core::simulation::gridlock::GridlockPolicy;
+    /// use micro_traffic_sim_core::simulation::session::Session;
+    ///
+    /// let mut session = Session::default(None);
+    /// session.set_gridlock_policy(GridlockPolicy::Teleport { timeout: 30 });
+    /// assert_eq!(session.get_gridlock_policy(), GridlockPolicy::Teleport { timeout: 30 });
+    /// ```
+    pub fn set_gridlock_policy(&mut self, policy: GridlockPolicy) {
+        self.gridlock_policy = policy;
+    }
+
+    /// Sets the driver model for vehicles which do not have own one (see [`Vehicle::driver_model`]).
+    ///
+    /// The model is assigned to vehicles when they are added to the session or generated by trips
+    /// without a driver model, so it is better to set it before adding vehicles and trips.
+    /// Pass `None` to fall back to NaSch rules.
+    ///
+    /// # Example
+    /// ```
+    /// use micro_traffic_sim_core::simulation::session::Session;
+    /// use micro_traffic_sim_core::behaviour::VdrModel;
+    /// use std::sync::Arc;
+    ///
+    /// let mut session = Session::default(None);
+    /// session.set_driver_model(Some(Arc::new(VdrModel::new(0.5))));
+    /// assert!(session.get_driver_model().is_some());
+    /// ```
+    pub fn set_driver_model(&mut self, driver_model: Option<DriverModelRef>) {
+        self.driver_model = driver_model;
+    }
+
+    /// Returns the driver model for vehicles which do not have own one
+    pub fn get_driver_model(&self) -> Option<&DriverModelRef> {
+        self.driver_model.as_ref()
+    }
+
+    /// Sets distributions of driver parameters for vehicles generated by trips which do not have own ones
+    /// (see [`Trip::behaviour_distributions`]). Pass `None` to use the behaviour types' parameters only.
+    ///
+    /// Every generated vehicle samples its parameters from a random stream derived from the session's seed
+    /// (see [`Session::set_rng_seed`]), so runs with the same seed give the same drivers.
+    ///
+    /// # Example
+    /// ```
+    /// use micro_traffic_sim_core::simulation::session::Session;
+    /// use micro_traffic_sim_core::behaviour::{BehaviourDistributions, ParameterDistribution};
+    ///
+    /// let mut session = Session::default(None);
+    /// session.set_behaviour_distributions(Some(
+    ///     BehaviourDistributions::new()
+    ///         .with_speed_compliance(ParameterDistribution::Uniform { min: 0.8, max: 1.2 })
+    ///         .build(),
+    /// ));
+    /// assert!(session.get_behaviour_distributions().is_some());
+    /// ```
+    pub fn set_behaviour_distributions(&mut self, distributions: Option<BehaviourDistributions>) {
+        self.behaviour_distributions = distributions;
+    }
+
+    /// Returns distributions of driver parameters for trips which do not have own ones
+    pub fn get_behaviour_distributions(&self) -> Option<&BehaviourDistributions> {
+        self.behaviour_distributions.as_ref()
+    }
+
     /// Returns a reference to the cell with the given ID if it exists in the vehicles grid.
     pub fn get_cell(&self, cell_id: &CellID) -> Option<&Cell> {
         self.grids_storage.get_cell(cell_id)
     }
 
+    /// Returns a reference to the vehicles grid. It is shortcut to GridsStorage's get_vehicles_net_ref method
+    pub fn get_vehicles_net_ref(&self) -> &GridRoads {
+        self.grids_storage.get_vehicles_net_ref()
+    }
+
+    /// Returns a reference to the pedestrians grid. It is shortcut to GridsStorage's get_pedestrians_net_ref method
+    pub fn get_pedestrians_net_ref(&self) -> &GridRoads {
+        self.grids_storage.get_pedestrians_net_ref()
+    }
+
     /// Returns a reference to the traffic light with the given ID if it exists in the traffic lights storage.
     pub fn get_tls_ref(&self, ) -> &HashMap<TrafficLightID, TrafficLight> {
         self.grids_storage.get_tls_ref()
@@ -256,14 +519,19 @@ impl Session {
 
         // Add trip to storage
         self.trips_data.insert(trip_id, trip);
+        self.trips_routes.remove(&trip_id);
 
         trip_id
     }
 
-    /// Adds given vehicles to the session vehicles storage
+    /// Adds given vehicles to the session vehicles storage.
+    /// Vehicles without own driver model get the session's one (see [`Session::set_driver_model`])
     pub fn add_vehicles(&mut self, vehicles: Vec<Vehicle>) {
-        for vehicle in vehicles {
+        for mut vehicle in vehicles {
             let vehicle_id = vehicle.id;
+            if vehicle.driver_model.is_none() {
+                vehicle.driver_model = self.driver_model.clone();
+            }
             self.vehicles.insert(vehicle_id, vehicle);
             if vehicle_id >= self.last_vehicle_id {
                 self.last_vehicle_id = vehicle_id + 1;
@@ -274,12 +542,79 @@ impl Session {
     /// Returns a reference to the vehicles storage
     pub fn get_vehicles(&self) -> &VehiclesStorage { &self.vehicles }
 
+    /// Adds given pedestrians to the session pedestrians storage
+    pub fn add_pedestrians(&mut self, pedestrians: Vec<Pedestrian>) {
+        for pedestrian in pedestrians {
+            let pedestrian_id = pedestrian.id;
+            self.pedestrians.insert(pedestrian_id, pedestrian);
+            if pedestrian_id >= self.last_pedestrian_id {
+                self.last_pedestrian_id = pedestrian_id + 1;
+            }
+        }
+    }
+
+    /// Returns a reference to the pedestrians storage
+    pub fn get_pedestrians(&self) -> &BTreeMap<PedestrianID, Pedestrian> { &self.pedestrians }
+
+    /// Adds cells to the pedestrians grid. It is shortcut to GridsStorage's add_pedestrian_cells method
+    pub fn add_pedestrian_cells(&mut self, cells_data: Vec<crate::grid::cell::Cell>) {
+        self.grids_storage.add_pedestrian_cells(cells_data);
+    }
+
     /// Adds cells to the grids. It is shortcut to GridsStorage's add_cells method
     pub fn add_cells(&mut self, cells_data: Vec<crate::grid::cell::Cell>) {
         self.grids_storage.add_cells(cells_data);
+        // Grid has been changed, so alternative routes should be recalculated
+        self.trips_routes.clear();
+    }
+
+    /// Returns alternative routes of the trip (available after the first vehicle generation for trips with route choice)
+    pub fn get_trip_routes(&self, trip_id: TripID) -> Option<&Vec<Route>> {
+        self.trips_routes.get(&trip_id)
+    }
+
+    /// Returns a reference to the current links travel times (observed only while the session has trips with route choice)
+    pub fn get_travel_times(&self) -> &LinkTravelTimes {
+        &self.travel_times
+    }
+
+    /// Returns a mutable reference to the current links travel times (e.g. to change decay factor)
+    pub fn get_travel_times_mut(&mut self) -> &mut LinkTravelTimes {
+        &mut self.travel_times
+    }
+
+    /// Enables (or disables with `None`) recording of experienced travel times per link and time slice.
+    /// Recording starts from the next step; the record is cleared on [`Session::reset`].
+    /// Like current travel times, the record is fed only while the session has trips with route choice.
+    pub fn set_travel_times_record(&mut self, record: Option<SlicedTravelTimes>) {
+        self.travel_times_record = record;
     }
 
-    /// Resets current/done vehicles, steps number, last vehicle ID, traffic lights states, trips
+    /// Returns a reference to the record of experienced travel times if recording is enabled
+    pub fn get_travel_times_record(&self) -> Option<&SlicedTravelTimes> {
+        self.travel_times_record.as_ref()
+    }
+
+    /// Takes the record of experienced travel times out of the session (recording gets disabled)
+    pub fn take_travel_times_record(&mut self) -> Option<SlicedTravelTimes> {
+        self.travel_times_record.take()
+    }
+
+    /// Sets fixed routes shares for trips with route choice. Pass `None` to use the logit model on
+    /// current travel times (default behaviour). Splits are kept on [`Session::reset`].
+    ///
+    /// Splits are ignored for a trip and time slice if there is no entry for them or the number of
+    /// probabilities differs from the number of the trip's alternative routes.
+    pub fn set_route_splits(&mut self, route_splits: Option<RouteSplits>) {
+        self.route_splits = route_splits;
+    }
+
+    /// Returns a reference to the fixed routes shares if they are set
+    pub fn get_route_splits(&self) -> Option<&RouteSplits> {
+        self.route_splits.as_ref()
+    }
+
+    /// Resets current/done vehicles and pedestrians, steps number, last vehicle ID, traffic lights states, bus stops departures, trips
     pub fn reset(&mut self) {
         self.verbose.log_with_fields(
             EVENT_SIMULATION_RESET,
@@ -292,18 +627,42 @@ impl Session {
             ]
         );
 
-        // Clear vehicles
+        // Clear vehicles and pedestrians
         self.vehicles.clear();
+        self.pedestrians.clear();
 
-        // Reset traffic lights
+        // Reset traffic lights and release crosswalks and junctions approaches
         self.grids_storage.tls_reset();
+        self.grids_storage.release_crosswalks();
+        self.grids_storage.release_approaches();
+        for junction in self.priority_junctions.values_mut() {
+            junction.reset();
+        }
+        for junction_box in self.junction_boxes.values_mut() {
+            junction_box.reset();
+        }
+        for bus_stop in self.bus_stops.values_mut() {
+            bus_stop.reset();
+        }
+        self.gridlocks_since.clear();
+        self.minor_entries.clear();
 
         // Clear trips
         self.trips_data.clear();
+        self.trips_routes.clear();
+        self.trips_departures.clear();
+        self.departures.clear();
+
+        // Clear travel times observations
+        self.travel_times.clear();
+        if let Some(record) = self.travel_times_record.as_mut() {
+            record.clear();
+        }
 
         // Reset counters
         self.steps = 0;
         self.last_vehicle_id = 1;
+        self.last_pedestrian_id = 1;
     }
 
     /// Adds traffic lights to the traffic lights storage.
@@ -312,31 +671,173 @@ impl Session {
         self.grids_storage.add_traffic_light(tl);
     }
 
+    /// Adds bus stop to the session storage (see [`transit`](crate::transit)). Bus stop replaces
+    /// the existing one on the same transit cell.
+    ///
+    /// # Example
+    /// ```
+    /// use micro_traffic_sim_core::simulation::session::Session;
+    /// use micro_traffic_sim_core::transit::{BusStop, DwellTime};
+    ///
+    /// let mut session = Session::default(None);
+    /// session.add_bus_stop(BusStop::new(1, 25).with_dwell_time(DwellTime::Fixed(10)).build());
+    /// assert_eq!(session.get_bus_stops()[&25].get_id(), 1);
+    /// ```
+    pub fn add_bus_stop(&mut self, bus_stop: BusStop) {
+        self.bus_stops.insert(bus_stop.get_cell_id(), bus_stop);
+    }
+
+    /// Returns bus stops mapped by their transit cells
+    pub fn get_bus_stops(&self) -> &HashMap<CellID, BusStop> {
+        &self.bus_stops
+    }
+
     /// Adds conflict zone to the session storage and maps cells to the conflict zone
     pub fn add_conflict_zone(&mut self, conflict_zone: ConflictZone) {
         let conflict_zone_id = conflict_zone.get_id();
         // Map cells to conflict zone
         let first_edge = conflict_zone.get_first_edge();
         let second_edge = conflict_zone.get_second_edge();
-        if first_edge.target >= 0 {
-            self.cells_conflicts_zones.insert(first_edge.target, conflict_zone_id);
-        }
-        if second_edge.target >= 0 {
-            self.cells_conflicts_zones.insert(second_edge.target, conflict_zone_id);
+        for target in [first_edge.target, second_edge.target] {
+            if target < 0 {
+                continue;
+            }
+            // Several conflict zones could share the same cell (e.g. merges fanning into one cell)
+            let zone_ids = self.cells_conflicts_zones.entry(target).or_default();
+            if !zone_ids.contains(&conflict_zone_id) {
+                zone_ids.push(conflict_zone_id);
+            }
         }
         // Add conflict zone to storage
         self.conflict_zones.insert(conflict_zone_id, conflict_zone);
     }
 
+    /// Detects conflict zones in the vehicles grid (see [`detect_conflict_zones`]) and adds the ones
+    /// which are not declared yet. New zones get identifiers following the greatest existing one.
+    ///
+    /// # Arguments
+    /// * `rule` - Default priority rule for detected zones
+    ///
+    /// # Returns
+    /// Identifiers of added conflict zones
+    ///
+    /// # Example
+    /// ```
+    /// use micro_traffic_sim_core::conflict_zones::ConflictWinnerRule;
+    /// use micro_traffic_sim_core::grid::{cell::Cell, road_network::GridRoads};
+    /// use micro_traffic_sim_core::simulation::grids_storage::GridsStorage;
+    /// use micro_traffic_sim_core::simulation::session::Session;
+    ///
+    /// // Two roads merge into cell 3
+    /// let mut grid = GridRoads::new();
+    /// grid.add_cell(Cell::new(1).with_forward_node(3).build());
+    /// grid.add_cell(Cell::new(2).with_forward_node(3).build());
+    /// grid.add_cell(Cell::new(3).build());
+    /// let mut session = Session::new(GridsStorage::new().with_vehicles_net(grid).build(), None);
+    /// assert_eq!(session.add_detected_conflict_zones(ConflictWinnerRule::Equal), vec![1]);
+    /// // Already declared zones are skipped
+    /// assert!(session.add_detected_conflict_zones(ConflictWinnerRule::Equal).is_empty());
+    /// ```
+    pub fn add_detected_conflict_zones(&mut self, rule: ConflictWinnerRule) -> Vec<ConflictZoneID> {
+        let first_id = self.conflict_zones.keys().max().map_or(1, |max_id| max_id + 1);
+        let detected = detect_conflict_zones(self.get_vehicles_net_ref(), rule, first_id);
+        let mut added = Vec::new();
+        for conflict_zone in detected {
+            let (first_edge, second_edge) = (conflict_zone.get_first_edge(), conflict_zone.get_second_edge());
+            let declared = self.conflict_zones.values().any(|zone| {
+                (zone.get_first_edge() == first_edge && zone.get_second_edge() == second_edge)
+                    || (zone.get_first_edge() == second_edge && zone.get_second_edge() == first_edge)
+            });
+            if declared {
+                continue;
+            }
+            let conflict_zone_id = first_id + added.len() as ConflictZoneID;
+            let conflict_zone = ConflictZone::new(conflict_zone_id, first_edge.clone(), second_edge.clone())
+                .with_winner_type(conflict_zone.get_winner_type())
+                .build();
+            self.add_conflict_zone(conflict_zone);
+            added.push(conflict_zone_id);
+        }
+        added
+    }
+
+    /// Adds unsignalised junction with priority controls on its approaches (see [`PriorityJunction`])
+    ///
+    /// # Example
+    /// ```
+    /// use micro_traffic_sim_core::conflict_zones::{ConflictZoneType, PriorityJunction};
+    /// use micro_traffic_sim_core::simulation::session::Session;
+    ///
+    /// let mut session = Session::default(None);
+    /// session.add_priority_junction(
+    ///     PriorityJunction::new(1)
+    ///         .with_approach(14, ConflictZoneType::PriorityRoad)
+    ///         .with_approach(105, ConflictZoneType::Yield)
+    ///         .build(),
+    /// );
+    /// assert_eq!(session.get_priority_junctions().len(), 1);
+    /// ```
+    pub fn add_priority_junction(&mut self, junction: PriorityJunction) {
+        self.priority_junctions.insert(junction.get_id(), junction);
+    }
+
+    /// Adds junction box locked by movements passing through it (see [`JunctionBox`])
+    ///
+    /// # Example
+    /// ```
+    /// use micro_traffic_sim_core::conflict_zones::JunctionBox;
+    /// use micro_traffic_sim_core::simulation::session::Session;
+    ///
+    /// let mut session = Session::default(None);
+    /// session.add_junction_box(JunctionBox::new(1).with_cells(vec![10, 11, 20, 21]).build());
+    /// assert_eq!(session.get_junction_boxes().len(), 1);
+    /// ```
+    pub fn add_junction_box(&mut self, junction_box: JunctionBox) {
+        self.junction_boxes.insert(junction_box.get_id(), junction_box);
+    }
+
+    /// Returns junction boxes mapped by their identifiers
+    pub fn get_junction_boxes(&self) -> &HashMap<JunctionBoxID, JunctionBox> {
+        &self.junction_boxes
+    }
+
+    /// Adds conflict zones and yield-at-entry junctions of the roundabout (see [`Roundabout`]).
+    /// Cells of the roundabout should be added to the vehicles grid beforehand. The roundabout should be generated
+    /// for the driving side of the session (see [`RoundaboutBuilder::with_driving_side`](crate::conflict_zones::RoundaboutBuilder::with_driving_side)).
+    ///
+    /// # Example
+    /// ```
+    /// use micro_traffic_sim_core::conflict_zones::Roundabout;
+    /// use micro_traffic_sim_core::simulation::session::Session;
+    ///
+    /// let mut session = Session::default(None);
+    /// session.add_roundabout(&Roundabout::new().with_arms(3).build());
+    /// assert_eq!(session.get_priority_junctions().len(), 3);
+    /// ```
+    pub fn add_roundabout(&mut self, roundabout: &Roundabout) {
+        for conflict_zone in roundabout.get_conflict_zones() {
+            self.add_conflict_zone(conflict_zone.clone());
+        }
+        for junction in roundabout.get_junctions() {
+            self.add_priority_junction(junction.clone());
+        }
+    }
+
+    /// Returns unsignalised junctions mapped by their identifiers
+    pub fn get_priority_junctions(&self) -> &HashMap<PriorityJunctionID, PriorityJunction> {
+        &self.priority_junctions
+    }
+
     /// Generates a single vehicle based on trip parameters
-    fn generate_vehicle(&self, trip: &Trip, trip_id: TripID) -> Option<Vehicle> {
+    /// Checks if the trip should generate an agent at the current time step
+    fn should_generate(&self, trip: &Trip, trip_id: TripID) -> bool {
         // Check if current time step is within trip time bounds
         if self.steps < trip.start_time || self.steps > trip.end_time {
-            return None;
+            return false;
         }
 
-        // Determine if vehicle should be generated based on trip type
-        let should_generate = match trip.trip_type {
+        // Determine if agent should be generated based on trip type
+        match trip.trip_type {
             TripType::Constant => {
                 // Generate vehicle every 'time' seconds
                 if trip.time <= 0 {
@@ -351,6 +852,11 @@ impl Session {
                 let norm_value: f64 = rng.random();
                 norm_value < trip.probability
             }
+            TripType::Timetable | TripType::Headway => {
+                // Departures which have not been made in time (origin cell has been occupied) are made as soon as possible
+                let departures_made = self.trips_departures.get(&trip_id).copied().unwrap_or(0);
+                trip.scheduled_departures(self.steps) > departures_made
+            }
             _ => {
                 if self.verbose.is_at_least(VerboseLevel::Detailed) {
                     self.verbose.log_with_fields(
@@ -364,14 +870,16 @@ impl Session {
                 }
                 false
             }
-        };
+        }
+    }
 
-        if !should_generate {
+    fn generate_vehicle(&self, trip: &Trip, trip_id: TripID) -> Option<Vehicle> {
+        if !self.should_generate(trip, trip_id) {
             return None;
         }
 
         // Determine target node
-        let target_node = if trip.allowed_agent_type == AgentType::Bus
+        let target_node = if matches!(trip.allowed_agent_type, AgentType::Bus | AgentType::LargeBus)
             && !trip.transit_cells.is_empty() {
             trip.transit_cells[0] // First transit cell for buses
         } else {
@@ -381,15 +889,16 @@ impl Session {
         // Create behaviour parameters based on allowed behaviour type
         let behaviour_params = BehaviourParameters::from_behaviour_type(trip.allowed_behaviour_type);
 
-        // Determine speed limit: use trip's explicit value if set, otherwise from behaviour
+        // Determine speed limit: use trip's explicit value if set, otherwise from behaviour (capped by agent's capabilities)
         let speed_limit = if trip.speed_limit >= 0 {
             trip.speed_limit
         } else {
-            behaviour_params.speed_limit()
+            let speed_limit = behaviour_params.speed_limit();
+            trip.allowed_agent_type.max_speed_default().map_or(speed_limit, |max_speed| speed_limit.min(max_speed))
         };
 
         // Create vehicle using builder pattern
-        let vehicle = Vehicle::new(self.last_vehicle_id)
+        let mut vehicle = Vehicle::new(self.last_vehicle_id)
             .with_type(trip.allowed_agent_type)
             .with_behaviour(trip.allowed_behaviour_type)
             .with_cell(trip.from_node)
@@ -404,12 +913,137 @@ impl Session {
             .with_transit_cells(trip.transit_cells.clone())
             .with_relax_time(trip.relax_time)
             .build();
+        vehicle.driver_model = trip.driver_model.clone().or_else(|| self.driver_model.clone());
+        vehicle.mobil = trip.mobil;
+        vehicle.lane_selection_distance = trip.lane_selection_distance;
+
+        // Initial heading is along the lane (see `is_turning`)
+        let net = self.grids_storage.get_vehicles_net_ref();
+        if let Some(cell) = net.get_cell(&trip.from_node)
+            && let Some(forward_cell) = net.get_cell(&cell.get_forward_id())
+        {
+            vehicle.bearing = get_bearing(cell.get_point(), forward_cell.get_point());
+        }
+
+        // Heterogeneous drivers: sample parameters on top of the behaviour type's ones
+        let distributions = trip.behaviour_distributions.as_ref().or(self.behaviour_distributions.as_ref());
+        let agent_slow_down = trip.allowed_agent_type.slow_down_distribution_default()
+            .filter(|_| distributions.is_none_or(|distributions| distributions.slow_down_factor.is_none()));
+        if distributions.is_some() || agent_slow_down.is_some() {
+            let mut rng = match self.rng_seed {
+                Some(seed) => StdRng::seed_from_u64(derive_seed(derive_seed(seed, BEHAVIOUR_STREAM), vehicle.id)),
+                None => StdRng::from_rng(&mut rand::rng()),
+            };
+            // Agent's own slowdown variance (e.g. cyclists) unless distributions set it explicitly
+            if let Some(value) = agent_slow_down.and_then(|distribution| distribution.sample(&mut rng)) {
+                vehicle.slow_down_factor = value.clamp(0.0, 1.0);
+            }
+            if let Some(distributions) = distributions {
+                distributions.apply(&mut vehicle, &mut rng);
+            }
+        }
+
+        // Assign route if trip has route choice (transit trips follow their transit cells)
+        if target_node == trip.to_node
+            && let (Some(route_choice), Some(routes)) = (&trip.route_choice, self.trips_routes.get(&trip_id))
+        {
+            // Route choice stream is separated from intentions streams (see `step`) by an extra derivation
+            let mut rng = match self.rng_seed {
+                Some(seed) => {
+                    let step_seed = derive_seed(derive_seed(seed, ROUTE_CHOICE_STREAM), self.steps as u64);
+                    StdRng::seed_from_u64(derive_seed(step_seed, vehicle.id))
+                }
+                None => StdRng::from_rng(&mut rand::rng()),
+            };
+            let fixed_split = self.route_splits
+                .as_ref()
+                .and_then(|splits| splits.get_at(trip_id, self.steps))
+                .filter(|probabilities| probabilities.len() == routes.len());
+            let route = match fixed_split {
+                Some(probabilities) => sample_index(probabilities, &mut rng).map(|idx| &routes[idx]),
+                None => route_choice.choose(routes, self.grids_storage.get_vehicles_net_ref(), &self.travel_times, &mut rng),
+            };
+            if let Some(route) = route {
+                vehicle.route_id = Some(route.id);
+                vehicle.route = route.cells.clone();
+            }
+        }
 
         Some(vehicle)
     }
 
+    /// Generates pedestrian walking the shortest path through the pedestrian network
+    fn generate_pedestrian(&self, trip: &Trip, trip_id: TripID) -> Option<Pedestrian> {
+        if !self.should_generate(trip, trip_id) {
+            return None;
+        }
+        let pedestrians_net = self.grids_storage.get_pedestrians_net_ref();
+        let path = match (pedestrians_net.get_cell(&trip.from_node), pedestrians_net.get_cell(&trip.to_node)) {
+            (Some(start), Some(goal)) => shortest_path(start, goal, pedestrians_net, true, None, AgentType::Pedestrian)
+                .map(|path| path.vertices().iter().skip(1).map(|cell| cell.get_id()).collect::<Vec<CellID>>())
+                .map_err(|e| e.to_string()),
+            _ => Err("Trip's cells are missing in the pedestrians grid".to_string()),
+        };
+        let path = match path {
+            Ok(path) => path,
+            Err(e) => {
+                if self.verbose.is_at_least(VerboseLevel::Main) {
+                    self.verbose.log_with_fields(
+                        EVENT_GEN_PEDESTRIAN,
+                        "Can't find path for pedestrian",
+                        &[
+                            ("trip_id", &trip_id),
+                            ("error", &e),
+                        ]
+                    );
+                }
+                return None;
+            }
+        };
+        let pedestrian = Pedestrian::new(self.last_pedestrian_id)
+            .with_cell(trip.from_node)
+            .with_destination(trip.to_node)
+            .with_speed(if trip.speed_limit > 0 { trip.speed_limit } else { 1 })
+            .with_path(path)
+            .with_trip(trip_id)
+            .build();
+        Some(pedestrian)
+    }
+
+    /// Calculates alternative routes for trips with route choice which do not have them yet
+    fn prepare_trips_routes(&mut self) {
+        for (trip_id, trip) in &self.trips_data {
+            let route_choice = match &trip.route_choice {
+                Some(route_choice) => route_choice,
+                None => continue,
+            };
+            if self.trips_routes.contains_key(trip_id) {
+                continue;
+            }
+            let routes = match find_routes(trip.from_node, trip.to_node, self.grids_storage.get_vehicles_net_ref(), route_choice.routes_num, trip.allowed_agent_type) {
+                Ok(routes) => routes,
+                Err(e) => {
+                    // Vehicles of the trip will follow the shortest path
+                    if self.verbose.is_at_least(VerboseLevel::Main) {
+                        self.verbose.log_with_fields(
+                            EVENT_GEN_VEHICLES,
+                            "Can't find alternative routes for trip",
+                            &[
+                                ("trip_id", trip_id),
+                                ("error", &e),
+                            ]
+                        );
+                    }
+                    vec![]
+                }
+            };
+            self.trips_routes.insert(*trip_id, routes);
+        }
+    }
+
     /// Generates vehicles based on the trips data
     pub fn generate_vehicles(&mut self) {
+        self.prepare_trips_routes();
         if self.verbose.is_at_least(VerboseLevel::Main) {
             self.verbose.log_with_fields(
                 EVENT_GEN_VEHICLES,
@@ -422,6 +1056,29 @@ impl Session {
             );
         }
         for (trip_id, trip) in &self.trips_data {
+            // Pedestrians are generated in the pedestrians grid
+            if trip.allowed_agent_type == AgentType::Pedestrian {
+                if self.pedestrians.values().any(|pedestrian| pedestrian.cell_id == trip.from_node) {
+                    continue;
+                }
+                if let Some(pedestrian) = self.generate_pedestrian(trip, *trip_id) {
+                    if self.verbose.is_at_least(VerboseLevel::Additional) {
+                        self.verbose.log_with_fields(
+                            EVENT_GEN_PEDESTRIAN,
+                            "Generate pedestrian for trip",
+                            &[
+                                ("step", &self.steps),
+                                ("trip_id", trip_id),
+                                ("pedestrian_id", &pedestrian.id),
+                            ]
+                        );
+                    }
+                    self.last_pedestrian_id = pedestrian.id + 1;
+                    self.pedestrians.insert(pedestrian.id, pedestrian);
+                    *self.trips_departures.entry(*trip_id).or_insert(0) += 1;
+                }
+                continue;
+            }
             // Check if there's already a vehicle at the source node
             let mut create = true;
             for vehicle in self.vehicles.values() {
@@ -450,7 +1107,13 @@ impl Session {
                 }
 
                 let vehicle_id = generated_vehicle.id;
+                self.departures.push(VehicleDeparture {
+                    vehicle_id,
+                    trip_id: *trip_id,
+                    route_id: generated_vehicle.route_id,
+                });
                 self.vehicles.insert(vehicle_id, generated_vehicle);
+                *self.trips_departures.entry(*trip_id).or_insert(0) += 1;
                 self.last_vehicle_id = vehicle_id + 1; // Increment for next vehicle
             }
         }
@@ -494,10 +1157,29 @@ impl Session {
                     }
                 }
             }
+            // Buses in bays are out of the lane (see `BusStop`)
+            if vehicle.is_in_bay() {
+                continue;
+            }
+            self.current_position.insert(vehicle.cell_id, vehicle.id);
+            for &tail_cell in &vehicle.tail_cells {
+                self.current_position.insert(tail_cell, vehicle.id);
+            }
+        }
+        // Buses which have done dwelling in bays merge back into the lane once their cells are free
+        for vehicle in self.vehicles.values_mut() {
+            if !vehicle.is_in_bay() || vehicle.get_relax_countdown() > 0 {
+                continue;
+            }
+            let mut cells = std::iter::once(vehicle.cell_id).chain(vehicle.tail_cells.iter().copied());
+            if cells.any(|cell_id| self.current_position.contains_key(&cell_id)) {
+                continue;
+            }
             self.current_position.insert(vehicle.cell_id, vehicle.id);
             for &tail_cell in &vehicle.tail_cells {
                 self.current_position.insert(tail_cell, vehicle.id);
             }
+            vehicle.leave_bay();
         }
     }
 
@@ -507,7 +1189,7 @@ impl Session {
     /// ```text
     /// 1. Generate vehicles (trips)
     /// 2. Update positions
-    /// 3. Traffic light updates
+    /// 3. Traffic light updates and pedestrians movement
     /// 4. Prepare intentions      ← intentions module
     /// 5. Collect conflicts       ← conflicts module
     /// 6. Solve conflicts         ← conflicts module
@@ -534,11 +1216,77 @@ impl Session {
         // 2. Update current positions
         self.update_current_positions();
 
-        // 3. Update and collect TLS state
+        // 3. Update and collect TLS state. Signals are preempted for emergency vehicles and give priority to buses first
+        let preemption_cells = find_preemption_cells(
+            self.grids_storage.get_vehicles_net_ref(),
+            &self.vehicles,
+            self.preemption_distance,
+        )?;
+        self.grids_storage.apply_preemption(&preemption_cells);
+        let approaching_transit = find_approaching_transit(
+            self.grids_storage.get_vehicles_net_ref(),
+            &self.vehicles,
+            self.grids_storage.transit_detection_distance(),
+        )?;
+        self.grids_storage.set_approaching_transit(approaching_transit);
+        self.grids_storage.release_crosswalks();
+        self.grids_storage.release_approaches();
         let tl_states_dump = self.grids_storage.tick_traffic_lights(&self.verbose)?;
 
+        // Pedestrians cross before vehicles make their intentions, so vehicles yield to occupied crosswalks
+        pedestrians_movement(
+            self.grids_storage.get_pedestrians_net_ref(),
+            self.grids_storage.get_crosswalks_ref(),
+            &self.current_position,
+            &mut self.pedestrians,
+            &self.verbose,
+        )?;
+        let occupied_crosswalks = self.grids_storage.get_crosswalks_ref().occupied_cells(self.pedestrians.values());
+        self.grids_storage.occupy_crosswalks(&occupied_crosswalks);
+
+        // Unsignalised junctions: vehicles wait in front of approaches until they stop or find an acceptable gap
+        let vehicles_grid = self.grids_storage.get_vehicles_net_ref();
+        let held_approaches: HashSet<CellID> = self
+            .priority_junctions
+            .values_mut()
+            .flat_map(|junction| junction.held_cells(vehicles_grid, &self.current_position, &self.vehicles, self.steps))
+            .collect();
+        self.grids_storage.hold_approaches(&held_approaches);
+
         // 4. Create intentions for all vehicles
-    let collected_intentions = prepare_intentions(self.grids_storage.get_vehicles_net_ref(), &self.current_position, &mut self.vehicles, &self.verbose)?;
+        let step_seed = self.rng_seed.map(|seed| derive_seed(seed, self.steps as u64));
+        let mut collected_intentions = prepare_intentions(
+            self.grids_storage.get_vehicles_net_ref(),
+            &self.current_position,
+            &mut self.vehicles,
+            step_seed,
+            self.driving_side,
+            &self.verbose,
+        )?;
+
+        // Minor stream drivers hold back until they accept the gap in the major stream (see `apply_gap_acceptance`)
+        let mut held_back = apply_gap_acceptance(
+            self.grids_storage.get_vehicles_net_ref(),
+            &self.current_position,
+            &mut self.vehicles,
+            &self.conflict_zones,
+            &mut self.minor_entries,
+            self.steps,
+        );
+        // Vehicles enter junction boxes only if they can leave them in time and no other movement holds them
+        let mut junction_boxes: Vec<&mut JunctionBox> = self.junction_boxes.values_mut().collect();
+        junction_boxes.sort_by_key(|junction_box| junction_box.get_id());
+        for junction_box in junction_boxes {
+            held_back.extend(junction_box.hold_entering(
+                self.grids_storage.get_vehicles_net_ref(),
+                &self.current_position,
+                &mut self.vehicles,
+                self.steps,
+            ));
+        }
+        if !held_back.is_empty() {
+            collected_intentions = Intentions::from_vehicles(&mut self.vehicles);
+        }
 
         // 5. Collect conflicts
         let conflicts_data = collect_conflicts(
@@ -546,16 +1294,73 @@ impl Session {
             self.grids_storage.get_vehicles_net_ref(),
             &self.conflict_zones,
             &self.cells_conflicts_zones,
+            self.driving_side,
             &self.verbose,
             &mut self.vehicles,
         )?;
 
+        // Zipper merges: priority passes to the other flow after each conflict (see `ConflictWinnerType::Alternate`)
+        for conflict in conflicts_data.iter().filter(|conflict| conflict.conflict_type == ConflictType::MergeForwardConflictZone) {
+            // Zone is picked by the winner and any other participant (several zones could share the cell)
+            let Some(winner) = conflict
+                .participants
+                .get(conflict.priority_participant_index)
+                .and_then(|vehicle_id| self.vehicles.get(vehicle_id))
+            else {
+                continue;
+            };
+            let zone_id = conflict
+                .participants
+                .iter()
+                .filter(|&&vehicle_id| vehicle_id != winner.id)
+                .filter_map(|vehicle_id| self.vehicles.get(vehicle_id))
+                .find_map(|vehicle| {
+                    find_merge_conflict_zone(conflict.cell_id, &self.conflict_zones, &self.cells_conflicts_zones, winner, vehicle)
+                });
+            if let Some(zone) = zone_id.and_then(|zone_id| self.conflict_zones.get_mut(&zone_id)) {
+                zone.pass_turn();
+            }
+        }
+
         // 6. Solve conflicts
     solve_conflicts(conflicts_data, &mut self.vehicles, &self.verbose)?;
 
+        // Circular waits of vehicles staying in place (see `find_gridlocks`)
+        let mut held_by_boxes: HashMap<VehicleID, Vec<VehicleID>> = HashMap::new();
+        for junction_box in self.junction_boxes.values() {
+            for (&vehicle_id, waits_for) in junction_box.get_held() {
+                held_by_boxes.entry(vehicle_id).or_default().extend(waits_for.iter().copied());
+            }
+        }
+        let gridlocked = find_gridlocks(
+            self.grids_storage.get_vehicles_net_ref(),
+            &self.current_position,
+            &self.vehicles,
+            &held_by_boxes,
+        );
+        self.gridlocks_since.retain(|vehicles, _| gridlocked.contains(vehicles));
+        let mut gridlocks: Vec<Gridlock> = gridlocked
+            .into_iter()
+            .map(|vehicles| {
+                let since = *self.gridlocks_since.entry(vehicles.clone()).or_insert(self.steps);
+                Gridlock { vehicles, duration: self.steps - since + 1, resolution: None }
+            })
+            .collect();
+
         // 7. Move vehicles
         let vehicles_grid = self.grids_storage.get_vehicles_net_ref();
-    movement(vehicles_grid, &mut self.vehicles, &self.verbose)?;
+        // Travel times are used by route choice only
+        if !self.trips_routes.is_empty() {
+            self.travel_times.observe(vehicles_grid, &self.vehicles);
+            if let Some(record) = self.travel_times_record.as_mut() {
+                record.observe(vehicles_grid, &self.vehicles, self.steps);
+            }
+        }
+        let mut bus_stop_events: Vec<BusStopEvent> = Vec::new();
+        movement(vehicles_grid, &mut self.vehicles, &mut self.bus_stops, self.steps, &mut bus_stop_events, &self.verbose)?;
+        anticipation_movement(vehicles_grid, &mut self.vehicles, &self.verbose)?;
+        self.resolve_gridlocks(&mut gridlocks);
+        let vehicles_grid = self.grids_storage.get_vehicles_net_ref();
 
         // 8. Collect current vehicles positions for state dump
         let mut states_dump: Vec<VehicleState> = Vec::with_capacity(self.vehicles.len());
@@ -584,6 +1389,23 @@ impl Session {
                 travel_time: vehicle.travel_time,
                 id: vehicle.id,
                 trip_id: vehicle.trip,
+                route_id: vehicle.route_id,
+            });
+        }
+
+        let pedestrians_grid = self.grids_storage.get_pedestrians_net_ref();
+        let mut pedestrians_dump: Vec<PedestrianState> = Vec::with_capacity(self.pedestrians.len());
+        for pedestrian in self.pedestrians.values() {
+            let pt = pedestrians_grid.get_cell(&pedestrian.cell_id)
+                .ok_or(SessionError::CellNotFound(pedestrian.cell_id))?
+                .get_point();
+            pedestrians_dump.push(PedestrianState {
+                id: pedestrian.id,
+                trip_id: pedestrian.trip,
+                last_cell: pedestrian.cell_id,
+                last_point: [pt.x(), pt.y()],
+                travel_time: pedestrian.travel_time,
+                waiting_time: pedestrian.waiting_time,
             });
         }
 
@@ -595,9 +1417,72 @@ impl Session {
             timestamp: timestamp,
             vehicles: states_dump,
             tls: tl_states_dump,
+            bus_stop_events,
+            pedestrians: pedestrians_dump,
+            gridlocks,
+            departures: std::mem::take(&mut self.departures),
         })
     }
 
+    /// Resolves gridlocks which have lasted long enough according to the policy (see [`GridlockPolicy`])
+    fn resolve_gridlocks(&mut self, gridlocks: &mut [Gridlock]) {
+        let timeout = match self.gridlock_policy {
+            GridlockPolicy::Report => return,
+            GridlockPolicy::Teleport { timeout } | GridlockPolicy::Remove { timeout } | GridlockPolicy::Priority { timeout } => timeout,
+        };
+        for gridlock in gridlocks.iter_mut().filter(|gridlock| gridlock.duration >= timeout) {
+            let vehicle_id = gridlock.vehicles[0];
+            let resolution = match self.gridlock_policy {
+                GridlockPolicy::Remove { .. } => {
+                    self.vehicles.swap_remove(&vehicle_id);
+                    GridlockResolution::Removed { vehicle_id }
+                }
+                GridlockPolicy::Priority { .. } => {
+                    let mut junction_boxes: Vec<&mut JunctionBox> = self.junction_boxes.values_mut().collect();
+                    junction_boxes.sort_by_key(|junction_box| junction_box.get_id());
+                    let prioritized = gridlock.vehicles.iter().find_map(|&vehicle_id| {
+                        junction_boxes
+                            .iter_mut()
+                            .find(|junction_box| junction_box.get_held().contains_key(&vehicle_id))
+                            .map(|junction_box| {
+                                junction_box.grant_priority(vehicle_id);
+                                vehicle_id
+                            })
+                    });
+                    match prioritized {
+                        Some(vehicle_id) => GridlockResolution::Prioritized { vehicle_id },
+                        None => self.teleport_or_remove(vehicle_id),
+                    }
+                }
+                _ => self.teleport_or_remove(vehicle_id),
+            };
+            if self.verbose.is_at_least(VerboseLevel::Main) {
+                self.verbose.log_with_fields(
+                    EVENT_GRIDLOCK,
+                    "Resolve gridlock",
+                    &[
+                        ("vehicles", &format!("{:?}", gridlock.vehicles)),
+                        ("duration", &gridlock.duration),
+                        ("resolution", &format!("{:?}", resolution)),
+                    ]
+                );
+            }
+            gridlock.resolution = Some(resolution);
+            self.gridlocks_since.remove(&gridlock.vehicles);
+        }
+    }
+
+    /// Teleports the vehicle downstream (see [`teleport_downstream`]) or removes it if there is no room on its way
+    fn teleport_or_remove(&mut self, vehicle_id: VehicleID) -> GridlockResolution {
+        match teleport_downstream(self.grids_storage.get_vehicles_net_ref(), &mut self.vehicles, vehicle_id, TELEPORT_LOOK_AHEAD) {
+            Some(cell_id) => GridlockResolution::Teleported { vehicle_id, cell_id },
+            None => {
+                self.vehicles.swap_remove(&vehicle_id);
+                GridlockResolution::Removed { vehicle_id }
+            }
+        }
+    }
+
     /// Gets the expiration time of the session
     pub fn get_expire_at(&self) -> i64 {
         self._expire_at
@@ -2,8 +2,12 @@ use crate::grid::cell::CellID;
 use crate::traffic_lights::lights::{TrafficLightID};
 use crate::agents_types::AgentType;
 use crate::agents::VehicleID;
+use crate::pedestrians::PedestrianID;
 use crate::traffic_lights::signals::SignalType;
 use crate::trips::trip::TripID;
+use crate::route_choice::RouteID;
+use crate::transit::BusStopID;
+use crate::simulation::gridlock::Gridlock;
 use std::collections::HashMap;
 
 /// Result of a single step execution
@@ -24,6 +28,25 @@ pub struct AutomataState {
     pub vehicles: Vec<VehicleState>,
     /// States of all traffic light groups at this timestamp
     pub tls: HashMap<TrafficLightID, Vec<TrafficLightGroupState>>,
+    /// Arrivals and departures of buses at transit cells at this timestamp
+    pub bus_stop_events: Vec<BusStopEvent>,
+    /// States of all pedestrians at this timestamp
+    pub pedestrians: Vec<PedestrianState>,
+    /// Circular waits of vehicles observed at this timestamp (see [`find_gridlocks`](crate::simulation::gridlock::find_gridlocks))
+    pub gridlocks: Vec<Gridlock>,
+    /// Vehicles generated by trips at this timestamp (including the ones which have already left the grid)
+    pub departures: Vec<VehicleDeparture>,
+}
+
+/// Vehicle generated by a trip
+#[derive(Debug, Clone, PartialEq)]
+pub struct VehicleDeparture {
+    /// Vehicle identifier
+    pub vehicle_id: VehicleID,
+    /// Trip identifier
+    pub trip_id: TripID,
+    /// Identifier of the route assigned by route choice (if any)
+    pub route_id: Option<RouteID>,
 }
 
 /// State of a single vehicle at a specific timestamp
@@ -51,6 +74,25 @@ pub struct VehicleState {
     pub id: VehicleID,
     /// Trip identifier the vehicle belongs to
     pub trip_id: TripID,
+    /// Identifier of the route assigned by route choice (if any)
+    pub route_id: Option<RouteID>,
+}
+
+/// State of a single pedestrian at a specific timestamp
+#[derive(Debug, Clone)]
+pub struct PedestrianState {
+    /// Unique identifier of the pedestrian
+    pub id: PedestrianID,
+    /// Trip identifier the pedestrian belongs to
+    pub trip_id: TripID,
+    /// Last known cell ID (in the pedestrians grid) of the pedestrian
+    pub last_cell: CellID,
+    /// Last known position of the pedestrian
+    pub last_point: [f64; 2],
+    /// Total travel time of the pedestrian in the simulation
+    pub travel_time: i64,
+    /// Total time the pedestrian has been waiting (e.g. at crosswalks)
+    pub waiting_time: i64,
 }
 
 /// State of a traffic light group at a specific timestamp
@@ -61,3 +103,31 @@ pub struct TrafficLightGroupState {
     /// Last known signal type of the traffic light group
     pub last_signal: SignalType
 }
+
+/// Kind of a bus stop event
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BusStopEventType {
+    /// Bus has reached the transit cell
+    Arrival,
+    /// Bus has left the transit cell after dwelling
+    Departure,
+}
+
+/// Arrival or departure of a bus at a transit cell
+#[derive(Debug, Clone, PartialEq)]
+pub struct BusStopEvent {
+    /// Bus stop identifier. `None` if there is no bus stop defined for the transit cell
+    pub stop_id: Option<BusStopID>,
+    /// Transit cell
+    pub cell_id: CellID,
+    /// Vehicle identifier
+    pub vehicle_id: VehicleID,
+    /// Trip identifier
+    pub trip_id: TripID,
+    /// Kind of the event
+    pub event_type: BusStopEventType,
+    /// Time of the event
+    pub timestamp: i32,
+    /// Travel time (in time units) of the vehicle so far. Departure time from the origin is `timestamp - travel_time`
+    pub travel_time: i64,
+}
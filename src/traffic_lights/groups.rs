@@ -18,6 +18,8 @@ pub struct TrafficLightGroup {
     signal: Vec<SignalType>,
     /// Unique ID of the group.
     id: i64,
+    /// Whether the group controls pedestrians (its cells are cells of the pedestrian network).
+    pedestrian: bool,
 }
 
 impl TrafficLightGroup {
@@ -51,6 +53,7 @@ impl TrafficLightGroup {
                 label: String::new(),
                 signal: Vec::new(),
                 id,
+                pedestrian: false,
             },
         }
     }
@@ -66,6 +69,10 @@ impl TrafficLightGroup {
     pub fn get_cells_ids(&self) -> &Vec<CellID> {
         &self.cells_ids
     }
+    /// Returns true if the group controls pedestrians
+    pub fn is_pedestrian(&self) -> bool {
+        self.pedestrian
+    }
 }
 
 /// A builder for constructing `TrafficLightGroup` instances.
@@ -122,6 +129,33 @@ impl TrafficLightGroupBuilder {
         self
     }
 
+    /// Marks the group as a pedestrian one: its cells are cells of the pedestrian network
+    /// (usually the first cells of crosswalks) and pedestrians do not enter them while the signal is banned.
+    ///
+    /// # Arguments
+    /// * `pedestrian` - Whether the group controls pedestrians.
+    ///
+    /// # Returns
+    /// A `TrafficLightGroupBuilder` instance for method chaining.
+    ///
+    /// # Example
+    /// ```
+    /// use micro_traffic_sim_core::traffic_lights::signals::SignalType;
+    /// use micro_traffic_sim_core::traffic_lights::groups::TrafficLightGroup;
+    ///
+    /// // Walk while vehicles have red
+    /// let group = TrafficLightGroup::new(2)
+    ///     .with_cells_ids(vec![5])
+    ///     .with_signal(vec![SignalType::Red, SignalType::Green])
+    ///     .with_pedestrian(true)
+    ///     .build();
+    /// assert!(group.is_pedestrian());
+    /// ```
+    pub fn with_pedestrian(mut self, pedestrian: bool) -> Self {
+        self.group.pedestrian = pedestrian;
+        self
+    }
+
     /// Builds the final `TrafficLightGroup` object.
     ///
     /// # Returns
@@ -1,5 +1,7 @@
 use crate::geom::{PointType, new_point};
 use crate::traffic_lights::groups::TrafficLightGroup;
+use crate::traffic_lights::priority::TransitPriority;
+use crate::traffic_lights::signals::SignalType;
 use std::fmt;
 
 /// Type alias for TrafficLight identifiers.
@@ -23,6 +25,20 @@ impl fmt::Display for TrafficLightError {
 
 impl std::error::Error for TrafficLightError {}
 
+/// Default number of time units groups closed by the preemption show yellow (see [`TrafficLight::set_preemption`]).
+pub const DEFAULT_CLEARANCE_YELLOW: i32 = 2;
+/// Default number of time units all groups closed by the preemption show red before the new phase starts.
+pub const DEFAULT_CLEARANCE_ALL_RED: i32 = 1;
+
+/// Clearance interval between the phase shown before the preemption switch and the active one.
+#[derive(Debug, Clone, Copy)]
+struct Clearance {
+    /// Phase which was shown before the switch
+    from_phase: usize,
+    /// Number of time units passed since the switch
+    elapsed: i32,
+}
+
 /// Represents a traffic light that controls one or more groups of cells.
 #[derive(Debug, Clone)]
 pub struct TrafficLight {
@@ -38,6 +54,22 @@ pub struct TrafficLight {
     timer: i32,
     /// Index of the current active signal phase.
     active_phase_idx: usize,
+    /// Group which is held on green for an emergency vehicle (signal preemption).
+    preempted_group_id: Option<i64>,
+    /// Phase index and timer interrupted by the preemption. They are restored when the preemption is released.
+    interrupted_phase: Option<(usize, i32)>,
+    /// Number of time units groups closed by the preemption switch show yellow.
+    clearance_yellow: i32,
+    /// Number of time units groups closed by the preemption switch show red before the new phase starts.
+    clearance_all_red: i32,
+    /// Ongoing clearance interval of the preemption switch, if any.
+    clearance: Option<Clearance>,
+    /// Transit signal priority settings. `None` if buses can't request priority.
+    transit_priority: Option<TransitPriority>,
+    /// Number of time units the active phase is extended for by transit priority requests.
+    priority_extension: i32,
+    /// Number of time units remaining until transit priority requests are served again.
+    priority_cooldown: i32,
 }
 
 impl TrafficLight {
@@ -57,6 +89,14 @@ impl TrafficLight {
                 id,
                 timer: 0,
                 active_phase_idx: 0,
+                preempted_group_id: None,
+                interrupted_phase: None,
+                clearance_yellow: DEFAULT_CLEARANCE_YELLOW,
+                clearance_all_red: DEFAULT_CLEARANCE_ALL_RED,
+                clearance: None,
+                transit_priority: None,
+                priority_extension: 0,
+                priority_cooldown: 0,
             },
         }
     }
@@ -64,11 +104,30 @@ impl TrafficLight {
     pub fn get_id(&self) -> TrafficLightID {
         self.id
     }
-    /// Increments internal timer and changes active phase if needed
+    /// Increments internal timer and changes active phase if needed.
+    /// Timer is frozen while the traffic light is preempted or its clearance interval is not over (see [`TrafficLight::set_preemption`]).
     pub fn step(&mut self) {
+        if let Some(clearance) = self.clearance.as_mut() {
+            clearance.elapsed += 1;
+            if clearance.elapsed > self.clearance_yellow + self.clearance_all_red {
+                self.clearance = None;
+            }
+            return;
+        }
+        if self.preempted_group_id.is_some() {
+            return;
+        }
+        if self.priority_cooldown > 0 {
+            self.priority_cooldown -= 1;
+        }
         self.timer += 1;
         let current_phase = self.active_phase_idx;
-        if self.timer >= self.times[current_phase] {
+        if self.timer >= self.times[current_phase] + self.priority_extension {
+            if self.priority_extension > 0 {
+                // Extended green is over: request has been served
+                self.priority_extension = 0;
+                self.priority_cooldown = self.transit_priority.map_or(0, |priority| priority.cooldown);
+            }
             self.active_phase_idx = (current_phase + 1) % self.times.len();
             self.timer = 0;
         }
@@ -78,6 +137,228 @@ impl TrafficLight {
     pub fn reset(&mut self) {
         self.timer = 0;
         self.active_phase_idx = 0;
+        self.preempted_group_id = None;
+        self.interrupted_phase = None;
+        self.clearance = None;
+        self.priority_extension = 0;
+        self.priority_cooldown = 0;
+    }
+
+    /// Requests or releases signal preemption (e.g. for an approaching emergency vehicle).
+    ///
+    /// While preempted, the traffic light holds a phase in which the given group is not banned:
+    /// the current phase if it is already so, otherwise the first such phase in the cycle.
+    /// On release the interrupted phase and its timer are restored, so the regular cycle continues from where it was stopped.
+    ///
+    /// Both switches go through the clearance interval if they close any open group: closed groups show yellow and then red
+    /// (see [`TrafficLightBuilder::with_clearance_times`]), while groups opened by the switch stay red until the interval is over
+    /// (see [`TrafficLight::get_signal`]). The cycle is frozen during the interval.
+    ///
+    /// # Arguments
+    /// * `group_id` - ID of the group to hold open, or `None` to release the preemption.
+    ///
+    /// # Returns
+    /// `true` if the request is applied, `false` if the group is unknown or it has no allowing phase.
+    ///
+    /// # Example
+    /// ```
+    /// use micro_traffic_sim_core::traffic_lights::groups::TrafficLightGroup;
+    /// use micro_traffic_sim_core::traffic_lights::lights::TrafficLight;
+    /// use micro_traffic_sim_core::traffic_lights::signals::SignalType;
+    ///
+    /// let mut tl = TrafficLight::new(1)
+    ///     .with_groups(vec![
+    ///         TrafficLightGroup::new(1).with_signal(vec![SignalType::Green, SignalType::Red]).build(),
+    ///         TrafficLightGroup::new(2).with_signal(vec![SignalType::Red, SignalType::Green]).build(),
+    ///     ])
+    ///     .with_phases_times(vec![10, 10])
+    ///     .build();
+    /// tl.step();
+    /// assert!(tl.set_preemption(Some(2)));
+    /// assert_eq!(tl.get_active_phase(), 1);
+    /// // Clearance interval: group 1 shows yellow, then red, and group 2 waits for it
+    /// let signals = |tl: &TrafficLight| tl.get_groups().iter().map(|group| tl.get_signal(group)).collect::<Vec<_>>();
+    /// assert_eq!(signals(&tl), vec![SignalType::Yellow, SignalType::Red]);
+    /// for _ in 0..3 {
+    ///     tl.step();
+    /// }
+    /// assert_eq!(signals(&tl), vec![SignalType::Red, SignalType::Red]);
+    /// tl.step();
+    /// assert_eq!(signals(&tl), vec![SignalType::Red, SignalType::Green]);
+    /// // Released: interrupted phase continues after the clearance interval
+    /// tl.set_preemption(None);
+    /// assert_eq!((tl.get_active_phase(), tl.get_current_time()), (0, 1));
+    /// assert_eq!(signals(&tl), vec![SignalType::Red, SignalType::Yellow]);
+    /// ```
+    pub fn set_preemption(&mut self, group_id: Option<i64>) -> bool {
+        let Some(group_id) = group_id else {
+            if let Some((phase_idx, timer)) = self.interrupted_phase.take() {
+                self.switch_phase(phase_idx);
+                self.timer = timer;
+            }
+            self.preempted_group_id = None;
+            return true;
+        };
+        let Some(group) = self.groups.iter().find(|group| group.get_id() == group_id) else {
+            return false;
+        };
+        let allows = |phase_idx: usize| !group.get_signal_at(phase_idx).is_banned();
+        let phase_idx = if allows(self.active_phase_idx) {
+            self.active_phase_idx
+        } else {
+            match (0..self.times.len()).find(|&phase_idx| allows(phase_idx)) {
+                Some(phase_idx) => phase_idx,
+                None => return false,
+            }
+        };
+        if self.interrupted_phase.is_none() {
+            self.interrupted_phase = Some((self.active_phase_idx, self.timer));
+        }
+        self.switch_phase(phase_idx);
+        self.preempted_group_id = Some(group_id);
+        true
+    }
+
+    /// Switches the active phase out of the regular cycle. Clearance interval is started if any group open
+    /// in the shown phase is banned in the new one. Ongoing interval keeps running unless no group is closed anymore.
+    fn switch_phase(&mut self, phase_idx: usize) {
+        let from_phase = self.clearance.map_or(self.active_phase_idx, |clearance| clearance.from_phase);
+        let closes_group = self
+            .groups
+            .iter()
+            .any(|group| !group.get_signal_at(from_phase).is_banned() && group.get_signal_at(phase_idx).is_banned());
+        self.clearance = match self.clearance {
+            _ if !closes_group || self.clearance_yellow + self.clearance_all_red <= 0 => None,
+            Some(clearance) => Some(clearance),
+            None => Some(Clearance { from_phase, elapsed: 0 }),
+        };
+        self.active_phase_idx = phase_idx;
+    }
+
+    /// Returns the signal currently shown by the group of the traffic light.
+    ///
+    /// It is the group's signal in the active phase unless the clearance interval of the preemption switch is going on
+    /// (see [`TrafficLight::set_preemption`]): then groups closed by the switch show yellow and then red,
+    /// groups banned before the switch show red and groups open before and after the switch keep going.
+    ///
+    /// # Arguments
+    /// * `group` - Signal group of the traffic light
+    ///
+    /// # Example
+    /// ```
+    /// use micro_traffic_sim_core::traffic_lights::groups::TrafficLightGroup;
+    /// use micro_traffic_sim_core::traffic_lights::lights::TrafficLight;
+    /// use micro_traffic_sim_core::traffic_lights::signals::SignalType;
+    ///
+    /// let tl = TrafficLight::new(1)
+    ///     .with_groups(vec![TrafficLightGroup::new(1).with_signal(vec![SignalType::Green, SignalType::Red]).build()])
+    ///     .with_phases_times(vec![10, 10])
+    ///     .build();
+    /// assert_eq!(tl.get_signal(&tl.get_groups()[0]), SignalType::Green);
+    /// ```
+    pub fn get_signal(&self, group: &TrafficLightGroup) -> SignalType {
+        let signal = *group.get_signal_at(self.active_phase_idx);
+        let Some(clearance) = self.clearance else {
+            return signal;
+        };
+        if group.get_signal_at(clearance.from_phase).is_banned() {
+            return SignalType::Red;
+        }
+        if !signal.is_banned() {
+            return signal;
+        }
+        if clearance.elapsed.max(1) <= self.clearance_yellow {
+            SignalType::Yellow
+        } else {
+            SignalType::Red
+        }
+    }
+
+    /// Returns the number of time units of yellow and all-red signals in the clearance interval of the preemption switch.
+    pub fn get_clearance_times(&self) -> (i32, i32) {
+        (self.clearance_yellow, self.clearance_all_red)
+    }
+
+    /// Returns ID of the group held by the signal preemption, if any.
+    pub fn get_preempted_group(&self) -> Option<i64> {
+        self.preempted_group_id
+    }
+
+    /// Requests transit signal priority for the group (e.g. for an approaching bus). Should be called before [`TrafficLight::step`].
+    ///
+    /// If the group is open and its green is about to end, the active phase is extended by one time unit
+    /// (up to `TransitPriority::max_extension` in total). If the group is banned, the active phase is cut short
+    /// once it has run for `TransitPriority::min_green`, so phases are skipped one by one until the group gets green (early green).
+    /// After an extended green or an early green the traffic light ignores requests for `TransitPriority::cooldown` time units.
+    /// Requests are ignored as well while the traffic light is preempted or in the clearance interval (see [`TrafficLight::set_preemption`]).
+    ///
+    /// # Arguments
+    /// * `group_id` - ID of the group the bus is going to pass.
+    ///
+    /// # Returns
+    /// `true` if the signal plan is changed by the request.
+    ///
+    /// # Example
+    /// ```
+    /// use micro_traffic_sim_core::traffic_lights::groups::TrafficLightGroup;
+    /// use micro_traffic_sim_core::traffic_lights::lights::TrafficLight;
+    /// use micro_traffic_sim_core::traffic_lights::priority::TransitPriority;
+    /// use micro_traffic_sim_core::traffic_lights::signals::SignalType;
+    ///
+    /// let mut tl = TrafficLight::new(1)
+    ///     .with_groups(vec![TrafficLightGroup::new(1).with_signal(vec![SignalType::Green, SignalType::Red]).build()])
+    ///     .with_phases_times(vec![2, 10])
+    ///     .with_transit_priority(TransitPriority::new().with_max_extension(3).build())
+    ///     .build();
+    /// tl.step();
+    /// // Green would end on the next step, but it is extended
+    /// assert!(tl.request_priority(1));
+    /// tl.step();
+    /// assert_eq!(tl.get_active_phase(), 0);
+    /// ```
+    pub fn request_priority(&mut self, group_id: i64) -> bool {
+        let Some(priority) = self.transit_priority else {
+            return false;
+        };
+        if self.preempted_group_id.is_some() || self.clearance.is_some() || self.priority_cooldown > 0 {
+            return false;
+        }
+        let Some(group) = self.groups.iter().find(|group| group.get_id() == group_id) else {
+            return false;
+        };
+        let phases_num = self.times.len();
+        let allows: Vec<bool> = (0..phases_num).map(|phase_idx| !group.get_signal_at(phase_idx).is_banned()).collect();
+        let current_phase = self.active_phase_idx;
+        if allows[current_phase] {
+            // Green extension: phase ends on the next step and the group is banned in the next phase
+            let ends = self.timer + 1 >= self.times[current_phase] + self.priority_extension;
+            if ends && !allows[(current_phase + 1) % phases_num] && self.priority_extension < priority.max_extension {
+                self.priority_extension += 1;
+                return true;
+            }
+            return false;
+        }
+        // Early green: the active phase is cut short after the minimum green
+        if self.timer < priority.min_green || !allows.contains(&true) {
+            return false;
+        }
+        self.active_phase_idx = (current_phase + 1) % phases_num;
+        self.timer = 0;
+        self.priority_extension = 0;
+        if allows[self.active_phase_idx] {
+            self.priority_cooldown = priority.cooldown;
+        }
+        true
+    }
+
+    /// Returns transit signal priority settings, if any.
+    pub fn get_transit_priority(&self) -> Option<&TransitPriority> {
+        self.transit_priority.as_ref()
+    }
+
+    /// Returns the number of time units the active phase is extended for by transit priority requests.
+    pub fn get_priority_extension(&self) -> i32 {
+        self.priority_extension
     }
 
     /// Returns the current active phase index.
@@ -149,6 +430,21 @@ impl TrafficLightBuilder {
         self
     }
 
+    /// Sets the number of time units of yellow and all-red signals in the clearance interval of the preemption switch
+    /// (see [`TrafficLight::set_preemption`]). Zero times switch phases at once.
+    /// Defaults are [`DEFAULT_CLEARANCE_YELLOW`] and [`DEFAULT_CLEARANCE_ALL_RED`].
+    pub fn with_clearance_times(mut self, yellow: i32, all_red: i32) -> Self {
+        self.traffic_light.clearance_yellow = yellow.max(0);
+        self.traffic_light.clearance_all_red = all_red.max(0);
+        self
+    }
+
+    /// Enables transit signal priority for the traffic light (see [`TrafficLight::request_priority`]).
+    pub fn with_transit_priority(mut self, priority: TransitPriority) -> Self {
+        self.traffic_light.transit_priority = Some(priority);
+        self
+    }
+
     /// Builds and returns the final `TrafficLight` instance.
     pub fn build(self) -> TrafficLight {
         self.traffic_light
@@ -158,7 +454,6 @@ impl TrafficLightBuilder {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::traffic_lights::signals::SignalType;
     #[test]
     fn test_traffic_light() {
         let groups = vec![
@@ -260,4 +555,122 @@ mod tests {
         assert_eq!(traffic_light.get_current_time(), 0);
         assert_eq!(traffic_light.get_active_phase(), 0);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_preemption() {
+        let groups = vec![
+            TrafficLightGroup::new(1).with_signal(vec![SignalType::Green, SignalType::Yellow, SignalType::Red]).build(),
+            TrafficLightGroup::new(2).with_signal(vec![SignalType::Red, SignalType::Red, SignalType::Green]).build(),
+        ];
+        let mut traffic_light = TrafficLight::new(1).with_groups(groups).with_phases_times(vec![5, 2, 5]).build();
+        traffic_light.step();
+        traffic_light.step();
+
+        // Group 1 is already open: phase is kept and the timer is frozen
+        assert!(traffic_light.set_preemption(Some(1)));
+        traffic_light.step();
+        assert_eq!((traffic_light.get_active_phase(), traffic_light.get_current_time()), (0, 2));
+
+        // Switch to group 2 keeps the originally interrupted phase
+        traffic_light.set_preemption(None);
+        assert!(traffic_light.set_preemption(Some(2)));
+        assert_eq!(traffic_light.get_active_phase(), 2);
+        assert_eq!(traffic_light.get_preempted_group(), Some(2));
+        traffic_light.set_preemption(None);
+        assert_eq!((traffic_light.get_active_phase(), traffic_light.get_current_time()), (0, 2));
+        assert_eq!(traffic_light.get_preempted_group(), None);
+
+        // Unknown group
+        assert!(!traffic_light.set_preemption(Some(3)));
+        assert_eq!(traffic_light.get_preempted_group(), None);
+    }
+
+    #[test]
+    fn test_preemption_clearance() {
+        // Groups 1 and 2 are conflicting: they are never open in the same phase
+        let groups = vec![
+            TrafficLightGroup::new(1).with_signal(vec![SignalType::Green, SignalType::Yellow, SignalType::Red]).build(),
+            TrafficLightGroup::new(2).with_signal(vec![SignalType::Red, SignalType::Red, SignalType::Green]).build(),
+        ];
+        let mut traffic_light = TrafficLight::new(1)
+            .with_groups(groups)
+            .with_phases_times(vec![20, 2, 20])
+            .with_clearance_times(2, 2)
+            .build();
+        // Preemption for group 2 is requested on steps 3..12 as it is done by the session: before the tick
+        let mut open_groups: Vec<Vec<i64>> = Vec::new();
+        for step in 0..20 {
+            traffic_light.set_preemption(if (3..12).contains(&step) { Some(2) } else { None });
+            traffic_light.step();
+            let open = traffic_light
+                .get_groups()
+                .iter()
+                .filter(|group| !traffic_light.get_signal(group).is_banned())
+                .map(|group| group.get_id())
+                .collect();
+            open_groups.push(open);
+        }
+        for (step, pair) in open_groups.windows(2).enumerate() {
+            let opened: Vec<&i64> = pair[0].iter().chain(pair[1].iter()).collect();
+            assert!(opened.len() <= 1 || opened.iter().all(|&&id| id == *opened[0]), "steps {} and {}: {:?}", step, step + 1, pair);
+        }
+        // Yellow (2) and all-red (2) steps go before group 2 opens and before group 1 opens again
+        assert_eq!(open_groups[2], vec![1]);
+        assert!(open_groups[3..7].iter().all(|open| open.is_empty()));
+        assert_eq!(open_groups[7], vec![2]);
+        assert_eq!(open_groups[11], vec![2]);
+        assert!(open_groups[12..16].iter().all(|open| open.is_empty()));
+        assert_eq!(open_groups[16], vec![1]);
+        assert_eq!(traffic_light.get_active_phase(), 0);
+
+        // Without clearance interval phases are switched at once
+        let groups = vec![
+            TrafficLightGroup::new(1).with_signal(vec![SignalType::Green, SignalType::Red]).build(),
+            TrafficLightGroup::new(2).with_signal(vec![SignalType::Red, SignalType::Green]).build(),
+        ];
+        let mut traffic_light = TrafficLight::new(1).with_groups(groups).with_phases_times(vec![20, 20]).with_clearance_times(0, 0).build();
+        assert!(traffic_light.set_preemption(Some(2)));
+        let signals: Vec<SignalType> = traffic_light.get_groups().iter().map(|group| traffic_light.get_signal(group)).collect();
+        assert_eq!(signals, vec![SignalType::Red, SignalType::Green]);
+    }
+
+    #[test]
+    fn test_transit_priority() {
+        let groups = vec![
+            TrafficLightGroup::new(1).with_signal(vec![SignalType::Green, SignalType::Yellow, SignalType::Red]).build(),
+            TrafficLightGroup::new(2).with_signal(vec![SignalType::Red, SignalType::Red, SignalType::Green]).build(),
+        ];
+        let priority = TransitPriority::new().with_max_extension(2).with_min_green(4).with_cooldown(5).build();
+        let mut traffic_light = TrafficLight::new(1)
+            .with_groups(groups)
+            .with_phases_times(vec![3, 2, 10])
+            .with_transit_priority(priority)
+            .build();
+
+        // Green extension is not needed until the end of the phase
+        traffic_light.step();
+        assert!(!traffic_light.request_priority(1));
+        traffic_light.step();
+        // Green is extended up to the maximum extension
+        assert!(traffic_light.request_priority(1));
+        traffic_light.step();
+        assert!(traffic_light.request_priority(1));
+        traffic_light.step();
+        assert!(!traffic_light.request_priority(1));
+        traffic_light.step();
+        assert_eq!((traffic_light.get_active_phase(), traffic_light.get_current_time()), (1, 0));
+
+        // Cooldown after the extended green
+        for _ in 0..6 {
+            assert!(!traffic_light.request_priority(1));
+            traffic_light.step();
+        }
+        assert_eq!((traffic_light.get_active_phase(), traffic_light.get_current_time()), (2, 4));
+
+        // Early green: red phase is cut short after the minimum green
+        assert!(traffic_light.request_priority(1));
+        assert_eq!((traffic_light.get_active_phase(), traffic_light.get_current_time()), (0, 0));
+        traffic_light.step();
+        assert!(!traffic_light.request_priority(2));
+    }
+}
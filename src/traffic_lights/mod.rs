@@ -29,11 +29,13 @@
 //! - [`lights::TrafficLight`] - Traffic light controller with timing and groups. One controller == one intersection
 //! - [`groups::TrafficLightGroup`] - Group of cells sharing the same coordinated signal behavior
 //! - [`signals::SignalType`] - Individual signal states (Red, Yellow, Green, etc.)
+//! - [`priority::TransitPriority`] - Transit signal priority settings (green extension and early green for buses)
 //! - [`lights::TrafficLightID`] - Unique identifier for traffic lights
 //!
 //! ### Builder Pattern
 //! - [`lights::TrafficLightBuilder`] - API builder for traffic lights
 //! - [`groups::TrafficLightGroupBuilder`] - API builder for signal groups
+//! - [`priority::TransitPriorityBuilder`] - API builder for transit signal priority settings
 //!
 //! ### Error Handling
 //! - [`lights::TrafficLightError`] - Traffic light operation errors
@@ -110,6 +112,12 @@
 //! All groups in a traffic light share the same phase timing but can have
 //! different signal states per phase, enabling complex intersection patterns.
 //!
+//! ### Transit Signal Priority
+//! Traffic lights with [`priority::TransitPriority`] serve requests of approaching buses which follow their transit cells:
+//! - **Green extension**: green of the bus group is held for up to `max_extension` time units
+//! - **Early green**: other phases are cut short after `min_green` until the bus group gets green
+//! - **Cooldown**: no requests are served for `cooldown` time units after an extended or early green
+//!
 //! ## Integration
 //!
 //! - **Grid System**: Controls cells via [`CellID`](crate::grid::cell::CellID) references
@@ -118,4 +126,5 @@
 //!
 pub mod signals;
 pub mod groups;
-pub mod lights;
\ No newline at end of file
+pub mod lights;
+pub mod priority;
\ No newline at end of file
@@ -0,0 +1,84 @@
+/// Transit signal priority (TSP) settings of a traffic light.
+///
+/// When a bus following its transit cells approaches a signal group within `detection_distance` cells,
+/// the traffic light either extends the current green of the group (green extension) or cuts short
+/// the current phases until the group gets green (early green). See [`TrafficLight::request_priority`](crate::traffic_lights::lights::TrafficLight::request_priority).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TransitPriority {
+    /// Distance (in cells) ahead of a bus at which it requests priority
+    pub detection_distance: i32,
+    /// Maximum number of time units a green phase could be extended for
+    pub max_extension: i32,
+    /// Minimum number of time units a phase runs before it could be cut short for early green,
+    /// so the other groups keep their minimum green. Phases shorter than it (e.g. yellow) are never cut short.
+    pub min_green: i32,
+    /// Number of time units after a served request during which new requests are ignored
+    pub cooldown: i32,
+}
+
+impl TransitPriority {
+    /// Creates a new `TransitPriorityBuilder` with default settings: detection distance of 10 cells,
+    /// maximum extension of 10 time units, minimum green of 5 time units and cooldown of 30 time units.
+    ///
+    /// # Returns
+    /// A `TransitPriorityBuilder` instance for configuring and building the settings.
+    ///
+    /// # Example
+    /// ```
+    /// use micro_traffic_sim_core::traffic_lights::priority::TransitPriority;
+    ///
+    /// let priority = TransitPriority::new()
+    ///     .with_detection_distance(15)
+    ///     .with_max_extension(8)
+    ///     .with_min_green(6)
+    ///     .with_cooldown(60)
+    ///     .build();
+    /// assert_eq!(priority.max_extension, 8);
+    /// ```
+    pub fn new() -> TransitPriorityBuilder {
+        TransitPriorityBuilder {
+            priority: TransitPriority {
+                detection_distance: 10,
+                max_extension: 10,
+                min_green: 5,
+                cooldown: 30,
+            },
+        }
+    }
+}
+
+/// A builder for constructing `TransitPriority` instances.
+pub struct TransitPriorityBuilder {
+    priority: TransitPriority,
+}
+
+impl TransitPriorityBuilder {
+    /// Sets the distance (in cells) ahead of a bus at which it requests priority.
+    pub fn with_detection_distance(mut self, distance: i32) -> Self {
+        self.priority.detection_distance = distance;
+        self
+    }
+
+    /// Sets the maximum number of time units a green phase could be extended for.
+    pub fn with_max_extension(mut self, max_extension: i32) -> Self {
+        self.priority.max_extension = max_extension;
+        self
+    }
+
+    /// Sets the minimum number of time units a phase runs before it could be cut short for early green.
+    pub fn with_min_green(mut self, min_green: i32) -> Self {
+        self.priority.min_green = min_green;
+        self
+    }
+
+    /// Sets the number of time units after a served request during which new requests are ignored.
+    pub fn with_cooldown(mut self, cooldown: i32) -> Self {
+        self.priority.cooldown = cooldown;
+        self
+    }
+
+    /// Builds and returns the final `TransitPriority` instance.
+    pub fn build(self) -> TransitPriority {
+        self.priority
+    }
+}
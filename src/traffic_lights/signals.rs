@@ -82,6 +82,21 @@ impl SignalType {
             .copied()
             .ok_or(SignalTypeError::InvalidSignalType(signal_str.to_string()))
     }
+
+    /// Checks whether vehicles are not allowed to enter cells under the signal.
+    ///
+    /// # Returns
+    /// `true` for Red, Yellow, RedYellow and Blinking signals.
+    ///
+    /// # Example
+    /// ```
+    /// use micro_traffic_sim_core::traffic_lights::signals::SignalType;
+    /// assert!(SignalType::Red.is_banned());
+    /// assert!(!SignalType::Green.is_banned());
+    /// ```
+    pub fn is_banned(&self) -> bool {
+        matches!(self, SignalType::Red | SignalType::Yellow | SignalType::RedYellow | SignalType::Blinking)
+    }
 }
 
 impl fmt::Display for SignalType {
@@ -0,0 +1,758 @@
+use crate::agents_types::AgentType;
+use crate::behaviour::BehaviourType;
+use crate::geom::{new_point, Point, SRID};
+use crate::grid::{cell::{Cell, CellID}, road_network::GridRoads, zones::ZoneType};
+use crate::trips::trip::{Trip, TripID, TripType};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+/// Error types for reading GTFS feeds and converting them into trips.
+#[derive(Debug, Clone, PartialEq)]
+pub enum GtfsError {
+    /// File of the feed can't be read
+    Io {
+        /// Name of the file
+        file: String,
+        /// Description of the error
+        reason: String,
+    },
+    /// Required column is missing in the file
+    MissingColumn {
+        /// Name of the file
+        file: String,
+        /// Name of the column
+        column: String,
+    },
+    /// Value can't be parsed
+    BadValue {
+        /// Name of the file
+        file: String,
+        /// Line number (starting from 1 for the header)
+        line: usize,
+        /// Name of the column
+        column: String,
+        /// Raw value
+        value: String,
+    },
+    /// Stop times reference the stop which is missing in `stops.txt`
+    UnknownStop(String),
+    /// There is no transit cell within the matching distance of the stop
+    NoTransitCell(String),
+    /// Simulation time unit (see [`GtfsImport::time_unit`]) is not a positive number of seconds
+    BadTimeUnit(f64),
+}
+
+impl fmt::Display for GtfsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GtfsError::Io { file, reason } => write!(f, "Can't read GTFS file '{}': {}", file, reason),
+            GtfsError::MissingColumn { file, column } => {
+                write!(f, "Column '{}' is missing in GTFS file '{}'", column, file)
+            }
+            GtfsError::BadValue { file, line, column, value } => {
+                write!(f, "Bad value '{}' of column '{}' at line {} of GTFS file '{}'", value, column, line, file)
+            }
+            GtfsError::UnknownStop(stop_id) => write!(f, "Stop '{}' is missing in stops.txt", stop_id),
+            GtfsError::NoTransitCell(stop_id) => write!(f, "No transit cell found for stop '{}'", stop_id),
+            GtfsError::BadTimeUnit(time_unit) => write!(f, "Time unit must be a positive number of seconds, got {}", time_unit),
+        }
+    }
+}
+
+/// Stop of the GTFS feed (`stops.txt`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct GtfsStop {
+    /// Stop identifier
+    pub stop_id: String,
+    /// Stop name
+    pub name: String,
+    /// Latitude (in degrees)
+    pub lat: f64,
+    /// Longitude (in degrees)
+    pub lon: f64,
+}
+
+/// Trip of the GTFS feed (`trips.txt`): single run of a bus along its route.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GtfsTrip {
+    /// Trip identifier
+    pub trip_id: String,
+    /// Route identifier
+    pub route_id: String,
+    /// Service (calendar) identifier
+    pub service_id: String,
+    /// Shape identifier (empty if the trip has no shape)
+    pub shape_id: String,
+}
+
+/// Time of a trip at a stop (`stop_times.txt`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct GtfsStopTime {
+    /// Trip identifier
+    pub trip_id: String,
+    /// Stop identifier
+    pub stop_id: String,
+    /// Order of the stop in the trip
+    pub stop_sequence: i32,
+    /// Arrival time (in seconds since the service day start, it could exceed 24 hours). `None` if the stop is not a timepoint
+    pub arrival_time: Option<i32>,
+    /// Departure time (in seconds since the service day start, it could exceed 24 hours). `None` if the stop is not a timepoint
+    pub departure_time: Option<i32>,
+}
+
+/// Local GTFS feed: stops, trips, stop times and shapes of bus routes.
+///
+/// Only the files needed for bus operations are read: `stops.txt`, `trips.txt`, `stop_times.txt`
+/// and the optional `shapes.txt`. See [`GtfsFeed::to_trips`] for the conversion into simulation trips.
+#[derive(Debug, Clone, Default)]
+pub struct GtfsFeed {
+    /// Stops mapped by their identifiers
+    pub stops: HashMap<String, GtfsStop>,
+    /// Trips in the order of `trips.txt`
+    pub trips: Vec<GtfsTrip>,
+    /// Stop times grouped by trips and sorted by the stop sequence
+    pub stop_times: HashMap<String, Vec<GtfsStopTime>>,
+    /// Shapes points `(lon, lat)` grouped by shapes and sorted by the point sequence
+    pub shapes: HashMap<String, Vec<(f64, f64)>>,
+}
+
+impl GtfsFeed {
+    /// Reads GTFS feed from the directory with `stops.txt`, `trips.txt`, `stop_times.txt` and (optionally) `shapes.txt`.
+    ///
+    /// # Arguments
+    /// * `dir` - Directory of the unpacked feed
+    ///
+    /// # Returns
+    /// * `Ok(GtfsFeed)` - Parsed feed
+    /// * `Err(GtfsError)` - If a required file can't be read or has bad data
+    pub fn read<P: AsRef<Path>>(dir: P) -> Result<GtfsFeed, GtfsError> {
+        let read_file = |name: &str| {
+            fs::read_to_string(dir.as_ref().join(name)).map_err(|e| GtfsError::Io {
+                file: name.to_string(),
+                reason: e.to_string(),
+            })
+        };
+        let shapes = match dir.as_ref().join("shapes.txt").exists() {
+            true => Some(read_file("shapes.txt")?),
+            false => None,
+        };
+        GtfsFeed::parse(
+            &read_file("stops.txt")?,
+            &read_file("trips.txt")?,
+            &read_file("stop_times.txt")?,
+            shapes.as_deref(),
+        )
+    }
+
+    /// Parses GTFS feed from contents of its files.
+    ///
+    /// # Arguments
+    /// * `stops` - Contents of `stops.txt`
+    /// * `trips` - Contents of `trips.txt`
+    /// * `stop_times` - Contents of `stop_times.txt`
+    /// * `shapes` - Contents of `shapes.txt` (if the feed has it)
+    ///
+    /// # Returns
+    /// * `Ok(GtfsFeed)` - Parsed feed
+    /// * `Err(GtfsError)` - If a required column is missing or a value can't be parsed
+    ///
+    /// # Example
+    /// ```
+    /// use micro_traffic_sim_core::transit::GtfsFeed;
+    ///
+    /// let feed = GtfsFeed::parse(
+    ///     "stop_id,stop_name,stop_lat,stop_lon\nA,\"Main St, North\",0.0,1.0\nB,Park,0.0,5.0\n",
+    ///     "route_id,service_id,trip_id\n10,weekday,T1\n",
+    ///     "trip_id,arrival_time,departure_time,stop_id,stop_sequence\nT1,07:00:00,07:00:00,A,1\nT1,07:02:30,07:03:00,B,2\n",
+    ///     None,
+    /// ).unwrap();
+    /// assert_eq!(feed.stops["A"].name, "Main St, North");
+    /// assert_eq!(feed.stop_times["T1"][1].arrival_time, Some(7 * 3600 + 150));
+    /// ```
+    pub fn parse(stops: &str, trips: &str, stop_times: &str, shapes: Option<&str>) -> Result<GtfsFeed, GtfsError> {
+        let mut feed = GtfsFeed::default();
+
+        let table = CsvTable::parse("stops.txt", stops, &["stop_id", "stop_lat", "stop_lon"])?;
+        for row in table.rows() {
+            // Stations and entrances (`location_type` > 0) are not served by buses directly
+            if !matches!(row.get("location_type"), None | Some("") | Some("0")) {
+                continue;
+            }
+            let stop = GtfsStop {
+                stop_id: row.get_required("stop_id")?.to_string(),
+                name: row.get("stop_name").unwrap_or_default().to_string(),
+                lat: row.parse("stop_lat")?,
+                lon: row.parse("stop_lon")?,
+            };
+            feed.stops.insert(stop.stop_id.clone(), stop);
+        }
+
+        let table = CsvTable::parse("trips.txt", trips, &["route_id", "service_id", "trip_id"])?;
+        for row in table.rows() {
+            feed.trips.push(GtfsTrip {
+                trip_id: row.get_required("trip_id")?.to_string(),
+                route_id: row.get_required("route_id")?.to_string(),
+                service_id: row.get_required("service_id")?.to_string(),
+                shape_id: row.get("shape_id").unwrap_or_default().to_string(),
+            });
+        }
+
+        let columns = ["trip_id", "arrival_time", "departure_time", "stop_id", "stop_sequence"];
+        let table = CsvTable::parse("stop_times.txt", stop_times, &columns)?;
+        for row in table.rows() {
+            let stop_time = GtfsStopTime {
+                trip_id: row.get_required("trip_id")?.to_string(),
+                stop_id: row.get_required("stop_id")?.to_string(),
+                stop_sequence: row.parse("stop_sequence")?,
+                arrival_time: row.parse_time("arrival_time")?,
+                departure_time: row.parse_time("departure_time")?,
+            };
+            feed.stop_times.entry(stop_time.trip_id.clone()).or_default().push(stop_time);
+        }
+        for trip_stop_times in feed.stop_times.values_mut() {
+            trip_stop_times.sort_by_key(|stop_time| stop_time.stop_sequence);
+        }
+
+        if let Some(shapes) = shapes {
+            let columns = ["shape_id", "shape_pt_lat", "shape_pt_lon", "shape_pt_sequence"];
+            let table = CsvTable::parse("shapes.txt", shapes, &columns)?;
+            let mut points: HashMap<String, Vec<(i32, f64, f64)>> = HashMap::new();
+            for row in table.rows() {
+                points.entry(row.get_required("shape_id")?.to_string()).or_default().push((
+                    row.parse("shape_pt_sequence")?,
+                    row.parse("shape_pt_lon")?,
+                    row.parse("shape_pt_lat")?,
+                ));
+            }
+            for (shape_id, mut shape_points) in points {
+                shape_points.sort_by_key(|&(sequence, _, _)| sequence);
+                feed.shapes.insert(shape_id, shape_points.into_iter().map(|(_, lon, lat)| (lon, lat)).collect());
+            }
+        }
+        Ok(feed)
+    }
+
+    /// Matches the stop to the nearest transit cell (see [`ZoneType::Transit`]) within the maximum distance.
+    ///
+    /// If the shape of the trip is known, transit cells of lanes heading the same way as the shape near
+    /// the stop are preferred, so stops on the opposite sides of a road are matched to the right lanes.
+    ///
+    /// Stops and shapes are compared with [`SRID::WGS84`] cells as they are. For cells in plain coordinates
+    /// they are projected with [`GtfsImport::projection`] first (kept as they are if there is no projection,
+    /// so the grid has to be built in degrees then).
+    ///
+    /// # Arguments
+    /// * `stop_id` - Stop identifier
+    /// * `shape_id` - Shape identifier of the trip (empty if unknown)
+    /// * `net` - The road network grid
+    /// * `options` - Import options: maximum matching distance and projection (see [`GtfsImport`])
+    ///
+    /// # Returns
+    /// * `Ok(CellID)` - Matched transit cell
+    /// * `Err(GtfsError)` - If the stop is unknown or there is no transit cell within the distance
+    pub fn match_stop(&self, stop_id: &str, shape_id: &str, net: &GridRoads, options: &GtfsImport) -> Result<CellID, GtfsError> {
+        let stop = self.stops.get(stop_id).ok_or_else(|| GtfsError::UnknownStop(stop_id.to_string()))?;
+        let project = |lon: f64, lat: f64| options.projection.map_or((lon, lat), |projection| projection(lon, lat));
+        let projected_stop = project(stop.lon, stop.lat);
+        // Coordinates of the stop in the SRID of the cell
+        let stop_xy = |srid: SRID| match srid {
+            SRID::WGS84 => (stop.lon, stop.lat),
+            SRID::Euclidean => projected_stop,
+        };
+        let mut candidates: Vec<(f64, &Cell)> = net
+            .iter()
+            .map(|(_, cell)| cell)
+            .filter(|cell| cell.get_zone_type() == ZoneType::Transit)
+            .map(|cell| {
+                let srid = cell.get_point().srid();
+                let (x, y) = stop_xy(srid);
+                (new_point(x, y, Some(srid)).distance_to(cell.get_point()), cell)
+            })
+            .filter(|&(distance, _)| distance <= options.max_distance)
+            .collect();
+        // Ties are broken by cell ID, so matching does not depend on the grid storage order
+        candidates.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.1.get_id().cmp(&b.1.get_id())));
+        let shape = self.shapes.get(shape_id);
+        let geographic_heading = shape.and_then(|shape| shape_heading(shape, stop.lon, stop.lat));
+        let projected_heading = shape.and_then(|shape| {
+            let projected: Vec<(f64, f64)> = shape.iter().map(|&(lon, lat)| project(lon, lat)).collect();
+            shape_heading(&projected, projected_stop.0, projected_stop.1)
+        });
+        let same_direction = |cell: &Cell| -> bool {
+            let shape_heading = match cell.get_point().srid() {
+                SRID::WGS84 => geographic_heading,
+                SRID::Euclidean => projected_heading,
+            };
+            let (Some(shape_heading), Some(forward_cell)) = (shape_heading, net.get_cell(&cell.get_forward_id())) else {
+                return true;
+            };
+            let (from, to) = (cell.get_point(), forward_cell.get_point());
+            let cell_heading = (to.y() - from.y()).atan2(to.x() - from.x());
+            (cell_heading - shape_heading).cos() > 0.0
+        };
+        candidates
+            .iter()
+            .find(|(_, cell)| same_direction(cell))
+            .or(candidates.first())
+            .map(|(_, cell)| cell.get_id())
+            .ok_or_else(|| GtfsError::NoTransitCell(stop_id.to_string()))
+    }
+
+    /// Converts GTFS trips into simulation trips of [`TripType::Timetable`].
+    ///
+    /// Every stop is matched to a transit cell (see [`GtfsFeed::match_stop`]). GTFS trips of the same route
+    /// with the same sequence of matched cells are merged into a single trip, so departures of the route are
+    /// the departure times from the first stop. A bus starts at the first stop, then goes through the rest
+    /// stops as its transit cells and finishes at the last one. Trips with less than two stops and
+    /// departures before the time origin are skipped.
+    ///
+    /// # Arguments
+    /// * `net` - The road network grid with transit cells
+    /// * `options` - Import options (see [`GtfsImport`])
+    ///
+    /// # Returns
+    /// * `Ok(Vec<Trip>)` - Trips ordered by route identifiers and first departures
+    /// * `Err(GtfsError)` - If the time unit is not positive or a stop can't be matched to a transit cell
+    ///
+    /// # Example
+    /// ```
+    /// use micro_traffic_sim_core::geom::new_point;
+    /// use micro_traffic_sim_core::grid::{cell::Cell, road_network::GridRoads, zones::ZoneType};
+    /// use micro_traffic_sim_core::transit::{GtfsFeed, GtfsImport};
+    ///
+    /// let mut grid = GridRoads::new();
+    /// for id in 1..=6 {
+    ///     let zone_type = if id == 2 || id == 5 { ZoneType::Transit } else { ZoneType::Common };
+    ///     grid.add_cell(Cell::new(id).with_point(new_point(id as f64, 0.0, None)).with_zone_type(zone_type).with_forward_node(id + 1).build());
+    /// }
+    /// let feed = GtfsFeed::parse(
+    ///     "stop_id,stop_name,stop_lat,stop_lon\nA,North,0.1,2.0\nB,South,0.0,5.2\n",
+    ///     "route_id,service_id,trip_id\n10,weekday,T1\n10,weekday,T2\n",
+    ///     "trip_id,arrival_time,departure_time,stop_id,stop_sequence\n\
+    ///      T1,07:00:00,07:00:00,A,1\nT1,07:02:00,07:02:00,B,2\n\
+    ///      T2,07:10:00,07:10:00,A,1\nT2,07:12:00,07:12:00,B,2\n",
+    ///     None,
+    /// ).unwrap();
+    /// let options = GtfsImport::new().with_time_origin(7 * 3600).with_max_distance(1.0).build();
+    /// let trips = feed.to_trips(&grid, &options).unwrap();
+    /// assert_eq!(trips.len(), 1);
+    /// assert_eq!((trips[0].from_node, trips[0].to_node), (2, 5));
+    /// assert_eq!(trips[0].transit_cells, vec![5]);
+    /// assert_eq!(trips[0].departures, vec![0, 600]);
+    /// ```
+    pub fn to_trips(&self, net: &GridRoads, options: &GtfsImport) -> Result<Vec<Trip>, GtfsError> {
+        if !(options.time_unit > 0.0 && options.time_unit.is_finite()) {
+            return Err(GtfsError::BadTimeUnit(options.time_unit));
+        }
+        // (route ID, matched cells) -> departures
+        let mut patterns: BTreeMap<(String, Vec<CellID>), Vec<i32>> = BTreeMap::new();
+        let mut matched: HashMap<(String, String), CellID> = HashMap::new();
+        for gtfs_trip in self.trips.iter() {
+            if !options.services.is_empty() && !options.services.contains(&gtfs_trip.service_id) {
+                continue;
+            }
+            let Some(trip_stop_times) = self.stop_times.get(&gtfs_trip.trip_id) else {
+                continue;
+            };
+            if trip_stop_times.len() < 2 {
+                continue;
+            }
+            let Some(departure) = trip_stop_times[0].departure_time.or(trip_stop_times[0].arrival_time) else {
+                continue;
+            };
+            let departure = ((departure - options.time_origin) as f64 / options.time_unit).round() as i32;
+            if departure < 0 {
+                continue;
+            }
+            let mut cells = Vec::with_capacity(trip_stop_times.len());
+            for stop_time in trip_stop_times.iter() {
+                let key = (stop_time.stop_id.clone(), gtfs_trip.shape_id.clone());
+                let cell_id = match matched.get(&key) {
+                    Some(&cell_id) => cell_id,
+                    None => {
+                        let cell_id = self.match_stop(&stop_time.stop_id, &gtfs_trip.shape_id, net, options)?;
+                        matched.insert(key, cell_id);
+                        cell_id
+                    }
+                };
+                // Consecutive stops matched to the same cell are served once
+                if cells.last() != Some(&cell_id) {
+                    cells.push(cell_id);
+                }
+            }
+            if cells.len() < 2 {
+                continue;
+            }
+            patterns.entry((gtfs_trip.route_id.clone(), cells)).or_default().push(departure);
+        }
+
+        let mut trips: Vec<Trip> = patterns
+            .into_iter()
+            .map(|((_, cells), mut departures)| {
+                departures.sort();
+                Trip::new(cells[0], cells[cells.len() - 1], TripType::Timetable)
+                    .with_allowed_agent_type(options.agent_type)
+                    .with_allowed_behaviour_type(options.behaviour_type)
+                    .with_transits_cells(cells[1..].to_vec(), options.dwell_time)
+                    .with_departures(departures)
+                    .build()
+            })
+            .collect();
+        // Routes are already ordered by their IDs, keep it stable by the first departures within a route
+        for (idx, trip) in trips.iter_mut().enumerate() {
+            trip.id = options.first_trip_id + idx as TripID;
+        }
+        Ok(trips)
+    }
+}
+
+/// Projection of geographic coordinates `(lon, lat)` into plain coordinates `(x, y)`
+pub type Projection = fn(f64, f64) -> (f64, f64);
+
+/// Options of the conversion of a GTFS feed into trips (see [`GtfsFeed::to_trips`]).
+#[derive(Debug, Clone)]
+pub struct GtfsImport {
+    /// Time (in seconds since the service day start) which corresponds to the simulation start
+    pub time_origin: i32,
+    /// Number of seconds in the simulation time unit
+    pub time_unit: f64,
+    /// Maximum distance between a stop and its transit cell (in units of the grid's coordinates)
+    pub max_distance: f64,
+    /// Projection of stops and shapes `(lon, lat)` into plain coordinates of the grid, e.g.
+    /// [`convert_epsg4326_to_3857`] for the grid in Web Mercator meters. `None` keeps degrees as they are
+    pub projection: Option<Projection>,
+    /// Services (calendar identifiers) to import. Empty means every service
+    pub services: Vec<String>,
+    /// Agent type of the generated vehicles
+    pub agent_type: AgentType,
+    /// Behaviour type of the generated vehicles
+    pub behaviour_type: BehaviourType,
+    /// Dwell time at stops without [`BusStop`](crate::transit::BusStop) (relax time of the trips)
+    pub dwell_time: i32,
+    /// Identifier of the first generated trip, the next ones are numbered sequentially
+    pub first_trip_id: TripID,
+}
+
+impl GtfsImport {
+    /// Creates a new `GtfsImportBuilder` with default options: time origin at midnight, time unit of 1 second,
+    /// matching distance of 50 units, no projection, every service, buses of the cooperative behaviour, dwell time of 0
+    /// and trips numbered from 1.
+    ///
+    /// # Returns
+    /// A `GtfsImportBuilder` instance for configuring and building the options.
+    ///
+    /// # Example
+    /// ```
+    /// use micro_traffic_sim_core::agents_types::AgentType;
+    /// use micro_traffic_sim_core::transit::GtfsImport;
+    ///
+    /// let options = GtfsImport::new()
+    ///     .with_time_origin(6 * 3600)
+    ///     .with_max_distance(30.0)
+    ///     .with_services(vec!["weekday".to_string()])
+    ///     .with_agent_type(AgentType::LargeBus)
+    ///     .with_dwell_time(20)
+    ///     .with_first_trip_id(100)
+    ///     .build();
+    /// assert_eq!(options.time_origin, 21600);
+    /// ```
+    pub fn new() -> GtfsImportBuilder {
+        GtfsImportBuilder {
+            options: GtfsImport {
+                time_origin: 0,
+                time_unit: 1.0,
+                max_distance: 50.0,
+                projection: None,
+                services: Vec::new(),
+                agent_type: AgentType::Bus,
+                behaviour_type: BehaviourType::Cooperative,
+                dwell_time: 0,
+                first_trip_id: 1,
+            },
+        }
+    }
+}
+
+/// A builder for constructing `GtfsImport` instances.
+pub struct GtfsImportBuilder {
+    options: GtfsImport,
+}
+
+impl GtfsImportBuilder {
+    /// Sets the time (in seconds since the service day start) which corresponds to the simulation start.
+    pub fn with_time_origin(mut self, time_origin: i32) -> Self {
+        self.options.time_origin = time_origin;
+        self
+    }
+
+    /// Sets the number of seconds in the simulation time unit.
+    pub fn with_time_unit(mut self, time_unit: f64) -> Self {
+        self.options.time_unit = time_unit;
+        self
+    }
+
+    /// Sets the maximum distance between a stop and its transit cell (in units of the grid's coordinates).
+    pub fn with_max_distance(mut self, max_distance: f64) -> Self {
+        self.options.max_distance = max_distance;
+        self
+    }
+
+    /// Sets projection of stops and shapes into plain coordinates of the grid.
+    pub fn with_projection(mut self, projection: Projection) -> Self {
+        self.options.projection = Some(projection);
+        self
+    }
+
+    /// Sets services (calendar identifiers) to import.
+    pub fn with_services(mut self, services: Vec<String>) -> Self {
+        self.options.services = services;
+        self
+    }
+
+    /// Sets agent type of the generated vehicles.
+    pub fn with_agent_type(mut self, agent_type: AgentType) -> Self {
+        self.options.agent_type = agent_type;
+        self
+    }
+
+    /// Sets behaviour type of the generated vehicles.
+    pub fn with_behaviour_type(mut self, behaviour_type: BehaviourType) -> Self {
+        self.options.behaviour_type = behaviour_type;
+        self
+    }
+
+    /// Sets dwell time at stops without a bus stop (relax time of the trips).
+    pub fn with_dwell_time(mut self, dwell_time: i32) -> Self {
+        self.options.dwell_time = dwell_time;
+        self
+    }
+
+    /// Sets identifier of the first generated trip.
+    pub fn with_first_trip_id(mut self, first_trip_id: TripID) -> Self {
+        self.options.first_trip_id = first_trip_id;
+        self
+    }
+
+    /// Builds and returns the final `GtfsImport` instance.
+    pub fn build(self) -> GtfsImport {
+        self.options
+    }
+}
+
+/// Heading (in radians, counterclockwise from the X axis) of the shape's segment nearest to the point
+fn shape_heading(shape: &[(f64, f64)], x: f64, y: f64) -> Option<f64> {
+    shape
+        .windows(2)
+        .map(|segment| {
+            let ((x1, y1), (x2, y2)) = (segment[0], segment[1]);
+            (segment_distance(x, y, x1, y1, x2, y2), (y2 - y1).atan2(x2 - x1))
+        })
+        .min_by(|a, b| a.0.total_cmp(&b.0))
+        .map(|(_, heading)| heading)
+}
+
+/// Distance from the point to the segment in plain coordinates
+fn segment_distance(x: f64, y: f64, x1: f64, y1: f64, x2: f64, y2: f64) -> f64 {
+    let (dx, dy) = (x2 - x1, y2 - y1);
+    let length_sq = dx * dx + dy * dy;
+    let t = if length_sq > 0.0 {
+        (((x - x1) * dx + (y - y1) * dy) / length_sq).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    ((x - x1 - t * dx).powi(2) + (y - y1 - t * dy).powi(2)).sqrt()
+}
+
+/// Parses GTFS time `HH:MM:SS` (hours could exceed 24) into seconds
+fn parse_gtfs_time(value: &str) -> Option<i32> {
+    let mut parts = value.trim().split(':');
+    let hours: i32 = parts.next()?.parse().ok()?;
+    let minutes: i32 = parts.next()?.parse().ok()?;
+    let seconds: i32 = parts.next()?.parse().ok()?;
+    if parts.next().is_some() || !(0..60).contains(&minutes) || !(0..60).contains(&seconds) {
+        return None;
+    }
+    Some(hours * 3600 + minutes * 60 + seconds)
+}
+
+/// Minimal CSV table (RFC 4180: quoted fields, escaped quotes, CRLF line endings) with the header row
+struct CsvTable<'a> {
+    file: &'a str,
+    columns: HashMap<String, usize>,
+    records: Vec<(usize, Vec<String>)>,
+}
+
+impl<'a> CsvTable<'a> {
+    fn parse(file: &'a str, contents: &str, required: &[&str]) -> Result<Self, GtfsError> {
+        let contents = contents.strip_prefix('\u{feff}').unwrap_or(contents);
+        let mut records = split_records(contents);
+        let header = if records.is_empty() { vec![] } else { records.remove(0).1 };
+        let columns: HashMap<String, usize> = header
+            .into_iter()
+            .enumerate()
+            .map(|(idx, column)| (column.trim().to_string(), idx))
+            .collect();
+        if let Some(column) = required.iter().find(|column| !columns.contains_key(**column)) {
+            return Err(GtfsError::MissingColumn { file: file.to_string(), column: column.to_string() });
+        }
+        Ok(CsvTable { file, columns, records })
+    }
+
+    fn rows(&self) -> impl Iterator<Item = CsvRow<'_>> {
+        self.records
+            .iter()
+            .filter(|(_, fields)| !(fields.len() == 1 && fields[0].trim().is_empty()))
+            .map(move |(line, fields)| CsvRow { table: self, line: *line, fields })
+    }
+}
+
+struct CsvRow<'a> {
+    table: &'a CsvTable<'a>,
+    line: usize,
+    fields: &'a [String],
+}
+
+impl CsvRow<'_> {
+    fn get(&self, column: &str) -> Option<&str> {
+        let idx = *self.table.columns.get(column)?;
+        self.fields.get(idx).map(|value| value.trim())
+    }
+
+    fn bad_value(&self, column: &str) -> GtfsError {
+        GtfsError::BadValue {
+            file: self.table.file.to_string(),
+            line: self.line,
+            column: column.to_string(),
+            value: self.get(column).unwrap_or_default().to_string(),
+        }
+    }
+
+    fn get_required(&self, column: &str) -> Result<&str, GtfsError> {
+        self.get(column).filter(|value| !value.is_empty()).ok_or_else(|| self.bad_value(column))
+    }
+
+    fn parse<T: std::str::FromStr>(&self, column: &str) -> Result<T, GtfsError> {
+        self.get_required(column)?.parse().map_err(|_| self.bad_value(column))
+    }
+
+    fn parse_time(&self, column: &str) -> Result<Option<i32>, GtfsError> {
+        match self.get(column) {
+            None | Some("") => Ok(None),
+            Some(value) => parse_gtfs_time(value).map(Some).ok_or_else(|| self.bad_value(column)),
+        }
+    }
+}
+
+/// Splits CSV contents into records with line numbers (starting from 1) of their beginnings
+fn split_records(contents: &str) -> Vec<(usize, Vec<String>)> {
+    let mut records = Vec::new();
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut line = 1;
+    let mut record_line = 1;
+    let mut chars = contents.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if in_quotes && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => in_quotes = !in_quotes,
+            ',' if !in_quotes => fields.push(std::mem::take(&mut field)),
+            '\r' if !in_quotes => {}
+            '\n' if !in_quotes => {
+                fields.push(std::mem::take(&mut field));
+                records.push((record_line, std::mem::take(&mut fields)));
+                line += 1;
+                record_line = line;
+            }
+            _ => {
+                if c == '\n' {
+                    line += 1;
+                }
+                field.push(c);
+            }
+        }
+    }
+    if !field.is_empty() || !fields.is_empty() {
+        fields.push(field);
+        records.push((record_line, fields));
+    }
+    records
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_csv_parsing() {
+        let records = split_records("a,b,c\r\n1,\"x, \"\"y\"\"\",3\n\"multi\nline\",,\n4,5,6");
+        assert_eq!(records.len(), 4);
+        assert_eq!(records[1].1, vec!["1", "x, \"y\"", "3"]);
+        assert_eq!(records[2], (3, vec!["multi\nline".to_string(), String::new(), String::new()]));
+        assert_eq!(records[3].0, 5);
+
+        assert_eq!(parse_gtfs_time("25:10:05"), Some(25 * 3600 + 605));
+        assert_eq!(parse_gtfs_time(" 7:00:00"), Some(7 * 3600));
+        assert_eq!(parse_gtfs_time("07:60:00"), None);
+        assert_eq!(parse_gtfs_time("07:00"), None);
+    }
+
+    #[test]
+    fn test_projected_grid() {
+        use crate::geom::convert_epsg4326_to_3857;
+
+        // Grid in Web Mercator meters along the equator with transit cells 2 and 5, cells are 100 m long
+        let mut grid = GridRoads::new();
+        for id in 1..=6 {
+            let zone_type = if id == 2 || id == 5 { ZoneType::Transit } else { ZoneType::Common };
+            let point = new_point(id as f64 * 100.0, 0.0, None);
+            grid.add_cell(Cell::new(id).with_point(point).with_zone_type(zone_type).with_forward_node(id + 1).build());
+        }
+        // Stops 10 m away from the cells, in degrees
+        let lon = |x: f64| x * 180.0 / 20037508.34;
+        let stops = format!("stop_id,stop_lat,stop_lon\nA,0.0,{}\nB,0.0,{}\n", lon(210.0), lon(490.0));
+        let feed = GtfsFeed::parse(
+            &stops,
+            "route_id,service_id,trip_id\n10,weekday,T1\n",
+            "trip_id,arrival_time,departure_time,stop_id,stop_sequence\nT1,07:00:00,07:00:00,A,1\nT1,07:02:00,07:02:00,B,2\n",
+            None,
+        )
+        .unwrap();
+        // Degrees are too far from the grid in meters
+        let options = GtfsImport::new().with_max_distance(20.0).build();
+        assert_eq!(feed.match_stop("A", "", &grid, &options), Err(GtfsError::NoTransitCell("A".to_string())));
+        let options = GtfsImport::new().with_max_distance(20.0).with_projection(convert_epsg4326_to_3857).build();
+        assert_eq!(feed.match_stop("A", "", &grid, &options), Ok(2));
+        assert_eq!(feed.match_stop("B", "", &grid, &options), Ok(5));
+
+        let options = GtfsImport::new().with_time_unit(0.0).with_projection(convert_epsg4326_to_3857).build();
+        assert_eq!(feed.to_trips(&grid, &options).err(), Some(GtfsError::BadTimeUnit(0.0)));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let stop_times = "trip_id,arrival_time,departure_time,stop_id,stop_sequence\n";
+        let missing = GtfsFeed::parse("stop_id,stop_lat\nA,0.0\n", "route_id,service_id,trip_id\n", stop_times, None);
+        assert_eq!(
+            missing.err(),
+            Some(GtfsError::MissingColumn { file: "stops.txt".to_string(), column: "stop_lon".to_string() })
+        );
+
+        let stops = "\u{feff}stop_id,stop_lat,stop_lon\nA,0.0,1.0\n\nB,north,1.0\n";
+        let bad = GtfsFeed::parse(stops, "route_id,service_id,trip_id\n", stop_times, None);
+        assert_eq!(
+            bad.err(),
+            Some(GtfsError::BadValue {
+                file: "stops.txt".to_string(),
+                line: 4,
+                column: "stop_lat".to_string(),
+                value: "north".to_string(),
+            })
+        );
+    }
+}
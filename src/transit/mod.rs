@@ -0,0 +1,57 @@
+//! # Transit module
+//!
+//! Bus stops and transit operations of buses following their transit cells.
+//!
+//! Bus (`AgentType::Bus` or `AgentType::LargeBus`) generated by a trip with transit cells
+//! (see [`TripBuilder::with_transits_cells`](crate::trips::trip::TripBuilder::with_transits_cells))
+//! goes from one transit cell to another. Transit cells are cells of [`ZoneType::Transit`](crate::grid::zones::ZoneType::Transit).
+//! When a bus reaches the next transit cell it dwells there:
+//! - for the stop's dwell time if the cell has a [`BusStop`] (fixed or boarding-based, see [`DwellTime`]);
+//! - for the trip's relax time otherwise.
+//!
+//! Bus stops with a bay let other vehicles pass while a bus dwells in the bay. Buses dwelling at
+//! stops without a bay block the lane.
+//!
+//! ## Schedules
+//!
+//! Regular departures of buses are generated by trips with [`TripType::Timetable`](crate::trips::trip::TripType::Timetable)
+//! (explicit departure times) or [`TripType::Headway`](crate::trips::trip::TripType::Headway) (fixed interval from
+//! the trip's start time). Departure which can't be made in time (the origin cell is occupied) is made as soon as possible.
+//!
+//! ## Records
+//!
+//! Every arrival and departure of a bus at a transit cell is reported in
+//! [`AutomataState::bus_stop_events`](crate::simulation::states::AutomataState::bus_stop_events), so punctuality
+//! and bunching could be measured.
+//!
+//! ## GTFS
+//!
+//! Bus routes and schedules could be imported from a local GTFS feed (`stops.txt`, `trips.txt`, `stop_times.txt`
+//! and `shapes.txt`): stops are map-matched to the nearest transit cells and bus trips with timetable
+//! departures are generated (see [`GtfsFeed::to_trips`]).
+//!
+//! ## Components
+//!
+//! - [`stops::BusStop`] - Bus stop on a transit cell (dwell time, bay)
+//! - [`stops::DwellTime`] - Fixed or boarding-based dwell time
+//! - [`gtfs::GtfsFeed`] - GTFS feed reader and its conversion into trips
+//! - [`gtfs::GtfsImport`] - Options of the conversion (time origin, matching distance, projection, services)
+//!
+//! ## Example
+//!
+//! ```rust
+//! use micro_traffic_sim_core::simulation::session::Session;
+//! use micro_traffic_sim_core::transit::{BusStop, DwellTime};
+//!
+//! let mut session = Session::default(None);
+//! session.add_bus_stop(
+//!     BusStop::new(1, 25)
+//!         .with_dwell_time(DwellTime::Boarding { base: 5, boarding_time: 2.0, arrival_rate: 0.05, max: 60 })
+//!         .with_bay(true)
+//!         .build(),
+//! );
+//! ```
+mod gtfs;
+mod stops;
+
+pub use self::{gtfs::*, stops::*};
@@ -0,0 +1,191 @@
+use crate::grid::cell::CellID;
+
+/// Type alias for bus stop identifiers.
+pub type BusStopID = i64;
+
+/// Dwell time of a bus at a stop.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DwellTime {
+    /// Same number of time units for every bus.
+    Fixed(i32),
+    /// Boarding-based dwell time: `base + boarding_time * waiting passengers`, capped by `max`.
+    /// Passengers arrive at the stop uniformly with `arrival_rate` per time unit and wait since the previous
+    /// departure (since the simulation start for the first bus), so a late bus dwells longer than a punctual one.
+    /// Passengers arriving while a bus dwells board it, so a bus bunched behind it takes nobody.
+    Boarding {
+        /// Time (in time units) to open and close doors
+        base: i32,
+        /// Time (in time units) for a single passenger to board
+        boarding_time: f64,
+        /// Number of arriving passengers per time unit
+        arrival_rate: f64,
+        /// Maximum dwell time (in time units)
+        max: i32,
+    },
+}
+
+/// Bus stop placed on a transit cell (see [`ZoneType::Transit`](crate::grid::zones::ZoneType::Transit)).
+///
+/// Bus which reaches the stop as the next one of its transit cells dwells there (see [`DwellTime`]).
+/// If the stop has a bay, the dwelling bus leaves the lane, so other vehicles pass through the stop cell;
+/// after dwelling it merges back once its cells are free. Otherwise the dwelling bus blocks the lane.
+#[derive(Debug, Clone)]
+pub struct BusStop {
+    /// Bus stop identifier
+    id: BusStopID,
+    /// Transit cell of the stop
+    cell_id: CellID,
+    /// Dwell time of buses at the stop
+    dwell_time: DwellTime,
+    /// Whether the stop has a bay (dwelling buses do not block the lane)
+    bay: bool,
+    /// Time of the last departure from the stop (expected one while a bus dwells)
+    last_departure: Option<i32>,
+}
+
+impl BusStop {
+    /// Creates a new `BusStopBuilder` for constructing a `BusStop` with a fixed dwell time of 0 and without a bay.
+    ///
+    /// # Arguments
+    /// * `id` - The identifier for the bus stop.
+    /// * `cell_id` - Transit cell of the stop.
+    ///
+    /// # Returns
+    /// A `BusStopBuilder` instance for configuring and building the bus stop.
+    ///
+    /// # Example
+    /// ```
+    /// use micro_traffic_sim_core::transit::{BusStop, DwellTime};
+    ///
+    /// let stop = BusStop::new(1, 25)
+    ///     .with_dwell_time(DwellTime::Fixed(10))
+    ///     .with_bay(true)
+    ///     .build();
+    /// assert_eq!(stop.get_cell_id(), 25);
+    /// assert!(stop.is_bay());
+    /// ```
+    pub fn new(id: BusStopID, cell_id: CellID) -> BusStopBuilder {
+        BusStopBuilder {
+            stop: BusStop {
+                id,
+                cell_id,
+                dwell_time: DwellTime::Fixed(0),
+                bay: false,
+                last_departure: None,
+            },
+        }
+    }
+
+    /// Returns the unique identifier (ID) of the bus stop
+    pub fn get_id(&self) -> BusStopID {
+        self.id
+    }
+
+    /// Returns transit cell of the stop
+    pub fn get_cell_id(&self) -> CellID {
+        self.cell_id
+    }
+
+    /// Checks whether the stop has a bay
+    pub fn is_bay(&self) -> bool {
+        self.bay
+    }
+
+    /// Returns time of the last departure from the stop (expected one while a bus dwells at the stop)
+    pub fn get_last_departure(&self) -> Option<i32> {
+        self.last_departure
+    }
+
+    /// Records departure (or expected departure of a dwelling bus) from the stop
+    pub fn set_last_departure(&mut self, timestamp: i32) {
+        self.last_departure = Some(timestamp);
+    }
+
+    /// Forgets the last departure, so passengers wait since the simulation start again.
+    pub fn reset(&mut self) {
+        self.last_departure = None;
+    }
+
+    /// Calculates dwell time for a bus arriving at the given time.
+    ///
+    /// # Arguments
+    /// * `timestamp` - Arrival time
+    ///
+    /// # Returns
+    /// Dwell time (in time units).
+    ///
+    /// # Example
+    /// ```
+    /// use micro_traffic_sim_core::transit::{BusStop, DwellTime};
+    ///
+    /// let mut stop = BusStop::new(1, 25)
+    ///     .with_dwell_time(DwellTime::Boarding { base: 2, boarding_time: 1.0, arrival_rate: 0.2, max: 20 })
+    ///     .build();
+    /// stop.set_last_departure(100);
+    /// // 6 passengers have been arrived since the previous bus
+    /// assert_eq!(stop.dwell_time(130), 8);
+    /// // Bunched bus takes nobody
+    /// assert_eq!(stop.dwell_time(100), 2);
+    /// ```
+    pub fn dwell_time(&self, timestamp: i32) -> i32 {
+        match self.dwell_time {
+            DwellTime::Fixed(time) => time.max(0),
+            DwellTime::Boarding { base, boarding_time, arrival_rate, max } => {
+                let waiting_time = (timestamp - self.last_departure.unwrap_or(0)).max(0);
+                let passengers = (arrival_rate * waiting_time as f64).round();
+                let dwell_time = base as f64 + (boarding_time * passengers).ceil();
+                (dwell_time as i32).clamp(0, max.max(0))
+            }
+        }
+    }
+}
+
+/// A builder for constructing `BusStop` instances.
+pub struct BusStopBuilder {
+    stop: BusStop,
+}
+
+impl BusStopBuilder {
+    /// Sets dwell time of buses at the stop.
+    pub fn with_dwell_time(mut self, dwell_time: DwellTime) -> Self {
+        self.stop.dwell_time = dwell_time;
+        self
+    }
+
+    /// Sets whether the stop has a bay: buses dwelling in it do not block the lane.
+    pub fn with_bay(mut self, bay: bool) -> Self {
+        self.stop.bay = bay;
+        self
+    }
+
+    /// Builds and returns the final `BusStop` instance.
+    pub fn build(self) -> BusStop {
+        self.stop
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dwell_time() {
+        let fixed = BusStop::new(1, 10).with_dwell_time(DwellTime::Fixed(5)).build();
+        assert_eq!(fixed.dwell_time(0), 5);
+        assert_eq!(fixed.dwell_time(1000), 5);
+
+        let mut boarding = BusStop::new(2, 20)
+            .with_dwell_time(DwellTime::Boarding { base: 3, boarding_time: 2.0, arrival_rate: 0.1, max: 15 })
+            .build();
+        // First bus: passengers have been waiting since the simulation start
+        assert_eq!(boarding.dwell_time(20), 3 + 4);
+        // Capped by the maximum dwell time
+        assert_eq!(boarding.dwell_time(200), 15);
+        boarding.set_last_departure(50);
+        assert_eq!(boarding.dwell_time(60), 3 + 2);
+        assert_eq!(boarding.dwell_time(50), 3);
+        boarding.reset();
+        assert_eq!(boarding.get_last_departure(), None);
+        assert_eq!(boarding.dwell_time(20), 3 + 4);
+    }
+}
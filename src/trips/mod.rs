@@ -10,7 +10,7 @@
 //! 
 //! - [`trip::Trip`] - Vehicle generator with routing and timing configuration
 //! - [`trip::TripBuilder`] - Builder pattern for creating trips
-//! - [`trip::TripType`] - Generation patterns (constant frequency vs random)
+//! - [`trip::TripType`] - Generation patterns (constant frequency, random, timetable or headway)
 //! - [`trip::TripID`] - Type alias for trip identifiers
 //!
 //! ## Generation Patterns
@@ -37,6 +37,25 @@
 //!     .build();
 //! ```
 //!
+//! ### Scheduled Generation
+//! Spawns vehicles (e.g. buses) at timetable departures or with a headway from the start time.
+//! Departures which can't be made in time because the origin cell is occupied are made as soon as possible:
+//! ```rust
+//! use micro_traffic_sim_core::agents_types::AgentType;
+//! use micro_traffic_sim_core::trips::trip::{Trip, TripType};
+//!
+//! let timetable = Trip::new(1, 100, TripType::Timetable)
+//!     .with_allowed_agent_type(AgentType::Bus)
+//!     .with_departures(vec![0, 420, 900])
+//!     .build();
+//! // Every 10 minutes from the 60th second
+//! let headway = Trip::new(1, 100, TripType::Headway)
+//!     .with_allowed_agent_type(AgentType::Bus)
+//!     .with_start_time(60)
+//!     .with_time(600)
+//!     .build();
+//! ```
+//!
 //! ## Integration
 //! 
 //! - **Grid System**: Uses [`CellID`](crate::grid::cell::CellID) for routing
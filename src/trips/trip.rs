@@ -1,6 +1,8 @@
 use crate::behaviour::{BehaviourType};
 use crate::agents_types::AgentType;
 use crate::grid::cell::CellID;
+use crate::route_choice::RouteChoice;
+use crate::behaviour::{BehaviourDistributions, DriverModelRef, MobilParameters};
 use std::fmt;
 
 /// Vehicle generation patterns for trip scheduling.
@@ -26,6 +28,10 @@ pub enum TripType {
     Constant,
     /// Generate vehicles probabilistically each time step (use `probability` field)
     Random,
+    /// Generate vehicles at given departure times (use `departures` field)
+    Timetable,
+    /// Generate vehicles at regular intervals starting from the trip's start time (use `time` field as headway)
+    Headway,
 }
 
 impl fmt::Display for TripType {
@@ -42,12 +48,16 @@ impl fmt::Display for TripType {
     /// assert_eq!(format!("{}", TripType::Undefined), "undefined");
     /// assert_eq!(format!("{}", TripType::Constant), "constant");
     /// assert_eq!(format!("{}", TripType::Random), "random");
+    /// assert_eq!(format!("{}", TripType::Timetable), "timetable");
+    /// assert_eq!(format!("{}", TripType::Headway), "headway");
     /// ```
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let trip_type_str = match self {
             TripType::Undefined => "undefined",
             TripType::Constant => "constant",
             TripType::Random => "random",
+            TripType::Timetable => "timetable",
+            TripType::Headway => "headway",
         };
         write!(f, "{}", trip_type_str)
     }
@@ -65,6 +75,8 @@ pub type TripID = i64; // Alias for TripID
 ///
 /// - **Constant**: Regular interval spawning using `time` field  
 /// - **Random**: Probabilistic spawning using `probability` field
+/// - **Timetable**: Spawning at given departure times using `departures` field
+/// - **Headway**: Regular interval spawning from `start_time` using `time` field
 /// - **Time-bounded**: Optional `start_time` and `end_time` limits
 ///
 /// # Examples
@@ -99,8 +111,10 @@ pub struct Trip {
     pub allowed_behaviour_type: BehaviourType,
     // Type trip for generating different vehicles. See TripType description
     pub trip_type: TripType,
-    // Frequency (seconds) with which vehicles are generated if trip type is TripType::Constant
+    // Frequency (seconds) with which vehicles are generated if trip type is TripType::Constant or TripType::Headway
     pub time: i32,
+    // Departure times (seconds) if trip type is TripType::Timetable
+    pub departures: Vec<i32>,
     // Start time (second) for the trip. Until that no vehicles will be generated. Default: 0
     pub start_time: i32,
     // End time (second) for the trip. After that no vehicles will be generated. Default:: i32::MAX
@@ -139,6 +153,52 @@ pub struct Trip {
     // Speed limit for generated vehicles. If >= 0, overrides the behaviour-derived speed limit.
     // Default: -1 (meaning "resolve from behaviour type")
     pub speed_limit: i32,
+    // Stochastic route assignment among alternative routes. If not set, vehicles follow the shortest path.
+    pub route_choice: Option<RouteChoice>,
+    // Custom driver behaviour model for generated vehicles. If not set, vehicles use the default one (NaSch).
+    pub driver_model: Option<DriverModelRef>,
+    // Discretionary lane changing parameters for generated vehicles. If not set, vehicles change lanes only when blocked.
+    pub mobil: Option<MobilParameters>,
+    // Distance (in cells) of lane pre-selection for generated vehicles. Zero disables it.
+    pub lane_selection_distance: i32,
+    // Distributions of driver parameters for generated vehicles. If not set, the session's ones are used (if any).
+    pub behaviour_distributions: Option<BehaviourDistributions>,
+}
+
+impl Trip {
+    /// Returns the number of departures scheduled up to the given time (inclusive) for `TripType::Timetable`
+    /// and `TripType::Headway` trips. Departures out of the trip's start and end times are not counted.
+    ///
+    /// # Arguments
+    /// * `time` - Time (seconds)
+    ///
+    /// # Returns
+    /// Number of scheduled departures (zero for other trip types).
+    ///
+    /// # Example
+    /// ```
+    /// use micro_traffic_sim_core::trips::trip::{Trip, TripType};
+    /// let headway = Trip::new(1, 10, TripType::Headway).with_start_time(5).with_time(10).build();
+    /// assert_eq!(headway.scheduled_departures(4), 0);
+    /// assert_eq!(headway.scheduled_departures(5), 1);
+    /// assert_eq!(headway.scheduled_departures(25), 3);
+    /// let timetable = Trip::new(1, 10, TripType::Timetable).with_departures(vec![3, 7, 30]).build();
+    /// assert_eq!(timetable.scheduled_departures(10), 2);
+    /// ```
+    pub fn scheduled_departures(&self, time: i32) -> usize {
+        let until = time.min(self.end_time);
+        match self.trip_type {
+            TripType::Timetable => self
+                .departures
+                .iter()
+                .filter(|&&departure| departure >= self.start_time && departure <= until)
+                .count(),
+            TripType::Headway if self.time > 0 && until >= self.start_time => {
+                ((until - self.start_time) / self.time) as usize + 1
+            }
+            _ => 0,
+        }
+    }
 }
 
 /// A builder pattern implementation for constructing `Trip` objects.
@@ -183,11 +243,17 @@ impl Trip {
                 allowed_behaviour_type: BehaviourType::Undefined,
                 trip_type,
                 time: -1,
+                departures: Vec::new(),
                 start_time: 0,
                 end_time: i32::MAX,
                 relax_time: -1,
                 vehicle_tail_size: 0,
                 speed_limit: -1,
+                route_choice: None,
+                driver_model: None,
+                mobil: None,
+                lane_selection_distance: 0,
+                behaviour_distributions: None,
             },
         }
     }
@@ -305,7 +371,7 @@ impl TripBuilder {
     ///
     /// # Arguments
     ///
-    /// * `time` - Frequency (seconds) with which vehicles are generated if trip type is TripType::Constant
+    /// * `time` - Frequency (seconds) with which vehicles are generated if trip type is TripType::Constant or TripType::Headway
     ///
     /// # Example
     ///
@@ -321,6 +387,29 @@ impl TripBuilder {
         self
     }
 
+    /// Sets departure times for the trip
+    ///
+    /// # Arguments
+    ///
+    /// * `departures` - Times (seconds) at which vehicles are generated if trip type is TripType::Timetable.
+    ///   If the origin cell is occupied at the departure time, vehicle is generated as soon as it gets free.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use micro_traffic_sim_core::agents_types::AgentType;
+    /// use micro_traffic_sim_core::trips::trip::{Trip, TripType};
+    /// let trip = Trip::new(1, 10, TripType::Timetable)
+    ///     .with_allowed_agent_type(AgentType::Bus)
+    ///     .with_departures(vec![0, 300, 600, 1200])
+    ///     .build();
+    /// assert_eq!(trip.departures.len(), 4);
+    /// ```
+    pub fn with_departures(mut self, departures: Vec<i32>) -> Self {
+        self.trip.departures = departures;
+        self
+    }
+
     /// Sets the probability for generating vehicle
     ///
     /// # Arguments
@@ -434,6 +523,117 @@ impl TripBuilder {
         self
     }
 
+    /// Sets route choice parameters. Each generated vehicle will be assigned to one of
+    /// the alternative routes between `from_node` and `to_node` with the given logit model.
+    ///
+    /// # Arguments
+    ///
+    /// * `route_choice` - Route choice parameters. See the ref. at `RouteChoice`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use micro_traffic_sim_core::trips::trip::{Trip, TripType};
+    /// use micro_traffic_sim_core::route_choice::{RouteChoice, RouteChoiceModel};
+    /// let trip = Trip::new(1, 10, TripType::Constant)
+    ///     .with_route_choice(RouteChoice::new(RouteChoiceModel::MultinomialLogit).build())
+    ///     .build();
+    /// assert!(trip.route_choice.is_some());
+    /// ```
+    pub fn with_route_choice(mut self, route_choice: RouteChoice) -> Self {
+        self.trip.route_choice = Some(route_choice);
+        self
+    }
+
+    /// Sets custom driver behaviour model for generated vehicles. The model is shared between them.
+    ///
+    /// # Arguments
+    ///
+    /// * `model` - Driver model. See the ref. at `DriverModel`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use micro_traffic_sim_core::trips::trip::{Trip, TripType};
+    /// use micro_traffic_sim_core::behaviour::NaSchModel;
+    /// use std::sync::Arc;
+    /// let trip = Trip::new(1, 10, TripType::Constant)
+    ///     .with_driver_model(Arc::new(NaSchModel))
+    ///     .build();
+    /// assert!(trip.driver_model.is_some());
+    /// ```
+    pub fn with_driver_model(mut self, model: DriverModelRef) -> Self {
+        self.trip.driver_model = Some(model);
+        self
+    }
+
+    /// Enables discretionary lane changing for generated vehicles.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - Incentive and safety parameters. See the ref. at `MobilParameters`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use micro_traffic_sim_core::trips::trip::{Trip, TripType};
+    /// use micro_traffic_sim_core::behaviour::MobilParameters;
+    /// let trip = Trip::new(1, 10, TripType::Constant)
+    ///     .with_mobil(MobilParameters::new().build())
+    ///     .build();
+    /// assert!(trip.mobil.is_some());
+    /// ```
+    pub fn with_mobil(mut self, params: MobilParameters) -> Self {
+        self.trip.mobil = Some(params);
+        self
+    }
+
+    /// Enables route-aware lane pre-selection for generated vehicles.
+    ///
+    /// # Arguments
+    ///
+    /// * `distance` - Distance (in cells) upstream at which vehicles begin moving to the lane they need
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use micro_traffic_sim_core::trips::trip::{Trip, TripType};
+    /// let trip = Trip::new(1, 10, TripType::Constant)
+    ///     .with_lane_selection_distance(15)
+    ///     .build();
+    /// assert_eq!(trip.lane_selection_distance, 15);
+    /// ```
+    pub fn with_lane_selection_distance(mut self, distance: i32) -> Self {
+        self.trip.lane_selection_distance = distance;
+        self
+    }
+
+    /// Sets distributions of driver parameters: every generated vehicle gets its own sample
+    /// on top of the behaviour type's parameters (see [`BehaviourDistributions`]).
+    ///
+    /// # Arguments
+    ///
+    /// * `distributions` - Distributions of driver parameters
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use micro_traffic_sim_core::trips::trip::{Trip, TripType};
+    /// use micro_traffic_sim_core::behaviour::{BehaviourDistributions, ParameterDistribution};
+    /// let trip = Trip::new(1, 10, TripType::Constant)
+    ///     .with_behaviour_distributions(
+    ///         BehaviourDistributions::new()
+    ///             .with_slow_down_factor(ParameterDistribution::Normal { mean: 0.2, std_dev: 0.05 })
+    ///             .build(),
+    ///     )
+    ///     .build();
+    /// assert!(trip.behaviour_distributions.is_some());
+    /// ```
+    pub fn with_behaviour_distributions(mut self, distributions: BehaviourDistributions) -> Self {
+        self.trip.behaviour_distributions = Some(distributions);
+        self
+    }
+
     /// Builds the final `Trip` object with the configured properties.
     ///
     /// If `vehicle_tail_size` was not explicitly set, it will be automatically
@@ -1,3 +1,4 @@
+use crate::conflict_zones::Roundabout;
 use crate::geom::{new_point};
 use crate::grid::cell::Cell;
 use crate::grid::zones::ZoneType;
@@ -140,3 +141,30 @@ pub fn generate_one_lane_cells(length_meters: f64, step: f64, lanes_num: usize)
 
     all_lanes_cells
 }
+
+/// Generates a roundabout with evenly distributed arms.
+///
+/// Shortcut for [`Roundabout::new`] with the given numbers of arms and lanes and default sizes:
+/// see [`RoundaboutBuilder`](crate::conflict_zones::RoundaboutBuilder) for other parameters.
+///
+/// # Arguments
+///
+/// - `arms_num` - Number of arms (entry and exit pairs)
+/// - `lanes_num` - Number of ring lanes (and entry lanes of each arm)
+///
+/// # Returns
+///
+/// [`Roundabout`] with cells, conflict zones between entering and circulating flows and yield-at-entry junctions.
+///
+/// # Examples
+///
+/// ```rust
+/// use micro_traffic_sim_core::utils::generators::generate_roundabout;
+///
+/// let roundabout = generate_roundabout(4, 1);
+/// assert_eq!(roundabout.get_arms().len(), 4);
+/// assert_eq!(roundabout.get_conflict_zones().len(), 4);
+/// ```
+pub fn generate_roundabout(arms_num: usize, lanes_num: usize) -> Roundabout {
+    Roundabout::new().with_arms(arms_num).with_lanes(lanes_num).build()
+}
@@ -6,6 +6,7 @@
 //! 
 //! ### Grid Generators
 //! - [`generators::generate_one_lane_cells`] - Creates multi-lane road segments
+//! - [`generators::generate_roundabout`] - Creates roundabouts with entry and exit arms
 //! 
 //! ### Test Grids  
 //! - [`test_grids::create_pretty_simple_grid`] - Complex intersection for testing
@@ -93,6 +93,7 @@ impl From<VerboseLevel> for String {
 // Event type constants
 pub const EVENT_SIMULATION_RESET: &str = "simulation_reset";
 pub const EVENT_STEP: &str = "step";
+pub const EVENT_EQUILIBRIUM_ITERATION: &str = "equilibrium_iteration";
 pub const EVENT_GEN_VEHICLES: &str = "generate_vehicles";
 pub const EVENT_GEN_VEHICLE: &str = "generate_vehicle";
 pub const EVENT_UPD_POS: &str = "update_pos";
@@ -108,6 +109,9 @@ pub const EVENT_MOVEMENT: &str = "movement";
 pub const EVENT_MOVEMENT_VEHICLE: &str = "movement_vehicle";
 pub const EVENT_MOVEMENT_DEAD_END: &str = "movement_dead_end";
 pub const EVENT_MOVEMENT_DESTINATION: &str = "movement_destination";
+pub const EVENT_GRIDLOCK: &str = "gridlock";
+pub const EVENT_PEDESTRIANS_MOVEMENT: &str = "pedestrians_movement";
+pub const EVENT_GEN_PEDESTRIAN: &str = "generate_pedestrian";
 pub const EVENT_SESSION_CREATE: &str = "session_create";
 pub const EVENT_SESSION_EXPIRED: &str = "session_expired";
 pub const EVENT_SESSION_EXTRACT_STATES: &str = "session_extract";
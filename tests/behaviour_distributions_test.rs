@@ -0,0 +1,182 @@
+use micro_traffic_sim_core::agents::{Vehicle, VehicleID};
+use micro_traffic_sim_core::agents_types::AgentType;
+use micro_traffic_sim_core::behaviour::{BehaviourDistributions, BehaviourType, ParameterDistribution};
+use micro_traffic_sim_core::grid::road_network::GridRoads;
+use micro_traffic_sim_core::simulation::grids_storage::GridsStorage;
+use micro_traffic_sim_core::simulation::session::Session;
+use micro_traffic_sim_core::trips::trip::{Trip, TripType};
+use micro_traffic_sim_core::utils::generators::generate_one_lane_cells;
+use micro_traffic_sim_core::verbose::VerboseLevel;
+use std::collections::BTreeMap;
+
+/// Number of cells of the road
+const ROAD_CELLS: i64 = 60;
+
+/// Sampled parameters of a driver: slowdown, speed compliance, cooperativity and safe distance
+type Driver = (f64, f64, f64, i32);
+
+/// Single lane road (speed limit 3): cells 1..60, the last one is the despawn cell
+fn road_session() -> Session {
+    let mut grid = GridRoads::new();
+    for cell in generate_one_lane_cells(ROAD_CELLS as f64 * 10.0, 10.0, 1) {
+        grid.add_cell(cell);
+    }
+    let grids_storage = GridsStorage::new().with_vehicles_net(grid).build();
+    let mut session = Session::new(grids_storage, None);
+    session.set_verbose_level(VerboseLevel::None);
+    session
+}
+
+fn trip() -> Trip {
+    Trip::new(1, ROAD_CELLS, TripType::Constant)
+        .with_id(1)
+        .with_time(3)
+        .with_initial_speed(1)
+        .with_allowed_agent_type(AgentType::Car)
+        .with_allowed_behaviour_type(BehaviourType::Aggressive)
+        .build()
+}
+
+fn distributions() -> BehaviourDistributions {
+    BehaviourDistributions::new()
+        .with_slow_down_factor(ParameterDistribution::TruncatedNormal { mean: 0.2, std_dev: 0.1, min: 0.0, max: 0.5 })
+        .with_speed_compliance(ParameterDistribution::Empirical(vec![0.8, 1.0, 1.0, 1.3]))
+        .with_cooperativity(ParameterDistribution::Uniform { min: 0.0, max: 1.0 })
+        .with_min_safe_distance(ParameterDistribution::Normal { mean: 1.0, std_dev: 1.0 })
+        .build()
+}
+
+/// Runs the session and collects parameters of generated drivers
+fn drivers(mut session: Session, steps: usize) -> BTreeMap<VehicleID, Driver> {
+    let mut drivers = BTreeMap::new();
+    for _ in 0..steps {
+        session.step().expect("Step should be done without errors");
+        for (id, vehicle) in session.get_vehicles() {
+            drivers.entry(*id).or_insert((
+                vehicle.slow_down_factor,
+                vehicle.speed_compliance,
+                vehicle.cooperativity,
+                vehicle.min_safe_distance,
+            ));
+        }
+    }
+    drivers
+}
+
+#[test]
+fn test_behaviour_distributions() {
+    let steps = 60;
+
+    // Without distributions drivers of the same behaviour type are identical
+    let mut session = road_session();
+    session.add_trip(trip());
+    let identical = drivers(session, steps);
+    assert!(identical.len() > 10);
+    assert!(identical.values().all(|driver| *driver == identical[&1]), "{:?}", identical);
+
+    // Trip's distributions give heterogeneous drivers
+    let seeded = |seed: u64| {
+        let mut session = road_session();
+        session.set_rng_seed(Some(seed));
+        session.add_trip(Trip { behaviour_distributions: Some(distributions()), ..trip() });
+        drivers(session, steps)
+    };
+    let sampled = seeded(42);
+    assert_eq!(sampled.len(), identical.len());
+    assert!(sampled.values().all(|(slow_down, compliance, cooperativity, min_safe_distance)| {
+        (0.0..=0.5).contains(slow_down)
+            && [0.8, 1.0, 1.3].contains(compliance)
+            && (0.0..=1.0).contains(cooperativity)
+            && *min_safe_distance >= 0
+    }));
+    let distinct = |value: fn(&Driver) -> f64| {
+        let mut values: Vec<f64> = sampled.values().map(value).collect();
+        values.sort_by(f64::total_cmp);
+        values.dedup();
+        values.len()
+    };
+    assert_eq!(distinct(|driver| driver.0), sampled.len());
+    assert_eq!(distinct(|driver| driver.1), 3);
+    assert!(distinct(|driver| driver.3 as f64) > 1);
+
+    // Same seed gives the same drivers
+    assert_eq!(seeded(42), sampled);
+    assert_ne!(seeded(7), sampled);
+
+    // Session's distributions are used for trips without own ones
+    let mut session = road_session();
+    session.set_rng_seed(Some(42));
+    session.set_behaviour_distributions(Some(distributions()));
+    session.add_trip(trip());
+    assert_eq!(drivers(session, steps), sampled);
+}
+
+/// Runs a single vehicle and returns its maximum speed
+fn max_speed(speed_compliance: f64, speed_limit_offset: i32) -> i32 {
+    let mut session = road_session();
+    session.add_vehicles(vec![Vehicle::new(1)
+        .with_cell(1)
+        .with_speed(1)
+        .with_speed_limit(5)
+        .with_slowdown(0.0)
+        .with_speed_compliance(speed_compliance)
+        .with_speed_limit_offset(speed_limit_offset)
+        .with_destination(ROAD_CELLS)
+        .build()]);
+    let mut max_speed = 0;
+    for _ in 0..10 {
+        let state = session.step().expect("Step should be done without errors");
+        max_speed = state.vehicles.iter().map(|v| v.last_speed).fold(max_speed, i32::max);
+    }
+    max_speed
+}
+
+#[test]
+fn test_speed_compliance() {
+    assert_eq!(max_speed(1.0, 0), 3);
+    // Speeding driver exceeds the posted limit along the whole road...
+    assert_eq!(max_speed(1.4, 0), 4);
+    assert_eq!(max_speed(1.0, 1), 4);
+    // ...but it is still capped by the vehicle's maximum speed
+    assert_eq!(max_speed(2.0, 0), 5);
+    assert_eq!(max_speed(1.0, 3), 5);
+    // Cautious driver
+    assert_eq!(max_speed(0.6, 0), 2);
+    assert_eq!(max_speed(1.0, -1), 2);
+}
+
+#[test]
+fn test_speeding_share() {
+    // A quarter of drivers exceeds the posted limit by one cell per time unit
+    let mut session = road_session();
+    session.set_rng_seed(Some(42));
+    session.add_trip(Trip {
+        speed_limit: 5,
+        behaviour_distributions: Some(
+            BehaviourDistributions::new()
+                .with_slow_down_factor(ParameterDistribution::Constant(0.0))
+                .with_speed_limit_offset(ParameterDistribution::Empirical(vec![0.0, 0.0, 0.0, 1.0]))
+                .build(),
+        ),
+        ..trip()
+    });
+    let mut offsets = BTreeMap::new();
+    let mut max_speeds: BTreeMap<VehicleID, i32> = BTreeMap::new();
+    for _ in 0..600 {
+        let state = session.step().expect("Step should be done without errors");
+        for (id, vehicle) in session.get_vehicles() {
+            offsets.insert(*id, vehicle.speed_limit_offset);
+        }
+        for vehicle in state.vehicles.iter() {
+            let max_speed = max_speeds.entry(vehicle.id).or_insert(0);
+            *max_speed = (*max_speed).max(vehicle.last_speed);
+        }
+    }
+    let speeding = offsets.values().filter(|&&offset| offset == 1).count();
+    let share = speeding as f64 / offsets.len() as f64;
+    assert!((0.15..0.35).contains(&share), "share: {}", share);
+    // Speeding drivers are the only ones driving faster than the posted limit
+    for (id, max_speed) in max_speeds {
+        assert_eq!(max_speed > 3, offsets[&id] == 1, "vehicle {}: max speed {}", id, max_speed);
+    }
+}
@@ -0,0 +1,52 @@
+mod common;
+
+use common::{grid, session, vehicle, visited_cells, LANE_CELLS};
+use micro_traffic_sim_core::agents_types::AgentType;
+use micro_traffic_sim_core::grid::road_network::GridRoads;
+use micro_traffic_sim_core::grid::zones::BUS_LANE_AGENTS;
+
+/// Two lanes road: the right lane (1..30) is the bus lane (except the first and the last cells), the left one (31..60) is regular
+fn bus_lane_grid() -> GridRoads {
+    grid(2, Some(&BUS_LANE_AGENTS))
+}
+
+#[test]
+fn test_bus_lane() {
+    // Broken vehicle in the regular lane and a vehicle behind it
+    let run = |vehicle_type: AgentType| {
+        let vehicles = vec![
+            vehicle(1, LANE_CELLS + 10, 0, AgentType::Car, 2 * LANE_CELLS),
+            vehicle(2, LANE_CELLS + 2, 3, vehicle_type, 2 * LANE_CELLS),
+        ];
+        visited_cells(session(bus_lane_grid(), vehicles), 2, 20)
+    };
+
+    // Bus uses the bus lane to pass the broken vehicle
+    let cells = run(AgentType::Bus);
+    assert!(cells.iter().any(|&cell_id| cell_id <= LANE_CELLS), "{:?}", cells);
+    assert!(cells.iter().any(|&cell_id| cell_id > LANE_CELLS + 10), "{:?}", cells);
+
+    // Car waits behind it
+    let cells = run(AgentType::Car);
+    assert!(cells.iter().all(|&cell_id| (LANE_CELLS + 2..LANE_CELLS + 10).contains(&cell_id)), "{:?}", cells);
+}
+
+#[test]
+fn test_bus_lane_exception() {
+    // Car leaves the road from the end of the bus lane (e.g. turns right)
+    let run = |grid: GridRoads| {
+        let vehicles = vec![vehicle(1, LANE_CELLS + 2, 3, AgentType::Car, LANE_CELLS)];
+        visited_cells(session(grid, vehicles), 1, 30)
+    };
+
+    // Destination can't be reached through the bus lane: car follows the regular lane
+    let cells = run(bus_lane_grid());
+    assert!(cells.iter().all(|&cell_id| cell_id > LANE_CELLS), "{:?}", cells);
+
+    // Car enters the last cells of the bus lane only
+    let mut exception_grid = bus_lane_grid();
+    exception_grid.allow_agents_before(LANE_CELLS - 1, 5, &[AgentType::Car]);
+    let cells = run(exception_grid);
+    assert!(cells.contains(&LANE_CELLS) || cells.contains(&(LANE_CELLS - 1)), "{:?}", cells);
+    assert!(cells.iter().all(|&cell_id| cell_id > LANE_CELLS || cell_id >= LANE_CELLS - 5), "{:?}", cells);
+}
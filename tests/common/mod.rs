@@ -0,0 +1,64 @@
+//! Fixtures shared by integration tests of vehicles on straight multi-lane roads.
+// Every test binary compiles its own copy of the module and uses only some of the fixtures
+#![allow(dead_code)]
+
+use micro_traffic_sim_core::agents::{Vehicle, VehicleID};
+use micro_traffic_sim_core::agents_types::AgentType;
+use micro_traffic_sim_core::grid::cell::CellID;
+use micro_traffic_sim_core::grid::road_network::GridRoads;
+use micro_traffic_sim_core::simulation::grids_storage::GridsStorage;
+use micro_traffic_sim_core::simulation::session::Session;
+use micro_traffic_sim_core::utils::generators::generate_one_lane_cells;
+use micro_traffic_sim_core::verbose::VerboseLevel;
+
+/// Number of cells in each lane
+pub const LANE_CELLS: CellID = 30;
+
+/// Road with given number of lanes: the right lane is 1..30, the next one (if any) is 31..60 and so on.
+/// Optionally the right lane (except the first and the last cells) is reserved for given agents, e.g. a bus lane
+pub fn grid(lanes_num: usize, right_lane_agents: Option<&[AgentType]>) -> GridRoads {
+    let mut grid = GridRoads::new();
+    for mut cell in generate_one_lane_cells(LANE_CELLS as f64 * 10.0, 10.0, lanes_num) {
+        if let Some(agents) = right_lane_agents
+            && (2..LANE_CELLS).contains(&cell.get_id())
+        {
+            cell.set_allowed_agents(Some(agents.to_vec()));
+        }
+        grid.add_cell(cell);
+    }
+    grid
+}
+
+/// Vehicle moving with speed 1 without random slowdown
+pub fn vehicle(id: VehicleID, cell_id: CellID, speed_limit: i32, vehicle_type: AgentType, destination: CellID) -> Vehicle {
+    Vehicle::new(id)
+        .with_type(vehicle_type)
+        .with_cell(cell_id)
+        .with_speed(1)
+        .with_speed_limit(speed_limit)
+        .with_slowdown(0.0)
+        .with_destination(destination)
+        .build()
+}
+
+/// Runs the session and returns cells visited by the vehicle
+pub fn visited_cells(mut session: Session, vehicle_id: VehicleID, steps: usize) -> Vec<CellID> {
+    let mut cells = vec![];
+    for _ in 0..steps {
+        let state = session.step().expect("Step should be done without errors");
+        let Some(vehicle) = state.vehicles.iter().find(|v| v.id == vehicle_id) else {
+            break;
+        };
+        cells.push(vehicle.last_cell);
+    }
+    cells
+}
+
+/// Silent session with given vehicles
+pub fn session(grid: GridRoads, vehicles: Vec<Vehicle>) -> Session {
+    let grids_storage = GridsStorage::new().with_vehicles_net(grid).build();
+    let mut session = Session::new(grids_storage, None);
+    session.set_verbose_level(VerboseLevel::None);
+    session.add_vehicles(vehicles);
+    session
+}
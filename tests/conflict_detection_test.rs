@@ -0,0 +1,76 @@
+use micro_traffic_sim_core::agents::{Vehicle, VehicleID};
+use micro_traffic_sim_core::conflict_zones::ConflictWinnerRule;
+use micro_traffic_sim_core::geom::new_point;
+use micro_traffic_sim_core::grid::cell::{Cell, CellID};
+use micro_traffic_sim_core::grid::road_network::GridRoads;
+use micro_traffic_sim_core::simulation::grids_storage::GridsStorage;
+use micro_traffic_sim_core::simulation::session::Session;
+use micro_traffic_sim_core::verbose::VerboseLevel;
+use std::collections::HashMap;
+
+/// Cell where all approaches merge
+const MERGE_CELL: CellID = 10;
+/// Last cell of the road leaving the merge
+const EXIT_LAST: CellID = 15;
+
+/// Three approaches 1, 2, 3 (speed limits 3, 2, 1) merging into cell 10 followed by the road 11..=15
+fn grid() -> GridRoads {
+    let mut grid = GridRoads::new();
+    for (id, speed_limit) in [(1, 3), (2, 2), (3, 1)] {
+        grid.add_cell(
+            Cell::new(id)
+                .with_point(new_point(-1.0, (2 - id) as f64, None))
+                .with_speed_limit(speed_limit)
+                .with_forward_node(MERGE_CELL)
+                .build(),
+        );
+    }
+    for id in MERGE_CELL..=EXIT_LAST {
+        grid.add_cell(
+            Cell::new(id)
+                .with_point(new_point((id - MERGE_CELL) as f64, 0.0, None))
+                .with_speed_limit(1)
+                .with_forward_node(if id < EXIT_LAST { id + 1 } else { -1 })
+                .build(),
+        );
+    }
+    grid
+}
+
+fn vehicle(id: VehicleID, cell_id: CellID) -> Vehicle {
+    Vehicle::new(id)
+        .with_cell(cell_id)
+        .with_speed(1)
+        .with_speed_limit(1)
+        .with_slowdown(0.0)
+        .with_destination(EXIT_LAST)
+        .build()
+}
+
+#[test]
+fn test_detected_zones_sharing_cell() {
+    let grids_storage = GridsStorage::new().with_vehicles_net(grid()).build();
+    let mut session = Session::new(grids_storage, None);
+    session.set_verbose_level(VerboseLevel::None);
+    // Every pair of approaches gets its own zone, all of them share the merge cell
+    let added = session.add_detected_conflict_zones(ConflictWinnerRule::SpeedLimit);
+    assert_eq!(added.len(), 3);
+
+    // Vehicles from the fastest (1) and the slowest (3) approaches, the one from approach 2 comes later
+    session.add_vehicles(vec![vehicle(1, 1), vehicle(3, 3)]);
+    let mut entered: HashMap<VehicleID, i32> = HashMap::new();
+    for step in 0..10 {
+        let state = session.step().expect("Step should be done without errors");
+        for vehicle in state.vehicles.iter().filter(|vehicle| vehicle.last_cell == MERGE_CELL) {
+            entered.entry(vehicle.id).or_insert(step);
+        }
+        if step == 0 {
+            // Zone of approaches 1 and 3: the faster approach wins
+            assert_eq!(entered.get(&1), Some(&0));
+            assert!(!entered.contains_key(&3));
+            session.add_vehicles(vec![vehicle(2, 2)]);
+        }
+    }
+    // Zone of approaches 2 and 3: approach 2 wins, so vehicle 3 enters the merge cell last
+    assert!(entered[&2] < entered[&3], "entered: {:?}", entered);
+}
@@ -0,0 +1,155 @@
+use micro_traffic_sim_core::agents::{Vehicle, VehicleID};
+use micro_traffic_sim_core::conflict_zones::{ConflictEdge, ConflictWinnerType, ConflictZone};
+use micro_traffic_sim_core::geom::new_point;
+use micro_traffic_sim_core::grid::cell::{Cell, CellID};
+use micro_traffic_sim_core::grid::road_network::GridRoads;
+use micro_traffic_sim_core::grid::zones::ZoneType;
+use micro_traffic_sim_core::simulation::grids_storage::GridsStorage;
+use micro_traffic_sim_core::simulation::session::Session;
+use micro_traffic_sim_core::verbose::VerboseLevel;
+use std::collections::HashSet;
+
+/// Last cell of the continuing lane
+const THROUGH_LANE_END: CellID = 30;
+
+/// Last cell of the dropped lane
+const DROPPED_LANE_END: CellID = 115;
+
+/// Cell of the continuing lane where vehicles from the dropped lane merge into
+const MERGE_CELL: CellID = 16;
+
+/// Road with a lane drop:
+/// ```text
+/// (101) → (102) → ... → (115)
+///                            ↘
+/// (1)   → (2)   → ... → (15) → (16) → ... → (30)
+/// ```
+fn lane_drop_grid() -> GridRoads {
+    let mut grid = GridRoads::new();
+    for id in 1..=THROUGH_LANE_END {
+        let zone_type = if id == THROUGH_LANE_END { ZoneType::Death } else { ZoneType::Common };
+        grid.add_cell(
+            Cell::new(id)
+                .with_point(new_point((id - 1) as f64, 0.0, None))
+                .with_zone_type(zone_type)
+                .with_speed_limit(3)
+                .with_forward_node(if id == THROUGH_LANE_END { -1 } else { id + 1 })
+                .build(),
+        );
+    }
+    for id in 101..=DROPPED_LANE_END {
+        let builder = Cell::new(id)
+            .with_point(new_point((id - 101) as f64, 1.0, None))
+            .with_speed_limit(3);
+        let cell = if id == DROPPED_LANE_END {
+            builder.with_right_node(MERGE_CELL).build()
+        } else {
+            builder.with_forward_node(id + 1).build()
+        };
+        grid.add_cell(cell);
+    }
+    grid
+}
+
+fn queued_vehicle(id: VehicleID, cell_id: CellID, cooperativity: f64) -> Vehicle {
+    Vehicle::new(id)
+        .with_cell(cell_id)
+        .with_speed(0)
+        .with_speed_limit(1)
+        .with_slowdown(0.0)
+        .with_cooperative_level(cooperativity)
+        .with_destination(THROUGH_LANE_END)
+        .build()
+}
+
+fn session(vehicles: Vec<Vehicle>, zipper: bool) -> Session {
+    let grids_storage = GridsStorage::new().with_vehicles_net(lane_drop_grid()).build();
+    let mut session = Session::new(grids_storage, None);
+    session.set_verbose_level(VerboseLevel::None);
+    session.set_rng_seed(Some(42));
+    if zipper {
+        session.add_conflict_zone(
+            ConflictZone::new(
+                1,
+                ConflictEdge { source: MERGE_CELL - 1, target: MERGE_CELL },
+                ConflictEdge { source: DROPPED_LANE_END, target: MERGE_CELL },
+            )
+            .with_winner_type(ConflictWinnerType::Alternate)
+            .build(),
+        );
+    }
+    session.add_vehicles(vehicles);
+    session
+}
+
+/// Runs the session and returns vehicles in order of passing the merge cell
+fn merge_order(mut session: Session, steps: usize) -> Vec<VehicleID> {
+    let mut order = Vec::new();
+    let mut passed = HashSet::new();
+    for _ in 0..steps {
+        let state = session.step().expect("Step should be done without errors");
+        let mut vehicles: Vec<_> = state
+            .vehicles
+            .iter()
+            .filter(|v| (MERGE_CELL..=THROUGH_LANE_END).contains(&v.last_cell) && !passed.contains(&v.id))
+            .collect();
+        // Vehicles which are further passed the merge cell earlier
+        vehicles.sort_by_key(|v| std::cmp::Reverse(v.last_cell));
+        for v in vehicles {
+            passed.insert(v.id);
+            order.push(v.id);
+        }
+    }
+    order
+}
+
+/// Platoon in the continuing lane and a single vehicle waiting at the lane drop
+fn platoon(cooperativity: f64) -> Vec<Vehicle> {
+    let mut vehicles: Vec<Vehicle> = (1..=6)
+        .map(|i| {
+            let mut vehicle = queued_vehicle(i, MERGE_CELL - 2 * i as CellID + 1, cooperativity);
+            vehicle.speed = 1;
+            vehicle
+        })
+        .collect();
+    vehicles.push(queued_vehicle(100, DROPPED_LANE_END, 0.0));
+    vehicles
+}
+
+#[test]
+fn test_cooperative_yielding() {
+    let steps = 25;
+    // Non-cooperative drivers do not leave a gap, so the waiting vehicle merges after the platoon
+    let order = merge_order(session(platoon(0.0), false), steps);
+    assert_eq!(order.iter().position(|&id| id == 100), Some(6), "{:?}", order);
+
+    // Cooperative driver holds back to let it merge
+    let order = merge_order(session(platoon(1.0), false), steps);
+    assert_eq!(order.iter().position(|&id| id == 100), Some(0), "{:?}", order);
+}
+
+/// Queues in both lanes: vehicles 1..=10 in the continuing lane and 101..=110 in the dropped one
+fn queues() -> Vec<Vehicle> {
+    let mut vehicles: Vec<Vehicle> = (1..=10)
+        .map(|i| queued_vehicle(i, MERGE_CELL - i as CellID, 0.0))
+        .collect();
+    vehicles.extend((1..=10).map(|i| queued_vehicle(100 + i, DROPPED_LANE_END + 1 - i as CellID, 0.0)));
+    vehicles
+}
+
+#[test]
+fn test_zipper_merge() {
+    let steps = 40;
+    let from_dropped_lane = |order: &[VehicleID]| order.iter().map(|&id| id > 100).collect::<Vec<bool>>();
+
+    // Vehicles going straight have priority, so the dropped lane waits for the continuing lane queue
+    let order = merge_order(session(queues(), false), steps);
+    let lanes = from_dropped_lane(&order);
+    assert!(lanes[..10].iter().all(|&dropped| !dropped), "{:?}", order);
+
+    // Zipper merge: lanes take turns
+    let order = merge_order(session(queues(), true), steps);
+    let lanes = from_dropped_lane(&order);
+    assert!(lanes.len() >= 10, "{:?}", order);
+    assert!(lanes[..10].windows(2).all(|pair| pair[0] != pair[1]), "Lanes should alternate: {:?}", order);
+}
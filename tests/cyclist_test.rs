@@ -0,0 +1,101 @@
+mod common;
+
+use common::{grid, session, vehicle, visited_cells, LANE_CELLS};
+use micro_traffic_sim_core::agents::VehicleID;
+use micro_traffic_sim_core::agents_types::AgentType;
+use micro_traffic_sim_core::behaviour::MobilParameters;
+use micro_traffic_sim_core::grid::zones::CYCLE_LANE_AGENTS;
+use micro_traffic_sim_core::simulation::grids_storage::GridsStorage;
+use micro_traffic_sim_core::simulation::session::Session;
+use micro_traffic_sim_core::trips::trip::{Trip, TripType};
+use micro_traffic_sim_core::verbose::VerboseLevel;
+
+#[test]
+fn test_cycle_lane() {
+    // Broken vehicle in the regular lane and an agent behind it
+    let run = |vehicle_type: AgentType| {
+        let vehicles = vec![
+            vehicle(1, LANE_CELLS + 10, 0, AgentType::Car, 2 * LANE_CELLS),
+            vehicle(2, LANE_CELLS + 2, 3, vehicle_type, 2 * LANE_CELLS),
+        ];
+        visited_cells(session(grid(2, Some(&CYCLE_LANE_AGENTS)), vehicles), 2, 30)
+    };
+
+    // Cyclist moves to the cycle lane on its kerb side to pass the broken vehicle
+    let cells = run(AgentType::Cyclist);
+    assert!(cells.iter().any(|&cell_id| cell_id <= LANE_CELLS), "{:?}", cells);
+
+    // Car is not permitted in the cycle lane and waits behind it
+    let cells = run(AgentType::Car);
+    assert!(cells.iter().all(|&cell_id| (LANE_CELLS + 2..LANE_CELLS + 10).contains(&cell_id)), "{:?}", cells);
+}
+
+#[test]
+fn test_cyclist_keeps_to_kerb() {
+    // No cycle lane: broken vehicle in the right lane and an agent behind it
+    let run = |vehicle_type: AgentType| {
+        let vehicles = vec![
+            vehicle(1, 10, 0, AgentType::Car, LANE_CELLS),
+            vehicle(2, 2, 1, vehicle_type, LANE_CELLS),
+        ];
+        visited_cells(session(grid(2, None), vehicles), 2, 20)
+    };
+
+    // Cyclist never moves to the overtaking lane
+    let cells = run(AgentType::Cyclist);
+    assert!(cells.iter().all(|&cell_id| cell_id < 10), "{:?}", cells);
+
+    // Car overtakes
+    let cells = run(AgentType::Car);
+    assert!(cells.iter().any(|&cell_id| cell_id > LANE_CELLS), "{:?}", cells);
+}
+
+#[test]
+fn test_overtake_cyclist() {
+    // Slow cyclist shares the right lane with a faster car (changing lanes to go faster) behind it
+    let run = |lanes_num: usize| {
+        let mut car = vehicle(2, 2, 3, AgentType::Car, LANE_CELLS);
+        car.mobil = Some(MobilParameters::new().build());
+        let vehicles = vec![vehicle(1, 6, 1, AgentType::Cyclist, LANE_CELLS), car];
+        let mut session = session(grid(lanes_num, None), vehicles);
+        let mut passed = false;
+        for _ in 0..15 {
+            let state = session.step().expect("Step should be done without errors");
+            let cell_of = |vehicle_id: VehicleID| {
+                state.vehicles.iter().find(|v| v.id == vehicle_id).map(|v| v.last_cell)
+            };
+            if let (Some(cyclist_cell), Some(car_cell)) = (cell_of(1), cell_of(2)) {
+                let car_position = if car_cell > LANE_CELLS { car_cell - LANE_CELLS } else { car_cell };
+                passed |= car_position > cyclist_cell;
+            }
+        }
+        passed
+    };
+
+    // Single lane: the car follows the cyclist
+    assert!(!run(1));
+    // Adjacent lane allows overtaking
+    assert!(run(2));
+}
+
+#[test]
+fn test_cyclist_generation() {
+    let grids_storage = GridsStorage::new().with_vehicles_net(grid(1, None)).build();
+    let mut session = Session::new(grids_storage, None);
+    session.set_verbose_level(VerboseLevel::None);
+    session.set_rng_seed(Some(42));
+    session.add_trip(
+        Trip::new(1, LANE_CELLS, TripType::Timetable)
+            .with_id(1)
+            .with_allowed_agent_type(AgentType::Cyclist)
+            .with_departures(vec![0])
+            .build(),
+    );
+    let mut speeds = vec![];
+    for _ in 0..20 {
+        let state = session.step().expect("Step should be done without errors");
+        speeds.extend(state.vehicles.iter().map(|vehicle| vehicle.last_speed));
+    }
+    assert!(!speeds.is_empty());
+    assert!(speeds.iter().all(|&speed| speed <= AgentType::Cyclist.max_speed_default().unwrap()), "{:?}", speeds);
+}
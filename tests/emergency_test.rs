@@ -0,0 +1,105 @@
+mod common;
+
+use common::{grid, session, vehicle, LANE_CELLS};
+use micro_traffic_sim_core::agents::VehicleID;
+use micro_traffic_sim_core::agents_types::AgentType;
+use micro_traffic_sim_core::grid::cell::CellID;
+use micro_traffic_sim_core::simulation::grids_storage::GridsStorage;
+use micro_traffic_sim_core::simulation::session::Session;
+use micro_traffic_sim_core::traffic_lights::groups::TrafficLightGroup;
+use micro_traffic_sim_core::traffic_lights::lights::TrafficLight;
+use micro_traffic_sim_core::traffic_lights::signals::SignalType;
+use micro_traffic_sim_core::verbose::VerboseLevel;
+use std::collections::HashMap;
+
+/// Cell controlled by the traffic light
+const STOP_LINE_CELL: CellID = 15;
+
+/// Runs the session and returns the step at which the vehicle reaches the cell
+fn arrival_step(session: &mut Session, vehicle_id: VehicleID, cell_id: CellID, steps: usize) -> Option<usize> {
+    for step in 0..steps {
+        let state = session.step().expect("Step should be done without errors");
+        if state.vehicles.iter().any(|v| v.id == vehicle_id && v.last_cell >= cell_id) {
+            return Some(step);
+        }
+    }
+    None
+}
+
+#[test]
+fn test_lane_clearing() {
+    // Slow car in the left lane (31..60) and a vehicle behind it
+    let run = |vehicle_type: AgentType| {
+        let mut session = session(
+            grid(2, None),
+            vec![
+                vehicle(1, LANE_CELLS + 6, 1, AgentType::Car, 2 * LANE_CELLS),
+                vehicle(2, LANE_CELLS + 2, 3, vehicle_type, 2 * LANE_CELLS),
+            ],
+        );
+        let mut car_cells = vec![];
+        let mut overtaken = false;
+        for _ in 0..15 {
+            let state = session.step().expect("Step should be done without errors");
+            let cell_of = |id| state.vehicles.iter().find(|v| v.id == id).map(|v| v.last_cell);
+            let (Some(car_cell), Some(other_cell)) = (cell_of(1), cell_of(2)) else {
+                break;
+            };
+            car_cells.push(car_cell);
+            overtaken |= other_cell > car_cell + LANE_CELLS;
+        }
+        (car_cells, overtaken)
+    };
+
+    // Car moves to the right lane and the ambulance passes it
+    let (car_cells, overtaken) = run(AgentType::Emergency);
+    assert!(car_cells.iter().any(|&cell_id| cell_id <= LANE_CELLS), "{:?}", car_cells);
+    assert!(overtaken);
+
+    // Regular vehicle just follows the car
+    let (car_cells, overtaken) = run(AgentType::Car);
+    assert!(car_cells.iter().all(|&cell_id| cell_id > LANE_CELLS), "{:?}", car_cells);
+    assert!(!overtaken);
+}
+
+#[test]
+fn test_signal_preemption() {
+    // Long red phase on the single lane road
+    let session = |preemption_distance: i32| {
+        let tl = TrafficLight::new(1)
+            .with_groups(vec![TrafficLightGroup::new(1)
+                .with_cells_ids(vec![STOP_LINE_CELL])
+                .with_signal(vec![SignalType::Red, SignalType::Green])
+                .build()])
+            .with_phases_times(vec![50, 10])
+            .build();
+        let grids_storage = GridsStorage::new()
+            .with_vehicles_net(grid(1, None))
+            .with_tls(HashMap::from([(1, tl)]))
+            .build();
+        let mut session = Session::new(grids_storage, None);
+        session.set_verbose_level(VerboseLevel::None);
+        session.set_preemption_distance(preemption_distance);
+        session.add_vehicles(vec![vehicle(1, 1, 3, AgentType::Emergency, LANE_CELLS)]);
+        session
+    };
+
+    // Ambulance gets the green light and passes the stop line without waiting
+    let mut preempted = session(10);
+    let passed = arrival_step(&mut preempted, 1, STOP_LINE_CELL + 1, 50);
+    assert!(passed.is_some_and(|step| step < 10), "{:?}", passed);
+    assert_eq!(preempted.get_tls_ref()[&1].get_preempted_group(), Some(1));
+
+    // Traffic light returns to the interrupted red phase once the ambulance has passed
+    arrival_step(&mut preempted, 1, STOP_LINE_CELL + 11, 50);
+    preempted.step().expect("Step should be done without errors");
+    let tl = &preempted.get_tls_ref()[&1];
+    assert_eq!(tl.get_preempted_group(), None);
+    assert_eq!(tl.get_active_phase(), 0);
+    assert!(tl.get_current_time() < 20);
+
+    // Without preemption the ambulance waits for the regular green
+    let mut regular = session(0);
+    let passed = arrival_step(&mut regular, 1, STOP_LINE_CELL + 1, 70);
+    assert!(passed.is_some_and(|step| step >= 49), "{:?}", passed);
+}
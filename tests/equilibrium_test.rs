@@ -0,0 +1,108 @@
+use micro_traffic_sim_core::geom::new_point;
+use micro_traffic_sim_core::grid::{cell::Cell, road_network::GridRoads, zones::ZoneType};
+use micro_traffic_sim_core::behaviour::BehaviourType;
+use micro_traffic_sim_core::agents_types::AgentType;
+use micro_traffic_sim_core::route_choice::{RouteChoice, RouteChoiceModel};
+use micro_traffic_sim_core::simulation::equilibrium::DynamicEquilibrium;
+use micro_traffic_sim_core::simulation::session::Session;
+use micro_traffic_sim_core::simulation::grids_storage::GridsStorage;
+use micro_traffic_sim_core::trips::trip::{Trip, TripType};
+
+/// TestDynamicEquilibrium tests iterative assignment on two routes: the short one has
+/// a slower section (speed limit 2), the long one is fast (speed limit 3) but has a merge at the end.
+/// Road layout:
+///    [12]-[13]-[14]-[15]-[16]-[17]-[18]-[19]
+///   /                                       \
+/// [1]-[2]-[3]-[4]-[5]-[6]-[7]-[8]-[9]--------[10]
+#[test]
+fn test_dynamic_equilibrium() {
+    let mut grid = GridRoads::new();
+    for i in 1..=10 {
+        let forward = if i < 10 { i + 1 } else { -1 };
+        let left = if i == 1 { 12 } else { -1 };
+        let zone = match i {
+            1 => ZoneType::Birth,
+            10 => ZoneType::Death,
+            _ => ZoneType::Common,
+        };
+        let speed_limit = if (3..=8).contains(&i) { 2 } else { 3 };
+        let meso_link = match i {
+            1 => 1,
+            10 => 4,
+            _ => 3,
+        };
+        grid.add_cell(
+            Cell::new(i)
+                .with_point(new_point((i - 1) as f64, 0.0, None))
+                .with_zone_type(zone)
+                .with_speed_limit(speed_limit)
+                .with_forward_node(forward)
+                .with_left_node(left)
+                .with_meso_link(meso_link)
+                .build(),
+        );
+    }
+    for i in 12..=19 {
+        let forward = if i < 19 { i + 1 } else { -1 };
+        let right = if i == 19 { 10 } else { -1 };
+        grid.add_cell(
+            Cell::new(i)
+                .with_point(new_point((i - 11) as f64, 1.0, None))
+                .with_zone_type(ZoneType::Common)
+                .with_speed_limit(3)
+                .with_forward_node(forward)
+                .with_right_node(right)
+                .with_meso_link(2)
+                .build(),
+        );
+    }
+
+    let grids_storage = GridsStorage::new().with_vehicles_net(grid).build();
+    let mut session = Session::new(grids_storage, None);
+    session.set_rng_seed(Some(42));
+    let trip = Trip::new(1, 10, TripType::Constant)
+        .with_id(1)
+        .with_time(1)
+        .with_initial_speed(1)
+        .with_allowed_agent_type(AgentType::Car)
+        .with_allowed_behaviour_type(BehaviourType::Cooperative)
+        .with_route_choice(
+            RouteChoice::new(RouteChoiceModel::MultinomialLogit)
+                .with_routes_num(2)
+                .with_theta(1.0)
+                .build(),
+        )
+        .build();
+
+    let max_iterations = 10;
+    let result = DynamicEquilibrium::new(vec![trip])
+        .with_steps(200)
+        .with_max_iterations(max_iterations)
+        .with_gap_threshold(0.001)
+        .with_slice_duration(50)
+        .build()
+        .run(&mut session)
+        .expect("Equilibrium should run without errors");
+
+    assert!(!result.iterations.is_empty());
+    assert!(result.converged || result.iterations.len() == max_iterations);
+    for (idx, iteration) in result.iterations.iter().enumerate() {
+        assert_eq!(iteration.iteration, idx + 1);
+        assert!(iteration.relative_gap.is_finite() && iteration.relative_gap >= 0.0);
+        assert!(iteration.vehicles_num > 0);
+    }
+    // Links of the origin and of the short route have been used
+    assert!(result.travel_times.get(1, 0).is_some());
+    assert!(result.travel_times.get(3, 0).is_some());
+    // Shares are valid probabilities and the short route is preferred
+    for probabilities in result.route_splits.get_splits().values() {
+        assert_eq!(probabilities.len(), 2);
+        assert!((probabilities.iter().sum::<f64>() - 1.0).abs() < 1e-9);
+        assert!(probabilities[0] > probabilities[1]);
+    }
+    // Session keeps state of the last iteration
+    assert_eq!(session.get_steps(), 200);
+    if !result.route_splits.is_empty() {
+        assert_eq!(session.get_route_splits(), Some(&result.route_splits));
+    }
+}
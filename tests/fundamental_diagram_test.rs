@@ -0,0 +1,168 @@
+use micro_traffic_sim_core::geom::new_point;
+use micro_traffic_sim_core::grid::{cell::Cell, road_network::GridRoads, zones::ZoneType};
+use micro_traffic_sim_core::agents::Vehicle;
+use micro_traffic_sim_core::behaviour::{AnticipationModel, BjhModel, DriverModelRef, TakayasuModel, VdrModel};
+use micro_traffic_sim_core::simulation::session::Session;
+use micro_traffic_sim_core::simulation::grids_storage::GridsStorage;
+use micro_traffic_sim_core::verbose::VerboseLevel;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+const RING_LENGTH: i64 = 100;
+const MAX_SPEED: i32 = 5;
+const WARMUP_STEPS: usize = 300;
+const MEASURE_STEPS: usize = 500;
+
+/// Initial state of the ring
+#[derive(Clone, Copy)]
+enum Start {
+    /// Vehicles are evenly distributed and move with given speed
+    Homogeneous(i32),
+    /// Vehicles are stopped and packed into a single jam
+    Jammed,
+}
+
+/// Ring road (as in `examples/ring`) with given density of vehicles.
+/// `global_model` is set for the session, `vehicle_model` is set for each vehicle.
+fn ring_session(
+    density: f64,
+    slowdown: f64,
+    start: Start,
+    global_model: Option<DriverModelRef>,
+    vehicle_model: Option<DriverModelRef>,
+) -> Session {
+    let mut grid = GridRoads::new();
+    for i in 0..RING_LENGTH {
+        let angle = 2.0 * std::f64::consts::PI * (i as f64) / (RING_LENGTH as f64);
+        grid.add_cell(
+            Cell::new(i)
+                .with_point(new_point(angle.cos(), angle.sin(), None))
+                .with_forward_node((i + 1) % RING_LENGTH)
+                .with_zone_type(ZoneType::Common)
+                .with_speed_limit(MAX_SPEED)
+                .build(),
+        );
+    }
+    let vehicles_num = (density * RING_LENGTH as f64).round() as i64;
+    let vehicles: Vec<Vehicle> = (0..vehicles_num)
+        .map(|i| {
+            let (cell_id, speed) = match start {
+                Start::Homogeneous(speed) => (i * RING_LENGTH / vehicles_num, speed),
+                Start::Jammed => (i, 0),
+            };
+            let builder = Vehicle::new((i + 1) as u64)
+                .with_cell(cell_id)
+                .with_speed(speed)
+                .with_speed_limit(MAX_SPEED)
+                .with_slowdown(slowdown)
+                .with_destination(-1);
+            match &vehicle_model {
+                Some(model) => builder.with_driver_model(model.clone()).build(),
+                None => builder.build(),
+            }
+        })
+        .collect();
+
+    let grids_storage = GridsStorage::new().with_vehicles_net(grid).build();
+    let mut session = Session::new(grids_storage, None);
+    session.set_verbose_level(VerboseLevel::None);
+    session.set_rng_seed(Some(2024));
+    session.set_driver_model(global_model);
+    session.add_vehicles(vehicles);
+    session
+}
+
+/// Runs the ring and returns mean flow (vehicles per time unit passing a cross-section).
+/// Also checks that vehicles never collide.
+fn run_ring(mut session: Session) -> f64 {
+    let vehicles_num = session.get_vehicles().len();
+    let mut speeds_sum = 0;
+    for step in 0..WARMUP_STEPS + MEASURE_STEPS {
+        let state = session.step().expect("Step should be done without errors");
+        assert_eq!(state.vehicles.len(), vehicles_num, "Vehicles should stay on the ring");
+        let cells: HashSet<_> = state.vehicles.iter().map(|v| v.last_cell).collect();
+        assert_eq!(cells.len(), vehicles_num, "Vehicles should never share a cell");
+        if step >= WARMUP_STEPS {
+            speeds_sum += state.vehicles.iter().map(|v| v.last_speed).sum::<i32>();
+        }
+    }
+    speeds_sum as f64 / (RING_LENGTH as f64 * MEASURE_STEPS as f64)
+}
+
+/// Flow on the ring with homogeneous start and the driver model set globally
+fn measure_flow(density: f64, slowdown: f64, driver_model: Option<DriverModelRef>) -> f64 {
+    run_ring(ring_session(density, slowdown, Start::Homogeneous(0), driver_model, None))
+}
+
+#[test]
+fn test_deterministic_nasch_fundamental_diagram() {
+    // J = min(rho * v_max, 1 - rho) for deterministic NaSch
+    for density in [0.1, 0.3, 0.5, 0.7] {
+        let flow = measure_flow(density, 0.0, None);
+        let expected = (density * MAX_SPEED as f64).min(1.0 - density);
+        assert!((flow - expected).abs() < 0.02, "density {}: flow {} != {}", density, flow, expected);
+    }
+}
+
+#[test]
+fn test_anticipation_fundamental_diagram() {
+    // Free flow is not affected
+    let flow = measure_flow(0.1, 0.0, Some(Arc::new(AnticipationModel::default())));
+    assert!((flow - 0.5).abs() < 0.02, "free flow {}", flow);
+    // Congested branch of deterministic NaSch is J = 1 - rho. Anticipating drivers use
+    // cells vacated by leaders, so the flow is higher
+    for density in [0.2, 0.3] {
+        let nasch = measure_flow(density, 0.0, None);
+        let anticipation = measure_flow(density, 0.0, Some(Arc::new(AnticipationModel::default())));
+        assert!(anticipation > nasch + 0.1, "density {}: anticipation {} vs NaSch {}", density, anticipation, nasch);
+    }
+    // The same holds with randomization
+    let nasch = measure_flow(0.3, 0.1, Some(Arc::new(VdrModel::new(0.1))));
+    let anticipation = measure_flow(0.3, 0.1, Some(Arc::new(AnticipationModel::new(0))));
+    assert!(anticipation > nasch, "anticipation {} vs NaSch {}", anticipation, nasch);
+}
+
+#[test]
+fn test_slow_to_start_fundamental_diagram() {
+    let slowdown = 0.1;
+    let slow_to_start = 0.5;
+    // VDR with the same probability for stopped and moving vehicles is the classic NaSch
+    let nasch: DriverModelRef = Arc::new(VdrModel::new(slowdown));
+    let variants: Vec<(&str, DriverModelRef)> = vec![
+        ("VDR", Arc::new(VdrModel::new(slow_to_start))),
+        ("Takayasu", Arc::new(TakayasuModel::new(slow_to_start))),
+        ("BJH", Arc::new(BjhModel::new(slow_to_start))),
+    ];
+    // Free flow: vehicles never stop, so slow-to-start rules do not matter
+    let free_flow = measure_flow(0.05, slowdown, Some(nasch.clone()));
+    for (name, model) in &variants {
+        let flow = measure_flow(0.05, slowdown, Some(model.clone()));
+        assert!((flow - free_flow).abs() < 0.01, "{}: free flow {} vs {}", name, flow, free_flow);
+    }
+    // Congested traffic: vehicles leave jams later, so the flow is lower
+    for density in [0.3, 0.5] {
+        let congested_flow = measure_flow(density, slowdown, Some(nasch.clone()));
+        for (name, model) in &variants {
+            let flow = measure_flow(density, slowdown, Some(model.clone()));
+            assert!(flow < congested_flow - 0.05, "{} at density {}: flow {} vs NaSch {}", name, density, flow, congested_flow);
+        }
+    }
+}
+
+#[test]
+fn test_vdr_hysteresis() {
+    // VDR has metastable states: homogeneous traffic keeps high flow, while a jam
+    // does not dissolve at the same density. Model is set per vehicle here
+    let density = 0.12;
+    let vdr: DriverModelRef = Arc::new(VdrModel::new(0.75));
+    let homogeneous = run_ring(ring_session(density, 0.01, Start::Homogeneous(MAX_SPEED), None, Some(vdr.clone())));
+    let jammed = run_ring(ring_session(density, 0.01, Start::Jammed, None, Some(vdr.clone())));
+    assert!(homogeneous > jammed + 0.1, "homogeneous {} vs jammed {}", homogeneous, jammed);
+    // Classic NaSch with the same randomization dissolves the jam
+    let nasch: DriverModelRef = Arc::new(VdrModel::new(0.01));
+    let nasch_jammed = run_ring(ring_session(density, 0.01, Start::Jammed, None, Some(nasch.clone())));
+    assert!(nasch_jammed > jammed + 0.1, "NaSch {} vs VDR {}", nasch_jammed, jammed);
+    // Vehicle's own model has priority over the global one
+    let overridden = run_ring(ring_session(density, 0.01, Start::Jammed, Some(nasch), Some(vdr)));
+    assert_eq!(overridden, jammed);
+}
@@ -0,0 +1,101 @@
+use micro_traffic_sim_core::behaviour::{BehaviourDistributions, ParameterDistribution};
+use micro_traffic_sim_core::conflict_zones::{ConflictEdge, ConflictWinnerType, ConflictZone};
+use micro_traffic_sim_core::geom::new_point;
+use micro_traffic_sim_core::grid::cell::{Cell, CellID};
+use micro_traffic_sim_core::grid::road_network::GridRoads;
+use micro_traffic_sim_core::grid::zones::ZoneType;
+use micro_traffic_sim_core::simulation::grids_storage::GridsStorage;
+use micro_traffic_sim_core::simulation::session::Session;
+use micro_traffic_sim_core::trips::trip::{Trip, TripType};
+use micro_traffic_sim_core::utils::generators::generate_one_lane_cells;
+use micro_traffic_sim_core::verbose::VerboseLevel;
+use std::collections::HashSet;
+
+/// Last cell of the main road
+const MAIN_LAST: CellID = 40;
+/// Main road cell where the on-ramp merges
+const MERGE_CELL: CellID = 22;
+/// Last cell of the on-ramp (lane drop)
+const RAMP_LAST: CellID = 110;
+
+/// Main road (1..40) and on-ramp (101..110) merging into it via lane change at cell 22
+fn grid() -> GridRoads {
+    let mut grid = GridRoads::new();
+    for cell in generate_one_lane_cells(MAIN_LAST as f64 * 10.0, 10.0, 1) {
+        grid.add_cell(cell);
+    }
+    for id in 101..=RAMP_LAST {
+        let x = (id - 101 + 11) as f64;
+        let (forward_id, left_id) = if id == RAMP_LAST { (-1, MERGE_CELL) } else { (id + 1, -1) };
+        grid.add_cell(
+            Cell::new(id)
+                .with_point(new_point(x, 0.0, None))
+                .with_zone_type(if id == 101 { ZoneType::Birth } else { ZoneType::Common })
+                .with_speed_limit(3)
+                .with_forward_node(forward_id)
+                .with_left_node(left_id)
+                .build(),
+        );
+    }
+    grid
+}
+
+/// Runs the session with platoons in the major stream and ramp drivers having the given critical gap.
+/// Returns the number of ramp vehicles which have merged into the main road
+fn merged_num(critical_gap: f64) -> usize {
+    let grids_storage = GridsStorage::new().with_vehicles_net(grid()).build();
+    let mut session = Session::new(grids_storage, None);
+    session.set_verbose_level(VerboseLevel::None);
+    session.set_rng_seed(Some(42));
+    session.add_conflict_zone(
+        ConflictZone::new(
+            1,
+            ConflictEdge { source: MERGE_CELL - 1, target: MERGE_CELL },
+            ConflictEdge { source: RAMP_LAST, target: MERGE_CELL },
+        )
+        .with_winner_type(ConflictWinnerType::First)
+        .build(),
+    );
+    session.add_trip(
+        Trip::new(1, MAIN_LAST, TripType::Timetable)
+            .with_id(1)
+            // Platoons of three vehicles with holes between them
+            .with_departures((0..80).filter(|t| t % 12 < 6 && t % 2 == 0).collect())
+            .with_speed_limit(3)
+            .build(),
+    );
+    session.add_trip(
+        Trip::new(101, MAIN_LAST, TripType::Timetable)
+            .with_id(2)
+            .with_departures((0..80).step_by(2).collect())
+            .with_speed_limit(3)
+            .with_behaviour_distributions(
+                BehaviourDistributions::new()
+                    .with_critical_gap(ParameterDistribution::Constant(critical_gap))
+                    .with_follow_up_time(ParameterDistribution::Constant(1.0))
+                    .build(),
+            )
+            .build(),
+    );
+    let mut merged = HashSet::new();
+    for _ in 0..100 {
+        let state = session.step().expect("Step should be done without errors");
+        merged.extend(
+            state
+                .vehicles
+                .iter()
+                .filter(|vehicle| vehicle.trip_id == 2 && vehicle.last_cell <= MAIN_LAST)
+                .map(|vehicle| vehicle.id),
+        );
+    }
+    merged.len()
+}
+
+#[test]
+fn test_on_ramp_capacity() {
+    let short_gap = merged_num(1.0);
+    let long_gap = merged_num(4.0);
+    assert!(long_gap > 0);
+    // Drivers rejecting short gaps merge less often
+    assert!(short_gap > long_gap, "short gap: {}, long gap: {}", short_gap, long_gap);
+}
@@ -0,0 +1,99 @@
+use micro_traffic_sim_core::agents::{Vehicle, VehicleID};
+use micro_traffic_sim_core::behaviour::BehaviourType;
+use micro_traffic_sim_core::grid::cell::{Cell, CellID};
+use micro_traffic_sim_core::grid::road_network::GridRoads;
+use micro_traffic_sim_core::simulation::grids_storage::GridsStorage;
+use micro_traffic_sim_core::simulation::gridlock::{GridlockPolicy, GridlockResolution};
+use micro_traffic_sim_core::simulation::session::Session;
+use micro_traffic_sim_core::simulation::states::AutomataState;
+use micro_traffic_sim_core::verbose::VerboseLevel;
+
+/// Number of ring cells
+const RING: CellID = 8;
+/// Last cell of the exit road
+const EXIT_LAST: CellID = 110;
+
+/// Ring road 1..=8 (8 → 1) with the exit road 101..=110 leaving it from cell 4.
+/// The exit road is blocked by a broken vehicle in cell 101
+fn grid() -> GridRoads {
+    let mut grid = GridRoads::new();
+    for id in 1..=RING {
+        grid.add_cell(
+            Cell::new(id)
+                .with_speed_limit(1)
+                .with_forward_node(id % RING + 1)
+                .with_right_node(if id == 4 { 101 } else { -1 })
+                .build(),
+        );
+    }
+    for id in 101..=EXIT_LAST {
+        grid.add_cell(Cell::new(id).with_speed_limit(1).with_forward_node(if id < EXIT_LAST { id + 1 } else { -1 }).build());
+    }
+    grid
+}
+
+/// Identifier of the broken vehicle
+const BROKEN: VehicleID = 100;
+
+/// Runs the session with the ring fully occupied by vehicles: vehicle 1 leaves the ring, others circulate
+fn run(policy: GridlockPolicy, steps: usize) -> (Session, Vec<AutomataState>) {
+    let grids_storage = GridsStorage::new().with_vehicles_net(grid()).build();
+    let mut session = Session::new(grids_storage, None);
+    session.set_verbose_level(VerboseLevel::None);
+    session.set_gridlock_policy(policy);
+    let vehicles = (1..=RING)
+        .map(|id| {
+            let destination = if id == 1 { EXIT_LAST } else { (id + RING - 2) % RING + 1 };
+            Vehicle::new(id as VehicleID).with_cell(id).with_speed_limit(1).with_slowdown(0.0).with_destination(destination).build()
+        })
+        .chain(std::iter::once(
+            Vehicle::new(BROKEN).with_cell(101).with_behaviour(BehaviourType::Block).build(),
+        ))
+        .collect();
+    session.add_vehicles(vehicles);
+    let states = (0..steps).map(|_| session.step().expect("Step should be done without errors")).collect();
+    (session, states)
+}
+
+#[test]
+fn test_gridlock_report() {
+    let (_, states) = run(GridlockPolicy::Report, 5);
+    for (step, state) in states.iter().enumerate() {
+        assert_eq!(state.gridlocks.len(), 1);
+        assert_eq!(state.gridlocks[0].vehicles, (1..=RING as VehicleID).collect::<Vec<_>>());
+        assert_eq!(state.gridlocks[0].duration, step as i32 + 1);
+        assert_eq!(state.gridlocks[0].resolution, None);
+    }
+}
+
+#[test]
+fn test_gridlock_teleport() {
+    let (session, states) = run(GridlockPolicy::Teleport { timeout: 3 }, 10);
+    assert!(states[1].gridlocks[0].resolution.is_none());
+    // Vehicle leaving the ring is moved to the first free cell of its way, behind the broken vehicle
+    assert_eq!(states[2].gridlocks[0].resolution, Some(GridlockResolution::Teleported { vehicle_id: 1, cell_id: 102 }));
+    let teleported = states[2].vehicles.iter().find(|vehicle| vehicle.id == 1).expect("Teleported vehicle should stay");
+    assert_eq!(teleported.last_cell, 102);
+    // State dump shows the vehicle standing in the new cell
+    assert_eq!(teleported.last_speed, 0);
+    assert!(teleported.last_intermediate_cells.is_empty());
+    // Vehicles circulate again
+    assert!(states[3..].iter().all(|state| state.gridlocks.is_empty()));
+    assert!(session.get_vehicles().values().any(|vehicle| vehicle.id == 2 && vehicle.cell_id != 2));
+}
+
+#[test]
+fn test_gridlock_remove() {
+    let (session, states) = run(GridlockPolicy::Remove { timeout: 2 }, 5);
+    assert_eq!(states[1].gridlocks[0].resolution, Some(GridlockResolution::Removed { vehicle_id: 1 }));
+    assert_eq!(states[1].vehicles.len(), RING as usize);
+    assert!(states[2..].iter().all(|state| state.gridlocks.is_empty()));
+    assert!(!session.get_vehicles().contains_key(&1));
+}
+
+#[test]
+fn test_gridlock_priority_fallback() {
+    // There are no junction boxes to give priority in, so the gridlock is resolved by teleporting
+    let (_, states) = run(GridlockPolicy::Priority { timeout: 1 }, 2);
+    assert_eq!(states[0].gridlocks[0].resolution, Some(GridlockResolution::Teleported { vehicle_id: 1, cell_id: 102 }));
+}
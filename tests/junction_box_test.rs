@@ -0,0 +1,95 @@
+use micro_traffic_sim_core::conflict_zones::JunctionBox;
+use micro_traffic_sim_core::geom::new_point;
+use micro_traffic_sim_core::grid::cell::{Cell, CellID, CellState};
+use micro_traffic_sim_core::grid::road_network::GridRoads;
+use micro_traffic_sim_core::grid::zones::ZoneType;
+use micro_traffic_sim_core::simulation::grids_storage::GridsStorage;
+use micro_traffic_sim_core::simulation::session::Session;
+use micro_traffic_sim_core::trips::trip::{Trip, TripType};
+use micro_traffic_sim_core::verbose::VerboseLevel;
+use std::collections::HashSet;
+
+/// Junction cell shared by both roads
+const JUNCTION: CellID = 10;
+/// West-east road cell which is closed (e.g. by an accident), so the queue grows back to the junction
+const CLOSED: CellID = 14;
+
+/// West-east road 1..=20 and south-north road 101..=120 crossing it at cell 10:
+/// south-north vehicles come from cell 109 and leave the junction to cell 111 via the left connection
+fn grid() -> GridRoads {
+    let mut grid = GridRoads::new();
+    for id in 1..=20 {
+        grid.add_cell(
+            Cell::new(id)
+                .with_point(new_point(id as f64, 0.0, None))
+                .with_zone_type(if id == 1 { ZoneType::Birth } else { ZoneType::Common })
+                .with_speed_limit(2)
+                .with_forward_node(if id < 20 { id + 1 } else { -1 })
+                .with_left_node(if id == JUNCTION { 111 } else { -1 })
+                .build(),
+        );
+    }
+    for id in (101..=120).filter(|&id| id != 100 + JUNCTION) {
+        let forward_id = match id {
+            109 => JUNCTION,
+            120 => -1,
+            _ => id + 1,
+        };
+        grid.add_cell(
+            Cell::new(id)
+                .with_point(new_point(JUNCTION as f64, (id - 100 - JUNCTION) as f64, None))
+                .with_zone_type(if id == 101 { ZoneType::Birth } else { ZoneType::Common })
+                .with_speed_limit(2)
+                .with_forward_node(forward_id)
+                .build(),
+        );
+    }
+    grid.get_cell_mut(CLOSED).unwrap().set_state(CellState::Banned);
+    grid
+}
+
+/// Runs the session and returns the number of south-north vehicles which have passed the junction
+fn passed_num(with_box: bool) -> usize {
+    let grids_storage = GridsStorage::new().with_vehicles_net(grid()).build();
+    let mut session = Session::new(grids_storage, None);
+    session.set_verbose_level(VerboseLevel::None);
+    session.set_rng_seed(Some(42));
+    if with_box {
+        session.add_junction_box(JunctionBox::new(1).with_cells(vec![JUNCTION]).build());
+    }
+    session.add_trip(
+        Trip::new(1, 20, TripType::Timetable)
+            .with_id(1)
+            .with_departures((0..60).step_by(2).collect())
+            .with_speed_limit(2)
+            .build(),
+    );
+    session.add_trip(
+        Trip::new(101, 120, TripType::Timetable)
+            .with_id(2)
+            .with_departures((0..60).step_by(4).collect())
+            .with_speed_limit(2)
+            .build(),
+    );
+    let mut passed = HashSet::new();
+    for _ in 0..80 {
+        let state = session.step().expect("Step should be done without errors");
+        passed.extend(
+            state
+                .vehicles
+                .iter()
+                .filter(|vehicle| vehicle.trip_id == 2 && vehicle.last_cell > 110)
+                .map(|vehicle| vehicle.id),
+        );
+    }
+    passed.len()
+}
+
+#[test]
+fn test_dont_block_the_box() {
+    let blocked = passed_num(false);
+    let locked = passed_num(true);
+    // Queue from the closed cell spills back into the junction and stops the crossing flow
+    assert!(locked > blocked, "with box: {}, without box: {}", locked, blocked);
+    assert!(locked >= 10, "with box: {}", locked);
+}
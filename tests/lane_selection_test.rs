@@ -0,0 +1,120 @@
+use micro_traffic_sim_core::agents::Vehicle;
+use micro_traffic_sim_core::behaviour::BehaviourType;
+use micro_traffic_sim_core::grid::road_network::GridRoads;
+use micro_traffic_sim_core::simulation::grids_storage::GridsStorage;
+use micro_traffic_sim_core::simulation::session::Session;
+use micro_traffic_sim_core::utils::generators::generate_one_lane_cells;
+use micro_traffic_sim_core::verbose::VerboseLevel;
+
+/// Number of cells in each lane of the motorway
+const LANE_CELLS: i64 = 50;
+
+/// Exit at the end of the left lane
+const EXIT_CELL: i64 = 2 * LANE_CELLS;
+
+/// Two-lane motorway (speed limit 3): right lane is 1..50, left lane is 51..100.
+/// The last lane change to the left lane is possible from cell 48.
+fn motorway_session(vehicles: Vec<Vehicle>) -> Session {
+    let mut grid = GridRoads::new();
+    for mut cell in generate_one_lane_cells(LANE_CELLS as f64 * 10.0, 10.0, 2) {
+        if cell.get_id() == LANE_CELLS - 1 {
+            cell.set_left_id(-1);
+        }
+        grid.add_cell(cell);
+    }
+    let grids_storage = GridsStorage::new().with_vehicles_net(grid).build();
+    let mut session = Session::new(grids_storage, None);
+    session.set_verbose_level(VerboseLevel::None);
+    session.add_vehicles(vehicles);
+    session
+}
+
+/// Car in the right lane heading to the exit
+fn exiting_car(cell_id: i64, lane_selection_distance: i32) -> Vehicle {
+    Vehicle::new(1)
+        .with_cell(cell_id)
+        .with_speed(1)
+        .with_speed_limit(3)
+        .with_slowdown(0.0)
+        .with_destination(EXIT_CELL)
+        .with_lane_selection_distance(lane_selection_distance)
+        .build()
+}
+
+/// Runs the session and returns the car's cells until it leaves the network
+fn car_trace(mut session: Session, steps: usize) -> Vec<i64> {
+    let mut trace = Vec::new();
+    for _ in 0..steps {
+        let state = session.step().expect("Step should be done without errors");
+        match state.vehicles.iter().find(|v| v.id == 1) {
+            Some(v) => trace.push(v.last_cell),
+            None => break,
+        }
+    }
+    trace
+}
+
+#[test]
+fn test_lane_preselection() {
+    let steps = 30;
+    let first_change = |trace: &[i64]| trace.iter().position(|&cell| cell > LANE_CELLS);
+
+    // Short-horizon pathfinding keeps the car in its lane until it is too late, so it misses the exit
+    let late = car_trace(motorway_session(vec![exiting_car(2, 0)]), steps);
+    assert!(first_change(&late).is_none(), "Car should miss the exit: {:?}", late);
+
+    // Car begins moving to the exit lane in advance
+    let distance = 40;
+    let early = car_trace(motorway_session(vec![exiting_car(2, distance)]), steps);
+    let change = first_change(&early).expect("Car should reach the exit lane");
+    let upstream = LANE_CELLS - 2 - early[change - 1];
+    assert!(upstream <= distance as i64 && upstream > 10, "Car should change the lane in advance: {:?}", early);
+    // ...stays there and reaches the exit
+    assert!(early.iter().skip(change).all(|&cell| cell > LANE_CELLS), "{:?}", early);
+    assert!(*early.last().unwrap() >= EXIT_CELL - 3, "{:?}", early);
+}
+
+/// Stopped vehicles occupy the left lane next to the car, so it can't reach the exit lane
+fn blocked_session(lane_selection_distance: i32) -> Session {
+    let mut vehicles: Vec<Vehicle> = (LANE_CELLS + 41..EXIT_CELL)
+        .map(|cell_id| {
+            Vehicle::new(cell_id as u64)
+                .with_cell(cell_id)
+                .with_speed(0)
+                .with_behaviour(BehaviourType::Block)
+                .with_destination(EXIT_CELL)
+                .build()
+        })
+        .collect();
+    vehicles.push(exiting_car(42, lane_selection_distance));
+    motorway_session(vehicles)
+}
+
+/// Runs the session and returns the car's cells and confusion flags until it leaves the network
+fn car_confusion_trace(mut session: Session, steps: usize) -> Vec<(i64, bool)> {
+    let mut trace = Vec::new();
+    for _ in 0..steps {
+        session.step().expect("Step should be done without errors");
+        match session.get_vehicles().get(&1) {
+            Some(vehicle) => trace.push((vehicle.cell_id, vehicle.confusion)),
+            None => break,
+        }
+    }
+    trace
+}
+
+#[test]
+fn test_lane_preselection_give_up() {
+    let last_chance = LANE_CELLS - 2;
+    let steps_at = |trace: &[(i64, bool)], cell_id: i64| trace.iter().filter(|(cell, _)| *cell == cell_id).count();
+
+    // Without pre-selection the car waits for the lane change at the last possible cell
+    let waiting = car_confusion_trace(blocked_session(0), 30);
+    assert!(steps_at(&waiting, last_chance) > 1, "{:?}", waiting);
+
+    // With pre-selection the car gives up at once and goes on confused (it has to reroute)
+    let giving_up = car_confusion_trace(blocked_session(10), 30);
+    assert_eq!(steps_at(&giving_up, last_chance), 1, "{:?}", giving_up);
+    assert!(giving_up.contains(&(last_chance + 1, true)), "Car should give up: {:?}", giving_up);
+    assert!(giving_up.iter().all(|(cell, _)| *cell <= LANE_CELLS), "{:?}", giving_up);
+}
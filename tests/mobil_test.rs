@@ -0,0 +1,162 @@
+use micro_traffic_sim_core::agents::Vehicle;
+use micro_traffic_sim_core::behaviour::MobilParameters;
+use micro_traffic_sim_core::grid::road_network::GridRoads;
+use micro_traffic_sim_core::maneuver::DrivingSide;
+use micro_traffic_sim_core::simulation::session::Session;
+use micro_traffic_sim_core::simulation::grids_storage::GridsStorage;
+use micro_traffic_sim_core::utils::generators::generate_one_lane_cells;
+use micro_traffic_sim_core::verbose::VerboseLevel;
+
+/// Number of cells in each lane of the motorway
+const LANE_CELLS: i64 = 50;
+
+/// Two-lane motorway (speed limit 3): right lane is 1..50, left lane is 51..100
+fn motorway_session(vehicles: Vec<Vehicle>) -> Session {
+    let mut grid = GridRoads::new();
+    for cell in generate_one_lane_cells(LANE_CELLS as f64 * 10.0, 10.0, 2) {
+        grid.add_cell(cell);
+    }
+    let grids_storage = GridsStorage::new().with_vehicles_net(grid).build();
+    let mut session = Session::new(grids_storage, None);
+    session.set_verbose_level(VerboseLevel::None);
+    session.add_vehicles(vehicles);
+    session
+}
+
+/// Slow vehicle in the right lane
+fn slow_vehicle() -> Vehicle {
+    Vehicle::new(1)
+        .with_cell(12)
+        .with_speed(1)
+        .with_speed_limit(1)
+        .with_slowdown(0.0)
+        .with_destination(LANE_CELLS)
+        .build()
+}
+
+/// Fast car approaching the slow vehicle from behind
+fn fast_car(mobil: Option<MobilParameters>) -> Vehicle {
+    let builder = Vehicle::new(2)
+        .with_cell(2)
+        .with_speed(3)
+        .with_speed_limit(3)
+        .with_slowdown(0.0)
+        .with_destination(LANE_CELLS);
+    match mobil {
+        Some(params) => builder.with_mobil(params).build(),
+        None => builder.build(),
+    }
+}
+
+/// Runs the session and returns the fast car's cells
+fn fast_car_trace(mut session: Session, steps: usize) -> Vec<i64> {
+    let mut trace = Vec::new();
+    for _ in 0..steps {
+        let state = session.step().expect("Step should be done without errors");
+        let cells: Vec<i64> = state.vehicles.iter().map(|v| v.last_cell).collect();
+        let unique: std::collections::HashSet<_> = cells.iter().collect();
+        assert_eq!(unique.len(), cells.len(), "Vehicles should never share a cell");
+        match state.vehicles.iter().find(|v| v.id == 2) {
+            Some(v) => trace.push(v.last_cell),
+            None => break,
+        }
+    }
+    trace
+}
+
+#[test]
+fn test_mobil_overtaking() {
+    let params = MobilParameters::new().build();
+    let steps = 20;
+
+    // Without discretionary lane changing the car is never blocked completely, so it follows the slow vehicle
+    let regular = fast_car_trace(motorway_session(vec![slow_vehicle(), fast_car(None)]), steps);
+    assert!(regular.iter().all(|&cell| cell <= LANE_CELLS), "Car should stay in its lane: {:?}", regular);
+
+    // Incentive-based lane changing makes the car overtake
+    let mobil = fast_car_trace(motorway_session(vec![slow_vehicle(), fast_car(Some(params))]), steps);
+    assert!(mobil.iter().any(|&cell| cell > LANE_CELLS), "Car should overtake: {:?}", mobil);
+    // ...and arrive earlier
+    assert!(mobil.len() < regular.len(), "MOBIL {:?} vs regular {:?}", mobil, regular);
+}
+
+#[test]
+fn test_mobil_keeps_lane_on_free_road() {
+    let params = MobilParameters::new().build();
+    let trace = fast_car_trace(motorway_session(vec![fast_car(Some(params))]), 10);
+    assert!(trace.iter().all(|&cell| cell <= LANE_CELLS), "No reason to change the lane: {:?}", trace);
+}
+
+/// Steps at which the car changes the lane when slow vehicles are placed in both lanes one after another
+fn lane_change_steps(maneuver_delay: i64) -> Vec<usize> {
+    // Threshold is lowered, so the car gets back to the right lane as soon as it is allowed
+    let params = MobilParameters::new().with_threshold(0.5).with_maneuver_delay(maneuver_delay).build();
+    let vehicles = vec![
+        slow_vehicle(),
+        Vehicle::new(3)
+            .with_cell(LANE_CELLS + 18)
+            .with_speed(1)
+            .with_speed_limit(1)
+            .with_slowdown(0.0)
+            .with_destination(2 * LANE_CELLS)
+            .build(),
+        fast_car(Some(params)),
+    ];
+    let trace = fast_car_trace(motorway_session(vehicles), 20);
+    let lanes: Vec<bool> = trace.iter().map(|&cell| cell > LANE_CELLS).collect();
+    (1..lanes.len()).filter(|&i| lanes[i] != lanes[i - 1]).collect()
+}
+
+#[test]
+fn test_mobil_maneuver_delay() {
+    let delayed = lane_change_steps(5);
+    assert_eq!(delayed.len(), 2, "Car should overtake both slow vehicles: {:?}", delayed);
+    assert!(delayed[1] - delayed[0] >= 5, "Lane changes are too frequent: {:?}", delayed);
+    let eager = lane_change_steps(1);
+    assert_eq!(eager.len(), 2);
+    assert!(eager[1] < delayed[1], "Short delay allows to change the lane earlier: {:?} vs {:?}", eager, delayed);
+}
+
+/// Lanes occupied by the MOBIL car overtaking a slow vehicle which is placed in the slow lane of the driving side.
+/// Returns `true` for the right lane (1..50) and `false` for the left one (51..100)
+fn overtaking_lanes(driving_side: DrivingSide) -> Vec<bool> {
+    // Left lane is the slow one for left-hand traffic
+    let offset = match driving_side {
+        DrivingSide::Right => 0,
+        DrivingSide::Left => LANE_CELLS,
+    };
+    let params = MobilParameters::new().build();
+    let vehicles = vec![
+        Vehicle::new(1)
+            .with_cell(offset + 12)
+            .with_speed(1)
+            .with_speed_limit(1)
+            .with_slowdown(0.0)
+            .with_destination(offset + LANE_CELLS)
+            .build(),
+        Vehicle::new(2)
+            .with_cell(offset + 2)
+            .with_speed(3)
+            .with_speed_limit(3)
+            .with_slowdown(0.0)
+            .with_destination(offset + LANE_CELLS)
+            .with_mobil(params)
+            .build(),
+    ];
+    let mut session = motorway_session(vehicles);
+    session.set_driving_side(driving_side);
+    fast_car_trace(session, 20).iter().map(|&cell| cell <= LANE_CELLS).collect()
+}
+
+#[test]
+fn test_mobil_driving_side() {
+    // Right-hand traffic: overtake on the left and get back to the right lane
+    let lanes = overtaking_lanes(DrivingSide::Right);
+    let overtaking = lanes.iter().position(|&right| !right).expect("Car should overtake on the left");
+    assert!(lanes[overtaking..].iter().any(|&right| right), "Car should keep right after overtaking: {:?}", lanes);
+
+    // Left-hand traffic: the same maneuvers are mirrored
+    let lanes = overtaking_lanes(DrivingSide::Left);
+    let overtaking = lanes.iter().position(|&right| right).expect("Car should overtake on the right");
+    assert!(lanes[overtaking..].iter().any(|&right| !right), "Car should keep left after overtaking: {:?}", lanes);
+}
@@ -0,0 +1,120 @@
+use micro_traffic_sim_core::agents::Vehicle;
+use micro_traffic_sim_core::agents_types::AgentType;
+use micro_traffic_sim_core::geom::new_point;
+use micro_traffic_sim_core::grid::cell::{Cell, CellID};
+use micro_traffic_sim_core::grid::road_network::GridRoads;
+use micro_traffic_sim_core::grid::zones::ZoneType;
+use micro_traffic_sim_core::simulation::grids_storage::GridsStorage;
+use micro_traffic_sim_core::simulation::session::Session;
+use micro_traffic_sim_core::simulation::states::AutomataState;
+use micro_traffic_sim_core::traffic_lights::groups::TrafficLightGroup;
+use micro_traffic_sim_core::traffic_lights::lights::TrafficLight;
+use micro_traffic_sim_core::traffic_lights::signals::SignalType;
+use micro_traffic_sim_core::trips::trip::{Trip, TripType};
+use micro_traffic_sim_core::utils::generators::generate_one_lane_cells;
+use micro_traffic_sim_core::verbose::VerboseLevel;
+
+/// Road cell crossed by the crosswalk
+const CROSSWALK_CELL: CellID = 10;
+
+/// Walkway crossing the road (cells 1..30): (101) → (102) → (10: crosswalk) → (103) → (104)
+fn walkway() -> GridRoads {
+    let mut grid = GridRoads::new();
+    let cells = [(101, -2.0), (102, -1.0), (CROSSWALK_CELL, 1.0), (103, 2.0), (104, 3.0)];
+    for (idx, &(id, y)) in cells.iter().enumerate() {
+        let forward_id = cells.get(idx + 1).map(|&(next_id, _)| next_id).unwrap_or(-1);
+        let zone_type = if id == CROSSWALK_CELL { ZoneType::Crosswalk } else { ZoneType::Common };
+        grid.add_cell(
+            Cell::new(id)
+                .with_point(new_point(9.0, y, None))
+                .with_zone_type(zone_type)
+                .with_forward_node(forward_id)
+                .build(),
+        );
+    }
+    grid
+}
+
+fn new_session(tls: Vec<TrafficLight>) -> Session {
+    let mut road = GridRoads::new();
+    for cell in generate_one_lane_cells(300.0, 10.0, 1) {
+        road.add_cell(cell);
+    }
+    let grids_storage = GridsStorage::new().with_vehicles_net(road).with_pedestrians_net(walkway()).build();
+    let mut session = Session::new(grids_storage, None);
+    session.set_verbose_level(VerboseLevel::None);
+    for tl in tls {
+        session.add_traffic_light(tl);
+    }
+    session.add_trip(
+        Trip::new(101, 104, TripType::Timetable)
+            .with_id(1)
+            .with_allowed_agent_type(AgentType::Pedestrian)
+            .with_departures(vec![0])
+            .build(),
+    );
+    session
+}
+
+fn car(id: u64, cell_id: CellID) -> Vehicle {
+    Vehicle::new(id)
+        .with_type(AgentType::Car)
+        .with_cell(cell_id)
+        .with_speed(3)
+        .with_speed_limit(3)
+        .with_slowdown(0.0)
+        .with_destination(30)
+        .build()
+}
+
+fn run(mut session: Session, steps: usize) -> Vec<AutomataState> {
+    (0..steps).map(|_| session.step().expect("Step should be done without errors")).collect()
+}
+
+#[test]
+fn test_vehicles_yield_to_pedestrians() {
+    let mut session = new_session(vec![]);
+    session.add_vehicles(vec![car(1, 4)]);
+    let states = run(session, 20);
+
+    let on_crosswalk: Vec<bool> = states
+        .iter()
+        .map(|state| state.pedestrians.iter().any(|pedestrian| pedestrian.last_cell == CROSSWALK_CELL))
+        .collect();
+    assert!(on_crosswalk.contains(&true));
+    for (state, &occupied) in states.iter().zip(on_crosswalk.iter()) {
+        if occupied {
+            assert!(state.vehicles.iter().all(|vehicle| vehicle.last_cell != CROSSWALK_CELL), "{:?}", state);
+        }
+    }
+    // Car waits in front of the crosswalk and then continues its trip
+    assert!(states.iter().any(|state| state.vehicles.iter().any(|vehicle| vehicle.last_cell == CROSSWALK_CELL - 1)));
+    assert!(states.iter().any(|state| state.vehicles.iter().any(|vehicle| vehicle.last_cell > CROSSWALK_CELL)));
+    // Pedestrian finishes the walk
+    assert!(states.last().unwrap().pedestrians.is_empty());
+}
+
+#[test]
+fn test_pedestrian_signal() {
+    // Pedestrians get green on the second phase only
+    let tl = TrafficLight::new(1)
+        .with_groups(vec![
+            TrafficLightGroup::new(1)
+                .with_cells_ids(vec![CROSSWALK_CELL])
+                .with_signal(vec![SignalType::Red, SignalType::Green])
+                .with_pedestrian(true)
+                .build(),
+        ])
+        .with_phases_times(vec![10, 10])
+        .build();
+    let states = run(new_session(vec![tl]), 20);
+
+    let entered = states
+        .iter()
+        .find(|state| state.pedestrians.iter().any(|pedestrian| pedestrian.last_cell == CROSSWALK_CELL))
+        .expect("Pedestrian should cross on green");
+    assert!(entered.timestamp >= 9, "{}", entered.timestamp);
+    let waiting = states[8].pedestrians.iter().find(|pedestrian| pedestrian.id == 1).unwrap();
+    assert_eq!(waiting.last_cell, 102);
+    assert!(waiting.waiting_time > 0);
+}
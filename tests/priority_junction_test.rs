@@ -0,0 +1,134 @@
+use micro_traffic_sim_core::agents::{Vehicle, VehicleID};
+use micro_traffic_sim_core::conflict_zones::{ConflictEdge, ConflictWinnerType, ConflictZone, ConflictZoneType, PriorityJunction};
+use micro_traffic_sim_core::geom::new_point;
+use micro_traffic_sim_core::grid::cell::{Cell, CellID};
+use micro_traffic_sim_core::grid::road_network::GridRoads;
+use micro_traffic_sim_core::simulation::grids_storage::GridsStorage;
+use micro_traffic_sim_core::simulation::session::Session;
+use micro_traffic_sim_core::utils::generators::generate_one_lane_cells;
+use micro_traffic_sim_core::verbose::VerboseLevel;
+
+/// Junction cell where the side road joins the main road
+const JUNCTION_CELL: CellID = 15;
+/// Last cell of the main road before the junction
+const MAIN_APPROACH: CellID = JUNCTION_CELL - 1;
+/// Last cell of the side road before the junction
+const SIDE_APPROACH: CellID = 105;
+
+/// Main road (1..30) and side road (101..105) joining it at cell 15
+fn grid() -> GridRoads {
+    let mut grid = GridRoads::new();
+    for cell in generate_one_lane_cells(300.0, 10.0, 1) {
+        grid.add_cell(cell);
+    }
+    for id in 101..=SIDE_APPROACH {
+        let forward_id = if id == SIDE_APPROACH { JUNCTION_CELL } else { id + 1 };
+        grid.add_cell(
+            Cell::new(id)
+                .with_point(new_point(14.0, (id - SIDE_APPROACH) as f64, None))
+                .with_speed_limit(3)
+                .with_forward_node(forward_id)
+                .build(),
+        );
+    }
+    grid
+}
+
+fn vehicle(id: VehicleID, cell_id: CellID, speed: i32) -> Vehicle {
+    Vehicle::new(id)
+        .with_cell(cell_id)
+        .with_speed(speed)
+        .with_speed_limit(3)
+        .with_slowdown(0.0)
+        .with_destination(30)
+        .build()
+}
+
+/// Runs the session and returns positions (cell and speed) of every vehicle on every step
+fn run(side_control: Option<ConflictZoneType>, vehicles: Vec<Vehicle>, steps: usize) -> Vec<Vec<(VehicleID, CellID, i32)>> {
+    let grids_storage = GridsStorage::new().with_vehicles_net(grid()).build();
+    let mut session = Session::new(grids_storage, None);
+    session.set_verbose_level(VerboseLevel::None);
+    session.add_conflict_zone(
+        ConflictZone::new(
+            1,
+            ConflictEdge { source: MAIN_APPROACH, target: JUNCTION_CELL },
+            ConflictEdge { source: SIDE_APPROACH, target: JUNCTION_CELL },
+        )
+        .with_winner_type(ConflictWinnerType::First)
+        .build(),
+    );
+    if let Some(control) = side_control {
+        session.add_priority_junction(
+            PriorityJunction::new(1)
+                .with_approach(MAIN_APPROACH, ConflictZoneType::PriorityRoad)
+                .with_approach(SIDE_APPROACH, control)
+                .with_critical_gap(3)
+                .build(),
+        );
+    }
+    session.add_vehicles(vehicles);
+    (0..steps)
+        .map(|_| {
+            let state = session.step().expect("Step should be done without errors");
+            state.vehicles.iter().map(|v| (v.id, v.last_cell, v.last_speed)).collect()
+        })
+        .collect()
+}
+
+/// Returns the index of the first step where the side road vehicle has passed the yield or stop line
+fn first_step_entered(states: &[Vec<(VehicleID, CellID, i32)>]) -> Option<usize> {
+    states
+        .iter()
+        .position(|state| state.iter().any(|&(id, cell, _)| id == 1 && (cell == SIDE_APPROACH || cell <= 30)))
+}
+
+/// Returns the index of the first step where the vehicle has passed the cell of the main road
+fn first_step_passed(states: &[Vec<(VehicleID, CellID, i32)>], vehicle_id: VehicleID, cell_id: CellID) -> Option<usize> {
+    states
+        .iter()
+        .position(|state| state.iter().any(|&(id, cell, _)| id == vehicle_id && cell >= cell_id && cell <= 30))
+}
+
+#[test]
+fn test_stop_sign() {
+    let stopped_at_line = |states: &[Vec<(VehicleID, CellID, i32)>]| {
+        states
+            .iter()
+            .any(|state| state.iter().any(|&(id, cell, speed)| id == 1 && cell == SIDE_APPROACH - 1 && speed == 0))
+    };
+
+    // Free junction: vehicle on the yield approach keeps going
+    let states = run(Some(ConflictZoneType::Yield), vec![vehicle(1, 101, 1)], 12);
+    assert!(!stopped_at_line(&states), "{:?}", states);
+    assert!(first_step_entered(&states).is_some());
+
+    // Stop sign imposes a full stop in front of the approach cell even on a free junction
+    let states = run(Some(ConflictZoneType::Stop), vec![vehicle(1, 101, 1)], 12);
+    assert!(stopped_at_line(&states), "{:?}", states);
+    let entered = first_step_entered(&states).expect("Vehicle should enter the junction after the stop");
+    let stopped = states
+        .iter()
+        .position(|state| state.iter().any(|&(id, _, speed)| id == 1 && speed == 0))
+        .unwrap();
+    assert!(stopped < entered);
+    assert!(first_step_passed(&states, 1, JUNCTION_CELL + 1).is_some(), "{:?}", states);
+}
+
+#[test]
+fn test_yield_gap() {
+    // Side road vehicle is waiting at the yield line, priority vehicle arrives in two time units
+    let vehicles = || vec![vehicle(1, SIDE_APPROACH - 1, 0), vehicle(2, 8, 3)];
+
+    // Without controls the side road vehicle cuts in front of the priority one
+    let states = run(None, vehicles(), 10);
+    let entered = first_step_entered(&states).unwrap();
+    let passed = first_step_passed(&states, 2, JUNCTION_CELL).unwrap();
+    assert!(entered < passed, "{:?}", states);
+
+    // Yield: side road vehicle waits for the gap
+    let states = run(Some(ConflictZoneType::Yield), vehicles(), 10);
+    let entered = first_step_entered(&states).expect("Vehicle should enter the junction after the gap");
+    let main_cell = states[entered].iter().find(|&&(id, _, _)| id == 2).map(|&(_, cell, _)| cell).unwrap();
+    assert!(main_cell > JUNCTION_CELL, "{:?}", states);
+}
@@ -0,0 +1,137 @@
+use micro_traffic_sim_core::agents::{Vehicle, VehicleID};
+use micro_traffic_sim_core::conflict_zones::Roundabout;
+use micro_traffic_sim_core::grid::cell::CellID;
+use micro_traffic_sim_core::grid::road_network::GridRoads;
+use micro_traffic_sim_core::maneuver::DrivingSide;
+use micro_traffic_sim_core::simulation::grids_storage::GridsStorage;
+use micro_traffic_sim_core::simulation::session::Session;
+use micro_traffic_sim_core::verbose::VerboseLevel;
+
+fn vehicle(id: VehicleID, cell_id: CellID, speed: i32, destination: CellID) -> Vehicle {
+    Vehicle::new(id)
+        .with_cell(cell_id)
+        .with_speed(speed)
+        .with_speed_limit(3)
+        .with_slowdown(0.0)
+        .with_destination(destination)
+        .build()
+}
+
+/// Runs the session and returns cells of every vehicle on every step
+fn run(roundabout: &Roundabout, vehicles: Vec<Vehicle>, steps: usize) -> Vec<Vec<(VehicleID, CellID)>> {
+    let mut grid = GridRoads::new();
+    for cell in roundabout.get_cells() {
+        grid.add_cell(cell.clone());
+    }
+    let grids_storage = GridsStorage::new().with_vehicles_net(grid).build();
+    let mut session = Session::new(grids_storage, None);
+    session.set_verbose_level(VerboseLevel::None);
+    session.set_driving_side(roundabout.get_driving_side());
+    session.add_roundabout(roundabout);
+    session.add_vehicles(vehicles);
+    (0..steps)
+        .map(|_| {
+            let state = session.step().expect("Step should be done without errors");
+            state.vehicles.iter().map(|v| (v.id, v.last_cell)).collect()
+        })
+        .collect()
+}
+
+fn cells_of(states: &[Vec<(VehicleID, CellID)>], vehicle_id: VehicleID) -> Vec<CellID> {
+    states
+        .iter()
+        .filter_map(|state| state.iter().find(|&&(id, _)| id == vehicle_id).map(|&(_, cell)| cell))
+        .collect()
+}
+
+#[test]
+fn test_roundabout_exits() {
+    // Vehicle from every arm leaves the roundabout via the next but one arm
+    let roundabout = Roundabout::new().with_arms(4).build();
+    let arms = roundabout.get_arms();
+    let vehicles = (0..4)
+        .map(|arm| vehicle(arm as VehicleID + 1, arms[arm].entry_lanes[0][0], 1, *arms[(arm + 2) % 4].exit_lane.last().unwrap()))
+        .collect();
+    let states = run(&roundabout, vehicles, 40);
+    for arm in 0..4 {
+        let cells = cells_of(&states, arm as VehicleID + 1);
+        assert!(cells.iter().any(|cell_id| arms[(arm + 2) % 4].exit_lane.contains(cell_id)), "{:?}", cells);
+    }
+    assert!(states.last().unwrap().is_empty());
+}
+
+#[test]
+fn test_yield_at_entry() {
+    let roundabout = Roundabout::new().with_arms(4).build();
+    let ring = &roundabout.get_ring_lanes()[0];
+    let arms = roundabout.get_arms();
+    let entry = &arms[0].entry_lanes[0];
+    // Entering vehicle waits at the yield line, circulating vehicle approaches the entry
+    let vehicles = vec![
+        vehicle(1, entry[entry.len() - 2], 0, *arms[2].exit_lane.last().unwrap()),
+        vehicle(2, ring[ring.len() - 3], 2, *arms[1].exit_lane.last().unwrap()),
+    ];
+    let states = run(&roundabout, vehicles, 20);
+    let on_ring = |vehicle_id: VehicleID| {
+        states
+            .iter()
+            .position(|state| state.iter().any(|&(id, cell)| id == vehicle_id && ring[1..].contains(&cell)))
+            .unwrap()
+    };
+    // Circulating vehicle passes the entry first
+    assert!(on_ring(2) < on_ring(1), "{:?}", states);
+    let entered = cells_of(&states, 1);
+    assert!(entered.iter().any(|cell_id| arms[2].exit_lane.contains(cell_id)), "{:?}", entered);
+}
+
+#[test]
+fn test_lane_choice_by_exit() {
+    let roundabout = Roundabout::new().with_arms(4).with_lanes(2).build();
+    let (outer, inner) = (&roundabout.get_ring_lanes()[0], &roundabout.get_ring_lanes()[1]);
+    let arms = roundabout.get_arms();
+    let routed = |id: VehicleID, exit_arm: usize| {
+        let route = roundabout.route(0, exit_arm);
+        let mut vehicle = vehicle(id, route[0], 1, *route.last().unwrap());
+        vehicle.route = route;
+        vehicle
+    };
+    let states = run(&roundabout, vec![routed(1, 1), routed(2, 3)], 40);
+
+    // Turning right: outer lane only
+    let cells = cells_of(&states, 1);
+    assert!(cells.iter().all(|cell_id| !inner.contains(cell_id)), "{:?}", cells);
+    assert!(cells.iter().any(|cell_id| arms[1].exit_lane.contains(cell_id)), "{:?}", cells);
+    // Turning left: inner lane, then the outer one right before the exit
+    let cells = cells_of(&states, 2);
+    assert!(cells.iter().any(|cell_id| inner.contains(cell_id)), "{:?}", cells);
+    assert!(cells.iter().any(|cell_id| arms[3].exit_lane.contains(cell_id)), "{:?}", cells);
+    // Outer lane is used in the last sector before the exit only (4 ring cells per arm, the exit diverges before the arm)
+    let last_sector = &outer[3 * 4 - 4..3 * 4];
+    assert!(cells.iter().filter(|cell_id| outer.contains(cell_id)).all(|cell_id| last_sector.contains(cell_id)), "{:?}", cells);
+}
+
+#[test]
+fn test_left_hand_roundabout() {
+    let roundabout = Roundabout::new().with_arms(4).with_lanes(2).with_driving_side(DrivingSide::Left).build();
+    let inner = &roundabout.get_ring_lanes()[1];
+    let arms = roundabout.get_arms();
+    // Vehicle from every arm leaves the roundabout via the next arm (outer lane) or the last one (inner lane)
+    let mut vehicles = vec![];
+    for arm in 0..4 {
+        for (idx, exit_arm) in [(arm + 1) % 4, (arm + 3) % 4].into_iter().enumerate() {
+            let route = roundabout.route(arm, exit_arm);
+            let mut vehicle = vehicle((2 * arm + idx) as VehicleID + 1, route[0], 1, *route.last().unwrap());
+            vehicle.route = route;
+            vehicles.push(vehicle);
+        }
+    }
+    let states = run(&roundabout, vehicles, 60);
+    for arm in 0..4 {
+        for (idx, exit_arm) in [(arm + 1) % 4, (arm + 3) % 4].into_iter().enumerate() {
+            let cells = cells_of(&states, (2 * arm + idx) as VehicleID + 1);
+            assert!(cells.iter().any(|cell_id| arms[exit_arm].exit_lane.contains(cell_id)), "{:?}", cells);
+            assert_eq!(cells.iter().any(|cell_id| inner.contains(cell_id)), idx == 1, "{:?}", cells);
+        }
+    }
+    assert!(states.last().unwrap().is_empty());
+}
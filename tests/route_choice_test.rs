@@ -0,0 +1,132 @@
+use micro_traffic_sim_core::geom::new_point;
+use micro_traffic_sim_core::grid::{cell::Cell, road_network::GridRoads, zones::ZoneType};
+use micro_traffic_sim_core::behaviour::BehaviourType;
+use micro_traffic_sim_core::agents_types::AgentType;
+use micro_traffic_sim_core::route_choice::{RouteChoice, RouteChoiceModel};
+use micro_traffic_sim_core::simulation::session::Session;
+use micro_traffic_sim_core::simulation::grids_storage::GridsStorage;
+use micro_traffic_sim_core::trips::trip::{Trip, TripBuilder, TripType};
+use std::collections::HashSet;
+
+/// Road layout:
+///    [12]-[13]-[14]-[15]-[16]-[17]-[18]-[19]
+///   /                                       \
+/// [1]-[2]-[3]-[4]-[5]-[6]-[7]-[8]-[9]--------[10]
+fn grid() -> GridRoads {
+    let mut grid = GridRoads::new();
+    for i in 1..=10 {
+        let forward = if i < 10 { i + 1 } else { -1 };
+        let left = if i == 1 { 12 } else { -1 };
+        let zone = match i {
+            1 => ZoneType::Birth,
+            10 => ZoneType::Death,
+            _ => ZoneType::Common,
+        };
+        grid.add_cell(
+            Cell::new(i)
+                .with_point(new_point((i - 1) as f64, 0.0, None))
+                .with_zone_type(zone)
+                .with_speed_limit(3)
+                .with_forward_node(forward)
+                .with_left_node(left)
+                .with_meso_link(1)
+                .build(),
+        );
+    }
+    for i in 12..=19 {
+        let forward = if i < 19 { i + 1 } else { -1 };
+        let right = if i == 19 { 10 } else { -1 };
+        grid.add_cell(
+            Cell::new(i)
+                .with_point(new_point((i - 11) as f64, 1.0, None))
+                .with_zone_type(ZoneType::Common)
+                .with_speed_limit(3)
+                .with_forward_node(forward)
+                .with_right_node(right)
+                .with_meso_link(2)
+                .build(),
+        );
+    }
+    grid
+}
+
+fn trip() -> TripBuilder {
+    Trip::new(1, 10, TripType::Constant)
+        .with_id(1)
+        .with_time(2)
+        .with_initial_speed(1)
+        .with_allowed_agent_type(AgentType::Car)
+        .with_allowed_behaviour_type(BehaviourType::Cooperative)
+}
+
+/// TestRouteChoice tests that vehicles generated by a trip with route choice are spread
+/// between two alternative routes and follow the assigned ones.
+#[test]
+fn test_route_choice() {
+    let grids_storage = GridsStorage::new().with_vehicles_net(grid()).build();
+    let mut session = Session::new(grids_storage, None);
+    let trip = trip()
+        .with_route_choice(
+            RouteChoice::new(RouteChoiceModel::PathSizeLogit)
+                .with_routes_num(2)
+                .with_theta(1.0)
+                .build(),
+        )
+        .build();
+    session.add_trip(trip);
+
+    let mut assigned_routes = HashSet::new();
+    let mut detour_used = false;
+    for step in 0..100 {
+        let state = session.step().unwrap_or_else(|e| panic!("Error at step {}: {}", step, e));
+        for vehicle in state.vehicles.iter() {
+            let route_id = vehicle.route_id.expect("Vehicle should have assigned route");
+            assigned_routes.insert(route_id);
+            if route_id == 1 && (12..=19).contains(&vehicle.last_cell) {
+                detour_used = true;
+            }
+        }
+    }
+    let routes = session.get_trip_routes(1).expect("Routes should be calculated");
+    assert_eq!(routes.len(), 2);
+    assert_eq!(routes[0].cells, (1..=10).collect::<Vec<i64>>());
+    assert_eq!(assigned_routes.len(), 2, "Both routes should be assigned");
+    assert!(detour_used, "Vehicles with the second route should follow it");
+    assert!(session.get_travel_times().get(1).is_some(), "Travel times should be observed for route choice");
+}
+
+/// Travel times are not observed when no trip uses route choice
+#[test]
+fn test_no_route_choice() {
+    let grids_storage = GridsStorage::new().with_vehicles_net(grid()).build();
+    let mut session = Session::new(grids_storage, None);
+    session.add_trip(trip().build());
+    for step in 0..20 {
+        let state = session.step().unwrap_or_else(|e| panic!("Error at step {}: {}", step, e));
+        assert!(state.vehicles.iter().all(|vehicle| vehicle.route_id.is_none()));
+    }
+    assert!(session.get_travel_times().get(1).is_none());
+}
+
+/// Departures are reported even for vehicles which reach the destination on the step they are generated at
+#[test]
+fn test_departures() {
+    let grids_storage = GridsStorage::new().with_vehicles_net(grid()).build();
+    let mut session = Session::new(grids_storage, None);
+    let trip = Trip::new(9, 10, TripType::Constant)
+        .with_id(1)
+        .with_time(2)
+        .with_initial_speed(1)
+        .with_allowed_agent_type(AgentType::Car)
+        .with_allowed_behaviour_type(BehaviourType::Cooperative)
+        .with_route_choice(RouteChoice::new(RouteChoiceModel::MultinomialLogit).build())
+        .build();
+    session.add_trip(trip);
+    let mut departures = Vec::new();
+    for step in 0..6 {
+        let state = session.step().unwrap_or_else(|e| panic!("Error at step {}: {}", step, e));
+        assert!(state.vehicles.is_empty(), "Vehicle should leave the grid at once");
+        departures.extend(state.departures.into_iter().map(|departure| (state.timestamp, departure.trip_id, departure.route_id)));
+    }
+    assert_eq!(departures, vec![(0, 1, Some(0)), (2, 1, Some(0)), (4, 1, Some(0))]);
+}
@@ -0,0 +1,97 @@
+use micro_traffic_sim_core::agents::Vehicle;
+use micro_traffic_sim_core::agents_types::AgentType;
+use micro_traffic_sim_core::grid::cell::CellID;
+use micro_traffic_sim_core::grid::road_network::GridRoads;
+use micro_traffic_sim_core::simulation::grids_storage::GridsStorage;
+use micro_traffic_sim_core::simulation::session::Session;
+use micro_traffic_sim_core::traffic_lights::groups::TrafficLightGroup;
+use micro_traffic_sim_core::traffic_lights::lights::TrafficLight;
+use micro_traffic_sim_core::traffic_lights::priority::TransitPriority;
+use micro_traffic_sim_core::traffic_lights::signals::SignalType;
+use micro_traffic_sim_core::utils::generators::generate_one_lane_cells;
+use micro_traffic_sim_core::verbose::VerboseLevel;
+use std::collections::HashMap;
+
+/// Number of cells of the road
+const ROAD_CELLS: CellID = 40;
+
+/// Cell controlled by the traffic light
+const STOP_LINE_CELL: CellID = 20;
+
+/// Single lane road with a traffic light: green and red phases of the given durations, starting from the given phase
+fn session(times: Vec<i32>, active_phase: usize, priority: Option<TransitPriority>, vehicle_type: AgentType) -> Session {
+    let mut grid = GridRoads::new();
+    for cell in generate_one_lane_cells(ROAD_CELLS as f64 * 10.0, 10.0, 1) {
+        grid.add_cell(cell);
+    }
+    let mut tl = TrafficLight::new(1)
+        .with_groups(vec![TrafficLightGroup::new(1)
+            .with_cells_ids(vec![STOP_LINE_CELL])
+            .with_signal(vec![SignalType::Green, SignalType::Red])
+            .build()])
+        .with_phases_times(times)
+        .with_active_phase(active_phase);
+    if let Some(priority) = priority {
+        tl = tl.with_transit_priority(priority);
+    }
+    let grids_storage = GridsStorage::new()
+        .with_vehicles_net(grid)
+        .with_tls(HashMap::from([(1, tl.build())]))
+        .build();
+    let mut session = Session::new(grids_storage, None);
+    session.set_verbose_level(VerboseLevel::None);
+    session.add_vehicles(vec![Vehicle::new(1)
+        .with_type(vehicle_type)
+        .with_cell(1)
+        .with_speed(3)
+        .with_speed_limit(3)
+        .with_slowdown(0.0)
+        .with_destination(ROAD_CELLS)
+        .with_transit_cells(vec![ROAD_CELLS])
+        .build()]);
+    session
+}
+
+/// Runs the session and returns the step at which the vehicle passes the stop line
+fn passing_step(mut session: Session) -> Option<usize> {
+    for step in 0..100 {
+        let state = session.step().expect("Step should be done without errors");
+        if state.vehicles.iter().all(|v| v.last_cell > STOP_LINE_CELL) {
+            return Some(step);
+        }
+    }
+    None
+}
+
+fn priority() -> TransitPriority {
+    TransitPriority::new()
+        .with_detection_distance(10)
+        .with_max_extension(5)
+        .with_min_green(5)
+        .build()
+}
+
+#[test]
+fn test_early_green() {
+    // Bus approaches the red light
+    let regular = passing_step(session(vec![10, 40], 1, None, AgentType::Bus)).unwrap();
+    let prioritized = passing_step(session(vec![10, 40], 1, Some(priority()), AgentType::Bus)).unwrap();
+    assert!(regular >= 39, "{}", regular);
+    assert!(prioritized < 12, "{}", prioritized);
+    // Regular vehicles don't request priority
+    let car = passing_step(session(vec![10, 40], 1, Some(priority()), AgentType::Car)).unwrap();
+    assert_eq!(car, regular);
+}
+
+#[test]
+fn test_green_extension() {
+    // Green ends just before the bus arrives
+    let regular = passing_step(session(vec![5, 40], 0, None, AgentType::Bus)).unwrap();
+    let prioritized = passing_step(session(vec![5, 40], 0, Some(priority()), AgentType::Bus)).unwrap();
+    assert!(regular >= 40, "{}", regular);
+    assert!(prioritized < 10, "{}", prioritized);
+    // Extension is not enough, and the cooldown prevents early green during the red phase
+    let short = TransitPriority { max_extension: 1, cooldown: 100, ..priority() };
+    // (red starts one step later)
+    assert_eq!(passing_step(session(vec![5, 40], 0, Some(short), AgentType::Bus)).unwrap(), regular + 1);
+}
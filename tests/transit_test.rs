@@ -0,0 +1,284 @@
+use micro_traffic_sim_core::agents::VehicleID;
+use micro_traffic_sim_core::agents_types::AgentType;
+use micro_traffic_sim_core::behaviour::{BehaviourDistributions, BehaviourType, ParameterDistribution};
+use micro_traffic_sim_core::geom::new_point;
+use micro_traffic_sim_core::grid::cell::{Cell, CellID};
+use micro_traffic_sim_core::grid::road_network::GridRoads;
+use micro_traffic_sim_core::grid::zones::ZoneType;
+use micro_traffic_sim_core::simulation::grids_storage::GridsStorage;
+use micro_traffic_sim_core::simulation::session::Session;
+use micro_traffic_sim_core::simulation::states::{BusStopEvent, BusStopEventType};
+use micro_traffic_sim_core::transit::{BusStop, DwellTime, GtfsFeed, GtfsImport};
+use micro_traffic_sim_core::trips::trip::{Trip, TripBuilder, TripType};
+use micro_traffic_sim_core::verbose::VerboseLevel;
+use std::collections::HashMap;
+
+/// Number of cells of the road
+const ROAD_CELLS: CellID = 40;
+
+/// Transit cell of the bus stop
+const STOP_CELL: CellID = 10;
+
+/// Single lane road (speed limit 3) with the transit cell: (1: spawn) → ... → (10: transit) → ... → (40: despawn)
+fn road() -> GridRoads {
+    let mut grid = GridRoads::new();
+    for id in 1..=ROAD_CELLS {
+        let zone_type = match id {
+            1 => ZoneType::Birth,
+            STOP_CELL => ZoneType::Transit,
+            ROAD_CELLS => ZoneType::Death,
+            _ => ZoneType::Common,
+        };
+        grid.add_cell(
+            Cell::new(id)
+                .with_point(new_point((id - 1) as f64, 0.0, None))
+                .with_zone_type(zone_type)
+                .with_speed_limit(3)
+                .with_left_node(-1)
+                .with_forward_node(if id == ROAD_CELLS { -1 } else { id + 1 })
+                .with_right_node(-1)
+                .with_meso_link(1)
+                .build(),
+        );
+    }
+    grid
+}
+
+fn new_session(stop: Option<BusStop>) -> Session {
+    let grids_storage = GridsStorage::new().with_vehicles_net(road()).build();
+    let mut session = Session::new(grids_storage, None);
+    session.set_verbose_level(VerboseLevel::None);
+    if let Some(stop) = stop {
+        session.add_bus_stop(stop);
+    }
+    session
+}
+
+/// Drivers without random slowdown, so the timings are deterministic
+fn no_slowdown() -> BehaviourDistributions {
+    BehaviourDistributions::new()
+        .with_slow_down_factor(ParameterDistribution::Constant(0.0))
+        .build()
+}
+
+fn bus_trip(id: i64, trip_type: TripType) -> TripBuilder {
+    Trip::new(1, ROAD_CELLS, trip_type)
+        .with_id(id)
+        .with_initial_speed(1)
+        .with_speed_limit(3)
+        .with_allowed_agent_type(AgentType::Bus)
+        .with_allowed_behaviour_type(BehaviourType::Cooperative)
+        .with_behaviour_distributions(no_slowdown())
+        .with_transits_cells(vec![STOP_CELL, ROAD_CELLS], 0)
+}
+
+fn car_trip(id: i64, departures: Vec<i32>) -> Trip {
+    Trip::new(1, ROAD_CELLS, TripType::Timetable)
+        .with_id(id)
+        .with_initial_speed(1)
+        .with_speed_limit(3)
+        .with_allowed_agent_type(AgentType::Car)
+        .with_allowed_behaviour_type(BehaviourType::Cooperative)
+        .with_behaviour_distributions(no_slowdown())
+        .with_departures(departures)
+        .build()
+}
+
+/// Runs the session: returns bus stop events and steps at which vehicles have been seen last time
+fn run(mut session: Session, steps: usize) -> (Vec<BusStopEvent>, HashMap<VehicleID, i32>) {
+    let mut events = Vec::new();
+    let mut last_seen = HashMap::new();
+    for _ in 0..steps {
+        let state = session.step().expect("Step should be done without errors");
+        events.extend(state.bus_stop_events);
+        for vehicle in state.vehicles {
+            last_seen.insert(vehicle.id, state.timestamp);
+        }
+    }
+    (events, last_seen)
+}
+
+/// Dwell times (from arrival to departure) per vehicle
+fn dwell_times(events: &[BusStopEvent]) -> HashMap<VehicleID, i32> {
+    let arrivals: HashMap<VehicleID, i32> = events
+        .iter()
+        .filter(|event| event.event_type == BusStopEventType::Arrival)
+        .map(|event| (event.vehicle_id, event.timestamp))
+        .collect();
+    events
+        .iter()
+        .filter(|event| event.event_type == BusStopEventType::Departure)
+        .map(|event| (event.vehicle_id, event.timestamp - arrivals[&event.vehicle_id]))
+        .collect()
+}
+
+#[test]
+fn test_fixed_dwell_time() {
+    let stop = BusStop::new(7, STOP_CELL).with_dwell_time(DwellTime::Fixed(10)).build();
+    let mut session = new_session(Some(stop));
+    session.add_trip(bus_trip(1, TripType::Timetable).with_departures(vec![0]).build());
+    let (events, _) = run(session, 40);
+
+    // Arrival and departure are recorded once, bus stays for the dwell time after its arrival
+    assert_eq!(events.len(), 2, "{:?}", events);
+    assert!(events.iter().all(|event| event.stop_id == Some(7) && event.cell_id == STOP_CELL && event.trip_id == 1));
+    assert_eq!(events[0].event_type, BusStopEventType::Arrival);
+    assert_eq!(dwell_times(&events)[&events[0].vehicle_id], 10 + 1);
+
+    // Without a bus stop the trip's relax time is used
+    let mut session = new_session(None);
+    session.add_trip(bus_trip(1, TripType::Timetable).with_departures(vec![0]).with_transits_cells(vec![STOP_CELL, ROAD_CELLS], 4).build());
+    let (events, _) = run(session, 40);
+    assert!(events.iter().all(|event| event.stop_id.is_none()));
+    assert_eq!(dwell_times(&events)[&events[0].vehicle_id], 4 + 1);
+}
+
+#[test]
+fn test_bus_bay() {
+    // Car departs right after the bus
+    let run_with_bay = |bay: bool| {
+        let stop = BusStop::new(1, STOP_CELL).with_dwell_time(DwellTime::Fixed(15)).with_bay(bay).build();
+        let mut session = new_session(Some(stop));
+        session.add_trip(bus_trip(1, TripType::Timetable).with_departures(vec![0]).build());
+        session.add_trip(car_trip(2, vec![2]));
+        let (events, last_seen) = run(session, 60);
+        let bus_id = events[0].vehicle_id;
+        let car_id = *last_seen.keys().find(|&&id| id != bus_id).unwrap();
+        (last_seen[&bus_id], last_seen[&car_id], dwell_times(&events)[&bus_id])
+    };
+
+    // Car waits behind the bus dwelling in the lane
+    let (bus_left, car_left, _) = run_with_bay(false);
+    assert!(car_left > bus_left, "bus: {}, car: {}", bus_left, car_left);
+
+    // Car passes the bus dwelling in the bay, then bus merges back
+    let (bus_left, car_left, dwell_time) = run_with_bay(true);
+    assert!(car_left < bus_left, "bus: {}, car: {}", bus_left, car_left);
+    assert_eq!(dwell_time, 15 + 1);
+}
+
+#[test]
+fn test_schedules() {
+    // Departures are made at timetable times
+    let mut session = new_session(None);
+    session.add_trip(bus_trip(1, TripType::Timetable).with_departures(vec![0, 5, 30]).build());
+    let (events, _) = run(session, 60);
+    let mut departures: Vec<i32> = events
+        .iter()
+        .filter(|event| event.event_type == BusStopEventType::Arrival)
+        // Travel time includes the step of the vehicle generation
+        .map(|event| event.timestamp - event.travel_time as i32 + 1)
+        .collect();
+    departures.sort();
+    assert_eq!(departures, vec![0, 5, 30]);
+
+    // Headway from the start time
+    let mut session = new_session(None);
+    session.add_trip(bus_trip(1, TripType::Headway).with_start_time(3).with_time(12).build());
+    let (events, _) = run(session, 40);
+    let arrivals: Vec<_> = events.iter().filter(|event| event.event_type == BusStopEventType::Arrival).collect();
+    assert_eq!(arrivals.len(), 3);
+    assert!(arrivals.windows(2).all(|pair| pair[1].timestamp - pair[0].timestamp == 12));
+
+    // Departure is delayed while the origin cell is occupied, but it is not skipped
+    let mut session = new_session(None);
+    session.add_trip(car_trip(1, vec![0, 1, 2]));
+    let (_, last_seen) = run(session, 40);
+    assert_eq!(last_seen.len(), 3);
+}
+
+#[test]
+fn test_boarding_dwell_time() {
+    // Second bus arrives shortly after the first one, so it has fewer passengers to board
+    let stop = BusStop::new(1, STOP_CELL)
+        .with_dwell_time(DwellTime::Boarding { base: 2, boarding_time: 1.0, arrival_rate: 0.5, max: 30 })
+        .with_bay(true)
+        .build();
+    let mut session = new_session(Some(stop));
+    session.add_trip(bus_trip(1, TripType::Timetable).with_departures(vec![20, 26]).build());
+    let (events, _) = run(session, 80);
+    let mut dwell_times: Vec<(i32, i32)> = events
+        .iter()
+        .filter(|event| event.event_type == BusStopEventType::Arrival)
+        .map(|event| (event.timestamp, dwell_times(&events)[&event.vehicle_id]))
+        .collect();
+    dwell_times.sort();
+    assert_eq!(dwell_times.len(), 2);
+    assert!(dwell_times[0].1 > dwell_times[1].1, "{:?}", dwell_times);
+}
+
+#[test]
+fn test_reset_dwell_time() {
+    let stop = BusStop::new(1, STOP_CELL)
+        .with_dwell_time(DwellTime::Boarding { base: 2, boarding_time: 1.0, arrival_rate: 0.5, max: 30 })
+        .build();
+    let mut session = new_session(Some(stop));
+    let mut runs = Vec::new();
+    for _ in 0..2 {
+        session.reset();
+        session.add_trip(bus_trip(1, TripType::Timetable).with_departures(vec![10, 40]).build());
+        let mut events = Vec::new();
+        for _ in 0..80 {
+            events.extend(session.step().expect("Step should be done without errors").bus_stop_events);
+        }
+        let mut run_dwell_times: Vec<i32> = dwell_times(&events).into_values().collect();
+        run_dwell_times.sort();
+        runs.push(run_dwell_times);
+    }
+    // Passengers wait since the simulation start again after the reset
+    assert_eq!(runs[0].len(), 2);
+    assert_eq!(runs[0], runs[1]);
+}
+
+
+#[test]
+fn test_gtfs_import() {
+    // Second stop of the route
+    let mut grid = road();
+    grid.add_cell(
+        Cell::new(30)
+            .with_point(new_point(29.0, 0.0, None))
+            .with_zone_type(ZoneType::Transit)
+            .with_speed_limit(3)
+            .with_forward_node(31)
+            .with_meso_link(1)
+            .build(),
+    );
+    let feed = GtfsFeed::parse(
+        "stop_id,stop_name,stop_lat,stop_lon\r\nA,First,0.2,9.1\r\nB,Second,-0.1,29.3\r\n",
+        "route_id,service_id,trip_id\n7,weekday,T1\n7,weekday,T2\n7,weekend,T3\n",
+        "trip_id,arrival_time,departure_time,stop_id,stop_sequence\n\
+         T2,08:00:20,08:00:20,A,1\nT2,08:01:00,08:01:00,B,2\n\
+         T1,08:00:05,08:00:05,A,1\nT1,08:00:45,08:00:45,B,2\n\
+         T3,08:00:10,08:00:10,A,1\nT3,08:00:50,08:00:50,B,2\n",
+        None,
+    )
+    .unwrap();
+    let options = GtfsImport::new()
+        .with_time_origin(8 * 3600)
+        .with_max_distance(1.0)
+        .with_services(vec!["weekday".to_string()])
+        .build();
+    let trips = feed.to_trips(&grid, &options).unwrap();
+    assert_eq!(trips.len(), 1);
+    assert_eq!((trips[0].from_node, trips[0].to_node, trips[0].transit_cells.clone()), (STOP_CELL, 30, vec![30]));
+    assert_eq!(trips[0].departures, vec![5, 20]);
+    assert_eq!(trips[0].allowed_agent_type, AgentType::Bus);
+
+    // Buses depart from the first stop and arrive at the second one
+    let grids_storage = GridsStorage::new().with_vehicles_net(grid).build();
+    let mut session = Session::new(grids_storage, None);
+    session.set_verbose_level(VerboseLevel::None);
+    for mut trip in trips {
+        trip.behaviour_distributions = Some(no_slowdown());
+        session.add_trip(trip);
+    }
+    let (events, _) = run(session, 60);
+    let mut departures: Vec<i32> = events
+        .iter()
+        .filter(|event| event.event_type == BusStopEventType::Arrival && event.cell_id == 30)
+        .map(|event| event.timestamp - event.travel_time as i32 + 1)
+        .collect();
+    departures.sort();
+    assert_eq!(departures, vec![5, 20]);
+}
@@ -0,0 +1,54 @@
+use micro_traffic_sim_core::agents::Vehicle;
+use micro_traffic_sim_core::agents_types::AgentType;
+use micro_traffic_sim_core::grid::road_network::GridRoads;
+use micro_traffic_sim_core::simulation::session::Session;
+use micro_traffic_sim_core::simulation::grids_storage::GridsStorage;
+use micro_traffic_sim_core::utils::generators::generate_one_lane_cells;
+use micro_traffic_sim_core::verbose::VerboseLevel;
+
+/// Speeds of a single vehicle starting from standstill on an empty single lane road (speed limit 3)
+fn speed_profile(vehicle: Vehicle, steps: usize) -> Vec<i32> {
+    let mut grid = GridRoads::new();
+    for cell in generate_one_lane_cells(500.0, 10.0, 1) {
+        grid.add_cell(cell);
+    }
+    let grids_storage = GridsStorage::new().with_vehicles_net(grid).build();
+    let mut session = Session::new(grids_storage, None);
+    session.set_verbose_level(VerboseLevel::None);
+    session.add_vehicles(vec![vehicle]);
+    (0..steps)
+        .map(|_| {
+            let state = session.step().expect("Step should be done without errors");
+            state.vehicles[0].last_speed
+        })
+        .collect()
+}
+
+#[test]
+fn test_acceleration_by_agent_type() {
+    let make_vehicle = |agent_type: AgentType| {
+        Vehicle::new(1)
+            .with_type(agent_type)
+            .with_cell(1)
+            .with_speed(0)
+            .with_speed_limit(3)
+            .with_slowdown(0.0)
+            .with_destination(50)
+            .build()
+    };
+    // Car gains one cell per time unit
+    assert_eq!(speed_profile(make_vehicle(AgentType::Car), 6), vec![1, 2, 3, 3, 3, 3]);
+    // Truck gains one cell per two time units
+    assert_eq!(speed_profile(make_vehicle(AgentType::Truck), 6), vec![1, 1, 2, 2, 3, 3]);
+    // Type's default is overridden by the builder
+    let agile_truck = Vehicle::new(1)
+        .with_type(AgentType::Truck)
+        .with_cell(1)
+        .with_speed(0)
+        .with_speed_limit(3)
+        .with_slowdown(0.0)
+        .with_destination(50)
+        .with_max_acceleration(3.0)
+        .build();
+    assert_eq!(speed_profile(agile_truck, 3), vec![3, 3, 3]);
+}